@@ -1,8 +1,9 @@
 //! Hash benchmarks using Criterion.
 
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use md5::Md5;
 use sha2::{Digest, Sha256, Sha512};
+use std::hint::black_box;
 
 fn bench_hashing(c: &mut Criterion) {
     let data_sizes = [64, 1024, 65536]; // 64B, 1KB, 64KB
@@ -0,0 +1,232 @@
+//! Edge values and shrinking for property tests.
+//!
+//! Random inputs rarely hit the values that break code: empty strings,
+//! integer bounds, NaN. The `edge_*` functions list those boundary values so
+//! a test can check them first, [`with_edges`] mixes them with random
+//! values, and [`shrink_int`] proposes simpler candidates for a failing one.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::{arbitrary, generators};
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! // All edge values first, then random ones
+//! let inputs = arbitrary::with_edges(&mut rng, arbitrary::edge_int(), 20, |rng| {
+//!     generators::int_range(rng, -1000, 1000)
+//! });
+//! assert_eq!(inputs.len(), 20);
+//! assert_eq!(inputs[0], i64::MIN);
+//! ```
+
+use rand::Rng;
+
+/// Boundary integers: the `i64`, `i32` and `i16` limits, their neighbours,
+/// and the values around zero.
+pub fn edge_int() -> impl Iterator<Item = i64> {
+    [
+        i64::MIN,
+        i64::MIN + 1,
+        i32::MIN as i64,
+        i16::MIN as i64,
+        -1,
+        0,
+        1,
+        i16::MAX as i64,
+        i32::MAX as i64,
+        u32::MAX as i64,
+        i64::MAX - 1,
+        i64::MAX,
+    ]
+    .into_iter()
+}
+
+/// Boundary unsigned integers, including the `u8`, `u16` and `u32` limits.
+pub fn edge_uint() -> impl Iterator<Item = u64> {
+    [
+        0,
+        1,
+        u8::MAX as u64,
+        u16::MAX as u64,
+        u32::MAX as u64,
+        u32::MAX as u64 + 1,
+        u64::MAX - 1,
+        u64::MAX,
+    ]
+    .into_iter()
+}
+
+/// Boundary floats: signed zeros, the smallest and largest finite values,
+/// subnormals, infinities and NaN.
+pub fn edge_float() -> impl Iterator<Item = f64> {
+    [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f64::EPSILON,
+        f64::MIN_POSITIVE,
+        f64::MIN_POSITIVE / 2.0,
+        f64::MIN,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ]
+    .into_iter()
+}
+
+/// Awkward strings: empty, whitespace, control characters, quotes,
+/// multi-byte and combining characters, and a long run of one character.
+pub fn edge_string() -> impl Iterator<Item = String> {
+    [
+        "",
+        " ",
+        "\t\n\r",
+        "\0",
+        "\"'`\\",
+        "null",
+        "é",
+        "e\u{301}",
+        "日本語",
+        "\u{1F600}",
+        "\u{202E}abc",
+        "\u{FEFF}",
+    ]
+    .into_iter()
+    .map(str::to_string)
+    .chain(std::iter::once("a".repeat(10_000)))
+}
+
+/// Boundary dates: the chrono limits, the Unix epoch and its 32-bit end,
+/// leap days and year boundaries.
+#[cfg(feature = "temporal")]
+pub fn edge_date() -> impl Iterator<Item = chrono::NaiveDate> {
+    use chrono::NaiveDate;
+
+    [
+        Some(NaiveDate::MIN),
+        NaiveDate::from_ymd_opt(1, 1, 1),
+        NaiveDate::from_ymd_opt(1900, 2, 28),
+        NaiveDate::from_ymd_opt(1969, 12, 31),
+        NaiveDate::from_ymd_opt(1970, 1, 1),
+        NaiveDate::from_ymd_opt(1999, 12, 31),
+        NaiveDate::from_ymd_opt(2000, 2, 29),
+        NaiveDate::from_ymd_opt(2024, 2, 29),
+        NaiveDate::from_ymd_opt(2024, 12, 31),
+        NaiveDate::from_ymd_opt(2038, 1, 19),
+        NaiveDate::from_ymd_opt(9999, 12, 31),
+        Some(NaiveDate::MAX),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Take every edge value, then fill up to `count` with `random`.
+///
+/// If there are more edges than `count`, only the first `count` are used.
+pub fn with_edges<R, T, I, F>(rng: &mut R, edges: I, count: usize, mut random: F) -> Vec<T>
+where
+    R: ?Sized + Rng,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&mut R) -> T,
+{
+    let mut values: Vec<T> = edges.into_iter().take(count).collect();
+    while values.len() < count {
+        values.push(random(rng));
+    }
+    values
+}
+
+/// Simpler candidates for a failing integer, simplest first.
+///
+/// Yields `0` and then values halfway, three quarters and so on towards
+/// `value`, never `value` itself. Nothing is yielded for `0`.
+pub fn shrink_int(value: i64) -> impl Iterator<Item = i64> {
+    let mut diff = value;
+    std::iter::from_fn(move || {
+        if diff == 0 {
+            return None;
+        }
+        let candidate = value - diff;
+        diff /= 2;
+        Some(candidate)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_edge_int_includes_bounds_and_zero() {
+        let edges: Vec<i64> = edge_int().collect();
+        assert!(edges.contains(&i64::MIN));
+        assert!(edges.contains(&0));
+        assert!(edges.contains(&i64::MAX));
+    }
+
+    #[test]
+    fn test_edge_uint_includes_bounds() {
+        let edges: Vec<u64> = edge_uint().collect();
+        assert_eq!(edges.first(), Some(&0));
+        assert_eq!(edges.last(), Some(&u64::MAX));
+    }
+
+    #[test]
+    fn test_edge_float_includes_special_values() {
+        let edges: Vec<f64> = edge_float().collect();
+        assert!(edges.iter().any(|f| f.is_nan()));
+        assert!(edges.contains(&f64::INFINITY));
+        assert!(edges.contains(&f64::NEG_INFINITY));
+        assert!(edges.iter().any(|f| *f == 0.0 && f.is_sign_negative()));
+        assert!(edges.iter().any(|f| f.is_subnormal()));
+    }
+
+    #[test]
+    fn test_edge_string_includes_empty_and_multibyte() {
+        let edges: Vec<String> = edge_string().collect();
+        assert!(edges.contains(&String::new()));
+        assert!(edges.iter().any(|s| s.len() > s.chars().count()));
+        assert!(edges.iter().any(|s| s.len() >= 10_000));
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_edge_date_includes_limits_and_leap_day() {
+        use chrono::{Datelike, NaiveDate};
+        let edges: Vec<NaiveDate> = edge_date().collect();
+        assert_eq!(edges.first(), Some(&NaiveDate::MIN));
+        assert_eq!(edges.last(), Some(&NaiveDate::MAX));
+        assert!(edges.iter().any(|d| d.month() == 2 && d.day() == 29));
+    }
+
+    #[test]
+    fn test_with_edges() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let values = with_edges(&mut rng, edge_int(), 50, |rng| rng.random_range(-10..=10));
+        assert_eq!(values.len(), 50);
+        assert!(values.starts_with(&edge_int().collect::<Vec<_>>()));
+        assert!(values[12..].iter().all(|v| (-10..=10).contains(v)));
+
+        let values = with_edges(&mut rng, edge_int(), 3, |_| 99);
+        assert_eq!(values, vec![i64::MIN, i64::MIN + 1, i32::MIN as i64]);
+    }
+
+    #[test]
+    fn test_shrink_int() {
+        assert_eq!(
+            shrink_int(100).collect::<Vec<_>>(),
+            vec![0, 50, 75, 88, 94, 97, 99]
+        );
+        assert_eq!(shrink_int(-8).collect::<Vec<_>>(), vec![0, -4, -6, -7]);
+        assert_eq!(shrink_int(0).count(), 0);
+        assert!(shrink_int(i64::MIN).all(|v| v != i64::MIN));
+        assert!(shrink_int(i64::MAX).all(|v| (0..i64::MAX).contains(&v)));
+    }
+}
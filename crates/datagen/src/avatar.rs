@@ -0,0 +1,113 @@
+//! Identicon avatars.
+//!
+//! An identicon is a small symmetric pattern derived from a string such as a
+//! username or email address. The same seed always gives the same pattern,
+//! so fixtures get stable, distinguishable avatars without image files.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::avatar;
+//!
+//! let grid = avatar::identicon("jane.doe@example.com");
+//! assert_eq!(grid.len(), 5);
+//! assert_eq!(grid, avatar::identicon("jane.doe@example.com"));
+//!
+//! let svg = avatar::identicon_svg("jane.doe@example.com", "#2B7BC4");
+//! assert!(svg.starts_with("<svg"));
+//! ```
+
+use crate::fpe::fnv1a;
+
+/// Width and height of an identicon grid.
+pub const IDENTICON_SIZE: usize = 5;
+
+/// Width and height of one cell in [`identicon_svg`] output.
+const CELL: usize = 10;
+
+/// A left-right symmetric grid of filled (`true`) cells, indexed `[row][col]`.
+///
+/// The left three columns come from bits of a stable hash of `seed`; the
+/// right two mirror them.
+pub fn identicon(seed: &str) -> Vec<Vec<bool>> {
+    let hash = fnv1a(seed.as_bytes());
+    let half = IDENTICON_SIZE.div_ceil(2);
+
+    (0..IDENTICON_SIZE)
+        .map(|row| {
+            (0..IDENTICON_SIZE)
+                .map(|col| {
+                    let col = col.min(IDENTICON_SIZE - 1 - col);
+                    let bit = row * half + col;
+                    (hash >> (63 - bit)) & 1 == 1
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The identicon for `seed` as an SVG image with cells filled in `color`.
+///
+/// The image is 50x50 units with a transparent background; `color` is any
+/// SVG color such as `#2B7BC4` or `teal`.
+pub fn identicon_svg(seed: &str, color: &str) -> String {
+    let size = IDENTICON_SIZE * CELL;
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{size}" height="{size}" viewBox="0 0 {size} {size}">"#
+    );
+    for (row, cells) in identicon(seed).iter().enumerate() {
+        for (col, _) in cells.iter().enumerate().filter(|(_, filled)| **filled) {
+            svg.push_str(&format!(
+                r#"<rect x="{}" y="{}" width="{CELL}" height="{CELL}" fill="{}"/>"#,
+                col * CELL,
+                row * CELL,
+                escape_attr(color)
+            ));
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Escape a value for use inside a double-quoted XML attribute.
+fn escape_attr(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identicon_is_deterministic() {
+        assert_eq!(identicon("alice"), identicon("alice"));
+        assert_ne!(identicon("alice"), identicon("bob"));
+    }
+
+    #[test]
+    fn test_identicon_is_symmetric() {
+        for seed in ["alice", "bob", "", "jane.doe@example.com"] {
+            let grid = identicon(seed);
+            assert_eq!(grid.len(), IDENTICON_SIZE);
+            for row in &grid {
+                assert_eq!(row.len(), IDENTICON_SIZE);
+                let mirrored: Vec<bool> = row.iter().rev().copied().collect();
+                assert_eq!(row, &mirrored, "{}", seed);
+            }
+        }
+    }
+
+    #[test]
+    fn test_identicon_svg() {
+        let svg = identicon_svg("alice", "#2B7BC4");
+        let filled = identicon("alice").iter().flatten().filter(|c| **c).count();
+        assert_eq!(svg.matches("<rect").count(), filled);
+        assert!(svg.contains(r##"fill="#2B7BC4""##) || filled == 0);
+        assert!(svg.ends_with("</svg>"));
+
+        assert!(identicon_svg("alice", r#"red" onload="x"#).contains("red&quot; onload"));
+    }
+}
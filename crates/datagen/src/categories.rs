@@ -269,3 +269,72 @@ pub const SHIPPING_METHODS: &[&str] = &[
     "pickup",
     "freight",
 ];
+
+/// Every category above by name, for lookup from user input.
+pub const ALL: &[(&str, &[&str])] = &[
+    ("cities", CITIES),
+    ("countries", COUNTRIES),
+    ("statuses", STATUSES),
+    ("priorities", PRIORITIES),
+    ("departments", DEPARTMENTS),
+    ("fruits", FRUITS),
+    ("colors", COLORS),
+    ("sizes", SIZES),
+    ("days", DAYS),
+    ("months", MONTHS),
+    ("seasons", SEASONS),
+    ("genders", GENDERS),
+    ("genders_binary", GENDERS_BINARY),
+    ("age_groups", AGE_GROUPS),
+    ("age_group_names", AGE_GROUP_NAMES),
+    ("generations", GENERATIONS),
+    ("marital_statuses", MARITAL_STATUSES),
+    ("education_levels", EDUCATION_LEVELS),
+    ("employment_statuses", EMPLOYMENT_STATUSES),
+    ("file_extensions", FILE_EXTENSIONS),
+    ("mime_types", MIME_TYPES),
+    ("http_methods", HTTP_METHODS),
+    ("http_status_codes", HTTP_STATUS_CODES),
+    ("currencies", CURRENCIES),
+    ("payment_methods", PAYMENT_METHODS),
+    ("transaction_types", TRANSACTION_TYPES),
+    ("product_categories", PRODUCT_CATEGORIES),
+    ("order_statuses", ORDER_STATUSES),
+    ("shipping_methods", SHIPPING_METHODS),
+];
+
+/// Names of all categories in [`ALL`], e.g. `"fruits"`.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    ALL.iter().map(|(name, _)| *name)
+}
+
+/// Look up a category's values by name.
+pub fn get(name: &str) -> Option<&'static [&'static str]> {
+    ALL.iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, values)| *values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_unique_and_complete() {
+        let names: Vec<&str> = names().collect();
+        assert_eq!(names.len(), 29);
+        assert!(names.contains(&"fruits"));
+        let mut sorted = names.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), names.len());
+    }
+
+    #[test]
+    fn test_get() {
+        assert_eq!(get("fruits"), Some(FRUITS));
+        assert_eq!(get("shipping_methods"), Some(SHIPPING_METHODS));
+        assert_eq!(get("Fruits"), None);
+        assert!(ALL.iter().all(|(_, values)| !values.is_empty()));
+    }
+}
@@ -6,6 +6,9 @@
 //! - RGBA tuples with alpha
 //! - HSL values
 //! - Named colors
+//! - Palettes that stay distinguishable under color vision deficiency
+//! - Gradients and interpolation between colors
+//! - Coordinated themes with readable text contrast
 //!
 //! # Example
 //!
@@ -21,6 +24,7 @@
 //! ```
 
 use rand::Rng;
+use std::fmt;
 
 /// Named colors with their hex values
 pub const COLORS: &[(&str, &str)] = &[
@@ -405,6 +409,444 @@ pub fn css_hsla<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("hsla({}, {}%, {}%, {:.2})", h, s, l, a)
 }
 
+/// Format an RGB tuple as a hex color string (#RRGGBB).
+pub fn rgb_to_hex((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02X}{:02X}{:02X}", r, g, b)
+}
+
+/// A color string that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorError {
+    pub input: String,
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid hex color '{}': expected #RRGGBB or #RGB",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+/// Parse a hex color (`#RRGGBB`, `#RGB`, with or without the `#`).
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::parse_hex;
+///
+/// assert_eq!(parse_hex("#FF5733").unwrap(), (255, 87, 51));
+/// assert_eq!(parse_hex("fff").unwrap(), (255, 255, 255));
+/// assert!(parse_hex("#GG0000").is_err());
+/// ```
+pub fn parse_hex(hex: &str) -> Result<(u8, u8, u8), ColorError> {
+    let err = || ColorError {
+        input: hex.to_string(),
+    };
+    let digits = hex.strip_prefix('#').unwrap_or(hex);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(err());
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).map_err(|_| err());
+    match digits.len() {
+        6 => Ok((
+            channel(&digits[0..2])?,
+            channel(&digits[2..4])?,
+            channel(&digits[4..6])?,
+        )),
+        3 => {
+            let short = |i: usize| channel(&digits[i..i + 1]).map(|c| c * 17);
+            Ok((short(0)?, short(1)?, short(2)?))
+        }
+        _ => Err(err()),
+    }
+}
+
+/// Convert RGB to HSL as (hue: 0-360, saturation: 0-100, lightness: 0-100).
+pub fn rgb_to_hsl((r, g, b): (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+    let d = max - min;
+    if d == 0.0 {
+        return (0.0, 0.0, l * 100.0);
+    }
+
+    let s = d / (1.0 - (2.0 * l - 1.0).abs());
+    let h = if max == r {
+        60.0 * ((g - b) / d).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    (h, s * 100.0, l * 100.0)
+}
+
+/// Convert HSL (hue: 0-360, saturation: 0-100, lightness: 0-100) to RGB.
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::hsl_to_rgb;
+///
+/// assert_eq!(hsl_to_rgb(0.0, 100.0, 50.0), (255, 0, 0));
+/// assert_eq!(hsl_to_rgb(120.0, 100.0, 25.0), (0, 128, 0));
+/// ```
+pub fn hsl_to_rgb(h: f64, s: f64, l: f64) -> (u8, u8, u8) {
+    let h = h.rem_euclid(360.0);
+    let (s, l) = ((s / 100.0).clamp(0.0, 1.0), (l / 100.0).clamp(0.0, 1.0));
+    let c = (1.0 - (2.0 * l - 1.0).abs()) * s;
+    let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+    let m = l - c / 2.0;
+    let (r, g, b) = match h as u32 / 60 {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    };
+    let channel = |v: f64| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    (channel(r), channel(g), channel(b))
+}
+
+/// Color space used to interpolate between colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Mix light intensities; avoids the dark band of naive sRGB mixing
+    #[default]
+    LinearRgb,
+    /// Rotate hue the short way round; keeps intermediate colors saturated
+    Hsl,
+}
+
+/// Interpolate between two colors in linear RGB; `t` is clamped to 0.0-1.0.
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::lerp_color;
+///
+/// assert_eq!(lerp_color((0, 0, 0), (255, 255, 255), 0.0), (0, 0, 0));
+/// assert_eq!(lerp_color((0, 0, 0), (255, 255, 255), 1.0), (255, 255, 255));
+/// ```
+pub fn lerp_color(a: (u8, u8, u8), b: (u8, u8, u8), t: f64) -> (u8, u8, u8) {
+    lerp_color_in(a, b, t, ColorSpace::LinearRgb)
+}
+
+/// Interpolate between two colors in the given color space.
+pub fn lerp_color_in(a: (u8, u8, u8), b: (u8, u8, u8), t: f64, space: ColorSpace) -> (u8, u8, u8) {
+    let t = t.clamp(0.0, 1.0);
+    let mix = |x: f64, y: f64| x + (y - x) * t;
+    match space {
+        ColorSpace::LinearRgb => {
+            let channel = |x: u8, y: u8| from_linear(mix(to_linear(x), to_linear(y)));
+            (channel(a.0, b.0), channel(a.1, b.1), channel(a.2, b.2))
+        }
+        ColorSpace::Hsl => {
+            let (h1, s1, l1) = rgb_to_hsl(a);
+            let (h2, s2, l2) = rgb_to_hsl(b);
+            // Shortest way round the hue circle
+            let dh = (h2 - h1 + 540.0).rem_euclid(360.0) - 180.0;
+            hsl_to_rgb(h1 + dh * t, mix(s1, s2), mix(l1, l2))
+        }
+    }
+}
+
+/// A gradient of `steps` hex colors from `start` to `end`, in linear RGB.
+///
+/// Both endpoints are included. Fails if either color isn't valid hex.
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::gradient;
+///
+/// let legend = gradient("#000000", "#FFFFFF", 3).unwrap();
+/// assert_eq!(legend, ["#000000", "#BCBCBC", "#FFFFFF"]);
+/// assert!(gradient("black", "#FFFFFF", 3).is_err());
+/// ```
+pub fn gradient(start: &str, end: &str, steps: usize) -> Result<Vec<String>, ColorError> {
+    gradient_in(start, end, steps, ColorSpace::LinearRgb)
+}
+
+/// A gradient of `steps` hex colors from `start` to `end` in the given color space.
+pub fn gradient_in(
+    start: &str,
+    end: &str,
+    steps: usize,
+    space: ColorSpace,
+) -> Result<Vec<String>, ColorError> {
+    let (a, b) = (parse_hex(start)?, parse_hex(end)?);
+    Ok((0..steps)
+        .map(|i| {
+            let t = if steps > 1 {
+                i as f64 / (steps - 1) as f64
+            } else {
+                0.0
+            };
+            rgb_to_hex(lerp_color_in(a, b, t, space))
+        })
+        .collect())
+}
+
+/// WCAG relative luminance of an sRGB color (0.0 for black, 1.0 for white).
+pub fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    0.2126 * to_linear(r) + 0.7152 * to_linear(g) + 0.0722 * to_linear(b)
+}
+
+/// WCAG contrast ratio between two colors, from 1.0 (same) to 21.0.
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::contrast_ratio;
+///
+/// let ratio = contrast_ratio((0, 0, 0), (255, 255, 255));
+/// assert!((ratio - 21.0).abs() < 1e-9);
+/// ```
+pub fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (la, lb) = (relative_luminance(a), relative_luminance(b));
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// Minimum text/background contrast for [`theme`] (WCAG AA for normal text).
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+/// A coordinated set of hex colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    pub primary: String,
+    /// Analogous to the primary color
+    pub secondary: String,
+    /// Complementary to the primary color
+    pub accent: String,
+    pub background: String,
+    /// Readable on the background, see [`MIN_TEXT_CONTRAST`]
+    pub text: String,
+}
+
+/// Generate a theme from one random base hue.
+///
+/// The secondary color is analogous (30° away) and the accent complementary
+/// (180° away). The background is a near-white or near-black tint of the
+/// base hue, and the text is darkened or lightened until its contrast with
+/// the background is at least [`MIN_TEXT_CONTRAST`].
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::{contrast_ratio, parse_hex, theme, MIN_TEXT_CONTRAST};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let theme = theme(&mut rng);
+/// let ratio = contrast_ratio(parse_hex(&theme.text).unwrap(), parse_hex(&theme.background).unwrap());
+/// assert!(ratio >= MIN_TEXT_CONTRAST);
+/// ```
+pub fn theme<R: ?Sized + Rng>(rng: &mut R) -> Theme {
+    let hue = rng.random_range(0.0..360.0);
+    let saturation = rng.random_range(55.0..85.0);
+    let lightness = rng.random_range(40.0..55.0);
+    let offset = if rng.random_bool(0.5) { 30.0 } else { -30.0 };
+
+    let primary = hsl_to_rgb(hue, saturation, lightness);
+    let secondary = hsl_to_rgb(hue + offset, saturation * 0.8, lightness + 5.0);
+    let accent = hsl_to_rgb(hue + 180.0, saturation, lightness);
+
+    let dark = rng.random_bool(0.3);
+    let (background, start, step) = if dark {
+        (
+            hsl_to_rgb(
+                hue,
+                rng.random_range(10.0..25.0),
+                rng.random_range(6.0..12.0),
+            ),
+            85.0,
+            5.0,
+        )
+    } else {
+        (
+            hsl_to_rgb(
+                hue,
+                rng.random_range(10.0..25.0),
+                rng.random_range(94.0..98.0),
+            ),
+            20.0,
+            -5.0,
+        )
+    };
+
+    // Move text lightness towards the far end until it is readable
+    let mut text_lightness: f64 = start;
+    let mut text = hsl_to_rgb(hue, 15.0, text_lightness);
+    while contrast_ratio(text, background) < MIN_TEXT_CONTRAST
+        && (0.0..=100.0).contains(&text_lightness)
+    {
+        text_lightness += step;
+        text = hsl_to_rgb(hue, 15.0, text_lightness.clamp(0.0, 100.0));
+    }
+
+    Theme {
+        primary: rgb_to_hex(primary),
+        secondary: rgb_to_hex(secondary),
+        accent: rgb_to_hex(accent),
+        background: rgb_to_hex(background),
+        text: rgb_to_hex(text),
+    }
+}
+
+/// A type of color vision deficiency (dichromacy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CvdType {
+    /// Missing green cones (the most common form)
+    Deuteranopia,
+    /// Missing red cones
+    Protanopia,
+    /// Missing blue cones
+    Tritanopia,
+}
+
+/// Simulation matrices in linear RGB, from Machado et al. (2009) at full severity.
+fn cvd_matrix(cvd: CvdType) -> [[f64; 3]; 3] {
+    match cvd {
+        CvdType::Deuteranopia => [
+            [0.367322, 0.860646, -0.227968],
+            [0.280085, 0.672501, 0.047413],
+            [-0.011820, 0.042940, 0.968881],
+        ],
+        CvdType::Protanopia => [
+            [0.152286, 1.052583, -0.204868],
+            [0.114503, 0.786281, 0.099216],
+            [-0.003882, -0.048116, 1.051998],
+        ],
+        CvdType::Tritanopia => [
+            [1.255528, -0.076749, -0.178779],
+            [-0.078411, 0.930809, 0.147602],
+            [0.004733, 0.691367, 0.303900],
+        ],
+    }
+}
+
+/// sRGB channel (0-255) to linear light (0.0-1.0).
+fn to_linear(c: u8) -> f64 {
+    let c = c as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Linear light (0.0-1.0) to an sRGB channel (0-255).
+fn from_linear(c: f64) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+/// Approximate how an RGB color looks to someone with the given deficiency.
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::{simulate_cvd, CvdType};
+///
+/// // Red and green become hard to tell apart under deuteranopia
+/// let red = simulate_cvd((255, 0, 0), CvdType::Deuteranopia);
+/// let green = simulate_cvd((0, 128, 0), CvdType::Deuteranopia);
+/// assert!(red.0.abs_diff(green.0) < 60);
+/// ```
+pub fn simulate_cvd(rgb: (u8, u8, u8), cvd: CvdType) -> (u8, u8, u8) {
+    let lin = [to_linear(rgb.0), to_linear(rgb.1), to_linear(rgb.2)];
+    let m = cvd_matrix(cvd);
+    let channel = |row: [f64; 3]| from_linear(row[0] * lin[0] + row[1] * lin[1] + row[2] * lin[2]);
+    (channel(m[0]), channel(m[1]), channel(m[2]))
+}
+
+/// CIE L*a*b* coordinates of an sRGB color (D65 white point).
+fn to_lab(rgb: (u8, u8, u8)) -> (f64, f64, f64) {
+    let (r, g, b) = (to_linear(rgb.0), to_linear(rgb.1), to_linear(rgb.2));
+    let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+
+    let f = |t: f64| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+    let (fx, fy, fz) = (f(x), f(y), f(z));
+    (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+}
+
+/// Perceptual distance between two colors (CIE76 ΔE in L*a*b*).
+///
+/// Around 2.3 is a just-noticeable difference; colors in a chart should be
+/// much further apart.
+pub fn delta_e(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let (l1, a1, b1) = to_lab(a);
+    let (l2, a2, b2) = to_lab(b);
+    ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+}
+
+/// Minimum pairwise [`delta_e`], under simulation, that [`safe_palette`] aims for.
+pub const SAFE_MIN_DISTANCE: f64 = 20.0;
+
+/// Candidates tried per color before [`safe_palette`] relaxes its distance.
+const SAFE_ATTEMPTS: usize = 500;
+
+/// Generate `n` hex colors that stay distinguishable under a deficiency.
+///
+/// Random candidates are simulated with [`simulate_cvd`] and rejected if
+/// they are within [`SAFE_MIN_DISTANCE`] of a color already chosen. If no
+/// candidate fits (only likely for large `n`), the required distance is
+/// lowered step by step, so the palette is always complete.
+///
+/// # Example
+/// ```
+/// use dx_datagen::color::{safe_palette, CvdType};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let palette = safe_palette(&mut rng, 5, CvdType::Deuteranopia);
+/// assert_eq!(palette.len(), 5);
+/// assert!(palette.iter().all(|c| c.starts_with('#') && c.len() == 7));
+/// ```
+pub fn safe_palette<R: ?Sized + Rng>(rng: &mut R, n: usize, cvd: CvdType) -> Vec<String> {
+    let mut chosen: Vec<(u8, u8, u8)> = Vec::with_capacity(n);
+    let mut seen: Vec<(u8, u8, u8)> = Vec::with_capacity(n);
+    let mut min_distance = SAFE_MIN_DISTANCE;
+
+    while chosen.len() < n {
+        let found = (0..SAFE_ATTEMPTS).find_map(|_| {
+            let candidate = rgb(rng);
+            let simulated = simulate_cvd(candidate, cvd);
+            seen.iter()
+                .all(|&s| delta_e(s, simulated) >= min_distance)
+                .then_some((candidate, simulated))
+        });
+        match found {
+            Some((candidate, simulated)) => {
+                chosen.push(candidate);
+                seen.push(simulated);
+            }
+            None => min_distance *= 0.9,
+        }
+    }
+
+    chosen.into_iter().map(rgb_to_hex).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -430,6 +872,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::absurd_extreme_comparisons, unused_comparisons)]
     fn test_rgb() {
         let mut rng = StdRng::seed_from_u64(42);
         let (r, g, b) = rgb(&mut rng);
@@ -438,6 +881,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::absurd_extreme_comparisons, unused_comparisons)]
     fn test_rgba() {
         let mut rng = StdRng::seed_from_u64(42);
         let (r, g, b, a) = rgba(&mut rng);
@@ -469,6 +913,161 @@ mod tests {
         assert!(color.ends_with(')'));
     }
 
+    #[test]
+    fn test_rgb_to_hex() {
+        assert_eq!(rgb_to_hex((255, 87, 51)), "#FF5733");
+        assert_eq!(rgb_to_hex((0, 0, 0)), "#000000");
+    }
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#FF5733"), Ok((255, 87, 51)));
+        assert_eq!(parse_hex("ff5733"), Ok((255, 87, 51)));
+        assert_eq!(parse_hex("#0a3"), Ok((0, 170, 51)));
+        for bad in ["", "#", "#12345", "#1234567", "#GGGGGG", "#+1+2+3", "#ÿÿ"] {
+            assert!(parse_hex(bad).is_err(), "{}", bad);
+        }
+        assert_eq!(
+            parse_hex("red").unwrap_err().to_string(),
+            "Invalid hex color 'red': expected #RRGGBB or #RGB"
+        );
+    }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        for rgb in [(255, 87, 51), (0, 128, 0), (12, 34, 200), (128, 128, 128)] {
+            let (h, s, l) = rgb_to_hsl(rgb);
+            assert_eq!(hsl_to_rgb(h, s, l), rgb, "{:?}", (h, s, l));
+        }
+        assert_eq!(rgb_to_hsl((0, 0, 255)), (240.0, 100.0, 50.0));
+    }
+
+    #[test]
+    fn test_gradient_black_to_white() {
+        let steps = gradient("#000000", "#FFFFFF", 3).unwrap();
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[0], "#000000");
+        assert_eq!(steps[2], "#FFFFFF");
+        // Mid-gray: equal channels, halfway in light intensity
+        let mid = parse_hex(&steps[1]).unwrap();
+        assert!(mid.0 == mid.1 && mid.1 == mid.2);
+        assert!((to_linear(mid.0) - 0.5).abs() < 0.01, "{}", steps[1]);
+
+        assert_eq!(gradient("#123456", "#654321", 1).unwrap(), ["#123456"]);
+        assert!(gradient("#123456", "#654321", 0).unwrap().is_empty());
+        assert!(gradient("#000", "nope", 3).is_err());
+    }
+
+    #[test]
+    fn test_gradient_in_hsl() {
+        // Red to blue the short way round passes through magenta, not green
+        let steps = gradient_in("#FF0000", "#0000FF", 3, ColorSpace::Hsl).unwrap();
+        assert_eq!(steps, ["#FF0000", "#FF00FF", "#0000FF"]);
+        assert_eq!(
+            lerp_color_in((255, 0, 0), (0, 0, 255), 2.0, ColorSpace::Hsl),
+            (0, 0, 255)
+        );
+        assert_eq!(lerp_color((0, 0, 0), (255, 255, 255), -1.0), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((contrast_ratio((255, 255, 255), (0, 0, 0)) - 21.0).abs() < 1e-9);
+        assert_eq!(contrast_ratio((119, 119, 119), (119, 119, 119)), 1.0);
+        // #767676 on white is the classic just-passing AA gray
+        let ratio = contrast_ratio((0x76, 0x76, 0x76), (255, 255, 255));
+        assert!(ratio > 4.5 && ratio < 4.6, "{}", ratio);
+    }
+
+    #[test]
+    fn test_theme_text_is_readable() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..200 {
+            let theme = theme(&mut rng);
+            let text = parse_hex(&theme.text).unwrap();
+            let background = parse_hex(&theme.background).unwrap();
+            let ratio = contrast_ratio(text, background);
+            assert!(ratio >= MIN_TEXT_CONTRAST, "{:?}: {}", theme, ratio);
+        }
+    }
+
+    #[test]
+    fn test_theme_hue_relationships() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let theme = theme(&mut rng);
+        let hue = |hex: &str| rgb_to_hsl(parse_hex(hex).unwrap()).0;
+        let distance = |a: f64, b: f64| {
+            let d = (a - b).rem_euclid(360.0);
+            d.min(360.0 - d)
+        };
+        let primary = hue(&theme.primary);
+        assert!((distance(primary, hue(&theme.accent)) - 180.0).abs() < 5.0);
+        assert!((distance(primary, hue(&theme.secondary)) - 30.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_simulate_cvd() {
+        for cvd in [
+            CvdType::Deuteranopia,
+            CvdType::Protanopia,
+            CvdType::Tritanopia,
+        ] {
+            // Grays are unaffected
+            assert_eq!(simulate_cvd((0, 0, 0), cvd), (0, 0, 0));
+            assert_eq!(simulate_cvd((255, 255, 255), cvd), (255, 255, 255));
+        }
+        // Red/green confusion
+        let red = (200, 30, 30);
+        let green = (60, 140, 40);
+        let normal = delta_e(red, green);
+        let simulated = delta_e(
+            simulate_cvd(red, CvdType::Protanopia),
+            simulate_cvd(green, CvdType::Protanopia),
+        );
+        assert!(simulated < normal / 2.0, "{} vs {}", simulated, normal);
+    }
+
+    #[test]
+    fn test_delta_e() {
+        assert_eq!(delta_e((10, 20, 30), (10, 20, 30)), 0.0);
+        let black_white = delta_e((0, 0, 0), (255, 255, 255));
+        assert!((black_white - 100.0).abs() < 0.1, "{}", black_white);
+    }
+
+    #[test]
+    fn test_safe_palette_is_distinguishable() {
+        for cvd in [
+            CvdType::Deuteranopia,
+            CvdType::Protanopia,
+            CvdType::Tritanopia,
+        ] {
+            let mut rng = StdRng::seed_from_u64(42);
+            let palette = safe_palette(&mut rng, 5, cvd);
+            assert_eq!(palette.len(), 5);
+
+            let simulated: Vec<_> = palette
+                .iter()
+                .map(|hex| {
+                    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+                    simulate_cvd((channel(1), channel(3), channel(5)), cvd)
+                })
+                .collect();
+            for (i, a) in simulated.iter().enumerate() {
+                for b in &simulated[i + 1..] {
+                    let distance = delta_e(*a, *b);
+                    assert!(distance >= SAFE_MIN_DISTANCE, "{:?}: {}", cvd, distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_safe_palette_always_completes() {
+        let mut rng = StdRng::seed_from_u64(7);
+        assert_eq!(safe_palette(&mut rng, 60, CvdType::Tritanopia).len(), 60);
+        assert!(safe_palette(&mut rng, 0, CvdType::Tritanopia).is_empty());
+    }
+
     #[test]
     fn test_determinism() {
         let mut rng1 = StdRng::seed_from_u64(123);
@@ -2,6 +2,32 @@
 
 use rand::Rng;
 
+/// A generated company whose domain and email follow from its name.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Company {
+    /// Company name, e.g. "Quantum Dynamics"
+    pub name: String,
+    /// Legal suffix, e.g. "Inc"
+    pub suffix: String,
+    /// Industry the company operates in
+    pub industry: String,
+    /// Domain derived from the name, e.g. "quantumdynamics.io"
+    pub domain: String,
+    /// Support address at the company domain
+    pub support_email: String,
+}
+
+impl Company {
+    /// Name with the legal suffix, e.g. "Quantum Dynamics Inc".
+    pub fn legal_name(&self) -> String {
+        format!("{} {}", self.name, self.suffix)
+    }
+}
+
+/// TLDs used for company domains
+const COMPANY_TLDS: &[&str] = &["com", "io", "co", "net"];
+
 /// Company name suffixes
 pub const COMPANY_SUFFIXES: &[&str] = &[
     "Inc",
@@ -210,6 +236,46 @@ pub fn company_name<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{} {}", prefix, suffix)
 }
 
+/// Generate a company record with a matching domain and support email.
+///
+/// The domain is the lowercased name with everything but letters and digits
+/// removed, plus a common TLD.
+///
+/// # Example
+/// ```
+/// use dx_datagen::commerce::company_record;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let company = company_record(&mut rng);
+/// assert!(company.support_email.ends_with(&format!("@{}", company.domain)));
+/// ```
+pub fn company_record<R: ?Sized + Rng>(rng: &mut R) -> Company {
+    let name = company_name(rng);
+    let suffix = company_suffix(rng).to_string();
+    let industry = industry(rng).to_string();
+    let tld = COMPANY_TLDS[rng.random_range(0..COMPANY_TLDS.len())];
+    let domain = format!("{}.{}", slugify(&name), tld);
+    let support_email = format!("support@{}", domain);
+
+    Company {
+        name,
+        suffix,
+        industry,
+        domain,
+        support_email,
+    }
+}
+
+/// Lowercase ASCII letters and digits of `name`, for use as a domain label.
+fn slugify(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
 /// Get a random company suffix.
 ///
 /// # Example
@@ -286,6 +352,45 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
 
+    #[test]
+    fn test_company_record_email_uses_domain() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..50 {
+            let company = company_record(&mut rng);
+            let (local, domain) = company.support_email.split_once('@').unwrap();
+            assert_eq!(local, "support");
+            assert_eq!(domain, company.domain);
+            assert!(crate::network::domain::is_valid_domain(&company.domain));
+            assert!(company.domain.starts_with(&slugify(&company.name)));
+            assert!(COMPANY_SUFFIXES.contains(&company.suffix.as_str()));
+            assert!(INDUSTRIES.contains(&company.industry.as_str()));
+        }
+    }
+
+    #[test]
+    fn test_company_record_deterministic() {
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        assert_eq!(company_record(&mut rng1), company_record(&mut rng2));
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Quantum Dynamics"), "quantumdynamics");
+        assert_eq!(slugify("Food & Beverage Co."), "foodbeverageco");
+    }
+
+    #[cfg(all(feature = "serde", feature = "schema"))]
+    #[test]
+    fn test_company_serde_round_trip() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let company = company_record(&mut rng);
+        let json = serde_json::to_value(&company).unwrap();
+        assert_eq!(json["domain"], company.domain.as_str());
+        let back: Company = serde_json::from_value(json).unwrap();
+        assert_eq!(back, company);
+    }
+
     #[test]
     fn test_company_name() {
         let mut rng = StdRng::seed_from_u64(42);
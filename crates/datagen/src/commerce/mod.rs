@@ -25,7 +25,8 @@ mod job;
 mod product;
 
 pub use company::{
-    catch_phrase, company_bs, company_name, company_suffix, industry, COMPANY_SUFFIXES, INDUSTRIES,
+    catch_phrase, company_bs, company_name, company_record, company_suffix, industry, Company,
+    COMPANY_SUFFIXES, INDUSTRIES,
 };
 pub use currency::{currency_code, currency_name, currency_symbol, CURRENCIES};
 pub use job::{
@@ -175,7 +175,7 @@ mod tests {
     fn test_price() {
         let mut rng = StdRng::seed_from_u64(42);
         let p = price(&mut rng, 10.0, 100.0);
-        assert!(p >= 10.0 && p <= 100.0);
+        assert!((10.0..=100.0).contains(&p));
         // Verify 2 decimal places
         let s = format!("{:.2}", p);
         assert!(s.contains('.'));
@@ -593,7 +593,7 @@ mod tests {
         let mut rng = test_rng();
         for _ in 0..100 {
             let r = rating(&mut rng);
-            assert!(r >= 1 && r <= 5);
+            assert!((1..=5).contains(&r));
         }
     }
 
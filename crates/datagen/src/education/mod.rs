@@ -290,7 +290,7 @@ mod tests {
     fn test_gpa() {
         let mut rng = StdRng::seed_from_u64(42);
         let g = gpa(&mut rng);
-        assert!(g >= 0.0 && g <= 4.0);
+        assert!((0.0..=4.0).contains(&g));
     }
 
     #[test]
@@ -6,15 +6,14 @@ use rand::Rng;
 
 use super::FunctionError;
 use crate::expression::ast::Argument;
+use crate::text::from_regex;
 
 /// Generate a string matching a regex pattern.
 ///
 /// Syntax: `regexify 'pattern'`
 ///
-/// Supports:
-/// - Character classes: `[A-Z]`, `[0-9]`, `[a-zA-Z]`
-/// - Quantifiers: `{n}`, `{n,m}`
-/// - Literal characters
+/// Supports the syntax of [`crate::text::from_regex`]: character classes,
+/// groups, alternation, quantifiers and anchors.
 ///
 /// Example: `regexify '[A-Z]{3}-[0-9]{4}'` -> "ABC-1234"
 pub fn regexify<R: Rng + ?Sized>(rng: &mut R, args: &[Argument]) -> Result<String, FunctionError> {
@@ -26,7 +25,7 @@ pub fn regexify<R: Rng + ?Sized>(rng: &mut R, args: &[Argument]) -> Result<Strin
         .as_string()
         .ok_or_else(|| FunctionError::wrong_arg_type("regexify", 0, "a string"))?;
 
-    generate_from_regex(rng, pattern)
+    from_regex(rng, pattern).map_err(|e| FunctionError::new(&format!("regexify: {}", e)))
 }
 
 /// Generate a string by replacing template characters.
@@ -233,156 +232,6 @@ fn parse_char_class(class: &str) -> Vec<char> {
     chars
 }
 
-/// Generate a string from a simplified regex pattern.
-fn generate_from_regex<R: Rng + ?Sized>(
-    rng: &mut R,
-    pattern: &str,
-) -> Result<String, FunctionError> {
-    let mut result = String::new();
-    let mut chars = pattern.chars().peekable();
-
-    while let Some(c) = chars.next() {
-        match c {
-            '[' => {
-                // Parse character class
-                let mut class_chars = Vec::new();
-                let mut prev_char: Option<char> = None;
-
-                while let Some(&next) = chars.peek() {
-                    if next == ']' {
-                        chars.next();
-                        break;
-                    }
-                    chars.next();
-
-                    if next == '-' {
-                        if let Some(start) = prev_char {
-                            if let Some(&end) = chars.peek() {
-                                if end != ']' {
-                                    chars.next();
-                                    // Remove the last char (it was the start of range)
-                                    class_chars.pop();
-                                    for ch in start..=end {
-                                        class_chars.push(ch);
-                                    }
-                                    prev_char = Some(end);
-                                    continue;
-                                }
-                            }
-                        }
-                        class_chars.push('-');
-                        prev_char = Some('-');
-                    } else {
-                        class_chars.push(next);
-                        prev_char = Some(next);
-                    }
-                }
-
-                // Check for quantifier
-                let count = parse_quantifier(&mut chars);
-                for _ in 0..count {
-                    if !class_chars.is_empty() {
-                        let idx = rng.random_range(0..class_chars.len());
-                        result.push(class_chars[idx]);
-                    }
-                }
-            }
-            '\\' => {
-                // Escape sequence
-                if let Some(escaped) = chars.next() {
-                    match escaped {
-                        'd' => {
-                            let count = parse_quantifier(&mut chars);
-                            for _ in 0..count {
-                                result.push(random_digit(rng));
-                            }
-                        }
-                        'w' => {
-                            let count = parse_quantifier(&mut chars);
-                            let word_chars: Vec<char> =
-                                "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789_"
-                                    .chars()
-                                    .collect();
-                            for _ in 0..count {
-                                let idx = rng.random_range(0..word_chars.len());
-                                result.push(word_chars[idx]);
-                            }
-                        }
-                        _ => result.push(escaped),
-                    }
-                }
-            }
-            '{' | '+' | '*' | '?' => {
-                // Skip standalone quantifiers (shouldn't happen in well-formed patterns)
-            }
-            _ => {
-                // Literal character - check for quantifier
-                let count = parse_quantifier(&mut chars);
-                if count > 1 {
-                    for _ in 0..count {
-                        result.push(c);
-                    }
-                } else {
-                    result.push(c);
-                }
-            }
-        }
-    }
-
-    Ok(result)
-}
-
-fn parse_quantifier(chars: &mut std::iter::Peekable<std::str::Chars>) -> usize {
-    match chars.peek() {
-        Some(&'{') => {
-            chars.next(); // consume '{'
-            let mut num_str = String::new();
-            let mut max_str = String::new();
-            let mut in_max = false;
-
-            while let Some(&c) = chars.peek() {
-                if c == '}' {
-                    chars.next();
-                    break;
-                } else if c == ',' {
-                    chars.next();
-                    in_max = true;
-                } else if c.is_ascii_digit() {
-                    chars.next();
-                    if in_max {
-                        max_str.push(c);
-                    } else {
-                        num_str.push(c);
-                    }
-                } else {
-                    break;
-                }
-            }
-
-            let min: usize = num_str.parse().unwrap_or(1);
-            if in_max && !max_str.is_empty() {
-                let max: usize = max_str.parse().unwrap_or(min);
-                rand::rng().random_range(min..=max)
-            } else {
-                min
-            }
-        }
-        Some(&'+') => {
-            chars.next();
-            rand::rng().random_range(1..=5) // Default 1-5 for +
-        }
-        Some(&'*') => {
-            chars.next();
-            rand::rng().random_range(0..=5) // Default 0-5 for *
-        }
-        Some(&'?') => {
-            chars.next();
-            rand::rng().random_range(0..=1)
-        }
-        _ => 1,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -413,6 +262,16 @@ mod tests {
         assert!(result.chars().nth(3) == Some('-'));
     }
 
+    #[test]
+    fn test_regexify_alternation_and_errors() {
+        let mut rng = test_rng();
+        let result = regexify(&mut rng, &[Argument::String("(cat|dog)".to_string())]).unwrap();
+        assert!(result == "cat" || result == "dog");
+
+        let err = regexify(&mut rng, &[Argument::String("[A-Z".to_string())]).unwrap_err();
+        assert!(err.message.contains("unclosed character class"));
+    }
+
     #[test]
     fn test_templatify() {
         let mut rng = test_rng();
@@ -501,6 +501,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_float_number() {
         let tokens = tokenize("#{test 3.14}");
         assert_eq!(
@@ -0,0 +1,128 @@
+//! Format-preserving tokens.
+//!
+//! Map real identifiers to fake ones that keep their format, for consistent
+//! pseudonymization: the same input with the same key always produces the
+//! same token, so joins across masked tables still line up.
+//!
+//! Templates use the [`from_pattern`](crate::text::from_pattern) syntax:
+//! `#` digit, `?` lowercase letter, `A` uppercase letter, `*` alphanumeric,
+//! `\` escapes the next character. Everything else is copied.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::fpe::{format_preserving_with_key, matches_template};
+//!
+//! let a = format_preserving_with_key(42, "AAA-###", "NOR-123");
+//! let b = format_preserving_with_key(42, "AAA-###", "NOR-123");
+//! assert_eq!(a, b);
+//! assert!(matches_template("AAA-###", &a));
+//! ```
+//!
+//! Tokens are derived with a keyed hash, not encryption: they can't be
+//! reversed, and distinct inputs may (rarely) collide.
+
+use crate::text::from_pattern;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Produce a token matching `template`, derived from `input`.
+///
+/// The key is drawn from `rng`, so a seeded RNG gives reproducible tokens.
+/// Each call consumes one value from `rng`; to map many inputs consistently,
+/// draw a key once and use [`format_preserving_with_key`].
+pub fn format_preserving<R: ?Sized + Rng>(rng: &mut R, template: &str, input: &str) -> String {
+    format_preserving_with_key(rng.random(), template, input)
+}
+
+/// Produce a token matching `template`, derived from `key` and `input`.
+pub fn format_preserving_with_key(key: u64, template: &str, input: &str) -> String {
+    let mut rng = StdRng::seed_from_u64(key ^ fnv1a(input.as_bytes()));
+    from_pattern(&mut rng, template)
+}
+
+/// Whether `value` could have been produced from `template`.
+pub fn matches_template(template: &str, value: &str) -> bool {
+    let mut values = value.chars();
+    let mut chars = template.chars();
+
+    while let Some(c) = chars.next() {
+        let Some(v) = values.next() else {
+            return false;
+        };
+        let ok = match c {
+            '#' => v.is_ascii_digit(),
+            '?' => v.is_ascii_lowercase(),
+            'A' => v.is_ascii_uppercase(),
+            '*' => v.is_ascii_alphanumeric(),
+            '\\' => chars.next() == Some(v),
+            _ => c == v,
+        };
+        if !ok {
+            return false;
+        }
+    }
+    values.next().is_none()
+}
+
+/// 64-bit FNV-1a, used because it is stable across Rust releases.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_matches_template() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for input in ["ABC-123", "XYZ-999", "", "anything"] {
+            let token = format_preserving(&mut rng, "AAA-###", input);
+            assert!(matches_template("AAA-###", &token), "{}", token);
+        }
+        let token = format_preserving(&mut rng, "?*\\#A", "x");
+        assert!(matches_template("?*\\#A", &token));
+        assert_eq!(&token[2..3], "#");
+    }
+
+    #[test]
+    fn test_same_input_and_seed_same_token() {
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        assert_eq!(
+            format_preserving(&mut rng1, "AAA-###", "NOR-123"),
+            format_preserving(&mut rng2, "AAA-###", "NOR-123")
+        );
+    }
+
+    #[test]
+    fn test_token_depends_on_input_and_key() {
+        let a = format_preserving_with_key(1, "AAAA-####", "alice");
+        assert_eq!(a, format_preserving_with_key(1, "AAAA-####", "alice"));
+        assert_ne!(a, format_preserving_with_key(1, "AAAA-####", "bob"));
+        assert_ne!(a, format_preserving_with_key(2, "AAAA-####", "alice"));
+    }
+
+    #[test]
+    fn test_matches_template() {
+        assert!(matches_template("AAA-###", "NOR-123"));
+        assert!(!matches_template("AAA-###", "NOR-12"));
+        assert!(!matches_template("AAA-###", "NOR-1234"));
+        assert!(!matches_template("AAA-###", "nor-123"));
+        assert!(!matches_template("AAA-###", "NOR_123"));
+        assert!(matches_template("\\A#", "A1"));
+        assert!(!matches_template("\\A#", "B1"));
+    }
+
+    #[test]
+    fn test_fnv1a_known_value() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+}
@@ -79,7 +79,7 @@ mod tests {
         let mut rng = StdRng::seed_from_u64(42);
         for _ in 0..100 {
             let v = int_range(&mut rng, 10, 20);
-            assert!(v >= 10 && v <= 20);
+            assert!((10..=20).contains(&v));
         }
     }
 
@@ -88,7 +88,7 @@ mod tests {
         let mut rng = StdRng::seed_from_u64(42);
         for _ in 0..100 {
             let v = float_range(&mut rng, 0.0, 1.0);
-            assert!(v >= 0.0 && v <= 1.0);
+            assert!((0.0..=1.0).contains(&v));
         }
     }
 
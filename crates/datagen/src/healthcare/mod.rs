@@ -3,7 +3,9 @@
 //! Provides generators for healthcare-related fake data including medical
 //! conditions, medications, body parts, and healthcare providers.
 
+use crate::selection::WeightedSelector;
 use rand::Rng;
+use std::sync::OnceLock;
 
 fn pick<R: ?Sized + Rng>(rng: &mut R, items: &[&'static str]) -> &'static str {
     items[rng.random_range(0..items.len())]
@@ -38,6 +40,35 @@ static CONDITIONS: &[&str] = &[
     "Sleep Apnea",
 ];
 
+/// Approximate adult prevalence (%) of each entry in [`CONDITIONS`].
+static CONDITION_WEIGHTS: &[(&str, f64)] = &[
+    ("Hypertension", 47.0),
+    ("Type 2 Diabetes", 11.0),
+    ("Asthma", 8.0),
+    ("Arthritis", 21.0),
+    ("Migraine", 12.0),
+    ("Allergies", 30.0),
+    ("Anxiety", 19.0),
+    ("Depression", 8.0),
+    ("Insomnia", 12.0),
+    ("Acid Reflux", 20.0),
+    ("Back Pain", 25.0),
+    ("Chronic Fatigue", 1.0),
+    ("Eczema", 7.0),
+    ("High Cholesterol", 38.0),
+    ("Hypothyroidism", 5.0),
+    ("Anemia", 6.0),
+    ("Bronchitis", 3.5),
+    ("Sinusitis", 11.0),
+    ("Osteoporosis", 10.0),
+    ("Fibromyalgia", 2.0),
+    ("Irritable Bowel Syndrome", 11.0),
+    ("Carpal Tunnel Syndrome", 3.0),
+    ("Tendinitis", 3.0),
+    ("Vertigo", 5.0),
+    ("Sleep Apnea", 6.0),
+];
+
 /// Medications.
 static MEDICATIONS: &[&str] = &[
     "Lisinopril",
@@ -99,6 +130,26 @@ static SYMPTOMS: &[&str] = &[
 /// Blood types.
 static BLOOD_TYPES: &[&str] = &["A+", "A-", "B+", "B-", "AB+", "AB-", "O+", "O-"];
 
+/// Approximate population share (%) of each blood type.
+static BLOOD_TYPE_WEIGHTS: &[(&str, f64)] = &[
+    ("A+", 35.7),
+    ("A-", 6.3),
+    ("B+", 8.5),
+    ("B-", 1.5),
+    ("AB+", 3.4),
+    ("AB-", 0.6),
+    ("O+", 37.4),
+    ("O-", 6.6),
+];
+
+fn selector(weights: &[(&'static str, f64)]) -> WeightedSelector<&'static str> {
+    let mut selector = WeightedSelector::new();
+    for &(item, weight) in weights {
+        selector.push(item, weight);
+    }
+    selector
+}
+
 /// Body organs.
 static ORGANS: &[&str] = &[
     "Heart",
@@ -212,6 +263,17 @@ pub fn condition<R: ?Sized + Rng>(rng: &mut R) -> &'static str {
     pick(rng, CONDITIONS)
 }
 
+/// Generate a medical condition, weighted by approximate adult prevalence.
+///
+/// Common conditions like hypertension come up far more often than rare
+/// ones like chronic fatigue.
+pub fn condition_weighted<R: ?Sized + Rng>(rng: &mut R) -> &'static str {
+    static SELECTOR: OnceLock<WeightedSelector<&'static str>> = OnceLock::new();
+    SELECTOR
+        .get_or_init(|| selector(CONDITION_WEIGHTS))
+        .pick(rng)
+}
+
 /// Generate a random medication name.
 pub fn medication<R: ?Sized + Rng>(rng: &mut R) -> &'static str {
     pick(rng, MEDICATIONS)
@@ -227,6 +289,16 @@ pub fn blood_type<R: ?Sized + Rng>(rng: &mut R) -> &'static str {
     pick(rng, BLOOD_TYPES)
 }
 
+/// Generate a blood type, weighted by approximate population share.
+///
+/// O+ and A+ together make up about three quarters of draws.
+pub fn blood_type_weighted<R: ?Sized + Rng>(rng: &mut R) -> &'static str {
+    static SELECTOR: OnceLock<WeightedSelector<&'static str>> = OnceLock::new();
+    SELECTOR
+        .get_or_init(|| selector(BLOOD_TYPE_WEIGHTS))
+        .pick(rng)
+}
+
 /// Generate a random organ.
 pub fn organ<R: ?Sized + Rng>(rng: &mut R) -> &'static str {
     pick(rng, ORGANS)
@@ -283,6 +355,52 @@ mod tests {
         assert!(BLOOD_TYPES.contains(&b));
     }
 
+    #[test]
+    fn test_weight_tables_cover_lists() {
+        let names: Vec<&str> = CONDITION_WEIGHTS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, CONDITIONS);
+        let names: Vec<&str> = BLOOD_TYPE_WEIGHTS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, BLOOD_TYPES);
+    }
+
+    #[test]
+    fn test_blood_type_weighted_o_positive_dominates() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..100_000 {
+            *counts.entry(blood_type_weighted(&mut rng)).or_insert(0u32) += 1;
+        }
+
+        let o_pos = counts["O+"];
+        for (&blood_type, &count) in &counts {
+            if blood_type != "O+" {
+                assert!(
+                    o_pos > count,
+                    "O+ ({}) <= {} ({})",
+                    o_pos,
+                    blood_type,
+                    count
+                );
+            }
+        }
+        // 37.4% expected
+        assert!(
+            (36_000..39_000).contains(&o_pos),
+            "O+ drawn {} times",
+            o_pos
+        );
+        assert!(counts["AB-"] < 1_000);
+    }
+
+    #[test]
+    fn test_condition_weighted_skews_to_common() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let draws: Vec<&str> = (0..20_000).map(|_| condition_weighted(&mut rng)).collect();
+        assert!(draws.iter().all(|c| CONDITIONS.contains(c)));
+        let count = |name: &str| draws.iter().filter(|c| **c == name).count();
+        assert!(count("Hypertension") > 10 * count("Chronic Fatigue"));
+    }
+
     #[test]
     fn test_hospital_name() {
         let mut rng = StdRng::seed_from_u64(42);
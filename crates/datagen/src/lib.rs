@@ -6,10 +6,17 @@
 //! - **password**: Password and charset-based string generation
 //! - **uuid**: UUID generation (v4, v7) with formatting options
 //! - **selection**: Weighted random selection
+//! - **seeded**: Seed recording so failed randomized tests can be replayed
+//! - **arbitrary**: Edge values and shrinking for property tests
+//! - **avatar**: Deterministic identicons for UI fixtures
+//! - **stats**: Summary statistics for generated numbers
 //! - **text**: Pattern-based text generation, word lists, lorem ipsum
 //! - **personal**: Personal data (names, email, phone, address, username)
 //! - **network**: Network data (IP addresses, MAC addresses, domains, URLs)
+//! - **fpe**: Format-preserving tokens for consistent pseudonymization
+//! - **mask**: Format-preserving masking of emails, phone numbers and cards
 //! - **numeric**: Formatted numeric identifiers (credit cards, ISBN, SSN, IBAN)
+//! - **payloads**: QR code payloads (vCard, WiFi, geo URI, URL)
 //! - **temporal**: Date and time generation (feature-gated with `temporal`)
 //! - **geo**: Geographic coordinates and GeoJSON points (feature-gated with `geo`)
 //!
@@ -46,7 +53,9 @@ pub mod uuid;
 
 // Extended modules
 pub mod animals;
+pub mod arbitrary;
 pub mod astrology;
+pub mod avatar;
 pub mod commerce;
 pub mod crypto;
 pub mod ecommerce;
@@ -55,17 +64,22 @@ pub mod entertainment;
 pub mod expression;
 pub mod file;
 pub mod food;
+pub mod fpe;
 pub mod government;
 pub mod hacker;
 pub mod healthcare;
 pub mod locale;
+pub mod mask;
 pub mod network;
 pub mod numeric;
+pub mod payloads;
 pub mod personal;
 pub mod science;
+pub mod seeded;
 pub mod selection;
 pub mod social;
 pub mod sports;
+pub mod stats;
 pub mod text;
 pub mod travel;
 pub mod vehicle;
@@ -93,13 +107,15 @@ pub use uuid::{
 // Re-export selection
 pub use selection::{
     generate_batch, generate_batch_map, generate_batch_nullable, generate_batch_unique,
-    generate_batch_unique_with_retries, generate_until, weighted_pick, weighted_pick_from,
-    UniqueError, UniqueGenerator, UniqueTracker, WeightedItem, WeightedSelector,
+    generate_batch_unique_with_retries, generate_batch_with_progress, generate_stream_cancellable,
+    generate_until, reservoir_sample, weighted_pick, weighted_pick_from, UniqueError,
+    UniqueGenerator, UniqueTracker, WeightedItem, WeightedSelector,
 };
 
 // Re-export text
 pub use text::{
-    adjective, from_pattern, noun, render, render_default, verb, word, ProviderRegistry, Template,
+    adjective, from_pattern, from_regex, noun, render, render_default, verb, word,
+    ProviderRegistry, Template,
 };
 
 // Re-export personal
@@ -132,8 +148,10 @@ pub use geo::{geojson_point, geojson_point_string};
 
 // Re-export color
 pub use color::{
-    color_name, css_color_name, css_hsl, css_hsla, css_rgb, css_rgba, hex_color, hex_color_alpha,
-    hsl, hsla, rgb, rgba,
+    color_name, contrast_ratio, css_color_name, css_hsl, css_hsla, css_rgb, css_rgba, delta_e,
+    gradient, gradient_in, hex_color, hex_color_alpha, hsl, hsl_to_rgb, hsla, lerp_color,
+    lerp_color_in, parse_hex, relative_luminance, rgb, rgb_to_hex, rgb_to_hsl, rgba, safe_palette,
+    simulate_cvd, theme, ColorError, ColorSpace, CvdType, Theme,
 };
 
 // Re-export file
@@ -145,9 +163,10 @@ pub use file::{
 
 // Re-export commerce
 pub use commerce::{
-    catch_phrase, company_bs, company_name, company_suffix, currency_code, currency_name,
-    currency_symbol, department, industry, job_area, job_descriptor, job_title, job_type, price,
-    price_formatted, product_adjective, product_category, product_material, product_name,
+    catch_phrase, company_bs, company_name, company_record, company_suffix, currency_code,
+    currency_name, currency_symbol, department, industry, job_area, job_descriptor, job_title,
+    job_type, price, price_formatted, product_adjective, product_category, product_material,
+    product_name,
 };
 
 // Re-export vehicle
@@ -187,8 +206,8 @@ pub use travel::{
 
 // Re-export healthcare
 pub use healthcare::{
-    blood_type, body_part, condition, doctor_title, hospital_name, medication, organ, specialty,
-    symptom,
+    blood_type, blood_type_weighted, body_part, condition, condition_weighted, doctor_title,
+    hospital_name, medication, organ, specialty, symptom,
 };
 
 // Re-export sports
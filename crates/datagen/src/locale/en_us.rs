@@ -2,6 +2,7 @@
 //!
 //! Provides US-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// US English locale marker type.
@@ -401,6 +402,14 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{:05}", rng.random_range(10000..99999))
 }
 
+/// Check a zip code: five digits, optionally followed by `-` and four more.
+pub fn validate_postal_code(code: &str) -> bool {
+    match code.split_once('-') {
+        Some((zip, plus4)) => is_digits(zip, 5) && is_digits(plus4, 4),
+        None => is_digits(code, 5),
+    }
+}
+
 /// Generate a zip code with +4 extension.
 pub fn postal_code_plus4<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!(
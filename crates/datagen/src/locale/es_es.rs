@@ -2,6 +2,7 @@
 //!
 //! Provides Spanish-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Spanish locale marker type.
@@ -362,6 +363,11 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{:05}", rng.random_range(1000..52999))
 }
 
+/// Check a postal code: five digits from `01000` to `52999`.
+pub fn validate_postal_code(code: &str) -> bool {
+    is_digits(code, 5) && ("01000"..="52999").contains(&code)
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
@@ -2,6 +2,7 @@
 //!
 //! Provides French-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// French locale marker type.
@@ -357,6 +358,11 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{:05}", rng.random_range(1000..99999))
 }
 
+/// Check a postal code: five digits, `01000` and up.
+pub fn validate_postal_code(code: &str) -> bool {
+    is_digits(code, 5) && code >= "01000"
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
@@ -2,6 +2,7 @@
 //!
 //! Provides Italian-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Italian locale marker type.
@@ -311,6 +312,11 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{:05}", rng.random_range(10000..99999))
 }
 
+/// Check a postal code (CAP): five digits.
+pub fn validate_postal_code(code: &str) -> bool {
+    is_digits(code, 5)
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
@@ -2,6 +2,7 @@
 //!
 //! Provides Japanese-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Japanese locale marker type.
@@ -248,6 +249,12 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     )
 }
 
+/// Check a postal code: `NNN-NNNN`.
+pub fn validate_postal_code(code: &str) -> bool {
+    code.split_once('-')
+        .is_some_and(|(a, b)| is_digits(a, 3) && is_digits(b, 4))
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let pref = prefecture(rng);
@@ -129,6 +129,26 @@ impl Locale {
         }
     }
 
+    /// Check whether `code` is a well-formed postal code for this locale.
+    ///
+    /// Accepts everything [`LocaleData::postal_code`] generates. Only the
+    /// format and numeric range are checked, not whether the code is in use.
+    pub fn validate_postal_code(&self, code: &str) -> bool {
+        match self {
+            Locale::EnUs => en_us::validate_postal_code(code),
+            Locale::NoNo => no_no::validate_postal_code(code),
+            Locale::DeDe => de_de::validate_postal_code(code),
+            Locale::FrFr => fr_fr::validate_postal_code(code),
+            Locale::EsEs => es_es::validate_postal_code(code),
+            Locale::JaJp => ja_jp::validate_postal_code(code),
+            Locale::ZhCn => zh_cn::validate_postal_code(code),
+            Locale::PtBr => pt_br::validate_postal_code(code),
+            Locale::ItIt => it_it::validate_postal_code(code),
+            Locale::NlNl => nl_nl::validate_postal_code(code),
+            Locale::SvSe => sv_se::validate_postal_code(code),
+        }
+    }
+
     /// Get all available locales.
     pub fn all() -> &'static [Locale] {
         &[
@@ -147,6 +167,11 @@ impl Locale {
     }
 }
 
+/// Whether `s` is exactly `len` ASCII digits.
+pub(crate) fn is_digits(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_digit())
+}
+
 /// Trait for locale-specific data generation.
 pub trait LocaleData {
     /// Get a random first name for this locale.
@@ -589,6 +614,68 @@ mod tests {
         assert!(all.contains(&Locale::SvSe));
     }
 
+    #[test]
+    fn test_validate_postal_code_round_trip() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for locale in Locale::all() {
+            for _ in 0..200 {
+                let code = locale.postal_code(&mut rng);
+                assert!(
+                    locale.validate_postal_code(&code),
+                    "{} rejected its own postal code {:?}",
+                    locale.code(),
+                    code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_postal_code_en_us() {
+        let us = Locale::EnUs;
+        assert!(us.validate_postal_code("90210"));
+        assert!(us.validate_postal_code("90210-1234"));
+        assert!(us.validate_postal_code(&en_us::postal_code_plus4(&mut StdRng::seed_from_u64(1))));
+        assert!(!us.validate_postal_code("9021"));
+        assert!(!us.validate_postal_code("902101"));
+        assert!(!us.validate_postal_code("90210-12"));
+        assert!(!us.validate_postal_code("9021O"));
+        assert!(!us.validate_postal_code(""));
+    }
+
+    #[test]
+    fn test_validate_postal_code_no_no() {
+        let no = Locale::NoNo;
+        assert!(no.validate_postal_code("0150"));
+        assert!(no.validate_postal_code("9990"));
+        assert!(!no.validate_postal_code("0000"));
+        assert!(!no.validate_postal_code("150"));
+        assert!(!no.validate_postal_code("01500"));
+        assert!(!no.validate_postal_code("N-0150"));
+    }
+
+    #[test]
+    fn test_validate_postal_code_nl_nl() {
+        let nl = Locale::NlNl;
+        assert!(nl.validate_postal_code("1012 AB"));
+        assert!(nl.validate_postal_code("1012AB"));
+        assert!(!nl.validate_postal_code("0123 AB"));
+        assert!(!nl.validate_postal_code("1012 ab"));
+        assert!(!nl.validate_postal_code("1012 A1"));
+        assert!(!nl.validate_postal_code("AB 1012"));
+        assert!(!nl.validate_postal_code("10€"));
+    }
+
+    #[test]
+    fn test_validate_postal_code_rejects_other_formats() {
+        assert!(!Locale::JaJp.validate_postal_code("1000001"));
+        assert!(Locale::JaJp.validate_postal_code("100-0001"));
+        assert!(!Locale::PtBr.validate_postal_code("01310-10"));
+        assert!(!Locale::EsEs.validate_postal_code("53000"));
+        assert!(!Locale::SvSe.validate_postal_code("011 20"));
+        assert!(!Locale::ZhCn.validate_postal_code("10000"));
+    }
+
     #[test]
     fn test_deterministic() {
         let mut rng1 = StdRng::seed_from_u64(42);
@@ -2,6 +2,7 @@
 //!
 //! Provides Dutch-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Dutch locale marker type.
@@ -249,6 +250,20 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{} {}", digits, letters)
 }
 
+/// Check a postal code: four digits not starting with `0`, an optional
+/// space, and two uppercase letters, as in `1234 AB`.
+pub fn validate_postal_code(code: &str) -> bool {
+    let (digits, letters) = match code.split_once(' ') {
+        Some(parts) => parts,
+        None if code.is_char_boundary(4) => code.split_at(4),
+        None => return false,
+    };
+    is_digits(digits, 4)
+        && !digits.starts_with('0')
+        && letters.len() == 2
+        && letters.bytes().all(|b| b.is_ascii_uppercase())
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
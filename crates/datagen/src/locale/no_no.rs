@@ -2,6 +2,7 @@
 //!
 //! Provides Norwegian-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Norwegian locale marker type.
@@ -349,6 +350,11 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{:04}", rng.random_range(1..9999))
 }
 
+/// Check a postal code: four digits, `0001` to `9999`.
+pub fn validate_postal_code(code: &str) -> bool {
+    is_digits(code, 4) && code != "0000"
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
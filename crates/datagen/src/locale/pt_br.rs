@@ -2,6 +2,7 @@
 //!
 //! Provides Brazilian-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Brazilian Portuguese locale marker type.
@@ -301,6 +302,12 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     )
 }
 
+/// Check a postal code (CEP): `NNNNN-NNN`.
+pub fn validate_postal_code(code: &str) -> bool {
+    code.split_once('-')
+        .is_some_and(|(a, b)| is_digits(a, 5) && is_digits(b, 3))
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
@@ -2,6 +2,7 @@
 //!
 //! Provides Swedish-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Swedish locale marker type.
@@ -295,6 +296,14 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     )
 }
 
+/// Check a postal code: `NNN NN`, with the space optional.
+pub fn validate_postal_code(code: &str) -> bool {
+    match code.split_once(' ') {
+        Some((a, b)) => is_digits(a, 3) && is_digits(b, 2) && !a.starts_with('0'),
+        None => is_digits(code, 5) && !code.starts_with('0'),
+    }
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let street = street_address(rng);
@@ -2,6 +2,7 @@
 //!
 //! Provides Chinese-specific names, addresses, phone numbers, and more.
 
+use super::is_digits;
 use rand::Rng;
 
 /// Chinese locale marker type.
@@ -175,6 +176,11 @@ pub fn postal_code<R: ?Sized + Rng>(rng: &mut R) -> String {
     format!("{:06}", rng.random_range(100000..999999))
 }
 
+/// Check a postal code: six digits.
+pub fn validate_postal_code(code: &str) -> bool {
+    is_digits(code, 6)
+}
+
 /// Generate a full address.
 pub fn full_address<R: ?Sized + Rng>(rng: &mut R) -> String {
     let prov = province(rng);
@@ -0,0 +1,253 @@
+//! Data masking.
+//!
+//! Replace personal data with random values of the same shape, so masked
+//! records still pass format checks. Separators and punctuation stay where
+//! they are; letters are replaced by letters of the same case and digits by
+//! digits.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::mask;
+//! use dx_datagen::numeric::validate_luhn;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let email = mask::mask_email(&mut rng, "jane.doe@example.com");
+//! assert!(email.ends_with("@example.com"));
+//!
+//! let card = mask::mask_credit_card(&mut rng, "4111 1111 1111 1111");
+//! assert!(card.ends_with("1111"));
+//! assert!(validate_luhn(&card));
+//! ```
+
+use rand::Rng;
+
+/// Mask the local part of an email address, keeping the domain.
+///
+/// Input without an `@` is masked as a whole.
+pub fn mask_email<R: ?Sized + Rng>(rng: &mut R, input: &str) -> String {
+    match input.rsplit_once('@') {
+        Some((local, domain)) => format!("{}@{}", scramble(rng, local), domain),
+        None => scramble(rng, input),
+    }
+}
+
+/// Mask a phone number, keeping its country code, area code and format.
+///
+/// A leading `+` group is the country code. The next digit group is kept as
+/// the area code when at least two more groups follow it, as in
+/// `+1 (555) 123-4567` or `555-123-4567`. All other digits are replaced.
+pub fn mask_phone<R: ?Sized + Rng>(rng: &mut R, input: &str) -> String {
+    let groups = digit_groups(input);
+    let mut keep = 0;
+    if input.trim_start().starts_with('+') && !groups.is_empty() {
+        keep += 1;
+    }
+    if groups.len() >= keep + 3 {
+        keep += 1;
+    }
+    let keep_until = if keep == 0 { 0 } else { groups[keep - 1].1 };
+
+    input
+        .char_indices()
+        .map(|(i, c)| {
+            if i >= keep_until && c.is_ascii_digit() {
+                random_digit(rng)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Mask a card number, keeping the last four digits and Luhn validity.
+///
+/// Spaces and dashes stay in place. Numbers with four digits or fewer are
+/// returned unchanged.
+pub fn mask_credit_card<R: ?Sized + Rng>(rng: &mut R, input: &str) -> String {
+    let mut digits: Vec<u32> = input.chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() <= 4 {
+        return input.to_string();
+    }
+
+    let masked = digits.len() - 4;
+    for d in &mut digits[..masked] {
+        *d = rng.random_range(0..10);
+    }
+
+    // Fix the digit just before the kept ones so the checksum works out.
+    // Luhn maps each digit 0-9 to a distinct contribution, so exactly one
+    // value fits.
+    let fix = masked - 1;
+    for d in 0..10 {
+        digits[fix] = d;
+        if luhn_sum(&digits).is_multiple_of(10) {
+            break;
+        }
+    }
+
+    let mut digits = digits.into_iter();
+    input
+        .chars()
+        .map(|c| {
+            if c.is_ascii_digit() {
+                digits
+                    .next()
+                    .and_then(|d| char::from_digit(d, 10))
+                    .unwrap_or(c)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+/// Replace each letter with a random letter of the same case and each digit
+/// with a random digit.
+fn scramble<R: ?Sized + Rng>(rng: &mut R, s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_lowercase() {
+                rng.random_range(b'a'..=b'z') as char
+            } else if c.is_ascii_uppercase() {
+                rng.random_range(b'A'..=b'Z') as char
+            } else if c.is_ascii_digit() {
+                random_digit(rng)
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn random_digit<R: ?Sized + Rng>(rng: &mut R) -> char {
+    rng.random_range(b'0'..=b'9') as char
+}
+
+/// Byte ranges `(start, end)` of each run of ASCII digits.
+fn digit_groups(s: &str) -> Vec<(usize, usize)> {
+    let mut groups = Vec::new();
+    let mut start = None;
+    for (i, c) in s.char_indices() {
+        match (c.is_ascii_digit(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(st)) => {
+                groups.push((st, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(st) = start {
+        groups.push((st, s.len()));
+    }
+    groups
+}
+
+fn luhn_sum(digits: &[u32]) -> u32 {
+    digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                d
+            }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::numeric::validate_luhn;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn same_shape(a: &str, b: &str) -> bool {
+        a.len() == b.len()
+            && a.chars().zip(b.chars()).all(|(x, y)| {
+                (x.is_ascii_digit() && y.is_ascii_digit())
+                    || (x.is_ascii_lowercase() && y.is_ascii_lowercase())
+                    || (x.is_ascii_uppercase() && y.is_ascii_uppercase())
+                    || x == y
+            })
+    }
+
+    #[test]
+    fn test_mask_email_keeps_domain() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let input = "Jane.Doe+news42@mail.example.com";
+        let masked = mask_email(&mut rng, input);
+        assert!(masked.ends_with("@mail.example.com"));
+        assert_ne!(masked, input);
+        assert!(same_shape(&masked, input));
+    }
+
+    #[test]
+    fn test_mask_email_without_at() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let masked = mask_email(&mut rng, "janedoe");
+        assert_eq!(masked.len(), 7);
+        assert!(!masked.contains('@'));
+    }
+
+    #[test]
+    fn test_mask_credit_card_keeps_last_four_and_luhn() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for input in [
+            "4111111111111111",
+            "4111-1111-1111-1234",
+            "3782 822463 10005",
+        ] {
+            for _ in 0..50 {
+                let masked = mask_credit_card(&mut rng, input);
+                assert_eq!(&masked[masked.len() - 4..], &input[input.len() - 4..]);
+                assert!(validate_luhn(&masked), "{} is not Luhn-valid", masked);
+                assert!(same_shape(&masked, input));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mask_credit_card_short_input_unchanged() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(mask_credit_card(&mut rng, "1234"), "1234");
+    }
+
+    #[test]
+    fn test_mask_phone_keeps_country_and_area_code() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let masked = mask_phone(&mut rng, "+1 (555) 123-4567");
+        assert!(masked.starts_with("+1 (555) "));
+        assert!(same_shape(&masked, "+1 (555) 123-4567"));
+
+        let masked = mask_phone(&mut rng, "555-123-4567");
+        assert!(masked.starts_with("555-"));
+
+        // Country code only; no area code group
+        let masked = mask_phone(&mut rng, "+47 91234567");
+        assert!(masked.starts_with("+47 "));
+        assert!(same_shape(&masked, "+47 91234567"));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        assert_eq!(
+            mask_email(&mut rng1, "a.b@c.com"),
+            mask_email(&mut rng2, "a.b@c.com")
+        );
+    }
+}
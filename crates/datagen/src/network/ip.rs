@@ -152,6 +152,77 @@ pub fn ipv6_unique_local<R: ?Sized + Rng>(rng: &mut R) -> Ipv6Addr {
     )
 }
 
+/// Generate a random unique local IPv6 address (fc00::/7).
+///
+/// Same as [`ipv6_unique_local`].
+pub fn ipv6_ula<R: ?Sized + Rng>(rng: &mut R) -> Ipv6Addr {
+    ipv6_unique_local(rng)
+}
+
+/// Generate a random documentation IPv6 address (2001:db8::/32, RFC 3849).
+pub fn ipv6_documentation<R: ?Sized + Rng>(rng: &mut R) -> Ipv6Addr {
+    let (network, prefix) = Ipv6Scope::Documentation.network();
+    ipv6_in_subnet(rng, network, prefix)
+}
+
+/// Generate an IPv6 address within a subnet.
+///
+/// # Arguments
+///
+/// * `network` - The network address (e.g., "2001:db8::")
+/// * `prefix` - The subnet prefix length (e.g., 32 for /32)
+pub fn ipv6_in_subnet<R: ?Sized + Rng>(rng: &mut R, network: Ipv6Addr, prefix: u8) -> Ipv6Addr {
+    let mask = ipv6_mask(prefix);
+    Ipv6Addr::from((u128::from(network) & mask) | (rng.random::<u128>() & !mask))
+}
+
+/// A special-purpose IPv6 address range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ipv6Scope {
+    /// Documentation addresses: 2001:db8::/32
+    Documentation,
+    /// Unique local addresses: fc00::/7
+    UniqueLocal,
+    /// Link-local addresses: fe80::/10
+    LinkLocal,
+}
+
+impl Ipv6Scope {
+    /// The network address and prefix length of the range.
+    pub fn network(self) -> (Ipv6Addr, u8) {
+        match self {
+            Self::Documentation => (Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0), 32),
+            Self::UniqueLocal => (Ipv6Addr::new(0xfc00, 0, 0, 0, 0, 0, 0, 0), 7),
+            Self::LinkLocal => (Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0), 10),
+        }
+    }
+}
+
+/// Check if an IPv6 address lies within a special-purpose range.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::network::ip::{ipv6_is_in_scope, Ipv6Scope};
+/// use std::net::Ipv6Addr;
+///
+/// let ip: Ipv6Addr = "fd12:3456::1".parse().unwrap();
+/// assert!(ipv6_is_in_scope(&ip, Ipv6Scope::UniqueLocal));
+/// assert!(!ipv6_is_in_scope(&ip, Ipv6Scope::LinkLocal));
+/// ```
+pub fn ipv6_is_in_scope(ip: &Ipv6Addr, scope: Ipv6Scope) -> bool {
+    let (network, prefix) = scope.network();
+    let mask = ipv6_mask(prefix);
+    u128::from(*ip) & mask == u128::from(network) & mask
+}
+
+/// Network mask for an IPv6 prefix length.
+fn ipv6_mask(prefix: u8) -> u128 {
+    u128::MAX
+        .checked_shl(128 - u32::from(prefix.min(128)))
+        .unwrap_or(0)
+}
+
 /// Generate an IPv4 address as a string.
 pub fn ipv4_string<R: ?Sized + Rng>(rng: &mut R) -> String {
     ipv4(rng).to_string()
@@ -242,6 +313,53 @@ mod tests {
         assert_eq!(ip.segments()[0], 0xfe80);
     }
 
+    #[test]
+    fn test_ipv6_ula() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..50 {
+            let ip = ipv6_ula(&mut rng);
+            let text = ip.to_string();
+            assert!(text.starts_with("fc") || text.starts_with("fd"), "{}", text);
+            assert!(ipv6_is_in_scope(&ip, Ipv6Scope::UniqueLocal));
+        }
+    }
+
+    #[test]
+    fn test_ipv6_documentation() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..50 {
+            let ip = ipv6_documentation(&mut rng);
+            assert_eq!(&ip.segments()[..2], &[0x2001, 0x0db8]);
+            assert!(ipv6_is_in_scope(&ip, Ipv6Scope::Documentation));
+            assert!(!ipv6_is_in_scope(&ip, Ipv6Scope::UniqueLocal));
+        }
+    }
+
+    #[test]
+    fn test_ipv6_scopes() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let ip = ipv6_link_local(&mut rng);
+        assert!(ipv6_is_in_scope(&ip, Ipv6Scope::LinkLocal));
+
+        let edge: Ipv6Addr = "febf:ffff::1".parse().unwrap();
+        assert!(ipv6_is_in_scope(&edge, Ipv6Scope::LinkLocal));
+        let outside: Ipv6Addr = "fec0::1".parse().unwrap();
+        assert!(!ipv6_is_in_scope(&outside, Ipv6Scope::LinkLocal));
+        let outside: Ipv6Addr = "2001:db9::1".parse().unwrap();
+        assert!(!ipv6_is_in_scope(&outside, Ipv6Scope::Documentation));
+
+        assert_eq!(ipv6_mask(0), 0);
+        assert_eq!(ipv6_mask(128), u128::MAX);
+    }
+
+    #[test]
+    fn test_ipv6_in_subnet() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let network: Ipv6Addr = "2001:db8:abcd::".parse().unwrap();
+        let ip = ipv6_in_subnet(&mut rng, network, 48);
+        assert_eq!(&ip.segments()[..3], &[0x2001, 0x0db8, 0xabcd]);
+    }
+
     #[test]
     fn test_deterministic() {
         let mut rng1 = StdRng::seed_from_u64(42);
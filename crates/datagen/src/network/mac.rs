@@ -90,30 +90,24 @@ pub fn mac_address_cisco<R: ?Sized + Rng>(rng: &mut R) -> String {
 ///
 /// The second least significant bit of the first octet is set to 1.
 pub fn mac_address_local<R: ?Sized + Rng>(rng: &mut R) -> String {
-    let first_byte = (rng.random::<u8>() | 0x02) & 0xFE; // Set local bit, clear multicast bit
-    let bytes: [u8; 6] = [
-        first_byte,
-        rng.random(),
-        rng.random(),
-        rng.random(),
-        rng.random(),
-        rng.random(),
-    ];
-    format!(
-        "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
-        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5]
-    )
+    mac_address_administered(rng, MacAdministration::Local)
 }
 
 /// Generate a universally administered MAC address.
 ///
 /// The second least significant bit of the first octet is cleared.
 pub fn mac_address_universal<R: ?Sized + Rng>(rng: &mut R) -> String {
-    let first_byte = rng.random::<u8>() & 0xFC; // Clear local and multicast bits
+    mac_address_administered(rng, MacAdministration::Universal)
+}
+
+/// Generate a MAC address with a specific OUI (Organizationally Unique Identifier).
+///
+/// The OUI is the first 3 bytes of the MAC address.
+pub fn mac_address_with_oui<R: ?Sized + Rng>(rng: &mut R, oui: [u8; 3]) -> String {
     let bytes: [u8; 6] = [
-        first_byte,
-        rng.random(),
-        rng.random(),
+        oui[0],
+        oui[1],
+        oui[2],
         rng.random(),
         rng.random(),
         rng.random(),
@@ -124,14 +118,39 @@ pub fn mac_address_universal<R: ?Sized + Rng>(rng: &mut R) -> String {
     )
 }
 
-/// Generate a MAC address with a specific OUI (Organizationally Unique Identifier).
+/// Generate a MAC address with a specific OUI.
 ///
-/// The OUI is the first 3 bytes of the MAC address.
-pub fn mac_address_with_oui<R: ?Sized + Rng>(rng: &mut R, oui: [u8; 3]) -> String {
+/// Same as [`mac_address_with_oui`].
+pub fn mac_with_oui<R: ?Sized + Rng>(rng: &mut R, oui: [u8; 3]) -> String {
+    mac_address_with_oui(rng, oui)
+}
+
+/// How the first octet of a MAC address marks who assigned it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MacAdministration {
+    /// Assigned by the manufacturer (local bit cleared)
+    #[default]
+    Universal,
+    /// Assigned locally, e.g. by a hypervisor or randomized Wi-Fi (local bit set)
+    Local,
+}
+
+/// Generate a unicast MAC address with the given administration bit.
+///
+/// The multicast bit (least significant bit of the first octet) is always
+/// cleared.
+pub fn mac_address_administered<R: ?Sized + Rng>(
+    rng: &mut R,
+    administration: MacAdministration,
+) -> String {
+    let first_byte = match administration {
+        MacAdministration::Universal => rng.random::<u8>() & 0xFC,
+        MacAdministration::Local => (rng.random::<u8>() | 0x02) & 0xFE,
+    };
     let bytes: [u8; 6] = [
-        oui[0],
-        oui[1],
-        oui[2],
+        first_byte,
+        rng.random(),
+        rng.random(),
         rng.random(),
         rng.random(),
         rng.random(),
@@ -144,7 +163,18 @@ pub fn mac_address_with_oui<R: ?Sized + Rng>(rng: &mut R, oui: [u8; 3]) -> Strin
 
 /// Common vendor OUIs.
 pub const VENDOR_OUIS: &[([u8; 3], &str)] = &[
-    ([0x00, 0x1A, 0x2B], "Apple"),
+    ([0x00, 0x03, 0x93], "Apple"),
+    ([0x00, 0x1E, 0xC2], "Apple"),
+    ([0x3C, 0x07, 0x54], "Apple"),
+    ([0xA4, 0x5E, 0x60], "Apple"),
+    ([0xF0, 0x18, 0x98], "Apple"),
+    ([0x00, 0x12, 0xFB], "Samsung"),
+    ([0x5C, 0x0A, 0x5B], "Samsung"),
+    ([0x00, 0x1B, 0x21], "Intel"),
+    ([0x3C, 0xFD, 0xFE], "Intel"),
+    ([0x00, 0x1B, 0x54], "Cisco"),
+    ([0xB8, 0x27, 0xEB], "Raspberry Pi"),
+    ([0xDC, 0xA6, 0x32], "Raspberry Pi"),
     ([0x00, 0x50, 0x56], "VMware"),
     ([0x00, 0x0C, 0x29], "VMware"),
     ([0x00, 0x15, 0x5D], "Microsoft"),
@@ -159,6 +189,36 @@ pub fn mac_address_vendor<R: ?Sized + Rng>(rng: &mut R) -> String {
     mac_address_with_oui(rng, oui)
 }
 
+/// Generate a MAC address with one of `vendor`'s OUIs from [`VENDOR_OUIS`].
+///
+/// The vendor name is matched case-insensitively. Returns `None` for
+/// vendors not in the table.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::network::mac::mac_for_vendor;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let mac = mac_for_vendor(&mut rng, "Apple").unwrap();
+/// assert_eq!(mac.len(), 17);
+/// assert!(mac_for_vendor(&mut rng, "Acme").is_none());
+/// ```
+pub fn mac_for_vendor<R: ?Sized + Rng>(rng: &mut R, vendor: &str) -> Option<String> {
+    let ouis: Vec<[u8; 3]> = VENDOR_OUIS
+        .iter()
+        .filter(|(_, name)| name.eq_ignore_ascii_case(vendor))
+        .map(|(oui, _)| *oui)
+        .collect();
+    if ouis.is_empty() {
+        return None;
+    }
+    let oui = ouis[rng.random_range(0..ouis.len())];
+    Some(mac_address_with_oui(rng, oui))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +274,49 @@ mod tests {
         assert!(mac.starts_with("00:50:56:"));
     }
 
+    #[test]
+    fn test_mac_for_vendor() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let apple: Vec<[u8; 3]> = VENDOR_OUIS
+            .iter()
+            .filter(|(_, name)| *name == "Apple")
+            .map(|(oui, _)| *oui)
+            .collect();
+        for _ in 0..20 {
+            let mac = mac_for_vendor(&mut rng, "apple").unwrap();
+            let bytes: Vec<u8> = mac
+                .split(':')
+                .map(|b| u8::from_str_radix(b, 16).unwrap())
+                .collect();
+            assert!(apple.contains(&[bytes[0], bytes[1], bytes[2]]), "{}", mac);
+            assert_eq!(bytes[0] & 0x01, 0, "multicast bit set: {}", mac);
+        }
+        assert_eq!(mac_for_vendor(&mut rng, "Acme"), None);
+    }
+
+    #[test]
+    fn test_vendor_ouis_are_universal_unicast() {
+        for (oui, vendor) in VENDOR_OUIS {
+            if *vendor != "QEMU/KVM" {
+                assert_eq!(oui[0] & 0x03, 0, "{} {:02X?}", vendor, oui);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mac_address_administered() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let local = mac_address_administered(&mut rng, MacAdministration::Local);
+            let first = u8::from_str_radix(&local[0..2], 16).unwrap();
+            assert_eq!(first & 0x03, 0x02);
+
+            let universal = mac_address_administered(&mut rng, MacAdministration::Universal);
+            let first = u8::from_str_radix(&universal[0..2], 16).unwrap();
+            assert_eq!(first & 0x03, 0x00);
+        }
+    }
+
     #[test]
     fn test_deterministic() {
         let mut rng1 = StdRng::seed_from_u64(42);
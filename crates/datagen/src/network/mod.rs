@@ -8,6 +8,9 @@ pub mod mac;
 pub mod url;
 
 pub use domain::{domain, subdomain, tld};
-pub use ip::{ipv4, ipv4_private, ipv4_public, ipv6};
-pub use mac::mac_address;
+pub use ip::{
+    ipv4, ipv4_private, ipv4_public, ipv6, ipv6_documentation, ipv6_is_in_scope, ipv6_ula,
+    Ipv6Scope,
+};
+pub use mac::{mac_address, mac_for_vendor, mac_with_oui, MacAdministration};
 pub use url::{url, url_https, url_with_path};
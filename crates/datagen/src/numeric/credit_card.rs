@@ -15,6 +15,7 @@
 //! assert!(validate_luhn(&visa));
 //! ```
 
+use super::format::NumberFormat;
 use rand::Rng;
 
 /// Credit card types with their IIN (Issuer Identification Number) prefixes.
@@ -149,6 +150,24 @@ pub fn credit_card_type<R: ?Sized + Rng>(rng: &mut R, card_type: CardType) -> St
     number
 }
 
+/// Generate a credit card number (any type) grouped by `format`.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::numeric::{credit_card_formatted, validate_luhn, NumberFormat};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let card = credit_card_formatted(&mut rng, NumberFormat::new(4, '-'));
+/// assert_eq!(card.chars().nth(4), Some('-'));
+/// assert!(validate_luhn(&card));
+/// ```
+pub fn credit_card_formatted<R: ?Sized + Rng>(rng: &mut R, format: NumberFormat) -> String {
+    format.apply(&credit_card(rng))
+}
+
 /// Generate a Visa card number.
 pub fn visa<R: ?Sized + Rng>(rng: &mut R) -> String {
     credit_card_type(rng, CardType::Visa)
@@ -206,6 +225,22 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
 
+    #[test]
+    fn test_credit_card_formatted() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let card = credit_card_formatted(&mut rng, NumberFormat::new(4, ' '));
+            let digits: String = card.chars().filter(|c| *c != ' ').collect();
+            assert!(validate_luhn(&digits));
+            for (i, c) in card.chars().enumerate() {
+                assert_eq!(c == ' ', i % 5 == 4, "{}", card);
+            }
+        }
+
+        let plain = credit_card_formatted(&mut rng, NumberFormat::plain());
+        assert!(plain.chars().all(|c| c.is_ascii_digit()));
+    }
+
     #[test]
     fn test_validate_luhn_valid() {
         assert!(validate_luhn("4532015112830366")); // Valid Visa
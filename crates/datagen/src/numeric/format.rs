@@ -0,0 +1,90 @@
+//! Grouping and separators for numeric identifiers.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::numeric::NumberFormat;
+//!
+//! let format = NumberFormat::new(4, '-');
+//! assert_eq!(format.apply("4111111111111111"), "4111-1111-1111-1111");
+//! assert_eq!(NumberFormat::plain().apply("4111 1111"), "41111111");
+//! ```
+
+/// How to group the characters of an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Characters per group; 0 disables grouping
+    pub group_size: usize,
+    /// Placed between groups
+    pub separator: char,
+}
+
+impl NumberFormat {
+    /// Groups of `group_size` characters joined by `separator`.
+    pub fn new(group_size: usize, separator: char) -> Self {
+        Self {
+            group_size,
+            separator,
+        }
+    }
+
+    /// No grouping: the bare digits and letters.
+    pub fn plain() -> Self {
+        Self::new(0, ' ')
+    }
+
+    /// Regroup `value`, dropping any separators it already has.
+    ///
+    /// Only ASCII letters and digits are kept.
+    pub fn apply(&self, value: &str) -> String {
+        let chars: Vec<char> = value
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect();
+        if self.group_size == 0 {
+            return chars.into_iter().collect();
+        }
+
+        let mut out = String::with_capacity(chars.len() + chars.len() / self.group_size);
+        for (i, group) in chars.chunks(self.group_size).enumerate() {
+            if i > 0 {
+                out.push(self.separator);
+            }
+            out.extend(group);
+        }
+        out
+    }
+}
+
+/// Groups of four separated by spaces.
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new(4, ' ')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let format = NumberFormat::new(3, '.');
+        assert_eq!(format.apply("123456789"), "123.456.789");
+        assert_eq!(format.apply("1234567"), "123.456.7");
+        assert_eq!(format.apply("12-34 5"), "123.45");
+        assert_eq!(format.apply(""), "");
+    }
+
+    #[test]
+    fn test_default_and_plain() {
+        assert_eq!(
+            NumberFormat::default().apply("NO9386011117947"),
+            "NO93 8601 1117 947"
+        );
+        assert_eq!(
+            NumberFormat::plain().apply("NO93 8601 1117 947"),
+            "NO9386011117947"
+        );
+    }
+}
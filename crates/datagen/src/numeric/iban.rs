@@ -15,6 +15,7 @@
 //! assert!(validate_iban(&iban_no));
 //! ```
 
+use super::format::NumberFormat;
 use rand::Rng;
 
 /// Country IBAN specifications: (country_code, total_length, bban_format).
@@ -97,6 +98,13 @@ fn mod97(number: &str) -> u32 {
     remainder
 }
 
+/// Generate a random IBAN grouped by `format`.
+///
+/// `NumberFormat::default()` gives the usual print form in groups of four.
+pub fn iban_formatted<R: ?Sized + Rng>(rng: &mut R, format: NumberFormat) -> String {
+    format.apply(&iban(rng))
+}
+
 /// Validate an IBAN number.
 pub fn validate_iban(iban: &str) -> bool {
     let cleaned: String = iban.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
@@ -170,6 +178,21 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
 
+    #[test]
+    fn test_iban_formatted() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..20 {
+            let iban = iban_formatted(&mut rng, NumberFormat::new(4, '-'));
+            assert!(validate_iban(&iban), "{}", iban);
+            let groups: Vec<&str> = iban.split('-').collect();
+            assert!(groups[..groups.len() - 1].iter().all(|g| g.len() == 4));
+            assert!((1..=4).contains(&groups.last().unwrap().len()));
+        }
+
+        let grouped = iban_formatted(&mut rng, NumberFormat::default());
+        assert_eq!(grouped, format_iban(&grouped));
+    }
+
     #[test]
     fn test_validate_iban_valid() {
         // Known valid IBANs (test examples)
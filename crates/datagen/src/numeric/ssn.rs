@@ -15,6 +15,7 @@
 //! let no_ssn = ssn_no(&mut rng);
 //! ```
 
+use super::format::NumberFormat;
 use rand::Rng;
 
 /// Generate a US Social Security Number (XXX-XX-XXXX format).
@@ -42,6 +43,14 @@ pub fn ssn_us_plain<R: ?Sized + Rng>(rng: &mut R) -> String {
     ssn_us(rng).replace('-', "")
 }
 
+/// Generate a US SSN grouped by `format` instead of the usual 3-2-4 form.
+///
+/// Groups are all the same size, so `NumberFormat::new(3, ' ')` gives
+/// `123 45 6789` as `123 456 789`.
+pub fn ssn_us_formatted<R: ?Sized + Rng>(rng: &mut R, format: NumberFormat) -> String {
+    format.apply(&ssn_us(rng))
+}
+
 /// Generate a Norwegian national identity number (fødselsnummer).
 ///
 /// Format: DDMMYYXXXCC where:
@@ -187,6 +196,21 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
 
+    #[test]
+    fn test_ssn_us_formatted() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let ssn = ssn_us_formatted(&mut rng, NumberFormat::new(3, ' '));
+        assert_eq!(ssn.len(), 11);
+        assert_eq!(
+            (ssn.chars().nth(3), ssn.chars().nth(7)),
+            (Some(' '), Some(' '))
+        );
+
+        let plain = ssn_us_formatted(&mut rng, NumberFormat::plain());
+        assert_eq!(plain.len(), 9);
+        assert_eq!(format_ssn_us(&plain).len(), 11);
+    }
+
     #[test]
     fn test_ssn_us() {
         let mut rng = StdRng::seed_from_u64(42);
@@ -0,0 +1,221 @@
+//! QR code payload generation.
+//!
+//! Generate realistic strings in the formats QR scanners understand:
+//! vCard contacts, WiFi network credentials, geo URIs, and URLs.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::payloads;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let card = payloads::vcard(&mut rng);
+//! assert!(card.starts_with("BEGIN:VCARD"));
+//!
+//! let wifi = payloads::wifi(&mut rng);
+//! assert!(wifi.starts_with("WIFI:"));
+//!
+//! let geo = payloads::geo_uri(&mut rng);
+//! assert!(geo.starts_with("geo:"));
+//! ```
+
+use crate::geo::point::coordinates;
+use crate::password::password;
+use crate::personal::names::last_name;
+use crate::personal::profile::{profile, Profile};
+use crate::text::words::noun;
+use rand::Rng;
+
+/// Suffixes appended to generated WiFi network names.
+pub const SSID_SUFFIXES: &[&str] = &["Home", "5G", "2.4G", "Guest", "Net", "WiFi", "Office"];
+
+/// Generate a vCard 3.0 contact from a freshly generated profile.
+pub fn vcard<R: ?Sized + Rng>(rng: &mut R) -> String {
+    vcard_from_profile(&profile(rng))
+}
+
+/// Format an existing profile as a vCard 3.0 contact.
+///
+/// Lines are separated by CRLF as required by RFC 2426.
+/// `ORG` and `TITLE` are omitted when the profile has no job.
+pub fn vcard_from_profile(p: &Profile) -> String {
+    let addr = &p.address;
+    [
+        Some("BEGIN:VCARD".to_string()),
+        Some("VERSION:3.0".to_string()),
+        Some(format!(
+            "N:{};{};;;",
+            escape_vcard(&p.last_name),
+            escape_vcard(&p.first_name)
+        )),
+        Some(format!("FN:{}", escape_vcard(&p.full_name()))),
+        p.company
+            .as_ref()
+            .map(|company| format!("ORG:{}", escape_vcard(company))),
+        p.job_title
+            .as_ref()
+            .map(|title| format!("TITLE:{}", escape_vcard(title))),
+        Some(format!("TEL;TYPE=CELL:{}", p.phone)),
+        Some(format!("EMAIL;TYPE=INTERNET:{}", p.email)),
+        Some(format!(
+            "ADR;TYPE=HOME:;;{};{};{};{};{}",
+            escape_vcard(&addr.street),
+            escape_vcard(&addr.city),
+            escape_vcard(&addr.state),
+            escape_vcard(&addr.zip),
+            escape_vcard(&addr.country)
+        )),
+        Some("END:VCARD".to_string()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join("\r\n")
+}
+
+/// Generate a WPA WiFi network payload (`WIFI:T:WPA;S:<ssid>;P:<password>;;`).
+pub fn wifi<R: ?Sized + Rng>(rng: &mut R) -> String {
+    let ssid = ssid(rng);
+    let pass = password(rng, 16, false);
+    format!(
+        "WIFI:T:WPA;S:{};P:{};;",
+        escape_wifi(&ssid),
+        escape_wifi(&pass)
+    )
+}
+
+/// Generate a WiFi network name (e.g., "Johnson-5G").
+pub fn ssid<R: ?Sized + Rng>(rng: &mut R) -> String {
+    let base = if rng.random_bool(0.5) {
+        last_name(rng).to_string()
+    } else {
+        let n = noun(rng);
+        format!("{}{}", n[..1].to_uppercase(), &n[1..])
+    };
+    let suffix = SSID_SUFFIXES[rng.random_range(0..SSID_SUFFIXES.len())];
+    format!("{}-{}", base, suffix)
+}
+
+/// Generate an RFC 5870 geo URI (e.g., "geo:59.913900,10.752200").
+pub fn geo_uri<R: ?Sized + Rng>(rng: &mut R) -> String {
+    let (lon, lat) = coordinates(rng);
+    format!("geo:{:.6},{:.6}", lat, lon)
+}
+
+/// Generate an HTTPS URL payload.
+pub fn url<R: ?Sized + Rng>(rng: &mut R) -> String {
+    crate::network::url_https(rng)
+}
+
+/// Escape text values for vCard (backslash, comma, semicolon, newline).
+fn escape_vcard(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' | ',' | ';' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Escape values for the WiFi payload (backslash, semicolon, comma, colon, quote).
+fn escape_wifi(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '\\' | ';' | ',' | ':' | '"') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_vcard() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let card = vcard(&mut rng);
+        assert!(card.starts_with("BEGIN:VCARD\r\n"));
+        assert!(card.ends_with("END:VCARD"));
+        assert!(card.contains("VERSION:3.0"));
+        assert!(card.contains("\r\nFN:"));
+        assert!(card.contains("\r\nEMAIL;TYPE=INTERNET:"));
+    }
+
+    #[test]
+    fn test_vcard_from_profile() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let p = profile(&mut rng);
+        let card = vcard_from_profile(&p);
+        assert!(card.contains(&format!("FN:{}", p.full_name())));
+        assert!(card.contains(&p.email));
+    }
+
+    #[test]
+    fn test_vcard_omits_job_for_minor() {
+        use crate::personal::profile::profile_with_age;
+        let mut rng = StdRng::seed_from_u64(42);
+        let card = vcard_from_profile(&profile_with_age(&mut rng, 10));
+        assert!(!card.contains("\r\nORG:"));
+        assert!(!card.contains("\r\nTITLE:"));
+
+        let card = vcard_from_profile(&profile_with_age(&mut rng, 40));
+        assert!(card.contains("\r\nORG:"));
+        assert!(card.contains("\r\nTITLE:"));
+    }
+
+    #[test]
+    fn test_wifi() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let w = wifi(&mut rng);
+        assert!(w.starts_with("WIFI:T:WPA;"));
+        assert!(w.contains(";S:"));
+        assert!(w.contains(";P:"));
+        assert!(w.ends_with(";;"));
+    }
+
+    #[test]
+    fn test_escape_wifi() {
+        assert_eq!(escape_wifi("a;b:c"), "a\\;b\\:c");
+        assert_eq!(escape_vcard("Acme, Inc."), "Acme\\, Inc.");
+    }
+
+    #[test]
+    fn test_geo_uri() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let g = geo_uri(&mut rng);
+        let coords = g.strip_prefix("geo:").unwrap();
+        let (lat, lon) = coords.split_once(',').unwrap();
+        let lat: f64 = lat.parse().unwrap();
+        let lon: f64 = lon.parse().unwrap();
+        assert!((-90.0..=90.0).contains(&lat));
+        assert!((-180.0..=180.0).contains(&lon));
+    }
+
+    #[test]
+    fn test_url() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert!(url(&mut rng).starts_with("https://"));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+        assert_eq!(vcard(&mut rng1), vcard(&mut rng2));
+        assert_eq!(wifi(&mut rng1), wifi(&mut rng2));
+    }
+}
@@ -124,6 +124,101 @@ pub fn phone_for_country<R: ?Sized + Rng>(rng: &mut R, country: &str) -> String
     from_pattern(rng, format)
 }
 
+/// Calling code prefixes and the ISO 3166-1 region they belong to.
+///
+/// The North American Numbering Plan shares `+1` between the US, Canada and
+/// much of the Caribbean, so a few Canadian area codes are listed as longer
+/// prefixes and every other `+1` number maps to `US`. In the same way `+7`
+/// is Russia except for Kazakhstan's `+76`/`+77`, and `+47 79` is Svalbard.
+pub const CALLING_CODE_REGIONS: &[(&str, &str)] = &[
+    ("1", "US"),
+    ("1204", "CA"),
+    ("1403", "CA"),
+    ("1416", "CA"),
+    ("1514", "CA"),
+    ("1604", "CA"),
+    ("1613", "CA"),
+    ("1647", "CA"),
+    ("1780", "CA"),
+    ("7", "RU"),
+    ("76", "KZ"),
+    ("77", "KZ"),
+    ("20", "EG"),
+    ("27", "ZA"),
+    ("30", "GR"),
+    ("31", "NL"),
+    ("32", "BE"),
+    ("33", "FR"),
+    ("34", "ES"),
+    ("351", "PT"),
+    ("353", "IE"),
+    ("354", "IS"),
+    ("358", "FI"),
+    ("36", "HU"),
+    ("39", "IT"),
+    ("41", "CH"),
+    ("43", "AT"),
+    ("44", "GB"),
+    ("45", "DK"),
+    ("46", "SE"),
+    ("47", "NO"),
+    ("4779", "SJ"),
+    ("48", "PL"),
+    ("49", "DE"),
+    ("52", "MX"),
+    ("54", "AR"),
+    ("55", "BR"),
+    ("61", "AU"),
+    ("64", "NZ"),
+    ("65", "SG"),
+    ("81", "JP"),
+    ("82", "KR"),
+    ("86", "CN"),
+    ("90", "TR"),
+    ("91", "IN"),
+    ("234", "NG"),
+    ("254", "KE"),
+    ("255", "TZ"),
+    ("256", "UG"),
+    ("260", "ZM"),
+    ("263", "ZW"),
+    ("880", "BD"),
+    ("971", "AE"),
+];
+
+/// Infer the ISO region of an E.164 phone number from its calling code.
+///
+/// The longest matching prefix in [`CALLING_CODE_REGIONS`] wins. Spaces,
+/// dashes, dots and parentheses are ignored; anything else that is not a
+/// digit after the leading `+` gives `None`.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::personal::phone::region_for;
+///
+/// assert_eq!(region_for("+47 912 34 567"), Some("NO"));
+/// assert_eq!(region_for("+1 (416) 555-0100"), Some("CA"));
+/// assert_eq!(region_for("+999"), None);
+/// ```
+pub fn region_for(e164: &str) -> Option<&'static str> {
+    let rest = e164.trim().strip_prefix('+')?;
+    let mut digits = String::with_capacity(rest.len());
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => digits.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {}
+            _ => return None,
+        }
+    }
+
+    CALLING_CODE_REGIONS
+        .iter()
+        .filter(|(prefix, _)| digits.starts_with(prefix))
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(_, region)| *region)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,6 +276,42 @@ mod tests {
         assert!(no.starts_with("+47"));
     }
 
+    #[test]
+    fn test_region_for() {
+        assert_eq!(region_for("+4791234567"), Some("NO"));
+        assert_eq!(region_for("+47 79 02 12 34"), Some("SJ"));
+        assert_eq!(region_for("+46 70 123 45 67"), Some("SE"));
+        assert_eq!(region_for("+77012345678"), Some("KZ"));
+        assert_eq!(region_for("+74951234567"), Some("RU"));
+        assert_eq!(region_for("+263 77 123 4567"), Some("ZW"));
+    }
+
+    #[test]
+    fn test_region_for_nanp() {
+        // +1 is shared; only listed Canadian area codes resolve to CA
+        assert_eq!(region_for("+14155552671"), Some("US"));
+        assert_eq!(region_for("+1 604 555 0100"), Some("CA"));
+        assert!(matches!(
+            region_for("+18765550100"),
+            Some("US") | Some("CA")
+        ));
+    }
+
+    #[test]
+    fn test_region_for_invalid() {
+        assert_eq!(region_for("4791234567"), None);
+        assert_eq!(region_for("+"), None);
+        assert_eq!(region_for("+47 abc"), None);
+        assert_eq!(region_for("+999 123"), None);
+    }
+
+    #[test]
+    fn test_region_for_generated() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(region_for(&phone_no_e164(&mut rng)), Some("NO"));
+        assert_eq!(region_for(&phone_for_country(&mut rng, "SE")), Some("SE"));
+    }
+
     #[test]
     fn test_deterministic() {
         let mut rng1 = StdRng::seed_from_u64(42);
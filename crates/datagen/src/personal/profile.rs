@@ -0,0 +1,164 @@
+//! Personal profile generation.
+//!
+//! Generate a coherent person record where the email is derived from the
+//! name, and contact details, address, and employment are filled in together.
+//! Age-dependent fields follow the age: minors get no job or credit card.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::personal::profile::profile;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let p = profile(&mut rng);
+//! println!("{} <{}>", p.full_name(), p.email);
+//! ```
+
+use super::address::{full_address, Address};
+use super::email::email_from_name;
+use super::names::{first_name, last_name};
+use super::phone::phone_us;
+use crate::commerce::{company_name, job_title};
+use crate::numeric::credit_card;
+use rand::Rng;
+
+/// Age from which a profile has a job and a credit card.
+pub const ADULT_AGE: u32 = 18;
+
+/// Oldest age [`profile`] generates.
+pub const MAX_AGE: u32 = 90;
+
+/// A generated person with consistent contact details.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    /// Given name
+    pub first_name: String,
+    /// Family name
+    pub last_name: String,
+    /// Email address derived from the name
+    pub email: String,
+    /// Phone number
+    pub phone: String,
+    /// Postal address
+    pub address: Address,
+    /// Age in whole years
+    pub age_years: u32,
+    /// Employer name; `None` for minors
+    pub company: Option<String>,
+    /// Job title at the employer; `None` for minors
+    pub job_title: Option<String>,
+    /// Credit card number; `None` for minors
+    pub credit_card: Option<String>,
+}
+
+impl Profile {
+    /// Full name as "First Last".
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.first_name, self.last_name)
+    }
+
+    /// Whether the person is at least [`ADULT_AGE`].
+    pub fn is_adult(&self) -> bool {
+        self.age_years >= ADULT_AGE
+    }
+}
+
+/// Generate a complete personal profile aged 0 to [`MAX_AGE`].
+pub fn profile<R: ?Sized + Rng>(rng: &mut R) -> Profile {
+    let age = rng.random_range(0..=MAX_AGE);
+    profile_with_age(rng, age)
+}
+
+/// Generate a complete personal profile of the given age.
+pub fn profile_with_age<R: ?Sized + Rng>(rng: &mut R, age_years: u32) -> Profile {
+    let first = first_name(rng).to_string();
+    let last = last_name(rng).to_string();
+    let email = email_from_name(rng, &first, &last);
+    let phone = phone_us(rng);
+    let address = full_address(rng);
+
+    let adult = age_years >= ADULT_AGE;
+    Profile {
+        email,
+        phone,
+        address,
+        age_years,
+        company: adult.then(|| company_name(rng)),
+        job_title: adult.then(|| job_title(rng)),
+        credit_card: adult.then(|| credit_card(rng)),
+        first_name: first,
+        last_name: last,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_profile() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let p = profile(&mut rng);
+        assert!(!p.first_name.is_empty());
+        assert!(!p.last_name.is_empty());
+        assert!(p.email.contains('@'));
+        assert!(p.age_years <= MAX_AGE);
+    }
+
+    #[test]
+    fn test_minor_has_no_job_or_card() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let p = profile_with_age(&mut rng, 12);
+        assert!(!p.is_adult());
+        assert_eq!(p.company, None);
+        assert_eq!(p.job_title, None);
+        assert_eq!(p.credit_card, None);
+
+        // Every minor drawn at random follows the same rule
+        let minors: Vec<Profile> = (0..200)
+            .map(|_| profile(&mut rng))
+            .filter(|p| p.age_years < ADULT_AGE)
+            .collect();
+        assert!(!minors.is_empty());
+        assert!(minors
+            .iter()
+            .all(|p| p.job_title.is_none() && p.credit_card.is_none()));
+    }
+
+    #[test]
+    fn test_adult_has_job_and_card() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let p = profile_with_age(&mut rng, ADULT_AGE);
+        assert!(p.is_adult());
+        assert!(!p.company.unwrap().is_empty());
+        assert!(!p.job_title.unwrap().is_empty());
+        assert!(crate::numeric::validate_luhn(&p.credit_card.unwrap()));
+    }
+
+    #[test]
+    fn test_email_matches_name() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let p = profile(&mut rng);
+        let local = p.email.split('@').next().unwrap();
+        assert!(local.contains(&p.last_name.to_lowercase().replace(' ', "")));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+        assert_eq!(profile(&mut rng1), profile(&mut rng2));
+    }
+
+    #[test]
+    fn test_trait_object_support() {
+        let mut rng: Box<dyn rand::RngCore> = Box::new(StdRng::seed_from_u64(42));
+        let p = profile(&mut *rng);
+        assert!(!p.full_name().is_empty());
+    }
+}
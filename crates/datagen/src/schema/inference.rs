@@ -6,6 +6,37 @@ use serde::Serialize;
 use serde_json::{json, Map, Value};
 use std::collections::{HashMap, HashSet};
 
+use super::json_schema::child_path;
+
+/// Options for schema inference.
+#[derive(Debug, Clone)]
+pub struct InferenceOptions {
+    /// Infer `number` for values mixing integers and floats, rather than
+    /// reporting a conflict (default: true)
+    pub widen_numeric: bool,
+    /// Add `"format": "int64"` to integers outside the 32-bit range
+    /// (default: false)
+    pub detect_int64: bool,
+}
+
+impl Default for InferenceOptions {
+    fn default() -> Self {
+        Self {
+            widen_numeric: true,
+            detect_int64: false,
+        }
+    }
+}
+
+/// An inferred schema and the places where samples disagreed on a type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InferredSchema {
+    pub schema: Value,
+    /// One message per conflicting subschema, such as
+    /// `#/properties/age: mixed types integer, number`
+    pub conflicts: Vec<String>,
+}
+
 /// Infer a JSON Schema from serializable data samples.
 ///
 /// # Arguments
@@ -37,12 +68,20 @@ use std::collections::{HashMap, HashSet};
 /// assert!(schema.get("properties").is_some());
 /// ```
 pub fn infer_schema<T: Serialize>(records: &[T]) -> Value {
+    infer_schema_with_options(records, &InferenceOptions::default()).schema
+}
+
+/// Infer a JSON Schema from serializable data samples with custom options.
+pub fn infer_schema_with_options<T: Serialize>(
+    records: &[T],
+    options: &InferenceOptions,
+) -> InferredSchema {
     let values: Vec<Value> = records
         .iter()
         .filter_map(|r| serde_json::to_value(r).ok())
         .collect();
 
-    infer_schema_from_values(&values)
+    infer_schema_from_values_with_options(&values, options)
 }
 
 /// Infer a JSON Schema from JSON values.
@@ -55,8 +94,36 @@ pub fn infer_schema<T: Serialize>(records: &[T]) -> Value {
 ///
 /// A JSON Schema that describes the structure of the data.
 pub fn infer_schema_from_values(values: &[Value]) -> Value {
+    infer_schema_from_values_with_options(values, &InferenceOptions::default()).schema
+}
+
+/// Infer a JSON Schema from JSON values with custom options.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::schema::{infer_schema_from_values_with_options, InferenceOptions};
+/// use serde_json::json;
+///
+/// let values = [json!(1), json!(2.5)];
+///
+/// let widened = infer_schema_from_values_with_options(&values, &InferenceOptions::default());
+/// assert_eq!(widened.schema["type"], "number");
+///
+/// let strict = InferenceOptions { widen_numeric: false, ..Default::default() };
+/// let inferred = infer_schema_from_values_with_options(&values, &strict);
+/// assert_eq!(inferred.conflicts, ["#: mixed types integer, number"]);
+/// ```
+pub fn infer_schema_from_values_with_options(
+    values: &[Value],
+    options: &InferenceOptions,
+) -> InferredSchema {
+    let mut conflicts = Vec::new();
     if values.is_empty() {
-        return json!({});
+        return InferredSchema {
+            schema: json!({}),
+            conflicts,
+        };
     }
 
     // Collect type information from all values
@@ -65,7 +132,8 @@ pub fn infer_schema_from_values(values: &[Value]) -> Value {
         inferrer.observe(value);
     }
 
-    inferrer.to_schema()
+    let schema = inferrer.to_schema(options, "#", &mut conflicts);
+    InferredSchema { schema, conflicts }
 }
 
 /// Tracks observed types and properties to infer schema.
@@ -186,11 +254,20 @@ impl SchemaInferrer {
         }
     }
 
-    fn to_schema(&self) -> Value {
+    fn to_schema(
+        &self,
+        options: &InferenceOptions,
+        path: &str,
+        conflicts: &mut Vec<String>,
+    ) -> Value {
         let mut schema = Map::new();
 
         // Determine type(s)
-        let types: Vec<&String> = self.types.iter().collect();
+        let mut types: Vec<&str> = self.types.iter().map(String::as_str).collect();
+        if options.widen_numeric && types.contains(&"integer") && types.contains(&"number") {
+            types.retain(|t| *t != "integer");
+        }
+        types.sort_unstable();
 
         if types.is_empty() {
             return json!({});
@@ -201,25 +278,19 @@ impl SchemaInferrer {
         } else {
             // Multiple types
             schema.insert("type".to_string(), json!(types));
+            let non_null: Vec<&str> = types.iter().copied().filter(|t| *t != "null").collect();
+            if non_null.len() > 1 {
+                conflicts.push(format!("{}: mixed types {}", path, non_null.join(", ")));
+            }
         }
 
         // Add type-specific constraints
-        if self.types.contains("string") && self.types.len() == 1 {
-            self.add_string_constraints(&mut schema);
-        }
-
-        if (self.types.contains("integer") || self.types.contains("number"))
-            && self.types.len() == 1
-        {
-            self.add_number_constraints(&mut schema);
-        }
-
-        if self.types.contains("array") && self.types.len() == 1 {
-            self.add_array_constraints(&mut schema);
-        }
-
-        if self.types.contains("object") && self.types.len() == 1 {
-            self.add_object_constraints(&mut schema);
+        match types[..] {
+            ["string"] => self.add_string_constraints(&mut schema),
+            ["integer"] | ["number"] => self.add_number_constraints(&mut schema, options),
+            ["array"] => self.add_array_constraints(&mut schema, options, path, conflicts),
+            ["object"] => self.add_object_constraints(&mut schema, options, path, conflicts),
+            _ => {}
         }
 
         Value::Object(schema)
@@ -256,7 +327,7 @@ impl SchemaInferrer {
         }
     }
 
-    fn add_number_constraints(&self, schema: &mut Map<String, Value>) {
+    fn add_number_constraints(&self, schema: &mut Map<String, Value>, options: &InferenceOptions) {
         // Use integer type if all values were integers
         let is_integer = self.all_integers && self.types.contains("integer");
         if is_integer {
@@ -277,9 +348,24 @@ impl SchemaInferrer {
                 schema.insert("maximum".to_string(), json!(max));
             }
         }
+
+        let int32 = i32::MIN as f64..=i32::MAX as f64;
+        let outside_int32 = [self.number_min, self.number_max]
+            .iter()
+            .flatten()
+            .any(|n| !int32.contains(n));
+        if is_integer && options.detect_int64 && outside_int32 {
+            schema.insert("format".to_string(), json!("int64"));
+        }
     }
 
-    fn add_array_constraints(&self, schema: &mut Map<String, Value>) {
+    fn add_array_constraints(
+        &self,
+        schema: &mut Map<String, Value>,
+        options: &InferenceOptions,
+        path: &str,
+        conflicts: &mut Vec<String>,
+    ) {
         if let Some(min) = self.array_min_items {
             if min > 0 {
                 schema.insert("minItems".to_string(), json!(min));
@@ -291,15 +377,33 @@ impl SchemaInferrer {
 
         // Add items schema
         if let Some(ref inferrer) = self.items_inferrer {
-            schema.insert("items".to_string(), inferrer.to_schema());
+            let items_path = child_path(path, "items");
+            schema.insert(
+                "items".to_string(),
+                inferrer.to_schema(options, &items_path, conflicts),
+            );
         }
     }
 
-    fn add_object_constraints(&self, schema: &mut Map<String, Value>) {
+    fn add_object_constraints(
+        &self,
+        schema: &mut Map<String, Value>,
+        options: &InferenceOptions,
+        path: &str,
+        conflicts: &mut Vec<String>,
+    ) {
         if !self.properties.is_empty() {
+            let properties_path = child_path(path, "properties");
+            // Sorted so conflicts are reported in a stable order
+            let mut keys: Vec<&String> = self.properties.keys().collect();
+            keys.sort();
             let mut props = Map::new();
-            for (key, inferrer) in &self.properties {
-                props.insert(key.clone(), inferrer.to_schema());
+            for key in keys {
+                let key_path = child_path(&properties_path, key);
+                props.insert(
+                    key.clone(),
+                    self.properties[key].to_schema(options, &key_path, conflicts),
+                );
             }
             schema.insert("properties".to_string(), Value::Object(props));
 
@@ -504,4 +608,58 @@ mod tests {
         assert!(required.contains(&json!("age")));
         assert!(!required.contains(&json!("email")));
     }
+
+    #[test]
+    fn test_mixed_numbers_widen_or_conflict() {
+        let values = vec![json!({"score": 1}), json!({"score": 2.5})];
+
+        let widened = infer_schema_from_values_with_options(&values, &InferenceOptions::default());
+        assert!(widened.conflicts.is_empty());
+        assert_eq!(
+            widened.schema["properties"]["score"],
+            json!({"type": "number", "minimum": 1.0, "maximum": 2.5})
+        );
+
+        let strict = InferenceOptions {
+            widen_numeric: false,
+            ..Default::default()
+        };
+        let inferred = infer_schema_from_values_with_options(&values, &strict);
+        assert_eq!(
+            inferred.schema["properties"]["score"]["type"],
+            json!(["integer", "number"])
+        );
+        assert_eq!(
+            inferred.conflicts,
+            ["#/properties/score: mixed types integer, number"]
+        );
+    }
+
+    #[test]
+    fn test_nullable_is_not_a_conflict() {
+        let values = vec![json!([1, null]), json!(["a"])];
+        let inferred = infer_schema_from_values_with_options(&values, &InferenceOptions::default());
+        assert_eq!(
+            inferred.schema["items"]["type"],
+            json!(["integer", "null", "string"])
+        );
+        assert_eq!(inferred.conflicts, ["#/items: mixed types integer, string"]);
+    }
+
+    #[test]
+    fn test_detect_int64() {
+        let values = vec![json!(1), json!(5_000_000_000i64)];
+        assert_eq!(infer_schema_from_values(&values).get("format"), None);
+
+        let options = InferenceOptions {
+            detect_int64: true,
+            ..Default::default()
+        };
+        let schema = infer_schema_from_values_with_options(&values, &options).schema;
+        assert_eq!(schema["format"], "int64");
+        assert_eq!(schema["type"], "integer");
+
+        let small = infer_schema_from_values_with_options(&[json!(-5), json!(70_000)], &options);
+        assert_eq!(small.schema.get("format"), None);
+    }
 }
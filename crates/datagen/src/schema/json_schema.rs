@@ -5,8 +5,13 @@
 
 use rand::Rng;
 use serde_json::{json, Map, Value};
+use std::borrow::Cow;
 use std::collections::HashSet;
+use std::fmt;
+use std::io::{self, Write};
 
+use crate::selection::weighted_pick_from;
+use crate::text::from_regex;
 use crate::{
     alphanumeric, boolean, email, first_name, float_range, full_name, hex_string, int_range, ipv4,
     last_name, url, uuid::v4 as uuid_v4,
@@ -29,6 +34,8 @@ pub struct JsonSchemaOptions {
     pub include_optional: bool,
     /// Probability of including optional properties (0.0-1.0, default: 0.5)
     pub optional_probability: f64,
+    /// Whether to pick `enum` members by their `x-weights` (default: true)
+    pub weighted_enums: bool,
 }
 
 impl Default for JsonSchemaOptions {
@@ -41,10 +48,649 @@ impl Default for JsonSchemaOptions {
             default_string_length: 10,
             include_optional: true,
             optional_probability: 0.5,
+            weighted_enums: true,
         }
     }
 }
 
+/// A problem in a JSON Schema that makes it impossible to generate
+/// conforming data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaError {
+    /// JSON pointer to the offending subschema, e.g. `#/properties/age`
+    pub path: String,
+    pub message: String,
+}
+
+impl SchemaError {
+    fn new(path: &str, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid schema at {}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/// Types defined by JSON Schema.
+const TYPES: &[&str] = &[
+    "string", "integer", "number", "boolean", "null", "array", "object",
+];
+
+/// Keyword pairs whose lower bound must not exceed the upper bound.
+const RANGES: &[(&str, &str)] = &[
+    ("minimum", "maximum"),
+    ("minLength", "maxLength"),
+    ("minItems", "maxItems"),
+    ("minProperties", "maxProperties"),
+];
+
+/// Generate random data conforming to a JSON Schema, rejecting schemas
+/// that cannot be satisfied.
+///
+/// Unlike [`from_json_schema`], which does its best with anything, this
+/// first checks the whole schema and reports unknown types, bounds such as
+/// `minimum > maximum`, arrays without `items`, empty `enum`s and
+/// subschemas that are not objects or booleans. Local `$ref`s that cannot
+/// be resolved, or that refer back to themselves, are errors too, as are
+/// `allOf` branches that cannot be merged, such as a `string` and an
+/// `integer`.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::schema::from_json_schema_checked;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use serde_json::json;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let schema = json!({
+///     "type": "object",
+///     "properties": {"age": {"type": "integer", "minimum": 65, "maximum": 18}}
+/// });
+///
+/// let err = from_json_schema_checked(&mut rng, &schema).unwrap_err();
+/// assert_eq!(err.path, "#/properties/age");
+/// assert_eq!(err.message, "minimum (65) is greater than maximum (18)");
+/// ```
+pub fn from_json_schema_checked<R: Rng + ?Sized>(
+    rng: &mut R,
+    schema: &Value,
+) -> Result<Value, SchemaError> {
+    check_schema(schema, "#")?;
+    let prepared = prepare(schema)?;
+    // Merging can make bounds contradict, e.g. `minimum` and `maximum` from
+    // different `allOf` branches
+    check_schema(&prepared, "#")?;
+    Ok(generate_value(
+        rng,
+        &prepared,
+        &JsonSchemaOptions::default(),
+        0,
+        &mut State::default(),
+    ))
+}
+
+/// Inline `$ref`s, then merge `allOf`s, ready for generation.
+fn prepare(schema: &Value) -> Result<Value, SchemaError> {
+    merge_all_of(&resolve_refs(schema)?, "#")
+}
+
+/// Keywords whose values are not subschemas, so `$ref` inside them is data.
+const DATA_KEYWORDS: &[&str] = &["const", "enum", "default", "examples"];
+
+/// Keywords mapping names to subschemas.
+const SCHEMA_MAPS: &[&str] = &["properties", "patternProperties", "dependentSchemas"];
+
+/// Inline every local `$ref` (`#`, `#/$defs/...`, `#/definitions/...` or
+/// any other JSON pointer into the document).
+///
+/// Keywords next to a `$ref` are merged over the referenced schema. The
+/// `$defs` and `definitions` sections are dropped from the result, since
+/// nothing refers to them any more.
+fn resolve_refs(schema: &Value) -> Result<Value, SchemaError> {
+    resolve_node(schema, schema, "#", &mut Vec::new())
+}
+
+/// Resolve `node`, located at `path`; `chain` holds the `$ref`s being expanded.
+fn resolve_node(
+    root: &Value,
+    node: &Value,
+    path: &str,
+    chain: &mut Vec<String>,
+) -> Result<Value, SchemaError> {
+    let obj = match node {
+        Value::Object(obj) => obj,
+        Value::Array(items) => {
+            return items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| resolve_node(root, item, &child_path(path, &i.to_string()), chain))
+                .collect::<Result<_, _>>()
+                .map(Value::Array)
+        }
+        _ => return Ok(node.clone()),
+    };
+
+    let mut resolved = match obj.get("$ref") {
+        None => Map::new(),
+        Some(reference) => {
+            let reference = reference
+                .as_str()
+                .ok_or_else(|| SchemaError::new(path, "$ref must be a string"))?;
+            if let Some(start) = chain.iter().position(|r| r == reference) {
+                let mut cycle = chain[start..].to_vec();
+                cycle.push(reference.to_string());
+                return Err(SchemaError::new(
+                    path,
+                    format!("cyclic $ref: {}", cycle.join(" -> ")),
+                ));
+            }
+            let target = lookup_ref(root, reference).ok_or_else(|| {
+                SchemaError::new(path, format!("cannot resolve $ref \"{}\"", reference))
+            })?;
+
+            chain.push(reference.to_string());
+            let target = resolve_node(root, target, reference, chain);
+            chain.pop();
+            match target? {
+                Value::Object(map) => map,
+                // `true`/`false` schemas have no keywords to merge into
+                other if obj.len() == 1 => return Ok(other),
+                Value::Bool(true) => Map::new(),
+                _ => {
+                    return Err(SchemaError::new(
+                        path,
+                        format!("$ref \"{}\" is not an object schema", reference),
+                    ))
+                }
+            }
+        }
+    };
+
+    for (key, value) in obj {
+        let key_path = child_path(path, key);
+        let value = match key.as_str() {
+            "$ref" | "$defs" | "definitions" => continue,
+            k if DATA_KEYWORDS.contains(&k) => value.clone(),
+            k if SCHEMA_MAPS.contains(&k) && value.is_object() => {
+                let mut map = Map::new();
+                for (name, child) in value.as_object().into_iter().flatten() {
+                    let child = resolve_node(root, child, &child_path(&key_path, name), chain)?;
+                    map.insert(name.clone(), child);
+                }
+                Value::Object(map)
+            }
+            _ => resolve_node(root, value, &key_path, chain)?,
+        };
+        resolved.insert(key.clone(), value);
+    }
+    Ok(Value::Object(resolved))
+}
+
+/// Find the target of a local `$ref` in `root`.
+fn lookup_ref<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    match reference {
+        "#" => Some(root),
+        _ => root.pointer(reference.strip_prefix('#')?),
+    }
+}
+
+/// `schema` with `$ref`s inlined and `allOf`s merged, or as much of that as
+/// succeeds.
+fn prepared_or_original(schema: &Value) -> Cow<'_, Value> {
+    match prepare(schema).or_else(|_| resolve_refs(schema)) {
+        Ok(prepared) => Cow::Owned(prepared),
+        Err(_) => Cow::Borrowed(schema),
+    }
+}
+
+/// Replace every `allOf` with the merge of its branches and the keywords
+/// beside it. Expects `$ref`s to be resolved already.
+fn merge_all_of(node: &Value, path: &str) -> Result<Value, SchemaError> {
+    let obj = match node {
+        Value::Object(obj) => obj,
+        Value::Array(items) => {
+            return items
+                .iter()
+                .enumerate()
+                .map(|(i, item)| merge_all_of(item, &child_path(path, &i.to_string())))
+                .collect::<Result<_, _>>()
+                .map(Value::Array)
+        }
+        _ => return Ok(node.clone()),
+    };
+
+    let mut merged = Map::new();
+    for (key, value) in obj {
+        let key_path = child_path(path, key);
+        let value = match key.as_str() {
+            "allOf" => continue,
+            k if DATA_KEYWORDS.contains(&k) => value.clone(),
+            k if SCHEMA_MAPS.contains(&k) && value.is_object() => {
+                let mut map = Map::new();
+                for (name, child) in value.as_object().into_iter().flatten() {
+                    map.insert(
+                        name.clone(),
+                        merge_all_of(child, &child_path(&key_path, name))?,
+                    );
+                }
+                Value::Object(map)
+            }
+            _ => merge_all_of(value, &key_path)?,
+        };
+        merged.insert(key.clone(), value);
+    }
+
+    let Some(branches) = obj.get("allOf") else {
+        return Ok(Value::Object(merged));
+    };
+    let all_of_path = child_path(path, "allOf");
+    let branches = branches
+        .as_array()
+        .ok_or_else(|| SchemaError::new(&all_of_path, "expected an array of schemas"))?;
+    for (i, branch) in branches.iter().enumerate() {
+        let branch_path = child_path(&all_of_path, &i.to_string());
+        match merge_all_of(branch, &branch_path)? {
+            Value::Object(branch) => merged = merge_schemas(merged, &branch, &branch_path)?,
+            Value::Bool(true) => {}
+            _ => {
+                return Err(SchemaError::new(
+                    &branch_path,
+                    "allOf branch can never match",
+                ))
+            }
+        }
+    }
+    Ok(Value::Object(merged))
+}
+
+/// Combine two schemas into one that a value must satisfy for both.
+fn merge_schemas(
+    mut into: Map<String, Value>,
+    from: &Map<String, Value>,
+    path: &str,
+) -> Result<Map<String, Value>, SchemaError> {
+    for (key, value) in from {
+        let Some(existing) = into.get(key) else {
+            into.insert(key.clone(), value.clone());
+            continue;
+        };
+        let combined = match key.as_str() {
+            "type" => merge_types(existing, value, path)?,
+            "properties" => {
+                let mut properties = existing.as_object().cloned().unwrap_or_default();
+                for (name, schema) in value.as_object().into_iter().flatten() {
+                    let merged = match (properties.get(name), schema) {
+                        (Some(Value::Object(a)), Value::Object(b)) => {
+                            Value::Object(merge_schemas(a.clone(), b, &child_path(path, name))?)
+                        }
+                        _ => schema.clone(),
+                    };
+                    properties.insert(name.clone(), merged);
+                }
+                Value::Object(properties)
+            }
+            "required" => {
+                let mut required = existing.as_array().cloned().unwrap_or_default();
+                for name in value.as_array().into_iter().flatten() {
+                    if !required.contains(name) {
+                        required.push(name.clone());
+                    }
+                }
+                Value::Array(required)
+            }
+            "minimum" | "exclusiveMinimum" | "minLength" | "minItems" | "minProperties" => {
+                tighter(existing, value, f64::max)
+            }
+            "maximum" | "exclusiveMaximum" | "maxLength" | "maxItems" | "maxProperties" => {
+                tighter(existing, value, f64::min)
+            }
+            "const" if existing != value => {
+                return Err(SchemaError::new(
+                    path,
+                    format!(
+                        "allOf has conflicting const values {} and {}",
+                        existing, value
+                    ),
+                ))
+            }
+            "enum" => {
+                let allowed = value.as_array().cloned().unwrap_or_default();
+                let common: Vec<Value> = existing
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|v| allowed.contains(v))
+                    .cloned()
+                    .collect();
+                if common.is_empty() {
+                    return Err(SchemaError::new(
+                        path,
+                        "allOf enums have no value in common",
+                    ));
+                }
+                Value::Array(common)
+            }
+            // Anything else: the first branch to set it wins
+            _ => existing.clone(),
+        };
+        into.insert(key.clone(), combined);
+    }
+    Ok(into)
+}
+
+/// The types allowed by both `a` and `b`; an `integer` is also a `number`.
+fn merge_types(a: &Value, b: &Value, path: &str) -> Result<Value, SchemaError> {
+    let list = |v: &Value| -> Vec<String> {
+        match v {
+            Value::String(t) => vec![t.clone()],
+            Value::Array(ts) => ts
+                .iter()
+                .filter_map(|t| t.as_str().map(String::from))
+                .collect(),
+            _ => Vec::new(),
+        }
+    };
+    let (a_types, b_types) = (list(a), list(b));
+    let allows = |types: &[String], t: &str| {
+        types
+            .iter()
+            .any(|u| u == t || (t == "integer" && u == "number"))
+    };
+    let mut common: Vec<String> = Vec::new();
+    for t in a_types.iter().chain(&b_types) {
+        if allows(&a_types, t) && allows(&b_types, t) && !common.contains(t) {
+            common.push(t.clone());
+        }
+    }
+    match common.len() {
+        0 => Err(SchemaError::new(
+            path,
+            format!("allOf branches have incompatible types {} and {}", a, b),
+        )),
+        1 => Ok(Value::String(common.remove(0))),
+        _ => Ok(Value::Array(
+            common.into_iter().map(Value::String).collect(),
+        )),
+    }
+}
+
+/// The stricter of two numeric bounds, as picked by `pick`.
+fn tighter(a: &Value, b: &Value, pick: fn(f64, f64) -> f64) -> Value {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) if pick(x, y) == y => b.clone(),
+        (Some(_), _) => a.clone(),
+        _ => b.clone(),
+    }
+}
+
+/// Attempts at generating a `oneOf` value that matches no other branch.
+const ONE_OF_ATTEMPTS: usize = 10;
+
+/// Whether `value` satisfies `schema`, as far as generation needs to know.
+///
+/// Covers types, `const`, `enum`, numeric bounds, lengths, item and property
+/// constraints and the combinators. Keywords it doesn't understand, such as
+/// `pattern` and `format`, are treated as satisfied.
+fn is_valid(value: &Value, schema: &Value) -> bool {
+    let obj = match schema {
+        Value::Bool(b) => return *b,
+        Value::Object(obj) => obj,
+        _ => return true,
+    };
+
+    let types = get_types(obj);
+    if !types.is_empty() && !types.iter().any(|t| has_type(value, t)) {
+        return false;
+    }
+    if obj.get("const").is_some_and(|c| c != value) {
+        return false;
+    }
+    if let Some(Value::Array(allowed)) = obj.get("enum") {
+        if !allowed.contains(value) {
+            return false;
+        }
+    }
+
+    let number = |key: &str| obj.get(key).and_then(|v| v.as_f64());
+    let count = |key: &str| obj.get(key).and_then(|v| v.as_u64()).map(|n| n as usize);
+    let within = |n: usize, min: &str, max: &str| {
+        count(min).is_none_or(|m| n >= m) && count(max).is_none_or(|m| n <= m)
+    };
+    match value {
+        Value::Number(n) => {
+            let n = n.as_f64().unwrap_or(f64::NAN);
+            if number("minimum").is_some_and(|m| n < m)
+                || number("maximum").is_some_and(|m| n > m)
+                || number("exclusiveMinimum").is_some_and(|m| n <= m)
+                || number("exclusiveMaximum").is_some_and(|m| n >= m)
+            {
+                return false;
+            }
+        }
+        Value::String(s) if !within(s.chars().count(), "minLength", "maxLength") => return false,
+        Value::Array(items) => {
+            if !within(items.len(), "minItems", "maxItems") {
+                return false;
+            }
+            if let Some(item_schema @ (Value::Object(_) | Value::Bool(_))) = obj.get("items") {
+                if !items.iter().all(|item| is_valid(item, item_schema)) {
+                    return false;
+                }
+            }
+        }
+        Value::Object(map) => {
+            if !within(map.len(), "minProperties", "maxProperties") {
+                return false;
+            }
+            let required = obj.get("required").and_then(|v| v.as_array());
+            if required
+                .into_iter()
+                .flatten()
+                .any(|name| name.as_str().is_some_and(|n| !map.contains_key(n)))
+            {
+                return false;
+            }
+            if let Some(properties) = obj.get("properties").and_then(|v| v.as_object()) {
+                if properties
+                    .iter()
+                    .any(|(name, schema)| map.get(name).is_some_and(|v| !is_valid(v, schema)))
+                {
+                    return false;
+                }
+            }
+        }
+        _ => {}
+    }
+
+    let branches = |key: &str| obj.get(key).and_then(|v| v.as_array());
+    if let Some(all) = branches("allOf") {
+        if !all.iter().all(|b| is_valid(value, b)) {
+            return false;
+        }
+    }
+    if let Some(any) = branches("anyOf") {
+        if !any.iter().any(|b| is_valid(value, b)) {
+            return false;
+        }
+    }
+    if let Some(one) = branches("oneOf") {
+        if one.iter().filter(|b| is_valid(value, b)).count() != 1 {
+            return false;
+        }
+    }
+    if obj.get("not").is_some_and(|not| is_valid(value, not)) {
+        return false;
+    }
+    true
+}
+
+fn has_type(value: &Value, t: &str) -> bool {
+    match t {
+        "string" => value.is_string(),
+        "integer" => {
+            value.is_i64() || value.is_u64() || value.as_f64().is_some_and(|n| n.fract() == 0.0)
+        }
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => false,
+    }
+}
+
+/// Check `schema` and all of its subschemas.
+fn check_schema(schema: &Value, path: &str) -> Result<(), SchemaError> {
+    let obj = match schema {
+        Value::Bool(_) => return Ok(()),
+        Value::Object(obj) => obj,
+        _ => return Err(SchemaError::new(path, "expected an object or boolean")),
+    };
+
+    let types: Vec<&str> = match obj.get("type") {
+        None => Vec::new(),
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types
+            .iter()
+            .map(|t| {
+                t.as_str().ok_or_else(|| {
+                    SchemaError::new(path, "type must be a string or an array of strings")
+                })
+            })
+            .collect::<Result<_, _>>()?,
+        Some(_) => {
+            return Err(SchemaError::new(
+                path,
+                "type must be a string or an array of strings",
+            ))
+        }
+    };
+    if let Some(unknown) = types.iter().find(|t| !TYPES.contains(t)) {
+        return Err(SchemaError::new(
+            path,
+            format!("unknown type \"{}\"", unknown),
+        ));
+    }
+
+    for (low, high) in RANGES {
+        check_range(obj, path, low, high, false)?;
+    }
+    check_range(obj, path, "exclusiveMinimum", "exclusiveMaximum", true)?;
+    check_range(obj, path, "minimum", "exclusiveMaximum", true)?;
+    check_range(obj, path, "exclusiveMinimum", "maximum", true)?;
+
+    if types.contains(&"array") && !obj.contains_key("items") && !obj.contains_key("prefixItems") {
+        return Err(SchemaError::new(
+            path,
+            "array schema has no \"items\" or \"prefixItems\"",
+        ));
+    }
+    if let Some(values) = obj.get("enum") {
+        if values.as_array().is_none_or(|v| v.is_empty()) {
+            return Err(SchemaError::new(path, "enum must be a non-empty array"));
+        }
+    }
+    if let Some(weights) = obj.get("x-weights") {
+        let members = obj
+            .get("enum")
+            .and_then(|v| v.as_array())
+            .map_or(0, |v| v.len());
+        if enum_weights(weights, members).is_none() {
+            return Err(SchemaError::new(
+                &child_path(path, "x-weights"),
+                weights_message(members),
+            ));
+        }
+    }
+
+    // Subschemas, keyed by name
+    for keyword in ["properties", "patternProperties", "$defs", "definitions"] {
+        if let Some(children) = obj.get(keyword) {
+            let keyword_path = child_path(path, keyword);
+            let children = children
+                .as_object()
+                .ok_or_else(|| SchemaError::new(&keyword_path, "expected an object of schemas"))?;
+            for (name, child) in children {
+                check_schema(child, &child_path(&keyword_path, name))?;
+            }
+        }
+    }
+    // Subschemas in arrays
+    for keyword in ["oneOf", "anyOf", "allOf", "prefixItems"] {
+        if let Some(children) = obj.get(keyword) {
+            check_schema_list(children, &child_path(path, keyword))?;
+        }
+    }
+    // Single subschemas; draft-07 also allows a list for `items`
+    for keyword in ["additionalProperties", "not"] {
+        if let Some(child) = obj.get(keyword) {
+            check_schema(child, &child_path(path, keyword))?;
+        }
+    }
+    if let Some(items) = obj.get("items") {
+        let items_path = child_path(path, "items");
+        match items {
+            Value::Array(_) => check_schema_list(items, &items_path)?,
+            _ => check_schema(items, &items_path)?,
+        }
+    }
+    Ok(())
+}
+
+fn check_schema_list(list: &Value, path: &str) -> Result<(), SchemaError> {
+    let list = list
+        .as_array()
+        .ok_or_else(|| SchemaError::new(path, "expected an array of schemas"))?;
+    for (i, child) in list.iter().enumerate() {
+        check_schema(child, &child_path(path, &i.to_string()))?;
+    }
+    Ok(())
+}
+
+/// Fail if the numeric keyword `low` is above `high`, or equal when `strict`.
+fn check_range(
+    obj: &Map<String, Value>,
+    path: &str,
+    low: &str,
+    high: &str,
+    strict: bool,
+) -> Result<(), SchemaError> {
+    let bound = |key: &str| -> Result<Option<f64>, SchemaError> {
+        match obj.get(key) {
+            None => Ok(None),
+            Some(v) => v
+                .as_f64()
+                .map(Some)
+                .ok_or_else(|| SchemaError::new(path, format!("{} must be a number", key))),
+        }
+    };
+    if let (Some(lo), Some(hi)) = (bound(low)?, bound(high)?) {
+        if lo > hi || (strict && lo == hi) {
+            let relation = if lo > hi { "greater than" } else { "equal to" };
+            return Err(SchemaError::new(
+                path,
+                format!("{} ({}) is {} {} ({})", low, lo, relation, high, hi),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Append `key` to a JSON pointer, escaping `~` and `/`.
+pub(super) fn child_path(path: &str, key: &str) -> String {
+    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1"))
+}
+
 /// Generate random data conforming to a JSON Schema.
 ///
 /// # Arguments
@@ -82,12 +728,126 @@ pub fn from_json_schema<R: Rng + ?Sized>(rng: &mut R, schema: &Value) -> Value {
 }
 
 /// Generate random data conforming to a JSON Schema with custom options.
+///
+/// Local `$ref`s are inlined first. If that fails, for example on a cyclic
+/// reference, generation goes ahead with the references unresolved; use
+/// [`from_json_schema_checked`] to get the error instead.
 pub fn from_json_schema_with_options<R: Rng + ?Sized>(
     rng: &mut R,
     schema: &Value,
     options: &JsonSchemaOptions,
 ) -> Value {
-    generate_value(rng, schema, options, 0, &mut HashSet::new())
+    from_json_schema_with_warnings(rng, schema, options).value
+}
+
+/// A generated value and what generation had to approximate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub value: Value,
+    /// Keywords that couldn't be honoured, such as a `pattern` using syntax
+    /// the regex generator doesn't support
+    pub warnings: Vec<String>,
+}
+
+/// Like [`from_json_schema_with_options`], but also returns warnings.
+///
+/// A string `pattern` is generated with [`crate::text::from_regex`]. When
+/// it uses unsupported syntax, such as lookaheads, the string is generated
+/// from `format` or the length bounds instead and a warning is recorded.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::schema::{from_json_schema_with_warnings, JsonSchemaOptions};
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use serde_json::json;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let options = JsonSchemaOptions::default();
+///
+/// let code = json!({"type": "string", "pattern": "^[A-Z]{3}$"});
+/// let generated = from_json_schema_with_warnings(&mut rng, &code, &options);
+/// assert_eq!(generated.value.as_str().unwrap().len(), 3);
+/// assert!(generated.warnings.is_empty());
+///
+/// let lookahead = json!({"type": "string", "pattern": "^(?=a)"});
+/// let generated = from_json_schema_with_warnings(&mut rng, &lookahead, &options);
+/// assert!(generated.value.is_string());
+/// assert_eq!(generated.warnings.len(), 1);
+/// ```
+pub fn from_json_schema_with_warnings<R: Rng + ?Sized>(
+    rng: &mut R,
+    schema: &Value,
+    options: &JsonSchemaOptions,
+) -> Generated {
+    let schema = prepared_or_original(schema);
+    let mut state = State::default();
+    let value = generate_value(rng, &schema, options, 0, &mut state);
+    Generated {
+        value,
+        warnings: state.warnings,
+    }
+}
+
+/// Stream `count` generated values to `writer` as a single JSON array.
+///
+/// Writes `[`, the values separated by commas, then `]`. Each value is
+/// written as soon as it is generated, so memory use doesn't grow with
+/// `count`. Wrap `writer` in a [`std::io::BufWriter`] for files and sockets.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::schema::to_json_array_stream;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use serde_json::json;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let schema = json!({"type": "integer", "minimum": 1, "maximum": 9});
+///
+/// let mut out = Vec::new();
+/// to_json_array_stream(&mut rng, &schema, 3, &mut out).unwrap();
+/// let parsed: Vec<i64> = serde_json::from_slice(&out).unwrap();
+/// assert_eq!(parsed.len(), 3);
+/// ```
+pub fn to_json_array_stream<R: Rng + ?Sized, W: Write>(
+    rng: &mut R,
+    schema: &Value,
+    count: usize,
+    mut writer: W,
+) -> io::Result<()> {
+    let options = JsonSchemaOptions::default();
+    // Resolve once rather than for every value
+    let schema = prepared_or_original(schema);
+    writer.write_all(b"[")?;
+    for i in 0..count {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        let value = generate_value(rng, &schema, &options, 0, &mut State::default());
+        serde_json::to_writer(&mut writer, &value)?;
+    }
+    writer.write_all(b"]")?;
+    writer.flush()
+}
+
+/// Per-value generation state.
+#[derive(Default)]
+struct State {
+    /// `$ref`s already followed, to stop unresolved cycles
+    visited_refs: HashSet<String>,
+    warnings: Vec<String>,
+}
+
+impl State {
+    /// Record a warning once, however many values hit it.
+    fn warn(&mut self, warning: String) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
 }
 
 fn generate_value<R: Rng + ?Sized>(
@@ -95,7 +855,7 @@ fn generate_value<R: Rng + ?Sized>(
     schema: &Value,
     options: &JsonSchemaOptions,
     depth: usize,
-    visited_refs: &mut HashSet<String>,
+    state: &mut State,
 ) -> Value {
     if depth > options.max_depth {
         return Value::Null;
@@ -118,10 +878,10 @@ fn generate_value<R: Rng + ?Sized>(
     // Handle $ref (simplified - doesn't resolve external refs)
     if let Some(ref_val) = obj.get("$ref") {
         if let Some(ref_str) = ref_val.as_str() {
-            if visited_refs.contains(ref_str) {
+            if state.visited_refs.contains(ref_str) {
                 return Value::Null; // Avoid infinite recursion
             }
-            visited_refs.insert(ref_str.to_string());
+            state.visited_refs.insert(ref_str.to_string());
         }
     }
 
@@ -130,22 +890,46 @@ fn generate_value<R: Rng + ?Sized>(
         return const_val.clone();
     }
 
-    // Handle enum
+    // Handle enum, weighted by x-weights if given
     if let Some(enum_val) = obj.get("enum") {
         if let Some(arr) = enum_val.as_array() {
             if !arr.is_empty() {
-                let idx = rng.random_range(0..arr.len());
-                return arr[idx].clone();
+                let weights = match obj.get("x-weights") {
+                    Some(weights) if options.weighted_enums => {
+                        let parsed = enum_weights(weights, arr.len());
+                        if parsed.is_none() {
+                            state
+                                .warn(format!("{}, picking uniformly", weights_message(arr.len())));
+                        }
+                        parsed
+                    }
+                    _ => None,
+                };
+                return match weights {
+                    Some(weights) => weighted_pick_from(rng, arr, &weights).clone(),
+                    None => arr[rng.random_range(0..arr.len())].clone(),
+                };
             }
         }
     }
 
-    // Handle oneOf
+    // Handle oneOf: pick a branch, retrying values that match another one
     if let Some(one_of) = obj.get("oneOf") {
         if let Some(arr) = one_of.as_array() {
             if !arr.is_empty() {
                 let idx = rng.random_range(0..arr.len());
-                return generate_value(rng, &arr[idx], options, depth + 1, visited_refs);
+                let mut value = Value::Null;
+                for _ in 0..ONE_OF_ATTEMPTS {
+                    value = generate_value(rng, &arr[idx], options, depth + 1, state);
+                    let matches_other = arr
+                        .iter()
+                        .enumerate()
+                        .any(|(i, branch)| i != idx && is_valid(&value, branch));
+                    if !matches_other {
+                        break;
+                    }
+                }
+                return value;
             }
         }
     }
@@ -155,18 +939,19 @@ fn generate_value<R: Rng + ?Sized>(
         if let Some(arr) = any_of.as_array() {
             if !arr.is_empty() {
                 let idx = rng.random_range(0..arr.len());
-                return generate_value(rng, &arr[idx], options, depth + 1, visited_refs);
+                return generate_value(rng, &arr[idx], options, depth + 1, state);
             }
         }
     }
 
-    // Handle allOf (merge schemas)
+    // Handle allOf (merge generated objects). Normally merged into one
+    // schema beforehand; this is the fallback for branches that conflict.
     if let Some(all_of) = obj.get("allOf") {
         if let Some(arr) = all_of.as_array() {
             let mut merged = Map::new();
             for sub_schema in arr {
                 if let Value::Object(sub_obj) =
-                    generate_value(rng, sub_schema, options, depth + 1, visited_refs)
+                    generate_value(rng, sub_schema, options, depth + 1, state)
                 {
                     for (k, v) in sub_obj {
                         merged.insert(k, v);
@@ -186,10 +971,10 @@ fn generate_value<R: Rng + ?Sized>(
             || obj.contains_key("additionalProperties")
             || obj.contains_key("patternProperties")
         {
-            return generate_object(rng, obj, options, depth, visited_refs);
+            return generate_object(rng, obj, options, depth, state);
         }
         if obj.contains_key("items") || obj.contains_key("prefixItems") {
-            return generate_array(rng, obj, options, depth, visited_refs);
+            return generate_array(rng, obj, options, depth, state);
         }
         if obj.contains_key("minimum")
             || obj.contains_key("maximum")
@@ -202,7 +987,7 @@ fn generate_value<R: Rng + ?Sized>(
             || obj.contains_key("pattern")
             || obj.contains_key("format")
         {
-            return generate_string(rng, obj, options);
+            return generate_string(rng, obj, options, state);
         }
         return generate_any_value(rng, options);
     }
@@ -216,17 +1001,34 @@ fn generate_value<R: Rng + ?Sized>(
     };
 
     match type_str {
-        "string" => generate_string(rng, obj, options),
+        "string" => generate_string(rng, obj, options, state),
         "integer" => generate_number(rng, obj, true),
         "number" => generate_number(rng, obj, false),
         "boolean" => Value::Bool(boolean(rng, 0.5)),
         "null" => Value::Null,
-        "array" => generate_array(rng, obj, options, depth, visited_refs),
-        "object" => generate_object(rng, obj, options, depth, visited_refs),
+        "array" => generate_array(rng, obj, options, depth, state),
+        "object" => generate_object(rng, obj, options, depth, state),
         _ => Value::Null,
     }
 }
 
+/// `x-weights` as numbers, if it has one non-negative number per member.
+fn enum_weights(weights: &Value, members: usize) -> Option<Vec<f64>> {
+    let weights: Vec<f64> = weights
+        .as_array()?
+        .iter()
+        .map(|w| w.as_f64().filter(|w| *w >= 0.0))
+        .collect::<Option<_>>()?;
+    (weights.len() == members).then_some(weights)
+}
+
+fn weights_message(members: usize) -> String {
+    format!(
+        "x-weights must be an array of {} non-negative numbers, one per enum member",
+        members
+    )
+}
+
 fn get_types(obj: &Map<String, Value>) -> Vec<String> {
     match obj.get("type") {
         Some(Value::String(s)) => vec![s.clone()],
@@ -242,18 +1044,21 @@ fn generate_string<R: Rng + ?Sized>(
     rng: &mut R,
     obj: &Map<String, Value>,
     options: &JsonSchemaOptions,
+    state: &mut State,
 ) -> Value {
-    // Check format first
-    if let Some(format) = obj.get("format").and_then(|v| v.as_str()) {
-        return Value::String(generate_formatted_string(rng, format));
+    // A pattern is the strictest constraint, so it goes first
+    if let Some(pattern) = obj.get("pattern").and_then(|v| v.as_str()) {
+        match from_regex(rng, pattern) {
+            Ok(s) => return Value::String(s),
+            Err(e) => state.warn(format!(
+                "unsupported pattern \"{}\" ({}), generating a plain string",
+                pattern, e
+            )),
+        }
     }
 
-    // Handle pattern (simplified regex support)
-    if let Some(_pattern) = obj.get("pattern").and_then(|v| v.as_str()) {
-        // For simplicity, just generate a random string
-        // Full regex generation would require a regex-to-string generator
-        let len = options.default_string_length;
-        return Value::String(alphanumeric(rng, len));
+    if let Some(format) = obj.get("format").and_then(|v| v.as_str()) {
+        return Value::String(generate_formatted_string(rng, format));
     }
 
     let min_len = obj.get("minLength").and_then(|v| v.as_u64()).unwrap_or(1) as usize;
@@ -397,7 +1202,7 @@ fn generate_array<R: Rng + ?Sized>(
     obj: &Map<String, Value>,
     options: &JsonSchemaOptions,
     depth: usize,
-    visited_refs: &mut HashSet<String>,
+    state: &mut State,
 ) -> Value {
     let min_items = obj
         .get("minItems")
@@ -419,7 +1224,7 @@ fn generate_array<R: Rng + ?Sized>(
     if let Some(prefix_items) = obj.get("prefixItems").and_then(|v| v.as_array()) {
         let mut arr: Vec<Value> = prefix_items
             .iter()
-            .map(|item_schema| generate_value(rng, item_schema, options, depth + 1, visited_refs))
+            .map(|item_schema| generate_value(rng, item_schema, options, depth + 1, state))
             .collect();
 
         // Add additional items if needed
@@ -430,7 +1235,7 @@ fn generate_array<R: Rng + ?Sized>(
                 additional_schema,
                 options,
                 depth + 1,
-                visited_refs,
+                state,
             ));
         }
         return Value::Array(arr);
@@ -453,7 +1258,7 @@ fn generate_array<R: Rng + ?Sized>(
         let mut attempts = 0;
 
         while arr.len() < count && attempts < max_attempts {
-            let val = generate_value(rng, item_schema, options, depth + 1, visited_refs);
+            let val = generate_value(rng, item_schema, options, depth + 1, state);
             let key = val.to_string();
             if !seen.contains(&key) {
                 seen.insert(key);
@@ -464,7 +1269,7 @@ fn generate_array<R: Rng + ?Sized>(
         Value::Array(arr)
     } else {
         let arr: Vec<Value> = (0..count)
-            .map(|_| generate_value(rng, item_schema, options, depth + 1, visited_refs))
+            .map(|_| generate_value(rng, item_schema, options, depth + 1, state))
             .collect();
         Value::Array(arr)
     }
@@ -475,7 +1280,7 @@ fn generate_object<R: Rng + ?Sized>(
     obj: &Map<String, Value>,
     options: &JsonSchemaOptions,
     depth: usize,
-    visited_refs: &mut HashSet<String>,
+    state: &mut State,
 ) -> Value {
     let mut result = Map::new();
 
@@ -499,7 +1304,7 @@ fn generate_object<R: Rng + ?Sized>(
                     && rng.random_range(0.0..1.0) < options.optional_probability);
 
             if should_include {
-                let val = generate_value(rng, prop_schema, options, depth + 1, visited_refs);
+                let val = generate_value(rng, prop_schema, options, depth + 1, state);
                 result.insert(key.clone(), val);
             }
         }
@@ -524,8 +1329,7 @@ fn generate_object<R: Rng + ?Sized>(
                 let needed = min_props - current_count;
                 for i in 0..needed {
                     let key = format!("additional_{}", i);
-                    let val =
-                        generate_value(rng, additional_schema, options, depth + 1, visited_refs);
+                    let val = generate_value(rng, additional_schema, options, depth + 1, state);
                     result.insert(key, val);
                 }
             }
@@ -562,6 +1366,35 @@ mod tests {
         ChaCha8Rng::seed_from_u64(42)
     }
 
+    #[test]
+    fn test_to_json_array_stream() {
+        let mut rng = test_rng();
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "name": { "type": "string" }
+            },
+            "required": ["id", "name"]
+        });
+
+        let mut out = Vec::new();
+        to_json_array_stream(&mut rng, &schema, 25, &mut out).unwrap();
+        let parsed: Value = serde_json::from_slice(&out).unwrap();
+        let items = parsed.as_array().unwrap();
+        assert_eq!(items.len(), 25);
+        assert!(items
+            .iter()
+            .all(|item| item["id"].is_i64() && item["name"].is_string()));
+    }
+
+    #[test]
+    fn test_to_json_array_stream_empty() {
+        let mut out = Vec::new();
+        to_json_array_stream(&mut test_rng(), &json!({"type": "string"}), 0, &mut out).unwrap();
+        assert_eq!(out, b"[]");
+    }
+
     #[test]
     fn test_simple_string() {
         let mut rng = test_rng();
@@ -614,6 +1447,46 @@ mod tests {
         assert!(["red", "green", "blue"].contains(&s));
     }
 
+    #[test]
+    fn test_weighted_enum() {
+        let schema = json!({"enum": ["common", "rare"], "x-weights": [90, 10]});
+        let count_common = |options: &JsonSchemaOptions| {
+            let mut rng = test_rng();
+            (0..1000)
+                .filter(|_| from_json_schema_with_options(&mut rng, &schema, options) == "common")
+                .count()
+        };
+
+        let weighted = count_common(&JsonSchemaOptions::default());
+        assert!((850..=950).contains(&weighted), "{}", weighted);
+
+        let uniform = count_common(&JsonSchemaOptions {
+            weighted_enums: false,
+            ..Default::default()
+        });
+        assert!((400..=600).contains(&uniform), "{}", uniform);
+    }
+
+    #[test]
+    fn test_bad_enum_weights() {
+        let schema = json!({"enum": ["a", "b", "c"], "x-weights": [1, 2]});
+        let generated =
+            from_json_schema_with_warnings(&mut test_rng(), &schema, &JsonSchemaOptions::default());
+        assert!(["a", "b", "c"].contains(&generated.value.as_str().unwrap()));
+        assert_eq!(
+            generated.warnings,
+            [
+                "x-weights must be an array of 3 non-negative numbers, one per enum member, \
+                 picking uniformly"
+            ]
+        );
+
+        let err = from_json_schema_checked(&mut test_rng(), &schema).unwrap_err();
+        assert_eq!(err.path, "#/x-weights");
+        let negative = json!({"enum": [1, 2], "x-weights": [1, -1]});
+        assert!(from_json_schema_checked(&mut test_rng(), &negative).is_err());
+    }
+
     #[test]
     fn test_const() {
         let mut rng = test_rng();
@@ -663,6 +1536,51 @@ mod tests {
         assert!(s.contains('@'));
     }
 
+    #[test]
+    fn test_pattern() {
+        let mut rng = test_rng();
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "code": {"type": "string", "pattern": "^[A-Z]{3}$"},
+                "sku": {"type": "string", "pattern": r"^SKU-\d{4}$", "format": "email"}
+            },
+            "required": ["code", "sku"]
+        });
+        for _ in 0..50 {
+            let data = from_json_schema(&mut rng, &schema);
+            let code = data["code"].as_str().unwrap();
+            assert_eq!(code.len(), 3);
+            assert!(code.chars().all(|c| c.is_ascii_uppercase()), "{}", code);
+            // pattern wins over format
+            let sku = data["sku"].as_str().unwrap();
+            assert!(sku.starts_with("SKU-") && sku.len() == 8, "{}", sku);
+        }
+    }
+
+    #[test]
+    fn test_unsupported_pattern_warns() {
+        let mut rng = test_rng();
+        let schema = json!({
+            "type": "array",
+            "items": {"type": "string", "pattern": "(?=x)", "minLength": 4, "maxLength": 4},
+            "minItems": 3,
+            "maxItems": 3
+        });
+        let generated =
+            from_json_schema_with_warnings(&mut rng, &schema, &JsonSchemaOptions::default());
+        let items = generated.value.as_array().unwrap();
+        assert!(items.iter().all(|s| s.as_str().unwrap().len() == 4));
+        // Reported once, not per item
+        assert_eq!(generated.warnings.len(), 1);
+        assert!(generated.warnings[0].starts_with("unsupported pattern \"(?=x)\""));
+
+        let email = json!({"type": "string", "pattern": "(?=x)", "format": "email"});
+        let generated =
+            from_json_schema_with_warnings(&mut rng, &email, &JsonSchemaOptions::default());
+        assert!(generated.value.as_str().unwrap().contains('@'));
+    }
+
     #[test]
     fn test_format_uuid() {
         let mut rng = test_rng();
@@ -685,6 +1603,294 @@ mod tests {
         assert!(result.is_string() || result.is_i64());
     }
 
+    #[test]
+    fn test_checked_rejects_minimum_above_maximum() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "age": {"type": "integer", "minimum": 65, "maximum": 18}
+            }
+        });
+        let err = from_json_schema_checked(&mut test_rng(), &schema).unwrap_err();
+        assert_eq!(
+            err,
+            SchemaError::new(
+                "#/properties/age",
+                "minimum (65) is greater than maximum (18)"
+            )
+        );
+        assert_eq!(
+            err.to_string(),
+            "Invalid schema at #/properties/age: minimum (65) is greater than maximum (18)"
+        );
+
+        let err = from_json_schema_checked(
+            &mut test_rng(),
+            &json!({"type": "number", "exclusiveMinimum": 5, "exclusiveMaximum": 5}),
+        )
+        .unwrap_err();
+        assert!(err.message.contains("is equal to exclusiveMaximum"));
+
+        let err = from_json_schema_checked(
+            &mut test_rng(),
+            &json!({"type": "string", "minLength": 3, "maxLength": "long"}),
+        )
+        .unwrap_err();
+        assert_eq!(err.message, "maxLength must be a number");
+    }
+
+    #[test]
+    fn test_checked_rejects_unknown_type() {
+        let schema = json!({
+            "type": "array",
+            "items": {"oneOf": [{"type": "string"}, {"type": ["integer", "int64"]}]}
+        });
+        let err = from_json_schema_checked(&mut test_rng(), &schema).unwrap_err();
+        assert_eq!(err.path, "#/items/oneOf/1");
+        assert_eq!(err.message, "unknown type \"int64\"");
+
+        let err = from_json_schema_checked(&mut test_rng(), &json!({"type": 5})).unwrap_err();
+        assert_eq!(err.message, "type must be a string or an array of strings");
+    }
+
+    #[test]
+    fn test_checked_rejects_other_problems() {
+        let cases = [
+            (json!({"type": "array"}), "#"),
+            (json!({"enum": []}), "#"),
+            (json!({"properties": {"a/b": 3}}), "#/properties/a~1b"),
+            (json!({"allOf": {"type": "string"}}), "#/allOf"),
+            (json!({"$defs": {"id": {"type": "uuid"}}}), "#/$defs/id"),
+        ];
+        for (schema, path) in cases {
+            let err = from_json_schema_checked(&mut test_rng(), &schema).unwrap_err();
+            assert_eq!(err.path, path, "{}", schema);
+        }
+    }
+
+    #[test]
+    fn test_checked_matches_unchecked_for_valid_schemas() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "id": {"type": "integer", "minimum": 1, "maximum": 1},
+                "tags": {"type": "array", "items": {"type": "string"}, "minItems": 2},
+                "pair": {"type": "array", "items": [{"type": "string"}, {"type": "null"}]},
+                "kind": {"enum": ["a", "b"]},
+                "any": true
+            },
+            "required": ["id", "tags", "kind"]
+        });
+        let checked = from_json_schema_checked(&mut test_rng(), &schema).unwrap();
+        assert_eq!(checked, from_json_schema(&mut test_rng(), &schema));
+        assert_eq!(checked["id"], json!(1));
+    }
+
+    fn address_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "home": {"$ref": "#/$defs/address"},
+                "work": {"$ref": "#/definitions/office", "required": ["address", "floor"]}
+            },
+            "required": ["home", "work"],
+            "$defs": {
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "city": {"type": "string", "minLength": 3, "maxLength": 3},
+                        "zip": {"$ref": "#/$defs/zip"}
+                    },
+                    "required": ["city", "zip"]
+                },
+                "zip": {"type": "string", "format": "uuid"}
+            },
+            "definitions": {
+                "office": {
+                    "type": "object",
+                    "properties": {
+                        "address": {"$ref": "#/$defs/address"},
+                        "floor": {"type": "integer", "minimum": 1, "maximum": 9}
+                    }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_ref_to_shared_defs() {
+        let schema = address_schema();
+        let resolved = resolve_refs(&schema).unwrap();
+        assert!(resolved.get("$defs").is_none());
+        assert_eq!(
+            resolved["properties"]["home"]["properties"]["zip"],
+            json!({"type": "string", "format": "uuid"})
+        );
+        // Keywords beside the $ref are merged over the target
+        assert_eq!(
+            resolved["properties"]["work"]["required"],
+            json!(["address", "floor"])
+        );
+
+        for data in [
+            from_json_schema_checked(&mut test_rng(), &schema).unwrap(),
+            from_json_schema(&mut test_rng(), &schema),
+        ] {
+            for address in [&data["home"], &data["work"]["address"]] {
+                assert_eq!(address["city"].as_str().unwrap().len(), 3);
+                assert_eq!(address["zip"].as_str().unwrap().len(), 36);
+            }
+            let floor = data["work"]["floor"].as_i64().unwrap();
+            assert!((1..=9).contains(&floor));
+        }
+    }
+
+    #[test]
+    fn test_ref_cycle_is_an_error() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"node": {"$ref": "#/$defs/a"}},
+            "$defs": {
+                "a": {"type": "object", "properties": {"next": {"$ref": "#/$defs/b"}}},
+                "b": {"type": "array", "items": {"$ref": "#/$defs/a"}}
+            }
+        });
+        let err = from_json_schema_checked(&mut test_rng(), &schema).unwrap_err();
+        assert_eq!(err.path, "#/$defs/b/items");
+        assert_eq!(
+            err.message,
+            "cyclic $ref: #/$defs/a -> #/$defs/b -> #/$defs/a"
+        );
+
+        let root = json!({"properties": {"child": {"$ref": "#"}}});
+        let err = resolve_refs(&root).unwrap_err();
+        assert_eq!(err.message, "cyclic $ref: # -> #");
+
+        // The lenient version still produces something
+        assert!(from_json_schema(&mut test_rng(), &schema).is_object());
+    }
+
+    #[test]
+    fn test_ref_errors_and_data_keywords() {
+        let missing = json!({"$ref": "#/$defs/nope"});
+        let err = from_json_schema_checked(&mut test_rng(), &missing).unwrap_err();
+        assert_eq!(err.message, "cannot resolve $ref \"#/$defs/nope\"");
+
+        let remote = json!({"$ref": "https://example.com/schema.json"});
+        assert!(resolve_refs(&remote).is_err());
+
+        // $ref inside const is data, and escaped pointers resolve
+        let schema = json!({
+            "properties": {
+                "raw": {"const": {"$ref": "#/missing"}},
+                "slash": {"$ref": "#/$defs/a~1b"}
+            },
+            "$defs": {"a/b": {"const": 7}}
+        });
+        let resolved = resolve_refs(&schema).unwrap();
+        assert_eq!(
+            resolved["properties"]["raw"]["const"],
+            json!({"$ref": "#/missing"})
+        );
+        assert_eq!(resolved["properties"]["slash"], json!({"const": 7}));
+
+        // A property called "$ref" is not a reference
+        let named = json!({"properties": {"$ref": {"type": "string"}}, "required": ["$ref"]});
+        assert!(from_json_schema_checked(&mut test_rng(), &named).unwrap()["$ref"].is_string());
+    }
+
+    #[test]
+    fn test_one_of_matches_exactly_one_branch() {
+        // The branches overlap on 5..=10
+        let schema = json!({
+            "oneOf": [
+                {"type": "integer", "minimum": 0, "maximum": 10},
+                {"type": "integer", "minimum": 5, "maximum": 15}
+            ]
+        });
+        let mut rng = test_rng();
+        let mut seen = HashSet::new();
+        for _ in 0..200 {
+            let n = from_json_schema(&mut rng, &schema).as_i64().unwrap();
+            let matches = [(0..=10).contains(&n), (5..=15).contains(&n)];
+            assert_eq!(matches.iter().filter(|m| **m).count(), 1, "{}", n);
+            seen.insert(n < 5);
+        }
+        assert_eq!(seen.len(), 2, "both branches are used");
+    }
+
+    #[test]
+    fn test_all_of_merges_required_properties() {
+        let schema = json!({
+            "allOf": [
+                {
+                    "type": "object",
+                    "properties": {"id": {"type": "integer", "minimum": 1}},
+                    "required": ["id"]
+                },
+                {
+                    "properties": {
+                        "id": {"maximum": 3},
+                        "name": {"type": "string"}
+                    },
+                    "required": ["name"]
+                }
+            ],
+            "properties": {"note": {"type": "string"}}
+        });
+
+        let merged = prepare(&schema).unwrap();
+        assert_eq!(merged["required"], json!(["id", "name"]));
+        assert_eq!(
+            merged["properties"]["id"],
+            json!({"type": "integer", "minimum": 1, "maximum": 3})
+        );
+
+        let mut rng = test_rng();
+        for _ in 0..50 {
+            let data = from_json_schema_checked(&mut rng, &schema).unwrap();
+            let id = data["id"].as_i64().unwrap();
+            assert!((1..=3).contains(&id));
+            assert!(data["name"].is_string());
+        }
+    }
+
+    #[test]
+    fn test_all_of_conflicts() {
+        let types = json!({"allOf": [{"type": "string"}, {"type": "integer"}]});
+        let err = from_json_schema_checked(&mut test_rng(), &types).unwrap_err();
+        assert_eq!(err.path, "#/allOf/1");
+        assert!(err.message.contains("incompatible types"));
+        // The lenient version still generates something
+        from_json_schema(&mut test_rng(), &types);
+
+        // number and integer intersect to integer
+        let numeric = json!({"allOf": [{"type": ["number", "string"]}, {"type": "integer"}]});
+        assert_eq!(prepare(&numeric).unwrap(), json!({"type": "integer"}));
+
+        let bounds = json!({"allOf": [{"type": "integer", "minimum": 9}, {"maximum": 2}]});
+        let err = from_json_schema_checked(&mut test_rng(), &bounds).unwrap_err();
+        assert_eq!(err.message, "minimum (9) is greater than maximum (2)");
+
+        let consts = json!({"allOf": [{"const": 1}, {"const": 2}]});
+        assert!(from_json_schema_checked(&mut test_rng(), &consts).is_err());
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let schema = json!({
+            "type": "object",
+            "properties": {"tags": {"type": "array", "items": {"type": "string"}, "maxItems": 2}},
+            "required": ["tags"]
+        });
+        assert!(is_valid(&json!({"tags": ["a"]}), &schema));
+        assert!(!is_valid(&json!({"tags": ["a", 1]}), &schema));
+        assert!(!is_valid(&json!({"tags": ["a", "b", "c"]}), &schema));
+        assert!(!is_valid(&json!({}), &schema));
+        assert!(is_valid(&json!(2.0), &json!({"type": "integer"})));
+        assert!(!is_valid(&json!(3), &json!({"not": {"minimum": 3}})));
+    }
+
     #[test]
     fn test_nested_object() {
         let mut rng = test_rng();
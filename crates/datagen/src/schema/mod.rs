@@ -45,7 +45,14 @@ mod sql;
 // Re-export main functions
 pub use avro::{from_avro_schema, to_avro_schema};
 pub use graphql::{from_graphql_query, from_graphql_schema};
-pub use inference::{infer_schema, infer_schema_from_values};
-pub use json_schema::{from_json_schema, from_json_schema_with_options, JsonSchemaOptions};
+pub use inference::{
+    infer_schema, infer_schema_from_values, infer_schema_from_values_with_options,
+    infer_schema_with_options, InferenceOptions, InferredSchema,
+};
+pub use json_schema::{
+    from_json_schema, from_json_schema_checked, from_json_schema_with_options,
+    from_json_schema_with_warnings, to_json_array_stream, Generated, JsonSchemaOptions,
+    SchemaError,
+};
 pub use openapi::{from_openapi, OpenApiSpec};
 pub use sql::{to_sql_ddl, to_sql_insert, to_sql_insert_batch, SqlDialect};
@@ -0,0 +1,173 @@
+//! Reproducible randomized tests.
+//!
+//! A [`SeedRecorder`] owns the seed behind a test's RNG. If the test panics,
+//! the seed is printed to stderr so the failure can be replayed by setting
+//! [`SEED_ENV_VAR`]:
+//!
+//! ```text
+//! dx-datagen: failed with seed 1234567 (rerun with DX_DATAGEN_SEED=1234567): assertion failed: n < 6
+//! ```
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::{generators, seeded};
+//!
+//! let n = seeded::run_with_recorded_seed(|rng| generators::int_range(rng, 1, 6));
+//! assert!((1..=6).contains(&n));
+//! ```
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Environment variable that fixes the seed used by [`SeedRecorder::from_env`].
+pub const SEED_ENV_VAR: &str = "DX_DATAGEN_SEED";
+
+/// A panic caught by [`SeedRecorder::try_run`], with the seed that caused it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeededPanic {
+    pub seed: u64,
+    /// The panic message, if it was a string
+    pub message: Option<String>,
+}
+
+impl fmt::Display for SeededPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed with seed {} (rerun with {}={})",
+            self.seed, SEED_ENV_VAR, self.seed
+        )?;
+        if let Some(message) = &self.message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SeededPanic {}
+
+/// Remembers the seed of an RNG so failures can be reproduced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedRecorder {
+    seed: u64,
+}
+
+impl SeedRecorder {
+    /// Use a fixed seed.
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Pick a fresh random seed.
+    pub fn random() -> Self {
+        Self::new(rand::rng().random())
+    }
+
+    /// Use the seed in [`SEED_ENV_VAR`] if it is set and valid, otherwise a
+    /// random one.
+    pub fn from_env() -> Self {
+        std::env::var(SEED_ENV_VAR)
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+            .map(Self::new)
+            .unwrap_or_else(Self::random)
+    }
+
+    /// The recorded seed.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// A new RNG seeded with the recorded seed.
+    pub fn rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.seed)
+    }
+
+    /// Run `f` with a seeded RNG, printing the seed to stderr if it panics.
+    ///
+    /// The panic is then resumed, so test harnesses still see the failure.
+    pub fn run<T>(&self, f: impl FnOnce(&mut StdRng) -> T) -> T {
+        let mut rng = self.rng();
+        match panic::catch_unwind(AssertUnwindSafe(|| f(&mut rng))) {
+            Ok(value) => value,
+            Err(payload) => {
+                eprintln!("dx-datagen: {}", self.panic_info(payload.as_ref()));
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+
+    /// Run `f` with a seeded RNG, returning a panic as an error instead.
+    pub fn try_run<T>(&self, f: impl FnOnce(&mut StdRng) -> T) -> Result<T, SeededPanic> {
+        let mut rng = self.rng();
+        panic::catch_unwind(AssertUnwindSafe(|| f(&mut rng)))
+            .map_err(|payload| self.panic_info(payload.as_ref()))
+    }
+
+    fn panic_info(&self, payload: &(dyn std::any::Any + Send)) -> SeededPanic {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+        SeededPanic {
+            seed: self.seed,
+            message,
+        }
+    }
+}
+
+/// Run `f` with an RNG seeded from [`SEED_ENV_VAR`] or at random, printing
+/// the seed to stderr if `f` panics.
+pub fn run_with_recorded_seed<T>(f: impl FnOnce(&mut StdRng) -> T) -> T {
+    SeedRecorder::from_env().run(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panic_surfaces_seed() {
+        let recorder = SeedRecorder::new(1234);
+        let err = recorder
+            .try_run(|rng| {
+                let n: u32 = rng.random();
+                panic!("deliberate failure after {}", n);
+            })
+            .unwrap_err();
+        assert_eq!(err.seed, 1234);
+        assert!(err.message.unwrap().starts_with("deliberate failure"));
+    }
+
+    #[test]
+    fn test_run_resumes_panic() {
+        let recorder = SeedRecorder::new(99);
+        let result = panic::catch_unwind(|| recorder.run(|_| panic!("boom")));
+        let payload = result.unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+    }
+
+    #[test]
+    fn test_same_seed_same_values() {
+        let recorder = SeedRecorder::new(7);
+        let a: Vec<u32> = recorder.run(|rng| (0..5).map(|_| rng.random()).collect());
+        let b: Vec<u32> = recorder.run(|rng| (0..5).map(|_| rng.random()).collect());
+        assert_eq!(a, b);
+        assert_eq!(recorder.try_run(|rng| rng.random::<u32>()), Ok(a[0]));
+    }
+
+    #[test]
+    fn test_display() {
+        let err = SeededPanic {
+            seed: 42,
+            message: Some("bad".to_string()),
+        };
+        assert_eq!(
+            err.to_string(),
+            "failed with seed 42 (rerun with DX_DATAGEN_SEED=42): bad"
+        );
+    }
+}
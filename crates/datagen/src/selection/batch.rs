@@ -53,6 +53,127 @@ where
     (0..count).map(|_| generator(rng)).collect()
 }
 
+/// Number of values between calls to the progress callback of
+/// [`generate_batch_with_progress`].
+pub const PROGRESS_INTERVAL: usize = 1024;
+
+/// Generate a batch of values, reporting progress as it goes.
+///
+/// `on_progress(done, count)` is called after every [`PROGRESS_INTERVAL`]
+/// values and once more at the end, so the last call is always
+/// `(count, count)`, including for an empty batch. This suits progress bars
+/// for batches of millions of values without tying the crate to one.
+///
+/// # Arguments
+///
+/// * `rng` - Random number generator
+/// * `count` - Number of values to generate
+/// * `generator` - Function that generates a single value
+/// * `on_progress` - Called with the number of values generated so far and `count`
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::selection::generate_batch_with_progress;
+/// use rand::{Rng, SeedableRng};
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let mut reports = Vec::new();
+/// let values: Vec<u8> = generate_batch_with_progress(
+///     &mut rng,
+///     2000,
+///     |r| r.random(),
+///     |done, total| reports.push((done, total)),
+/// );
+/// assert_eq!(values.len(), 2000);
+/// assert_eq!(reports, [(1024, 2000), (2000, 2000)]);
+/// ```
+pub fn generate_batch_with_progress<R, F, T, P>(
+    rng: &mut R,
+    count: usize,
+    generator: F,
+    mut on_progress: P,
+) -> Vec<T>
+where
+    R: ?Sized + Rng,
+    F: Fn(&mut R) -> T,
+    P: FnMut(usize, usize),
+{
+    let mut results = Vec::with_capacity(count);
+    for done in 1..=count {
+        results.push(generator(rng));
+        if done % PROGRESS_INTERVAL == 0 && done < count {
+            on_progress(done, count);
+        }
+    }
+    on_progress(count, count);
+    results
+}
+
+/// Generate up to `count` values into `sink` one at a time, stopping early
+/// when `should_stop` returns true.
+///
+/// `should_stop` is checked before each value, so nothing is generated
+/// after it is signalled. Nothing is buffered: each value goes to `sink` as
+/// soon as it exists, which suits writing to a socket or file. Returns the
+/// number of values produced.
+///
+/// # Arguments
+///
+/// * `rng` - Random number generator
+/// * `count` - Maximum number of values to generate
+/// * `generator` - Function that generates a single value
+/// * `sink` - Receives each value
+/// * `should_stop` - Polled between values, e.g. an `AtomicBool` set by another thread
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::selection::generate_stream_cancellable;
+/// use rand::{Rng, SeedableRng};
+/// use rand::rngs::StdRng;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let stop = AtomicBool::new(false);
+/// let mut lines = Vec::new();
+/// let produced = generate_stream_cancellable(
+///     &mut rng,
+///     1_000_000,
+///     |r| r.random_range(0..100),
+///     |n: u32| {
+///         lines.push(n.to_string());
+///         if lines.len() == 3 {
+///             stop.store(true, Ordering::Relaxed);
+///         }
+///     },
+///     || stop.load(Ordering::Relaxed),
+/// );
+/// assert_eq!(produced, 3);
+/// ```
+pub fn generate_stream_cancellable<R, F, T, S, C>(
+    rng: &mut R,
+    count: usize,
+    generator: F,
+    mut sink: S,
+    should_stop: C,
+) -> usize
+where
+    R: ?Sized + Rng,
+    F: Fn(&mut R) -> T,
+    S: FnMut(T),
+    C: Fn() -> bool,
+{
+    for produced in 0..count {
+        if should_stop() {
+            return produced;
+        }
+        sink(generator(rng));
+    }
+    count
+}
+
 /// Generate a batch of unique values using the provided generator function.
 ///
 /// # Arguments
@@ -269,6 +390,7 @@ mod tests {
     use rand::rngs::StdRng;
     use rand::SeedableRng;
     use std::collections::HashSet;
+    use std::sync::atomic::{AtomicBool, Ordering};
 
     #[test]
     fn test_generate_batch() {
@@ -288,6 +410,104 @@ mod tests {
         assert!(values.is_empty());
     }
 
+    #[test]
+    fn test_generate_batch_with_progress() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut reports = Vec::new();
+        let count = 3 * PROGRESS_INTERVAL + 5;
+        let values: Vec<u32> = generate_batch_with_progress(
+            &mut rng,
+            count,
+            |r| r.random_range(1..=100),
+            |done, total| reports.push((done, total)),
+        );
+
+        assert_eq!(values.len(), count);
+        assert!(reports.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(reports.iter().all(|&(_, total)| total == count));
+        assert_eq!(reports.len(), 4);
+        assert_eq!(reports[0].0, PROGRESS_INTERVAL);
+        assert_eq!(reports.last(), Some(&(count, count)));
+
+        // Same values as the plain batch for the same seed
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(
+            values,
+            generate_batch(&mut rng, count, |r| r.random_range(1..=100))
+        );
+    }
+
+    #[test]
+    fn test_generate_batch_with_progress_exact_and_empty() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut reports = Vec::new();
+        let _: Vec<u8> = generate_batch_with_progress(
+            &mut rng,
+            2 * PROGRESS_INTERVAL,
+            |r| r.random(),
+            |done, _| reports.push(done),
+        );
+        // No duplicate report when the count is a multiple of the interval
+        assert_eq!(reports, [PROGRESS_INTERVAL, 2 * PROGRESS_INTERVAL]);
+
+        let mut reports = Vec::new();
+        let values: Vec<u8> =
+            generate_batch_with_progress(&mut rng, 0, |r| r.random(), |d, t| reports.push((d, t)));
+        assert!(values.is_empty());
+        assert_eq!(reports, [(0, 0)]);
+    }
+
+    #[test]
+    fn test_generate_stream_cancellable_stops_at_flag() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let stop = AtomicBool::new(false);
+        let mut received = Vec::new();
+        let produced = generate_stream_cancellable(
+            &mut rng,
+            100,
+            |r| r.random_range(1..=100),
+            |v: i32| {
+                received.push(v);
+                if received.len() == 7 {
+                    stop.store(true, Ordering::SeqCst);
+                }
+            },
+            || stop.load(Ordering::SeqCst),
+        );
+
+        assert_eq!(produced, 7);
+        assert_eq!(received.len(), 7);
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(
+            received,
+            generate_batch(&mut rng, 7, |r| r.random_range(1..=100))
+        );
+    }
+
+    #[test]
+    fn test_generate_stream_cancellable_runs_to_count() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut received = 0;
+        let produced = generate_stream_cancellable(
+            &mut rng,
+            25,
+            |r| r.random::<u8>(),
+            |_| received += 1,
+            || false,
+        );
+        assert_eq!((produced, received), (25, 25));
+
+        // Already cancelled: nothing is generated
+        let produced = generate_stream_cancellable(
+            &mut rng,
+            25,
+            |_| -> u8 { panic!("generated after cancellation") },
+            |_| {},
+            || true,
+        );
+        assert_eq!(produced, 0);
+    }
+
     #[test]
     fn test_generate_batch_unique() {
         let mut rng = StdRng::seed_from_u64(42);
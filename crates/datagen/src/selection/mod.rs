@@ -4,14 +4,18 @@
 //! - **weighted**: Weighted random selection for non-uniform distributions
 //! - **unique**: Unique value generation with collision tracking
 //! - **batch**: Batch generation helpers for multiple values
+//! - **reservoir**: Single-pass sampling from iterators of unknown length
 
 pub mod batch;
+pub mod reservoir;
 pub mod unique;
 pub mod weighted;
 
 pub use batch::{
     generate_batch, generate_batch_map, generate_batch_nullable, generate_batch_unique,
-    generate_batch_unique_with_retries, generate_until,
+    generate_batch_unique_with_retries, generate_batch_with_progress, generate_stream_cancellable,
+    generate_until, PROGRESS_INTERVAL,
 };
+pub use reservoir::reservoir_sample;
 pub use unique::{UniqueError, UniqueGenerator, UniqueTracker};
 pub use weighted::{weighted_pick, weighted_pick_from, WeightedItem, WeightedSelector};
@@ -0,0 +1,109 @@
+//! Reservoir sampling.
+//!
+//! Picks a uniform random sample from an iterator of unknown length in a
+//! single pass, holding only the sample in memory.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::selection::reservoir_sample;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let sample = reservoir_sample(&mut rng, 1..=1_000_000, 3);
+//! assert_eq!(sample.len(), 3);
+//! ```
+
+use rand::Rng;
+
+/// Sample `k` items from `iter` uniformly at random (Algorithm R).
+///
+/// Every item has the same chance of being chosen. If the iterator yields
+/// `k` items or fewer, all of them are returned in their original order;
+/// otherwise the order of the sample is unspecified.
+///
+/// # Arguments
+///
+/// * `rng` - Random number generator
+/// * `iter` - Source items, consumed once
+/// * `k` - Sample size
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::selection::reservoir_sample;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+/// use std::io::{BufRead, Cursor};
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let file = Cursor::new("alpha\nbeta\ngamma\ndelta\n");
+/// let lines = reservoir_sample(&mut rng, file.lines().map_while(Result::ok), 2);
+/// assert_eq!(lines.len(), 2);
+/// ```
+pub fn reservoir_sample<R, T, I>(rng: &mut R, iter: I, k: usize) -> Vec<T>
+where
+    R: ?Sized + Rng,
+    I: Iterator<Item = T>,
+{
+    let mut reservoir = Vec::with_capacity(k);
+    if k == 0 {
+        return reservoir;
+    }
+
+    for (i, item) in iter.enumerate() {
+        if i < k {
+            reservoir.push(item);
+        } else {
+            let j = rng.random_range(0..=i);
+            if j < k {
+                reservoir[j] = item;
+            }
+        }
+    }
+    reservoir
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_sample_comes_from_source() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let sample = reservoir_sample(&mut rng, 0..100, 10);
+        assert_eq!(sample.len(), 10);
+        assert!(sample.iter().all(|n| (0..100).contains(n)));
+        let distinct: HashSet<_> = sample.iter().collect();
+        assert_eq!(distinct.len(), 10);
+    }
+
+    #[test]
+    fn test_k_at_least_n_returns_all() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(reservoir_sample(&mut rng, 0..5, 5), vec![0, 1, 2, 3, 4]);
+        assert_eq!(reservoir_sample(&mut rng, 0..5, 50), vec![0, 1, 2, 3, 4]);
+        assert!(reservoir_sample(&mut rng, 0..5, 0).is_empty());
+    }
+
+    #[test]
+    fn test_sample_is_roughly_uniform() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut counts = [0usize; 10];
+        for _ in 0..5000 {
+            for n in reservoir_sample(&mut rng, 0..10, 2) {
+                counts[n] += 1;
+            }
+        }
+        // Each item is expected 1000 times
+        assert!(
+            counts.iter().all(|&c| (850..1150).contains(&c)),
+            "{:?}",
+            counts
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Summary statistics for generated numbers.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::stats;
+//!
+//! let summary = stats::summary(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).unwrap();
+//! assert_eq!(summary.mean, 5.0);
+//! assert_eq!(summary.median, 4.5);
+//! assert_eq!(summary.std_dev, 2.0);
+//! ```
+
+/// Summary of a batch of numbers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Summary {
+    pub count: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    /// Population standard deviation
+    pub std_dev: f64,
+    /// 95th percentile
+    pub p95: f64,
+}
+
+/// Summarize `values`, or `None` for an empty slice.
+///
+/// Percentiles interpolate linearly between the two nearest sorted values.
+pub fn summary(values: &[f64]) -> Option<Summary> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let count = sorted.len();
+    let mean = sorted.iter().sum::<f64>() / count as f64;
+    let variance = sorted.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count as f64;
+
+    Some(Summary {
+        count,
+        min: sorted[0],
+        max: sorted[count - 1],
+        mean,
+        median: percentile(&sorted, 50.0),
+        std_dev: variance.sqrt(),
+        p95: percentile(&sorted, 95.0),
+    })
+}
+
+/// The `p`th percentile (0-100) of non-empty sorted values.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_known_dataset() {
+        let s = summary(&[9.0, 2.0, 5.0, 4.0, 4.0, 7.0, 4.0, 5.0]).unwrap();
+        assert_eq!(s.count, 8);
+        assert_eq!((s.min, s.max), (2.0, 9.0));
+        assert_eq!(s.mean, 5.0);
+        assert_eq!(s.median, 4.5);
+        assert_eq!(s.std_dev, 2.0);
+        // rank 0.95 * 7 = 6.65, between 7 and 9
+        assert!((s.p95 - 8.3).abs() < 1e-9, "{}", s.p95);
+    }
+
+    #[test]
+    fn test_summary_odd_count_and_single_value() {
+        let s = summary(&[1.0, 3.0, 2.0]).unwrap();
+        assert_eq!(s.median, 2.0);
+        assert!((s.p95 - 2.9).abs() < 1e-9);
+
+        let s = summary(&[42.0]).unwrap();
+        assert_eq!((s.min, s.max, s.median, s.p95), (42.0, 42.0, 42.0, 42.0));
+        assert_eq!(s.std_dev, 0.0);
+    }
+
+    #[test]
+    fn test_summary_empty() {
+        assert_eq!(summary(&[]), None);
+    }
+}
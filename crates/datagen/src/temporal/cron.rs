@@ -0,0 +1,384 @@
+//! Cron schedules.
+//!
+//! Compute the fire times of a standard 5-field cron expression
+//! (`minute hour day-of-month month day-of-week`), e.g. to generate
+//! realistic timestamps for scheduler tests.
+//!
+//! Each field accepts `*`, single values, ranges (`1-5`), steps (`*/15`,
+//! `0-30/10`, `5/20`) and comma-separated lists of those. Day-of-week runs
+//! from 0 (Sunday) to 6, with 7 as an alias for Sunday. As in classic cron,
+//! when both day-of-month and day-of-week are restricted a day matches if
+//! either one does.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::temporal::cron::next_n;
+//! use chrono::{TimeZone, Utc};
+//!
+//! let after = Utc.with_ymd_and_hms(2024, 1, 1, 10, 7, 0).unwrap();
+//! let times = next_n("*/15 * * * *", after, 2).unwrap();
+//! assert_eq!(times[0].to_rfc3339(), "2024-01-01T10:15:00+00:00");
+//! assert_eq!(times[1].to_rfc3339(), "2024-01-01T10:30:00+00:00");
+//! ```
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+
+/// Cron parse error, naming the field that failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CronError {
+    /// Field name, e.g. `"hour"`, or `"expression"` for a wrong field count
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl CronError {
+    fn new(field: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for CronError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid cron {}: {}", self.field, self.message)
+    }
+}
+
+impl std::error::Error for CronError {}
+
+/// Name and inclusive range of each field, in expression order.
+const FIELDS: [(&str, u32, u32); 5] = [
+    ("minute", 0, 59),
+    ("hour", 0, 23),
+    ("day of month", 1, 31),
+    ("month", 1, 12),
+    ("day of week", 0, 7),
+];
+
+/// How far ahead to search before giving up. Long enough for any schedule
+/// that only fires on February 29th.
+const SEARCH_YEARS: i32 = 10;
+
+/// A parsed cron expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    dom_restricted: bool,
+    dow_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parse a 5-field cron expression.
+    pub fn parse(expr: &str) -> Result<Self, CronError> {
+        let parts: Vec<&str> = expr.split_whitespace().collect();
+        if parts.len() != 5 {
+            return Err(CronError::new(
+                "expression",
+                format!("expected 5 fields, got {}", parts.len()),
+            ));
+        }
+
+        let mut sets = [0u64; 5];
+        for (i, (&part, &(name, min, max))) in parts.iter().zip(FIELDS.iter()).enumerate() {
+            sets[i] = parse_field(part, min, max).map_err(|msg| CronError::new(name, msg))?;
+        }
+
+        // Sunday may be written as 0 or 7
+        let mut days_of_week = sets[4];
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        let schedule = Self {
+            minutes: sets[0],
+            hours: sets[1],
+            days_of_month: sets[2],
+            months: sets[3],
+            days_of_week,
+            dom_restricted: !parts[2].starts_with('*'),
+            dow_restricted: !parts[4].starts_with('*'),
+        };
+
+        if !schedule.dow_restricted && !schedule.can_match_day_of_month() {
+            return Err(CronError::new(
+                "day of month",
+                format!("\"{}\" never occurs in the selected months", parts[2]),
+            ));
+        }
+        Ok(schedule)
+    }
+
+    /// Whether `dt` (truncated to the minute) is a fire time.
+    pub fn matches(&self, dt: &DateTime<Utc>) -> bool {
+        let dt = dt.naive_utc();
+        self.matches_day(dt.date()) && has(self.hours, dt.hour()) && has(self.minutes, dt.minute())
+    }
+
+    /// The first fire time strictly after `after`.
+    ///
+    /// Returns `None` if there is none within the search window.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let start = after.naive_utc();
+        let limit = start.year() + SEARCH_YEARS;
+
+        // Next whole minute
+        let mut t = start.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        while t.year() <= limit {
+            if !has(self.months, t.month()) {
+                t = first_of_next_month(t.date())?;
+            } else if !self.matches_day(t.date()) {
+                t = t.date().succ_opt()?.and_hms_opt(0, 0, 0)?;
+            } else if !has(self.hours, t.hour()) {
+                t = t.with_minute(0)? + Duration::hours(1);
+            } else if !has(self.minutes, t.minute()) {
+                t += Duration::minutes(1);
+            } else {
+                return Some(t.and_utc());
+            }
+        }
+        None
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        if !has(self.months, date.month()) {
+            return false;
+        }
+        let dom = has(self.days_of_month, date.day());
+        let dow = has(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.dom_restricted, self.dow_restricted) {
+            (true, true) => dom || dow,
+            (true, false) => dom,
+            (false, true) => dow,
+            (false, false) => true,
+        }
+    }
+
+    /// Whether some selected day of month exists in some selected month.
+    fn can_match_day_of_month(&self) -> bool {
+        (1..=12).filter(|&m| has(self.months, m)).any(|m| {
+            let days = match m {
+                2 => 29,
+                4 | 6 | 9 | 11 => 30,
+                _ => 31,
+            };
+            (1..=days).any(|d| has(self.days_of_month, d))
+        })
+    }
+}
+
+/// Compute the next `n` fire times of `expr` strictly after `after`.
+///
+/// Stops early if the schedule has no further fire times within ten years.
+pub fn next_n(expr: &str, after: DateTime<Utc>, n: usize) -> Result<Vec<DateTime<Utc>>, CronError> {
+    let schedule = CronSchedule::parse(expr)?;
+    let mut times = Vec::with_capacity(n);
+    let mut current = after;
+    while times.len() < n {
+        match schedule.next_after(current) {
+            Some(next) => {
+                times.push(next);
+                current = next;
+            }
+            None => break,
+        }
+    }
+    Ok(times)
+}
+
+fn has(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDateTime> {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)
+}
+
+/// Parse one field into a bitset of allowed values.
+fn parse_field(field: &str, min: u32, max: u32) -> Result<u64, String> {
+    let mut set = 0u64;
+    for item in field.split(',') {
+        if item.is_empty() {
+            return Err(format!("empty list item in \"{}\"", field));
+        }
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("invalid step \"{}\"", step))?;
+                if step == 0 {
+                    return Err("step must be greater than 0".to_string());
+                }
+                (range, Some(step))
+            }
+            None => (item, None),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((a, b)) = range.split_once('-') {
+            (parse_value(a, min, max)?, parse_value(b, min, max)?)
+        } else {
+            let value = parse_value(range, min, max)?;
+            // `5/20` means "from 5 to the end, every 20"
+            (value, if step.is_some() { max } else { value })
+        };
+        if start > end {
+            return Err(format!("range {}-{} is reversed", start, end));
+        }
+
+        for value in (start..=end).step_by(step.unwrap_or(1) as usize) {
+            set |= 1 << value;
+        }
+    }
+    Ok(set)
+}
+
+fn parse_value(s: &str, min: u32, max: u32) -> Result<u32, String> {
+    let value: u32 = s.parse().map_err(|_| format!("invalid value \"{}\"", s))?;
+    if value < min || value > max {
+        return Err(format!(
+            "{} is out of range (expected {}-{})",
+            value, min, max
+        ));
+    }
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn utc(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, mo, d, h, mi, 0).unwrap()
+    }
+
+    #[test]
+    fn test_daily_midnights() {
+        let times = next_n("0 0 * * *", utc(2024, 2, 27, 15, 30), 4).unwrap();
+        assert_eq!(
+            times,
+            vec![
+                utc(2024, 2, 28, 0, 0),
+                utc(2024, 2, 29, 0, 0),
+                utc(2024, 3, 1, 0, 0),
+                utc(2024, 3, 2, 0, 0),
+            ]
+        );
+        for pair in times.windows(2) {
+            assert_eq!(pair[1] - pair[0], Duration::days(1));
+        }
+    }
+
+    #[test]
+    fn test_quarter_hours() {
+        let times = next_n("*/15 * * * *", utc(2024, 12, 31, 23, 20), 4).unwrap();
+        assert_eq!(
+            times,
+            vec![
+                utc(2024, 12, 31, 23, 30),
+                utc(2024, 12, 31, 23, 45),
+                utc(2025, 1, 1, 0, 0),
+                utc(2025, 1, 1, 0, 15),
+            ]
+        );
+        assert!(times.iter().all(|t| t.minute() % 15 == 0));
+    }
+
+    #[test]
+    fn test_after_is_exclusive() {
+        let times = next_n("0 * * * *", utc(2024, 1, 1, 10, 0), 1).unwrap();
+        assert_eq!(times, vec![utc(2024, 1, 1, 11, 0)]);
+    }
+
+    #[test]
+    fn test_ranges_and_lists() {
+        // 09:30 and 17:30 on weekdays; 2024-01-06 is a Saturday
+        let times = next_n("30 9,17 * * 1-5", utc(2024, 1, 5, 18, 0), 3).unwrap();
+        assert_eq!(
+            times,
+            vec![
+                utc(2024, 1, 8, 9, 30),
+                utc(2024, 1, 8, 17, 30),
+                utc(2024, 1, 9, 9, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stepped_range_and_start_step() {
+        let schedule = CronSchedule::parse("0-30/10 5/6 * * *").unwrap();
+        assert!(schedule.matches(&utc(2024, 1, 1, 5, 20)));
+        assert!(schedule.matches(&utc(2024, 1, 1, 23, 30)));
+        assert!(!schedule.matches(&utc(2024, 1, 1, 5, 40)));
+        assert!(!schedule.matches(&utc(2024, 1, 1, 6, 0)));
+    }
+
+    #[test]
+    fn test_sunday_as_seven() {
+        // 2024-01-07 is a Sunday
+        let times = next_n("0 12 * * 7", utc(2024, 1, 1, 0, 0), 1).unwrap();
+        assert_eq!(times, vec![utc(2024, 1, 7, 12, 0)]);
+        assert_eq!(
+            CronSchedule::parse("0 12 * * 7").unwrap(),
+            CronSchedule::parse("0 12 * * 0").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_day_of_month_or_day_of_week() {
+        // The 15th, or any Monday
+        let times = next_n("0 0 15 * 1", utc(2024, 1, 9, 0, 0), 3).unwrap();
+        assert_eq!(
+            times,
+            vec![
+                utc(2024, 1, 15, 0, 0),
+                utc(2024, 1, 22, 0, 0),
+                utc(2024, 1, 29, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_leap_day() {
+        let times = next_n("0 0 29 2 *", utc(2024, 3, 1, 0, 0), 1).unwrap();
+        assert_eq!(times, vec![utc(2028, 2, 29, 0, 0)]);
+    }
+
+    #[test]
+    fn test_errors_name_the_field() {
+        let err = |expr| CronSchedule::parse(expr).unwrap_err();
+        assert_eq!(err("* * * *").field, "expression");
+        assert_eq!(err("60 * * * *").field, "minute");
+        assert_eq!(err("* 24 * * *").field, "hour");
+        assert_eq!(err("* * 0 * *").field, "day of month");
+        assert_eq!(err("* * * 1-13 *").field, "month");
+        assert_eq!(err("* * * * mon").field, "day of week");
+        assert_eq!(err("*/0 * * * *").field, "minute");
+        assert_eq!(err("* 5-2 * * *").field, "hour");
+        assert_eq!(err("* * 30 2 *").field, "day of month");
+        assert_eq!(
+            err("* 24 * * *").to_string(),
+            "Invalid cron hour: 24 is out of range (expected 0-23)"
+        );
+    }
+
+    #[test]
+    fn test_next_n_zero() {
+        assert!(next_n("* * * * *", utc(2024, 1, 1, 0, 0), 0)
+            .unwrap()
+            .is_empty());
+    }
+}
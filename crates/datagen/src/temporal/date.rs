@@ -17,6 +17,7 @@
 //! let d = date_between(&mut rng, start, end);
 //! ```
 
+use super::clock::{Clock, SystemClock};
 use chrono::{Datelike, Days, NaiveDate, Utc};
 use rand::Rng;
 
@@ -35,14 +36,32 @@ pub fn date_between<R: ?Sized + Rng>(rng: &mut R, start: NaiveDate, end: NaiveDa
 
 /// Generate a random date in the past (within the last N days).
 pub fn date_past<R: ?Sized + Rng>(rng: &mut R, days_ago: u64) -> NaiveDate {
-    let today = Utc::now().date_naive();
+    date_past_with_clock(rng, days_ago, &SystemClock)
+}
+
+/// Like [`date_past`], but relative to `clock` instead of the system time.
+pub fn date_past_with_clock<R: ?Sized + Rng>(
+    rng: &mut R,
+    days_ago: u64,
+    clock: &impl Clock,
+) -> NaiveDate {
+    let today = clock.today();
     let past = today.checked_sub_days(Days::new(days_ago)).unwrap_or(today);
     date_between(rng, past, today)
 }
 
 /// Generate a random date in the future (within the next N days).
 pub fn date_future<R: ?Sized + Rng>(rng: &mut R, days_ahead: u64) -> NaiveDate {
-    let today = Utc::now().date_naive();
+    date_future_with_clock(rng, days_ahead, &SystemClock)
+}
+
+/// Like [`date_future`], but relative to `clock` instead of the system time.
+pub fn date_future_with_clock<R: ?Sized + Rng>(
+    rng: &mut R,
+    days_ahead: u64,
+    clock: &impl Clock,
+) -> NaiveDate {
+    let today = clock.today();
     let future = today
         .checked_add_days(Days::new(days_ahead))
         .unwrap_or(today);
@@ -54,6 +73,11 @@ pub fn date_recent<R: ?Sized + Rng>(rng: &mut R) -> NaiveDate {
     date_past(rng, 7)
 }
 
+/// Like [`date_recent`], but relative to `clock` instead of the system time.
+pub fn date_recent_with_clock<R: ?Sized + Rng>(rng: &mut R, clock: &impl Clock) -> NaiveDate {
+    date_past_with_clock(rng, 7, clock)
+}
+
 /// Generate a date within a year range.
 pub fn date_in_year<R: ?Sized + Rng>(rng: &mut R, year: i32) -> NaiveDate {
     let start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
@@ -134,6 +158,46 @@ mod tests {
         assert!(d >= today);
     }
 
+    #[test]
+    fn test_date_past_with_clock_is_before_now() {
+        use super::super::clock::FixedClock;
+        let clock = FixedClock::from_ymd_hms(2024, 3, 15, 12, 0, 0);
+        let earliest = NaiveDate::from_ymd_opt(2024, 2, 14).unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..200 {
+            let d = date_past_with_clock(&mut rng, 30, &clock);
+            assert!(d <= clock.today());
+            assert!(d >= earliest);
+        }
+    }
+
+    #[test]
+    fn test_date_future_and_recent_with_clock() {
+        use super::super::clock::FixedClock;
+        let clock = FixedClock::from_ymd_hms(2024, 3, 15, 12, 0, 0);
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..200 {
+            let d = date_future_with_clock(&mut rng, 10, &clock);
+            assert!(d >= clock.today());
+            assert!(d <= NaiveDate::from_ymd_opt(2024, 3, 25).unwrap());
+            let d = date_recent_with_clock(&mut rng, &clock);
+            assert!(d <= clock.today());
+            assert!(d >= NaiveDate::from_ymd_opt(2024, 3, 8).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_with_clock_is_reproducible() {
+        use super::super::clock::FixedClock;
+        let clock = FixedClock::from_ymd_hms(2024, 3, 15, 12, 0, 0);
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        assert_eq!(
+            date_past_with_clock(&mut rng1, 365, &clock),
+            date_past_with_clock(&mut rng2, 365, &clock)
+        );
+    }
+
     #[test]
     fn test_date_in_year() {
         let mut rng = StdRng::seed_from_u64(42);
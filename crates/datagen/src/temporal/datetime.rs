@@ -23,6 +23,7 @@
 //! let dt = datetime_between(&mut rng, start, end);
 //! ```
 
+use super::clock::{Clock, SystemClock};
 use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use rand::Rng;
 
@@ -47,14 +48,32 @@ pub fn datetime_between<R: ?Sized + Rng>(
 
 /// Generate a random datetime in the past (within last N days).
 pub fn datetime_past<R: ?Sized + Rng>(rng: &mut R, days_ago: i64) -> NaiveDateTime {
-    let now = Utc::now().naive_utc();
+    datetime_past_with_clock(rng, days_ago, &SystemClock)
+}
+
+/// Like [`datetime_past`], but relative to `clock` instead of the system time.
+pub fn datetime_past_with_clock<R: ?Sized + Rng>(
+    rng: &mut R,
+    days_ago: i64,
+    clock: &impl Clock,
+) -> NaiveDateTime {
+    let now = clock.now_naive();
     let past = now - Duration::days(days_ago);
     datetime_between(rng, past, now)
 }
 
 /// Generate a random datetime in the future (within next N days).
 pub fn datetime_future<R: ?Sized + Rng>(rng: &mut R, days_ahead: i64) -> NaiveDateTime {
-    let now = Utc::now().naive_utc();
+    datetime_future_with_clock(rng, days_ahead, &SystemClock)
+}
+
+/// Like [`datetime_future`], but relative to `clock` instead of the system time.
+pub fn datetime_future_with_clock<R: ?Sized + Rng>(
+    rng: &mut R,
+    days_ahead: i64,
+    clock: &impl Clock,
+) -> NaiveDateTime {
+    let now = clock.now_naive();
     let future = now + Duration::days(days_ahead);
     datetime_between(rng, now, future)
 }
@@ -64,6 +83,14 @@ pub fn datetime_recent<R: ?Sized + Rng>(rng: &mut R) -> NaiveDateTime {
     datetime_past(rng, 7)
 }
 
+/// Like [`datetime_recent`], but relative to `clock` instead of the system time.
+pub fn datetime_recent_with_clock<R: ?Sized + Rng>(
+    rng: &mut R,
+    clock: &impl Clock,
+) -> NaiveDateTime {
+    datetime_past_with_clock(rng, 7, clock)
+}
+
 /// Generate a random unix timestamp (seconds since epoch).
 pub fn timestamp_unix<R: ?Sized + Rng>(rng: &mut R) -> i64 {
     // Range: 2000-01-01 to 2030-12-31
@@ -222,6 +249,22 @@ mod tests {
         assert!(dt >= now);
     }
 
+    #[test]
+    fn test_datetime_with_fixed_clock() {
+        use super::super::clock::{Clock, FixedClock};
+        let clock = FixedClock::from_ymd_hms(2024, 3, 15, 12, 0, 0);
+        let now = clock.now_naive();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..200 {
+            let dt = datetime_past_with_clock(&mut rng, 30, &clock);
+            assert!(dt <= now && dt >= now - Duration::days(30));
+            let dt = datetime_future_with_clock(&mut rng, 30, &clock);
+            assert!(dt >= now && dt <= now + Duration::days(30));
+            let dt = datetime_recent_with_clock(&mut rng, &clock);
+            assert!(dt <= now && dt >= now - Duration::days(7));
+        }
+    }
+
     #[test]
     fn test_timestamp_unix() {
         let mut rng = StdRng::seed_from_u64(42);
@@ -4,14 +4,21 @@
 //! This module requires the `temporal` feature flag.
 
 pub mod business;
+pub mod clock;
+pub mod cron;
 pub mod date;
 pub mod datetime;
 pub mod time;
 
 pub use business::{business_date, is_business_day, next_business_day};
-pub use date::{date_between, date_future, date_past, date_recent};
+pub use clock::{Clock, FixedClock, SystemClock};
+pub use date::{
+    date_between, date_future, date_future_with_clock, date_past, date_past_with_clock,
+    date_recent, date_recent_with_clock,
+};
 pub use datetime::{
-    datetime_between, datetime_past, iso8601, timestamp_future, timestamp_future_ms,
-    timestamp_range, timestamp_recent, timestamp_recent_ms, timestamp_unix, timestamp_unix_ms,
+    datetime_between, datetime_past, datetime_past_with_clock, iso8601, timestamp_future,
+    timestamp_future_ms, timestamp_range, timestamp_recent, timestamp_recent_ms, timestamp_unix,
+    timestamp_unix_ms,
 };
 pub use time::{time_between, time_of_day, time_random};
@@ -307,7 +307,7 @@ mod tests {
         let p = paragraph(&mut rng);
         // Should have multiple sentences (3-7)
         let period_count = p.matches('.').count();
-        assert!(period_count >= 3 && period_count <= 7);
+        assert!((3..=7).contains(&period_count));
     }
 
     #[test]
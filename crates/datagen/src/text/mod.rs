@@ -1,14 +1,18 @@
 //! Text generation utilities.
 //!
-//! This module provides pattern-based text generation, word lists, lorem ipsum,
-//! and template interpolation.
+//! This module provides pattern-based and regex-based text generation, word
+//! lists, lorem ipsum, template interpolation, and made-up names.
 
 pub mod lorem;
+pub mod names;
 pub mod patterns;
+pub mod regex;
 pub mod template;
 pub mod words;
 
 pub use lorem::{paragraph, paragraphs, sentence, sentences};
+pub use names::{brandable, brandable_with_style, NameStyle};
 pub use patterns::from_pattern;
+pub use regex::{from_regex, from_regex_with_max_repeat, RegexError};
 pub use template::{render, render_default, ProviderRegistry, Template};
 pub use words::{adjective, noun, verb, word};
@@ -0,0 +1,227 @@
+//! Pronounceable made-up names.
+//!
+//! Names are built from syllables of an onset (one or two consonants), a
+//! vowel and an optional closing consonant. Consonant runs never exceed two
+//! letters, so names stay easy to say.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::text::names::{brandable, brandable_with_style, NameStyle};
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//! let name = brandable(&mut rng, 3);
+//! assert!(name.chars().next().unwrap().is_uppercase());
+//!
+//! let elf = brandable_with_style(&mut rng, 3, NameStyle::Fantasy);
+//! assert!(!elf.is_empty());
+//! ```
+
+use rand::Rng;
+
+/// Sound palette for [`brandable_with_style`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NameStyle {
+    /// Balanced, neutral sounds
+    #[default]
+    Generic,
+    /// Short, crisp sounds with hard consonants (`Zentrix`, `Kovo`)
+    Tech,
+    /// Soft, flowing sounds with vowel pairs (`Thaelorin`, `Mairevel`)
+    Fantasy,
+}
+
+impl NameStyle {
+    /// Look up a style by name (`generic`, `tech`, `fantasy`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "generic" => Some(Self::Generic),
+            "tech" => Some(Self::Tech),
+            "fantasy" => Some(Self::Fantasy),
+            _ => None,
+        }
+    }
+
+    fn sounds(self) -> &'static Sounds {
+        match self {
+            Self::Generic => &GENERIC,
+            Self::Tech => &TECH,
+            Self::Fantasy => &FANTASY,
+        }
+    }
+}
+
+struct Sounds {
+    onsets: &'static [&'static str],
+    vowels: &'static [&'static str],
+    codas: &'static [&'static str],
+    /// Chance of closing a syllable with a consonant
+    coda_chance: f64,
+}
+
+const GENERIC: Sounds = Sounds {
+    onsets: &[
+        "b", "c", "d", "f", "g", "h", "j", "k", "l", "m", "n", "p", "r", "s", "t", "v", "w", "br",
+        "cl", "dr", "gr", "pr", "st", "tr",
+    ],
+    vowels: &["a", "e", "i", "o", "u", "ai", "ea", "io"],
+    codas: &["l", "m", "n", "r", "s", "t"],
+    coda_chance: 0.3,
+};
+
+const TECH: Sounds = Sounds {
+    onsets: &[
+        "b", "c", "d", "k", "l", "m", "n", "p", "q", "r", "s", "t", "v", "x", "z", "fl", "kr",
+        "pl", "qu", "st", "tr", "zy",
+    ],
+    vowels: &["a", "e", "i", "o", "u", "io"],
+    codas: &["k", "n", "r", "s", "x", "z"],
+    coda_chance: 0.4,
+};
+
+const FANTASY: Sounds = Sounds {
+    onsets: &[
+        "b", "d", "f", "g", "l", "m", "n", "r", "s", "t", "v", "z", "dr", "el", "gl", "kh", "th",
+    ],
+    vowels: &["a", "e", "i", "o", "u", "ae", "ai", "ei", "ia", "ou"],
+    codas: &["l", "n", "r", "s", "th"],
+    coda_chance: 0.35,
+};
+
+fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+/// Consonants at the end of `s`.
+fn trailing_consonants(s: &str) -> usize {
+    s.chars().rev().take_while(|&c| !is_vowel(c)).count()
+}
+
+/// Consonants at the start of `s`.
+fn leading_consonants(s: &str) -> usize {
+    s.chars().take_while(|&c| !is_vowel(c)).count()
+}
+
+/// Pick a part that keeps the consonant run after `name` at two or fewer.
+fn pick_part<R: ?Sized + Rng>(rng: &mut R, name: &str, parts: &[&'static str]) -> &'static str {
+    let trailing = trailing_consonants(name);
+    let fits = |part: &&&str| {
+        let leading = leading_consonants(part);
+        leading == 0 || trailing + leading <= 2
+    };
+    let count = parts.iter().filter(fits).count();
+    parts
+        .iter()
+        .filter(fits)
+        .nth(rng.random_range(0..count))
+        .copied()
+        .expect("every palette has single-consonant parts")
+}
+
+/// Generate a pronounceable made-up name with `syllables` syllables.
+///
+/// # Example
+///
+/// ```
+/// use dx_datagen::text::names::brandable;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let name = brandable(&mut rng, 2);
+/// assert!(name.len() >= 2);
+/// ```
+pub fn brandable<R: ?Sized + Rng>(rng: &mut R, syllables: usize) -> String {
+    brandable_with_style(rng, syllables, NameStyle::Generic)
+}
+
+/// Generate a made-up name using the sounds of `style`.
+///
+/// At least one syllable is always generated.
+pub fn brandable_with_style<R: ?Sized + Rng>(
+    rng: &mut R,
+    syllables: usize,
+    style: NameStyle,
+) -> String {
+    let sounds = style.sounds();
+    let mut name = String::new();
+
+    for _ in 0..syllables.max(1) {
+        // Names may also start with a bare vowel
+        if !name.is_empty() || rng.random_bool(0.8) {
+            let onset = pick_part(rng, &name, sounds.onsets);
+            name.push_str(onset);
+        }
+        let vowel = sounds.vowels[rng.random_range(0..sounds.vowels.len())];
+        name.push_str(vowel);
+        if rng.random_bool(sounds.coda_chance) {
+            let coda = pick_part(rng, &name, sounds.codas);
+            name.push_str(coda);
+        }
+    }
+
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn max_consonant_run(name: &str) -> usize {
+        let mut max = 0;
+        let mut run = 0;
+        for c in name.chars() {
+            run = if is_vowel(c) { 0 } else { run + 1 };
+            max = max.max(run);
+        }
+        max
+    }
+
+    #[test]
+    fn test_names_are_pronounceable() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for style in [NameStyle::Generic, NameStyle::Tech, NameStyle::Fantasy] {
+            for syllables in 2..=4 {
+                for _ in 0..200 {
+                    let name = brandable_with_style(&mut rng, syllables, style);
+                    assert!(!name.is_empty());
+                    assert!(max_consonant_run(&name) <= 2, "{}", name);
+                    // Every syllable contributes a vowel group
+                    let vowels = name.chars().filter(|&c| is_vowel(c)).count();
+                    assert!(vowels >= syllables, "{}", name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_capitalized_and_at_least_one_syllable() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let name = brandable(&mut rng, 0);
+        assert!(!name.is_empty());
+        assert!(name.chars().next().unwrap().is_uppercase());
+        assert!(name.chars().skip(1).all(|c| c.is_lowercase()));
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let a = brandable(&mut StdRng::seed_from_u64(9), 3);
+        let b = brandable(&mut StdRng::seed_from_u64(9), 3);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_style_from_name() {
+        assert_eq!(NameStyle::from_name("Tech"), Some(NameStyle::Tech));
+        assert_eq!(NameStyle::from_name("fantasy"), Some(NameStyle::Fantasy));
+        assert_eq!(NameStyle::from_name("gothic"), None);
+    }
+}
@@ -0,0 +1,519 @@
+//! Regex-driven string generation.
+//!
+//! Generate strings that match a regular expression. Supported syntax:
+//!
+//! - Literals and escapes: `a`, `\.`, `\n`, `\t`
+//! - Character classes: `[a-z0-9_]`, `[^aeiou]`, `.`, `\d`, `\w`, `\s` and
+//!   their negations `\D`, `\W`, `\S`
+//! - Groups and alternation: `(cat|dog)`, `(?:ab)+`
+//! - Quantifiers: `?`, `*`, `+`, `{n}`, `{n,}`, `{n,m}` (a trailing `?` for
+//!   lazy matching is accepted and ignored)
+//! - Anchors `^` and `$`, which generate nothing
+//!
+//! Open-ended quantifiers (`*`, `+`, `{n,}`) repeat at most [`MAX_REPEAT`]
+//! times beyond their minimum so output stays short. Negated classes pick
+//! from printable ASCII.
+//!
+//! # Example
+//!
+//! ```
+//! use dx_datagen::text::from_regex;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(42);
+//!
+//! let pet = from_regex(&mut rng, "^(cat|dog)s?$").unwrap();
+//! assert!(["cat", "cats", "dog", "dogs"].contains(&pet.as_str()));
+//!
+//! let code = from_regex(&mut rng, r"[A-Z]{3}-\d{4}").unwrap();
+//! assert_eq!(code.len(), 8);
+//!
+//! assert!(from_regex(&mut rng, "(unclosed").is_err());
+//! ```
+
+use rand::Rng;
+use std::fmt;
+
+/// Default cap on extra repetitions for `*`, `+` and `{n,}`.
+pub const MAX_REPEAT: usize = 8;
+
+/// Error from parsing a regex pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegexError {
+    /// Character offset in the pattern where the problem was found
+    pub position: usize,
+    pub message: String,
+}
+
+impl RegexError {
+    fn new(position: usize, message: &str) -> Self {
+        Self {
+            position,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid regex at {}: {}", self.position, self.message)
+    }
+}
+
+impl std::error::Error for RegexError {}
+
+/// Generate a string matching `pattern`.
+///
+/// Open-ended quantifiers repeat at most [`MAX_REPEAT`] extra times.
+pub fn from_regex<R: ?Sized + Rng>(rng: &mut R, pattern: &str) -> Result<String, RegexError> {
+    from_regex_with_max_repeat(rng, pattern, MAX_REPEAT)
+}
+
+/// Generate a string matching `pattern`, with open-ended quantifiers
+/// repeating at most `max_repeat` times beyond their minimum.
+pub fn from_regex_with_max_repeat<R: ?Sized + Rng>(
+    rng: &mut R,
+    pattern: &str,
+    max_repeat: usize,
+) -> Result<String, RegexError> {
+    let node = Parser::new(pattern, max_repeat).parse()?;
+    let mut out = String::new();
+    node.generate(rng, &mut out);
+    Ok(out)
+}
+
+/// Parsed pattern.
+#[derive(Debug)]
+enum Node {
+    Literal(char),
+    /// One character from the set
+    Class(Vec<char>),
+    /// Concatenation
+    Sequence(Vec<Node>),
+    /// One of several branches
+    Alternation(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: usize,
+        max: usize,
+    },
+    /// Anchors match without producing output
+    Empty,
+}
+
+impl Node {
+    fn generate<R: ?Sized + Rng>(&self, rng: &mut R, out: &mut String) {
+        match self {
+            Node::Literal(c) => out.push(*c),
+            Node::Class(chars) => out.push(chars[rng.random_range(0..chars.len())]),
+            Node::Sequence(nodes) => {
+                for node in nodes {
+                    node.generate(rng, out);
+                }
+            }
+            Node::Alternation(branches) => {
+                branches[rng.random_range(0..branches.len())].generate(rng, out)
+            }
+            Node::Repeat { node, min, max } => {
+                for _ in 0..rng.random_range(*min..=*max) {
+                    node.generate(rng, out);
+                }
+            }
+            Node::Empty => {}
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    max_repeat: usize,
+}
+
+impl Parser {
+    fn new(pattern: &str, max_repeat: usize) -> Self {
+        Self {
+            chars: pattern.chars().collect(),
+            pos: 0,
+            max_repeat,
+        }
+    }
+
+    fn parse(mut self) -> Result<Node, RegexError> {
+        let node = self.parse_alternation()?;
+        if self.pos < self.chars.len() {
+            // parse_alternation only stops early at a ')'
+            return Err(RegexError::new(self.pos, "unmatched ')'"));
+        }
+        Ok(node)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_alternation(&mut self) -> Result<Node, RegexError> {
+        let mut branches = vec![self.parse_sequence()?];
+        while self.eat('|') {
+            branches.push(self.parse_sequence()?);
+        }
+        Ok(if branches.len() == 1 {
+            branches.pop().unwrap()
+        } else {
+            Node::Alternation(branches)
+        })
+    }
+
+    fn parse_sequence(&mut self) -> Result<Node, RegexError> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.parse_atom()?;
+            nodes.push(self.parse_quantifier(atom)?);
+        }
+        Ok(Node::Sequence(nodes))
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, RegexError> {
+        let start = self.pos;
+        let c = self.next().expect("parse_atom called at end of pattern");
+        match c {
+            '(' => {
+                if self.eat('?') && !self.eat(':') {
+                    return Err(RegexError::new(
+                        self.pos,
+                        "only non-capturing (?:...) groups are supported",
+                    ));
+                }
+                let inner = self.parse_alternation()?;
+                if !self.eat(')') {
+                    return Err(RegexError::new(start, "unclosed group"));
+                }
+                Ok(inner)
+            }
+            '[' => self.parse_class(start),
+            '.' => Ok(Node::Class(printable_ascii().collect())),
+            '^' | '$' => Ok(Node::Empty),
+            '\\' => {
+                let escaped = self
+                    .next()
+                    .ok_or_else(|| RegexError::new(start, "trailing backslash"))?;
+                Ok(match escape_class(escaped) {
+                    Some(chars) => Node::Class(chars),
+                    None => Node::Literal(escape_literal(escaped)),
+                })
+            }
+            '*' | '+' | '?' | '{' => Err(RegexError::new(start, "nothing to repeat")),
+            _ => Ok(Node::Literal(c)),
+        }
+    }
+
+    fn parse_class(&mut self, start: usize) -> Result<Node, RegexError> {
+        let negated = self.eat('^');
+        let mut chars = Vec::new();
+        let mut first = true;
+
+        loop {
+            let c = self
+                .next()
+                .ok_or_else(|| RegexError::new(start, "unclosed character class"))?;
+            match c {
+                // A ']' right after '[' or '[^' is a literal
+                ']' if !first => break,
+                '\\' => {
+                    let escaped = self
+                        .next()
+                        .ok_or_else(|| RegexError::new(start, "unclosed character class"))?;
+                    match escape_class(escaped) {
+                        Some(class) => chars.extend(class),
+                        None => self.push_class_char(&mut chars, escape_literal(escaped))?,
+                    }
+                }
+                _ => self.push_class_char(&mut chars, c)?,
+            }
+            first = false;
+        }
+
+        if negated {
+            chars = printable_ascii().filter(|c| !chars.contains(c)).collect();
+        } else {
+            chars.sort_unstable();
+            chars.dedup();
+        }
+        if chars.is_empty() {
+            return Err(RegexError::new(start, "character class matches nothing"));
+        }
+        Ok(Node::Class(chars))
+    }
+
+    /// Add `c` to a class, expanding it to a range if a `-` follows.
+    fn push_class_char(&mut self, chars: &mut Vec<char>, c: char) -> Result<(), RegexError> {
+        let is_range =
+            self.peek() == Some('-') && !matches!(self.chars.get(self.pos + 1), Some(']') | None);
+        if !is_range {
+            chars.push(c);
+            return Ok(());
+        }
+
+        self.pos += 1; // '-'
+        let end_pos = self.pos;
+        let end = match self.next() {
+            Some('\\') => self
+                .next()
+                .map(escape_literal)
+                .ok_or_else(|| RegexError::new(end_pos, "unclosed character class"))?,
+            Some(end) => end,
+            None => unreachable!("checked above"),
+        };
+        if end < c {
+            return Err(RegexError::new(end_pos, "range out of order"));
+        }
+        chars.extend(c..=end);
+        Ok(())
+    }
+
+    fn parse_quantifier(&mut self, node: Node) -> Result<Node, RegexError> {
+        let start = self.pos;
+        let (min, max) = match self.peek() {
+            Some('?') => (0, 1),
+            Some('*') => (0, self.max_repeat),
+            Some('+') => (1, 1 + self.max_repeat),
+            Some('{') => {
+                self.pos += 1;
+                let min = self
+                    .parse_number()
+                    .ok_or_else(|| RegexError::new(start, "invalid repetition count"))?;
+                let max = if self.eat(',') {
+                    self.parse_number()
+                        .unwrap_or(min.saturating_add(self.max_repeat))
+                } else {
+                    min
+                };
+                if self.peek() != Some('}') {
+                    return Err(RegexError::new(start, "unclosed repetition"));
+                }
+                if max < min {
+                    return Err(RegexError::new(start, "repetition range out of order"));
+                }
+                (min, max)
+            }
+            _ => return Ok(node),
+        };
+        self.pos += 1;
+
+        // Lazy and possessive suffixes don't change what can be generated
+        if matches!(self.peek(), Some('?') | Some('+')) {
+            self.pos += 1;
+        }
+        if matches!(self.peek(), Some('?' | '*' | '+' | '{')) {
+            return Err(RegexError::new(self.pos, "nothing to repeat"));
+        }
+
+        Ok(Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+        })
+    }
+
+    fn parse_number(&mut self) -> Option<usize> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .ok()
+    }
+}
+
+fn printable_ascii() -> impl Iterator<Item = char> {
+    ' '..='~'
+}
+
+/// Characters for a class escape like `\d`, or `None` for other escapes.
+fn escape_class(c: char) -> Option<Vec<char>> {
+    let digit = |c: &char| c.is_ascii_digit();
+    let word = |c: &char| c.is_ascii_alphanumeric() || *c == '_';
+    let space = |c: &char| matches!(c, ' ' | '\t' | '\n' | '\r');
+
+    let chars: Vec<char> = match c {
+        'd' => ('0'..='9').collect(),
+        'w' => printable_ascii().filter(word).collect(),
+        's' => vec![' ', '\t', '\n', '\r'],
+        'D' => printable_ascii().filter(|c| !digit(c)).collect(),
+        'W' => printable_ascii().filter(|c| !word(c)).collect(),
+        'S' => printable_ascii().filter(|c| !space(c)).collect(),
+        _ => return None,
+    };
+    Some(chars)
+}
+
+/// The character a non-class escape stands for, like `\n` or `\.`.
+fn escape_literal(c: char) -> char {
+    match c {
+        'n' => '\n',
+        't' => '\t',
+        'r' => '\r',
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::HashSet;
+
+    fn samples(pattern: &str, n: usize) -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(42);
+        (0..n)
+            .map(|_| from_regex(&mut rng, pattern).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_alternation_yields_only_branches() {
+        let seen: HashSet<String> = samples("(cat|dog)", 200).into_iter().collect();
+        let expected: HashSet<String> = ["cat", "dog"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn test_repeated_group() {
+        for s in samples("a(bc)+d", 200) {
+            let middle = s
+                .strip_prefix('a')
+                .and_then(|s| s.strip_suffix('d'))
+                .unwrap_or_else(|| panic!("{:?} is not a...d", s));
+            assert!(!middle.is_empty());
+            assert_eq!(middle.len() % 2, 0);
+            assert_eq!(middle, "bc".repeat(middle.len() / 2));
+            assert!(middle.len() / 2 <= 1 + MAX_REPEAT);
+        }
+    }
+
+    #[test]
+    fn test_top_level_alternation_and_anchors() {
+        for s in samples("^ab$|^cd$", 50) {
+            assert!(s == "ab" || s == "cd", "{:?}", s);
+        }
+        assert_eq!(samples("^abc$", 1)[0], "abc");
+    }
+
+    #[test]
+    fn test_nested_groups_and_non_capturing() {
+        for s in samples("(?:x(y|z)){2}", 50) {
+            assert_eq!(s.len(), 4);
+            assert!(s.starts_with('x'));
+            assert_eq!(s.as_bytes()[2], b'x');
+            assert!(matches!(s.as_bytes()[1], b'y' | b'z'));
+        }
+    }
+
+    #[test]
+    fn test_classes_and_escapes() {
+        for s in samples(r"[A-Z]{3}-\d{4}\.[^a-z\d]", 50) {
+            assert_eq!(s.len(), 10);
+            assert!(s[..3].chars().all(|c| c.is_ascii_uppercase()));
+            assert!(s[4..8].chars().all(|c| c.is_ascii_digit()));
+            assert_eq!(&s[8..9], ".");
+            let last = s.chars().last().unwrap();
+            assert!(!last.is_ascii_lowercase() && !last.is_ascii_digit());
+        }
+        for s in samples("[-a]", 20) {
+            assert!(s == "-" || s == "a");
+        }
+        for s in samples("[]x]", 20) {
+            assert!(s == "]" || s == "x");
+        }
+    }
+
+    #[test]
+    fn test_quantifier_bounds() {
+        for s in samples("a{2,4}b?c*", 200) {
+            let a = s.chars().take_while(|&c| c == 'a').count();
+            assert!((2..=4).contains(&a), "{:?}", s);
+            assert!(s.matches('b').count() <= 1);
+            assert!(s.matches('c').count() <= MAX_REPEAT);
+        }
+        for s in samples("x{3,}", 50) {
+            assert!((3..=3 + MAX_REPEAT).contains(&s.len()));
+        }
+    }
+
+    #[test]
+    fn test_max_repeat_cap() {
+        let mut rng = StdRng::seed_from_u64(42);
+        assert_eq!(from_regex_with_max_repeat(&mut rng, "a*", 0).unwrap(), "");
+        assert_eq!(from_regex_with_max_repeat(&mut rng, "a+", 0).unwrap(), "a");
+        for _ in 0..50 {
+            let s = from_regex_with_max_repeat(&mut rng, "(ab)*", 2).unwrap();
+            assert!(s.len() <= 4);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for (pattern, message) in [
+            ("(ab", "unclosed group"),
+            ("ab)", "unmatched ')'"),
+            ("[a-", "unclosed character class"),
+            ("[z-a]", "range out of order"),
+            ("*a", "nothing to repeat"),
+            ("a**", "nothing to repeat"),
+            ("a{3,1}", "repetition range out of order"),
+            ("a{x}", "invalid repetition count"),
+            ("a{3", "unclosed repetition"),
+            ("ab\\", "trailing backslash"),
+            ("(?=a)", "only non-capturing (?:...) groups are supported"),
+        ] {
+            let err = from_regex(&mut rng, pattern).unwrap_err();
+            assert_eq!(err.message, message, "{}", pattern);
+        }
+        assert_eq!(
+            from_regex(&mut rng, "ab)").unwrap_err().to_string(),
+            "Invalid regex at 2: unmatched ')'"
+        );
+    }
+
+    #[test]
+    fn test_empty_pattern_and_branches() {
+        assert_eq!(samples("", 1)[0], "");
+        for s in samples("a(|b)", 20) {
+            assert!(s == "a" || s == "ab");
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let mut rng1 = StdRng::seed_from_u64(7);
+        let mut rng2 = StdRng::seed_from_u64(7);
+        assert_eq!(
+            from_regex(&mut rng1, "(a|b|c)+[0-9]*").unwrap(),
+            from_regex(&mut rng2, "(a|b|c)+[0-9]*").unwrap()
+        );
+    }
+}
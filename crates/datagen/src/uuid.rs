@@ -201,5 +201,6 @@ mod tests {
         assert_eq!(id1.len(), 26);
         // Note: IDs may differ due to timestamp component, but random parts should be same
         // with same seed
+        assert_eq!(id1[10..], id2[10..]);
     }
 }
@@ -318,7 +318,7 @@ mod tests {
     fn test_vehicle_year() {
         let mut rng = StdRng::seed_from_u64(42);
         let year = vehicle_year(&mut rng);
-        assert!(year >= 1990 && year <= 2025);
+        assert!((1990..=2025).contains(&year));
     }
 
     #[test]
@@ -200,21 +200,21 @@ mod tests {
     fn test_temperature_f() {
         let mut rng = StdRng::seed_from_u64(42);
         let t = temperature_f(&mut rng);
-        assert!(t >= -20 && t < 110);
+        assert!((-20..110).contains(&t));
     }
 
     #[test]
     fn test_temperature_c() {
         let mut rng = StdRng::seed_from_u64(42);
         let t = temperature_c(&mut rng);
-        assert!(t >= -30 && t < 45);
+        assert!((-30..45).contains(&t));
     }
 
     #[test]
     fn test_humidity() {
         let mut rng = StdRng::seed_from_u64(42);
         let h = humidity(&mut rng);
-        assert!(h >= 20 && h < 100);
+        assert!((20..100).contains(&h));
     }
 
     #[test]
@@ -236,9 +236,9 @@ mod tests {
     fn test_season_temperature() {
         let mut rng = StdRng::seed_from_u64(42);
         let winter_temp = temperature_f_season(&mut rng, "winter");
-        assert!(winter_temp >= 10 && winter_temp < 40);
+        assert!((10..40).contains(&winter_temp));
 
         let summer_temp = temperature_f_season(&mut rng, "summer");
-        assert!(summer_temp >= 70 && summer_temp < 100);
+        assert!((70..100).contains(&summer_temp));
     }
 }
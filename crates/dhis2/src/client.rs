@@ -1,49 +1,248 @@
 //! DHIS2 API client.
 
 use anyhow::{Context, Result};
-use reqwest::blocking::Client;
+use reqwest::blocking::{Client, Response};
 use serde::de::DeserializeOwned;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 /// DHIS2 API client with authentication.
 pub struct Dhis2Client {
-    client: Client,
+    transport: Transport,
     base_url: String,
     username: String,
     password: String,
+    cache: Option<ResponseCache>,
 }
 
-impl Dhis2Client {
-    /// Create a new DHIS2 client.
-    pub fn new(server: &str, username: &str, password: &str) -> Result<Self> {
+/// Where requests go.
+enum Transport {
+    Http(Client),
+    /// Canned `(status, body)` responses by endpoint, see [`Dhis2Client::mock`]
+    Mock(HashMap<String, (u16, String)>),
+}
+
+/// Builder for [`Dhis2Client`].
+///
+/// # Example
+/// ```no_run
+/// use dx_dhis2::Dhis2Client;
+/// use std::time::Duration;
+///
+/// let client = Dhis2Client::builder()
+///     .server("https://play.im.dhis2.org/demo")
+///     .credentials("admin", "district")
+///     .cache_ttl(Duration::from_secs(300))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Dhis2ClientBuilder {
+    server: String,
+    username: String,
+    password: String,
+    timeout: Duration,
+    cache_ttl: Option<Duration>,
+}
+
+impl Dhis2ClientBuilder {
+    /// Set the server URL (default: [`DEFAULT_SERVER`](crate::DEFAULT_SERVER)).
+    pub fn server(mut self, server: &str) -> Self {
+        self.server = server.to_string();
+        self
+    }
+
+    /// Set the username and password (default: the demo credentials).
+    pub fn credentials(mut self, username: &str, password: &str) -> Self {
+        self.username = username.to_string();
+        self.password = password.to_string();
+        self
+    }
+
+    /// Set the request timeout (default: 30 seconds).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Cache JSON responses in memory for `ttl`, keyed by request URL.
+    ///
+    /// Identical [`Dhis2Client::get`] calls within the TTL are answered from
+    /// the cache without a request. Caching is off by default.
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Create the client.
+    pub fn build(self) -> Result<Dhis2Client> {
         // Don't auto-follow redirects - we'll handle them manually to preserve auth
         let client = Client::builder()
             .redirect(reqwest::redirect::Policy::none())
-            .timeout(Duration::from_secs(30))
+            .timeout(self.timeout)
             .build()
             .context("Failed to create HTTP client")?;
 
         // Ensure base URL doesn't have trailing slash
-        let base_url = server.trim_end_matches('/').to_string();
+        let base_url = self.server.trim_end_matches('/').to_string();
 
-        Ok(Self {
-            client,
+        Ok(Dhis2Client {
+            transport: Transport::Http(client),
             base_url,
-            username: username.to_string(),
-            password: password.to_string(),
+            username: self.username,
+            password: self.password,
+            cache: self.cache_ttl.map(ResponseCache::new),
         })
     }
+}
+
+/// Response bodies by URL, each with the time it was fetched.
+struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, String)>>,
+}
+
+impl ResponseCache {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn get(&self, url: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        match entries.get(url) {
+            Some((fetched, body)) if fetched.elapsed() < self.ttl => Some(body.clone()),
+            Some(_) => {
+                entries.remove(url);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, url: &str, body: &str) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(url.to_string(), (Instant::now(), body.to_string()));
+    }
+}
+
+impl Dhis2Client {
+    /// Create a new DHIS2 client.
+    pub fn new(server: &str, username: &str, password: &str) -> Result<Self> {
+        Self::builder()
+            .server(server)
+            .credentials(username, password)
+            .build()
+    }
+
+    /// Create a client that answers from canned responses instead of HTTP.
+    ///
+    /// Keys are endpoints relative to `/api/`, such as `system/info.json`.
+    /// A request matches its full endpoint first, then the endpoint without
+    /// its query string. Unmatched requests fail, and non-2xx statuses fail
+    /// as they would against a real server. Use this to test code that takes
+    /// a `Dhis2Client`.
+    ///
+    /// # Example
+    /// ```
+    /// use dx_dhis2::Dhis2Client;
+    /// use std::collections::HashMap;
+    ///
+    /// let client = Dhis2Client::mock(HashMap::from([(
+    ///     "system/info.json".to_string(),
+    ///     (200, r#"{"version": "2.41.1"}"#.to_string()),
+    /// )]));
+    /// let info = dx_dhis2::info::fetch(&client).unwrap();
+    /// assert_eq!(info.version.as_deref(), Some("2.41.1"));
+    /// ```
+    pub fn mock(responses: HashMap<String, (u16, String)>) -> Self {
+        Self {
+            transport: Transport::Mock(responses),
+            base_url: "http://dhis2.mock".to_string(),
+            username: String::new(),
+            password: String::new(),
+            cache: None,
+        }
+    }
+
+    /// Start building a client with more options, such as a response cache.
+    pub fn builder() -> Dhis2ClientBuilder {
+        Dhis2ClientBuilder {
+            server: crate::DEFAULT_SERVER.to_string(),
+            username: crate::DEFAULT_USER.to_string(),
+            password: crate::DEFAULT_PASSWORD.to_string(),
+            timeout: Duration::from_secs(30),
+            cache_ttl: None,
+        }
+    }
 
     /// Fetch JSON from a DHIS2 API endpoint, following redirects with auth.
     pub fn get<T: DeserializeOwned>(&self, endpoint: &str) -> Result<T> {
-        let mut url = format!("{}/api/{}", self.base_url, endpoint);
+        let Some(cache) = &self.cache else {
+            let response = self.send(endpoint, &[])?;
+            let url = response.url().to_string();
+            return response
+                .json()
+                .with_context(|| format!("Failed to parse JSON from {}", url));
+        };
+
+        let url = self.url(endpoint);
+        let body = match cache.get(&url) {
+            Some(body) => body,
+            None => {
+                let body = self
+                    .send(endpoint, &[])?
+                    .text()
+                    .with_context(|| format!("Failed to read response from {}", url))?;
+                cache.insert(&url, &body);
+                body
+            }
+        };
+        serde_json::from_str(&body).with_context(|| format!("Failed to parse JSON from {}", url))
+    }
+
+    /// Drop all cached responses.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache
+                .entries
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clear();
+        }
+    }
+
+    fn url(&self, endpoint: &str) -> String {
+        format!("{}/api/{}", self.base_url, endpoint)
+    }
+
+    /// Send a GET request with extra headers, following redirects with auth.
+    ///
+    /// Returns the successful response without reading its body.
+    pub(crate) fn send(&self, endpoint: &str, headers: &[(&str, String)]) -> Result<Response> {
+        let client = match &self.transport {
+            Transport::Http(client) => client,
+            Transport::Mock(responses) => {
+                return check_status(mock_response(responses, endpoint)?);
+            }
+        };
+
+        let mut url = self.url(endpoint);
 
         // Follow redirects manually (up to 10) to preserve auth across hosts
         for _ in 0..10 {
-            let response = self
-                .client
+            let mut request = client
                 .get(&url)
-                .basic_auth(&self.username, Some(&self.password))
+                .basic_auth(&self.username, Some(&self.password));
+            for (name, value) in headers {
+                request = request.header(*name, value);
+            }
+            let response = request
                 .send()
                 .with_context(|| format!("Failed to fetch {}", url))?;
 
@@ -75,15 +274,7 @@ impl Dhis2Client {
                 }
             }
 
-            if !response.status().is_success() {
-                let status = response.status();
-                let body = response.text().unwrap_or_default();
-                anyhow::bail!("HTTP {}: {}", status, body);
-            }
-
-            return response
-                .json()
-                .with_context(|| format!("Failed to parse JSON from {}", url));
+            return check_status(response);
         }
 
         anyhow::bail!("Too many redirects")
@@ -94,3 +285,114 @@ impl Dhis2Client {
         &self.base_url
     }
 }
+
+/// Fail with the status and body unless the response is a success.
+fn check_status(response: Response) -> Result<Response> {
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        anyhow::bail!("HTTP {}: {}", status, body);
+    }
+    Ok(response)
+}
+
+/// Build the canned response for `endpoint`.
+fn mock_response(responses: &HashMap<String, (u16, String)>, endpoint: &str) -> Result<Response> {
+    let path = endpoint.split_once('?').map_or(endpoint, |(path, _)| path);
+    let (status, body) = responses
+        .get(endpoint)
+        .or_else(|| responses.get(path))
+        .with_context(|| format!("No mock response for {}", endpoint))?;
+    let response = http::Response::builder()
+        .status(*status)
+        .body(body.clone())
+        .with_context(|| format!("Invalid mock status {} for {}", status, endpoint))?;
+    Ok(response.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{mock_server, response};
+    use serde_json::Value;
+
+    #[test]
+    fn test_cache_skips_second_request() {
+        // The mock accepts one connection, so a second request would fail
+        let (url, server) = mock_server(response("200 OK", &[], br#"{"version": "2.41"}"#));
+        let client = Dhis2Client::builder()
+            .server(&url)
+            .cache_ttl(Duration::from_secs(60))
+            .build()
+            .unwrap();
+
+        let first: Value = client.get("system/info.json").unwrap();
+        server.join().unwrap();
+        let second: Value = client.get("system/info.json").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(second["version"], "2.41");
+
+        // A different URL is not cached
+        assert!(client.get::<Value>("me.json").is_err());
+        client.clear_cache();
+        assert!(client.get::<Value>("system/info.json").is_err());
+    }
+
+    #[test]
+    fn test_cache_entries_expire() {
+        let cache = ResponseCache::new(Duration::ZERO);
+        cache.insert("u", "{}");
+        assert_eq!(cache.get("u"), None);
+
+        let cache = ResponseCache::new(Duration::from_secs(60));
+        cache.insert("u", "{}");
+        assert_eq!(cache.get("u").as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn test_mock_responses() {
+        let client = Dhis2Client::mock(HashMap::from([
+            (
+                "me.json".to_string(),
+                (200, r#"{"name": "admin"}"#.to_string()),
+            ),
+            (
+                "dataSets.json?pageSize=1".to_string(),
+                (200, r#"{"exact": true}"#.to_string()),
+            ),
+            (
+                "dataSets.json".to_string(),
+                (200, r#"{"exact": false}"#.to_string()),
+            ),
+            ("users.json".to_string(), (403, "Access denied".to_string())),
+        ]));
+
+        let me: Value = client.get("me.json?fields=name").unwrap();
+        assert_eq!(me["name"], "admin");
+        let exact: Value = client.get("dataSets.json?pageSize=1").unwrap();
+        assert_eq!(exact["exact"], true);
+        let path_only: Value = client.get("dataSets.json?pageSize=5").unwrap();
+        assert_eq!(path_only["exact"], false);
+
+        let err = client.get::<Value>("users.json").unwrap_err().to_string();
+        assert!(
+            err.contains("403") && err.contains("Access denied"),
+            "{}",
+            err
+        );
+        let err = client
+            .get::<Value>("programs.json")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(err, "No mock response for programs.json");
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        let client = Dhis2Client::builder().build().unwrap();
+        assert_eq!(client.base_url(), crate::DEFAULT_SERVER);
+        assert!(client.cache.is_none());
+        let client = Dhis2Client::new("http://localhost:8080/", "a", "b").unwrap();
+        assert_eq!(client.base_url(), "http://localhost:8080");
+    }
+}
@@ -0,0 +1,97 @@
+//! DHIS2 data element groups command.
+
+use crate::Dhis2Client;
+use anyhow::Result;
+use colored::Colorize;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct DataElementGroupResponse {
+    #[serde(rename = "dataElementGroups")]
+    data_element_groups: Vec<DataElementGroup>,
+}
+
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct DataElementGroup {
+    pub id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: String,
+    pub name: String,
+    #[serde(rename = "shortName")]
+    pub short_name: Option<String>,
+    pub code: Option<String>,
+    #[serde(rename = "groupSets", default)]
+    pub group_sets: Vec<ItemRef>,
+    #[serde(rename = "dataElements", default)]
+    pub data_elements: Vec<ItemRef>,
+}
+
+/// Reference to another metadata object.
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct ItemRef {
+    pub id: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+}
+
+/// Fetch data element groups from DHIS2.
+pub fn fetch(client: &Dhis2Client, limit: usize) -> Result<Vec<DataElementGroup>> {
+    let url = format!("dataElementGroups.json?fields=*&pageSize={}", limit);
+
+    let response: DataElementGroupResponse = client.get(&url)?;
+    Ok(response.data_element_groups)
+}
+
+/// Run the data-element-groups subcommand.
+pub fn run(client: &Dhis2Client, limit: usize, json: bool) -> Result<()> {
+    let groups = fetch(client, limit)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&groups)?);
+        return Ok(());
+    }
+
+    if groups.is_empty() {
+        println!("No data element groups found.");
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!("Data Element Groups (showing {})", groups.len())
+            .cyan()
+            .bold()
+    );
+    println!();
+
+    // Print header
+    println!(
+        "  {:<12} {:<35} {:<12} {}",
+        "ID".yellow(),
+        "Name".yellow(),
+        "Code".yellow(),
+        "Elements".yellow()
+    );
+    println!("  {}", "-".repeat(80));
+
+    for group in &groups {
+        let code = group.code.as_deref().unwrap_or("-");
+
+        // Truncate name if too long
+        let name = if group.display_name.len() > 33 {
+            format!("{}...", &group.display_name[..30])
+        } else {
+            group.display_name.clone()
+        };
+
+        println!(
+            "  {:<12} {:<35} {:<12} {}",
+            group.id.cyan(),
+            name,
+            code,
+            group.data_elements.len().to_string().dimmed()
+        );
+    }
+
+    Ok(())
+}
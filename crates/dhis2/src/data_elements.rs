@@ -29,16 +29,17 @@ pub struct DataElement {
     pub description: Option<String>,
 }
 
+/// Fields requested for each [`DataElement`].
+pub(crate) const FIELDS: &str =
+    "id,displayName,name,shortName,code,valueType,aggregationType,domainType,description";
+
 /// Fetch data elements from DHIS2.
 pub fn fetch(
     client: &Dhis2Client,
     limit: usize,
     value_type: Option<&str>,
 ) -> Result<Vec<DataElement>> {
-    let fields =
-        "id,displayName,name,shortName,code,valueType,aggregationType,domainType,description";
-
-    let mut url = format!("dataElements.json?fields={}&pageSize={}", fields, limit);
+    let mut url = format!("dataElements.json?fields={}&pageSize={}", FIELDS, limit);
 
     if let Some(vt) = value_type {
         url.push_str(&format!("&filter=valueType:eq:{}", vt));
@@ -48,6 +49,17 @@ pub fn fetch(
     Ok(response.data_elements)
 }
 
+/// Fetch the members of a data element group.
+pub fn in_group(client: &Dhis2Client, group_uid: &str) -> Result<Vec<DataElement>> {
+    let url = format!(
+        "dataElementGroups/{}.json?fields=dataElements[{}]",
+        group_uid, FIELDS
+    );
+
+    let response: DataElementResponse = client.get(&url)?;
+    Ok(response.data_elements)
+}
+
 /// Run the elements subcommand.
 pub fn run(
     client: &Dhis2Client,
@@ -125,3 +137,30 @@ pub fn run(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{mock_server, response};
+
+    #[test]
+    fn test_in_group_extracts_members() {
+        let body = r#"{"dataElements": [
+            {"id": "fbfJHSPpUQD", "displayName": "ANC 1st visit", "name": "ANC 1st visit",
+             "valueType": "NUMBER"},
+            {"id": "cYeuwXTCPkU", "displayName": "ANC 2nd visit", "name": "ANC 2nd visit"}
+        ]}"#;
+        let (url, server) = mock_server(response("200 OK", &[], body.as_bytes()));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let elements = in_group(&client, "qfxEYY9xAl6").unwrap();
+        let ids: Vec<&str> = elements.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["fbfJHSPpUQD", "cYeuwXTCPkU"]);
+        assert_eq!(elements[0].value_type.as_deref(), Some("NUMBER"));
+
+        let request = server.join().unwrap();
+        assert!(
+            request.starts_with("GET /api/dataElementGroups/qfxEYY9xAl6.json?fields=dataElements[")
+        );
+    }
+}
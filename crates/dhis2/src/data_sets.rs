@@ -1,5 +1,6 @@
 //! DHIS2 data sets command.
 
+use crate::data_elements::{self, DataElement};
 use crate::Dhis2Client;
 use anyhow::Result;
 use colored::Colorize;
@@ -38,6 +39,34 @@ pub fn fetch(client: &Dhis2Client, limit: usize) -> Result<Vec<DataSet>> {
     Ok(response.data_sets)
 }
 
+#[derive(Debug, Deserialize)]
+struct DataSetElementsResponse {
+    #[serde(rename = "dataSetElements", default)]
+    data_set_elements: Vec<DataSetElement>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DataSetElement {
+    #[serde(rename = "dataElement")]
+    data_element: DataElement,
+}
+
+/// Fetch the data elements of a data set.
+pub fn data_elements(client: &Dhis2Client, data_set_uid: &str) -> Result<Vec<DataElement>> {
+    let url = format!(
+        "dataSets/{}.json?fields=dataSetElements[dataElement[{}]]",
+        data_set_uid,
+        data_elements::FIELDS
+    );
+
+    let response: DataSetElementsResponse = client.get(&url)?;
+    Ok(response
+        .data_set_elements
+        .into_iter()
+        .map(|dse| dse.data_element)
+        .collect())
+}
+
 /// Run the data-sets subcommand.
 pub fn run(client: &Dhis2Client, limit: usize, json: bool) -> Result<()> {
     let data_sets = fetch(client, limit)?;
@@ -102,3 +131,27 @@ pub fn run(client: &Dhis2Client, limit: usize, json: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{mock_server, response};
+
+    #[test]
+    fn test_data_elements_extracts_members() {
+        let body = r#"{"dataSetElements": [
+            {"dataElement": {"id": "s46m5MS0hxu", "displayName": "BCG doses", "name": "BCG doses"}},
+            {"dataElement": {"id": "UOlfIjgN8X6", "displayName": "Fever", "name": "Fever"}}
+        ]}"#;
+        let (url, server) = mock_server(response("200 OK", &[], body.as_bytes()));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let elements = data_elements(&client, "BfMAe6Itzgt").unwrap();
+        let ids: Vec<&str> = elements.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["s46m5MS0hxu", "UOlfIjgN8X6"]);
+
+        let request = server.join().unwrap();
+        assert!(request
+            .starts_with("GET /api/dataSets/BfMAe6Itzgt.json?fields=dataSetElements[dataElement["));
+    }
+}
@@ -0,0 +1,187 @@
+//! DHIS2 file resource downloads.
+//!
+//! Streams binary resources (data value attachments, icons) to a writer
+//! instead of buffering them, optionally resuming from a byte offset.
+//! See: https://docs.dhis2.org/en/develop/using-the-api/dhis-core-version-241/file-resources.html
+
+use crate::Dhis2Client;
+use anyhow::{bail, Context, Result};
+use reqwest::StatusCode;
+use std::io::{self, Read, Write};
+
+/// What a download wrote, and what the server said about the resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDownload {
+    /// The `Content-Type` of the response, if any
+    pub content_type: Option<String>,
+    /// Bytes written to the writer by this call
+    pub bytes_written: u64,
+    /// Full size of the resource, if the server reported it
+    pub total_size: Option<u64>,
+}
+
+/// Stream the data of file resource `id` to `writer`.
+pub fn download(client: &Dhis2Client, id: &str, writer: &mut impl Write) -> Result<FileDownload> {
+    download_from(client, id, 0, writer)
+}
+
+/// Stream the data of file resource `id` to `writer`, starting at `offset`.
+///
+/// Use this to resume an interrupted download: pass the number of bytes
+/// already saved and append the rest.
+pub fn download_from(
+    client: &Dhis2Client,
+    id: &str,
+    offset: u64,
+    writer: &mut impl Write,
+) -> Result<FileDownload> {
+    download_endpoint(
+        client,
+        &format!("fileResources/{}/data", id),
+        offset,
+        writer,
+    )
+}
+
+/// Stream any binary API endpoint, such as `icons/<key>/icon`, to `writer`.
+///
+/// With a non-zero `offset` a `Range` header is sent. If the server ignores
+/// it and returns the whole body, the first `offset` bytes are skipped so
+/// the writer still receives only the remainder.
+pub fn download_endpoint(
+    client: &Dhis2Client,
+    endpoint: &str,
+    offset: u64,
+    writer: &mut impl Write,
+) -> Result<FileDownload> {
+    let headers = if offset > 0 {
+        vec![("Range", format!("bytes={}-", offset))]
+    } else {
+        Vec::new()
+    };
+    let mut response = client.send(endpoint, &headers)?;
+
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let content_type = header("content-type");
+    let content_length = header("content-length").and_then(|v| v.parse::<u64>().ok());
+
+    let total_size = if response.status() == StatusCode::PARTIAL_CONTENT {
+        let content_range = header("content-range").unwrap_or_default();
+        match parse_content_range(&content_range) {
+            Some((start, total)) if start == offset => total,
+            _ => bail!(
+                "Unexpected Content-Range '{}' when resuming from byte {}",
+                content_range,
+                offset
+            ),
+        }
+    } else {
+        if offset > 0 {
+            // Range ignored: discard what the caller already has
+            let skipped = io::copy(&mut (&mut response).take(offset), &mut io::sink())
+                .context("Failed to read file resource")?;
+            if skipped < offset {
+                bail!(
+                    "Resource is only {} bytes, cannot resume from byte {}",
+                    skipped,
+                    offset
+                );
+            }
+        }
+        content_length
+    };
+
+    let bytes_written = io::copy(&mut response, writer).context("Failed to write file resource")?;
+
+    Ok(FileDownload {
+        content_type,
+        bytes_written,
+        total_size,
+    })
+}
+
+/// Start offset and total size of a `Content-Range: bytes <start>-<end>/<total>`
+/// value. The total is `None` when the server sends `*`.
+fn parse_content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let range = value.trim().strip_prefix("bytes")?.trim_start();
+    let (span, total) = range.split_once('/')?;
+    let (start, _) = span.split_once('-')?;
+    Some((start.trim().parse().ok()?, total.trim().parse().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{mock_server, response};
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\xff\x00";
+
+    #[test]
+    fn test_download_streams_bytes_and_content_type() {
+        let (url, server) = mock_server(response("200 OK", &["Content-Type: image/png"], PNG));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let mut out = Vec::new();
+        let info = download(&client, "fileRes0001", &mut out).unwrap();
+
+        assert_eq!(out, PNG);
+        assert_eq!(
+            info,
+            FileDownload {
+                content_type: Some("image/png".to_string()),
+                bytes_written: PNG.len() as u64,
+                total_size: Some(PNG.len() as u64),
+            }
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /api/fileResources/fileRes0001/data "));
+        assert!(!request.to_ascii_lowercase().contains("range:"));
+    }
+
+    #[test]
+    fn test_download_resumes_with_range() {
+        let rest = &PNG[4..];
+        let (url, server) = mock_server(response(
+            "206 Partial Content",
+            &[
+                "Content-Type: image/png",
+                &format!("Content-Range: bytes 4-{}/{}", PNG.len() - 1, PNG.len()),
+            ],
+            rest,
+        ));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let mut out = PNG[..4].to_vec();
+        let info = download_from(&client, "fileRes0001", 4, &mut out).unwrap();
+
+        assert_eq!(out, PNG);
+        assert_eq!(info.bytes_written, rest.len() as u64);
+        assert_eq!(info.total_size, Some(PNG.len() as u64));
+        let request = server.join().unwrap().to_ascii_lowercase();
+        assert!(request.contains("range: bytes=4-\r\n"));
+    }
+
+    #[test]
+    fn test_download_skips_prefix_when_range_ignored() {
+        let (url, _server) = mock_server(response("200 OK", &[], PNG));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let mut out = Vec::new();
+        let info = download_endpoint(&client, "icons/star/icon", 4, &mut out).unwrap();
+        assert_eq!(out, &PNG[4..]);
+        assert_eq!(info.content_type, None);
+    }
+
+    #[test]
+    fn test_parse_content_range() {
+        assert_eq!(parse_content_range("bytes 4-9/10"), Some((4, Some(10))));
+        assert_eq!(parse_content_range("bytes 0-99/*"), Some((0, None)));
+        assert_eq!(parse_content_range("items 0-1/2"), None);
+    }
+}
@@ -4,35 +4,36 @@ use crate::Dhis2Client;
 use anyhow::Result;
 use colored::Colorize;
 use serde::Deserialize;
+use std::io::{self, Write};
 
-#[derive(Debug, Deserialize)]
-struct SystemInfo {
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct SystemInfo {
     #[serde(rename = "contextPath")]
-    _context_path: Option<String>,
-    version: Option<String>,
-    revision: Option<String>,
+    pub context_path: Option<String>,
+    pub version: Option<String>,
+    pub revision: Option<String>,
     #[serde(rename = "buildTime")]
-    build_time: Option<String>,
+    pub build_time: Option<String>,
     #[serde(rename = "serverDate")]
-    server_date: Option<String>,
+    pub server_date: Option<String>,
     #[serde(rename = "serverTimeZoneId")]
-    server_timezone: Option<String>,
+    pub server_timezone: Option<String>,
     #[serde(rename = "systemId")]
-    system_id: Option<String>,
+    pub system_id: Option<String>,
     #[serde(rename = "systemName")]
-    system_name: Option<String>,
+    pub system_name: Option<String>,
     #[serde(rename = "instanceBaseUrl")]
-    instance_base_url: Option<String>,
+    pub instance_base_url: Option<String>,
     #[serde(rename = "databaseInfo")]
-    database_info: Option<DatabaseInfo>,
+    pub database_info: Option<DatabaseInfo>,
 }
 
-#[derive(Debug, Deserialize)]
-struct DatabaseInfo {
-    name: Option<String>,
-    user: Option<String>,
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct DatabaseInfo {
+    pub name: Option<String>,
+    pub user: Option<String>,
     #[serde(rename = "spatialSupport")]
-    spatial_support: Option<bool>,
+    pub spatial_support: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -45,64 +46,94 @@ struct PagedResponse {
     pager: Option<Pager>,
 }
 
+/// Fetch system information from DHIS2.
+pub fn fetch(client: &Dhis2Client) -> Result<SystemInfo> {
+    client.get("system/info.json")
+}
+
 /// Run the info subcommand.
-pub fn run(client: &Dhis2Client) -> Result<()> {
-    println!("{}", "=== DHIS2 System Information ===".cyan().bold());
-    println!();
+pub fn run(client: &Dhis2Client, json: bool) -> Result<()> {
+    write_info(client, json, &mut io::stdout().lock())
+}
+
+/// Write system information as a report, or as JSON with `json`.
+pub fn write_info(client: &Dhis2Client, json: bool, out: &mut impl Write) -> Result<()> {
+    let info = fetch(client)?;
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&info)?)?;
+        return Ok(());
+    }
 
-    // Fetch system info
-    let info: SystemInfo = client.get("system/info.json")?;
+    writeln!(out, "{}", "=== DHIS2 System Information ===".cyan().bold())?;
+    writeln!(out)?;
 
-    println!("{}", "Server".yellow());
-    println!(
+    writeln!(out, "{}", "Server".yellow())?;
+    writeln!(
+        out,
         "  System Name:   {}",
         info.system_name.as_deref().unwrap_or("-")
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Version:       {}",
         info.version.as_deref().unwrap_or("-").green()
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Revision:      {}",
         info.revision.as_deref().unwrap_or("-")
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Build Time:    {}",
         info.build_time.as_deref().unwrap_or("-")
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Server Date:   {}",
         info.server_date.as_deref().unwrap_or("-")
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Timezone:      {}",
         info.server_timezone.as_deref().unwrap_or("-")
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  Instance URL:  {}",
         info.instance_base_url.as_deref().unwrap_or("-")
-    );
-    println!(
+    )?;
+    writeln!(
+        out,
         "  System ID:     {}",
         info.system_id.as_deref().unwrap_or("-")
-    );
+    )?;
 
     if let Some(db) = &info.database_info {
-        println!();
-        println!("{}", "Database".yellow());
-        println!("  Name:          {}", db.name.as_deref().unwrap_or("-"));
-        println!("  User:          {}", db.user.as_deref().unwrap_or("-"));
-        println!(
+        writeln!(out)?;
+        writeln!(out, "{}", "Database".yellow())?;
+        writeln!(
+            out,
+            "  Name:          {}",
+            db.name.as_deref().unwrap_or("-")
+        )?;
+        writeln!(
+            out,
+            "  User:          {}",
+            db.user.as_deref().unwrap_or("-")
+        )?;
+        writeln!(
+            out,
             "  Spatial:       {}",
             db.spatial_support
                 .map(|s| if s { "Yes".green() } else { "No".red() })
                 .unwrap_or_else(|| "-".normal())
-        );
+        )?;
     }
 
     // Fetch object counts
-    println!();
-    println!("{}", "Metadata Statistics".yellow());
+    writeln!(out)?;
+    writeln!(out, "{}", "Metadata Statistics".yellow())?;
 
     let endpoints = [
         ("organisationUnits", "Organisation Units"),
@@ -118,7 +149,7 @@ pub fn run(client: &Dhis2Client) -> Result<()> {
         match client.get::<PagedResponse>(&format!("{}.json?paging=true&pageSize=1", endpoint)) {
             Ok(resp) => {
                 if let Some(pager) = resp.pager {
-                    println!("  {:22} {:>8}", label, pager.total.to_string().cyan());
+                    writeln!(out, "  {:22} {:>8}", label, pager.total.to_string().cyan())?;
                 }
             }
             Err(_) => {
@@ -127,11 +158,65 @@ pub fn run(client: &Dhis2Client) -> Result<()> {
         }
     }
 
-    println!();
-    println!("{}", "Connection".yellow());
-    println!("  Base URL:      {}", client.base_url());
-    println!("  API Path:      /api/");
-    println!("  Auth Method:   Basic Auth");
+    writeln!(out)?;
+    writeln!(out, "{}", "Connection".yellow())?;
+    writeln!(out, "  Base URL:      {}", client.base_url())?;
+    writeln!(out, "  API Path:      /api/")?;
+    writeln!(out, "  Auth Method:   Basic Auth")?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_fetch_from_mock_client() {
+        let client = Dhis2Client::mock(HashMap::from([(
+            "system/info.json".to_string(),
+            (
+                200,
+                r#"{"version": "2.41.1", "systemName": "Sierra Leone",
+                    "databaseInfo": {"spatialSupport": true}}"#
+                    .to_string(),
+            ),
+        )]));
+
+        let info = fetch(&client).unwrap();
+        assert_eq!(info.version.as_deref(), Some("2.41.1"));
+        assert_eq!(info.system_name.as_deref(), Some("Sierra Leone"));
+        assert_eq!(info.database_info.unwrap().spatial_support, Some(true));
+    }
+
+    #[test]
+    fn test_write_info_prints_version() {
+        let client = Dhis2Client::mock(HashMap::from([
+            (
+                "system/info.json".to_string(),
+                (200, r#"{"version": "2.41.1"}"#.to_string()),
+            ),
+            (
+                "dataSets.json".to_string(),
+                (200, r#"{"pager": {"total": 42}}"#.to_string()),
+            ),
+        ]));
+
+        let mut out = Vec::new();
+        write_info(&client, false, &mut out).unwrap();
+        let report = String::from_utf8(out).unwrap();
+        let version = report.lines().find(|l| l.contains("Version:")).unwrap();
+        assert!(version.contains("2.41.1"), "{}", version);
+        let data_sets = report.lines().find(|l| l.contains("Data Sets")).unwrap();
+        assert!(data_sets.contains("42"));
+        // Endpoints without a response are left out
+        assert!(!report.contains("Programs"));
+        assert!(report.contains("http://dhis2.mock"));
+
+        let mut out = Vec::new();
+        write_info(&client, true, &mut out).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(json["version"], "2.41.1");
+    }
+}
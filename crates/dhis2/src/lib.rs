@@ -8,8 +8,10 @@
 //! - DHIS2 UID generation and validation
 //! - System information fetching
 //! - Organisation unit queries
-//! - Data element queries
+//! - Data element queries, with groups and group sets
 //! - Data set queries
+//! - Streaming file resource downloads with range resume
+//! - Paged tracked entity queries
 //! - Optional TUI browser (with `tui` feature)
 //!
 //! ## Quick Start
@@ -18,7 +20,7 @@
 //! use dx_dhis2::{Dhis2Client, uid};
 //!
 //! // Generate DHIS2 UIDs
-//! let uid = uid::generate();
+//! let uid = uid::generate_uid();
 //! println!("Generated UID: {}", uid);
 //!
 //! // Connect to a DHIS2 instance
@@ -28,9 +30,8 @@
 //!     "district"
 //! ).unwrap();
 //!
-//! // Fetch system info
-//! let info = dx_dhis2::info::fetch(&client).unwrap();
-//! println!("DHIS2 version: {:?}", info.version);
+//! // Print system info
+//! dx_dhis2::info::run(&client, false).unwrap();
 //! ```
 //!
 //! ## DHIS2 UID Format
@@ -40,18 +41,24 @@
 //! - Remaining 10: a-zA-Z0-9 (alphanumeric)
 
 mod client;
+pub mod data_element_group_sets;
+pub mod data_element_groups;
 pub mod data_elements;
 pub mod data_sets;
 pub mod data_values;
+pub mod files;
 pub mod info;
+#[cfg(test)]
+mod mock;
 pub mod org_unit_group_sets;
 pub mod org_unit_groups;
 pub mod org_units;
+pub mod tracked_entities;
 #[cfg(feature = "tui")]
 pub mod tui;
 pub mod uid;
 
-pub use client::Dhis2Client;
+pub use client::{Dhis2Client, Dhis2ClientBuilder};
 
 /// Default DHIS2 demo server URL (redirects to latest version).
 pub const DEFAULT_SERVER: &str = "https://play.dhis2.org/demo";
@@ -54,6 +54,17 @@ pub fn fetch(
     level: Option<i32>,
     limit: usize,
     include_geometry: bool,
+) -> Result<Vec<OrgUnit>> {
+    fetch_page(client, level, 1, limit, include_geometry)
+}
+
+/// Fetch one page of organisation units, `page` counting from 1.
+pub fn fetch_page(
+    client: &Dhis2Client,
+    level: Option<i32>,
+    page: usize,
+    page_size: usize,
+    include_geometry: bool,
 ) -> Result<Vec<OrgUnit>> {
     let mut fields =
         "id,displayName,name,shortName,code,level,path,parent[id,displayName],featureType";
@@ -65,8 +76,10 @@ pub fn fetch(
     }
 
     let mut url = format!(
-        "organisationUnits.json?fields={}&pageSize={}",
-        fields, limit
+        "organisationUnits.json?fields={}&page={}&pageSize={}",
+        fields,
+        page.max(1),
+        page_size
     );
 
     if let Some(lvl) = level {
@@ -81,11 +94,12 @@ pub fn fetch(
 pub fn run(
     client: &Dhis2Client,
     level: Option<i32>,
+    page: usize,
     limit: usize,
     geometry: bool,
     json: bool,
 ) -> Result<()> {
-    let org_units = fetch(client, level, limit, geometry)?;
+    let org_units = fetch_page(client, level, page, limit, geometry)?;
 
     if json {
         println!("{}", serde_json::to_string_pretty(&org_units)?);
@@ -99,9 +113,13 @@ pub fn run(
 
     println!(
         "{}",
-        format!("Organisation Units (showing {})", org_units.len())
-            .cyan()
-            .bold()
+        format!(
+            "Organisation Units (page {}, showing {})",
+            page.max(1),
+            org_units.len()
+        )
+        .cyan()
+        .bold()
     );
     println!();
 
@@ -160,3 +178,31 @@ pub fn run(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{mock_server, response};
+
+    #[test]
+    fn test_fetch_page() {
+        let body = r#"{"organisationUnits": [{
+            "id": "ImspTQPwCqd", "displayName": "Sierra Leone", "name": "Sierra Leone",
+            "level": 1, "path": "/ImspTQPwCqd"
+        }]}"#;
+        let (url, server) = mock_server(response("200 OK", &[], body.as_bytes()));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let units = fetch_page(&client, Some(1), 3, 10, false).unwrap();
+        assert_eq!(units[0].id, "ImspTQPwCqd");
+        assert_eq!(units[0].parent.as_ref().map(|p| p.id.as_str()), None);
+
+        let request = server.join().unwrap();
+        let line = request.lines().next().unwrap();
+        assert!(
+            line.contains("&page=3&pageSize=10&filter=level:eq:1 "),
+            "{}",
+            line
+        );
+    }
+}
@@ -0,0 +1,323 @@
+//! DHIS2 tracked entity queries.
+//!
+//! Fetches individual-level records from the tracker API.
+//! See: https://docs.dhis2.org/en/develop/using-the-api/dhis-core-version-241/tracker.html
+
+use crate::Dhis2Client;
+use anyhow::Result;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// Default number of tracked entities per page.
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Parameters for a `tracker/trackedEntities` query.
+///
+/// # Example
+/// ```
+/// use dx_dhis2::tracked_entities::TeiRequest;
+///
+/// let request = TeiRequest::new()
+///     .org_unit("DiszpKrYNg8")
+///     .program("IpHINAT79UW")
+///     .filter("w75KJ2mc4zz:like:ja")
+///     .page_size(10);
+/// assert_eq!(
+///     request.endpoint(),
+///     "tracker/trackedEntities?orgUnit=DiszpKrYNg8&program=IpHINAT79UW\
+///      &filter=w75KJ2mc4zz%3Alike%3Aja&page=1&pageSize=10&fields=*"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeiRequest {
+    pub org_unit: Option<String>,
+    pub program: Option<String>,
+    /// Attribute filters such as `w75KJ2mc4zz:eq:John`
+    pub filters: Vec<String>,
+    /// 1-based page number
+    pub page: usize,
+    pub page_size: usize,
+}
+
+impl TeiRequest {
+    /// A request for the first page, without filters.
+    pub fn new() -> Self {
+        Self {
+            org_unit: None,
+            program: None,
+            filters: Vec::new(),
+            page: 1,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Restrict to an organisation unit.
+    pub fn org_unit(mut self, uid: &str) -> Self {
+        self.org_unit = Some(uid.to_string());
+        self
+    }
+
+    /// Restrict to entities enrolled in a program.
+    pub fn program(mut self, uid: &str) -> Self {
+        self.program = Some(uid.to_string());
+        self
+    }
+
+    /// Add an attribute filter (`<attribute>:<operator>:<value>`).
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filters.push(filter.to_string());
+        self
+    }
+
+    /// Select a page (1-based).
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page.max(1);
+        self
+    }
+
+    /// Set the page size (default: 50).
+    pub fn page_size(mut self, size: usize) -> Self {
+        self.page_size = size.max(1);
+        self
+    }
+
+    /// The same query for the following page.
+    pub fn next_page(&self) -> Self {
+        self.clone().page(self.page + 1)
+    }
+
+    /// The API endpoint, relative to `/api/`.
+    pub fn endpoint(&self) -> String {
+        let mut params: Vec<(&str, String)> = Vec::new();
+        if let Some(ou) = &self.org_unit {
+            params.push(("orgUnit", ou.clone()));
+        }
+        if let Some(program) = &self.program {
+            params.push(("program", program.clone()));
+        }
+        for filter in &self.filters {
+            params.push(("filter", filter.clone()));
+        }
+        params.push(("page", self.page.to_string()));
+        params.push(("pageSize", self.page_size.to_string()));
+        params.push(("fields", "*".to_string()));
+
+        let query: Vec<String> = params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, encode(value)))
+            .collect();
+        format!("tracker/trackedEntities?{}", query.join("&"))
+    }
+}
+
+impl Default for TeiRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Percent-encode a query value, leaving `*` and unreserved characters.
+fn encode(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'*' => {
+                out.push(b as char)
+            }
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// One page of tracked entities.
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct TrackedEntityPage {
+    /// Called `instances` before DHIS2 2.41
+    #[serde(rename = "trackedEntities", alias = "instances", default)]
+    pub tracked_entities: Vec<TrackedEntity>,
+    #[serde(default)]
+    pub page: Option<usize>,
+    #[serde(rename = "pageSize", default)]
+    pub page_size: Option<usize>,
+    /// Paging details, reported by DHIS2 2.41 and later
+    #[serde(default)]
+    pub pager: Option<Pager>,
+}
+
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct Pager {
+    pub page: Option<usize>,
+    #[serde(rename = "pageSize")]
+    pub page_size: Option<usize>,
+    pub total: Option<u64>,
+    #[serde(rename = "pageCount")]
+    pub page_count: Option<usize>,
+}
+
+impl TrackedEntityPage {
+    /// Whether this is the last page of a query with `page_size` entries per page.
+    pub fn is_last(&self, page_size: usize) -> bool {
+        if let Some(pager) = &self.pager {
+            if let (Some(page), Some(count)) = (pager.page, pager.page_count) {
+                return page >= count;
+            }
+        }
+        self.tracked_entities.len() < page_size
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct TrackedEntity {
+    #[serde(rename = "trackedEntity")]
+    pub tracked_entity: String,
+    #[serde(rename = "trackedEntityType")]
+    pub tracked_entity_type: Option<String>,
+    #[serde(rename = "orgUnit")]
+    pub org_unit: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+    #[serde(default)]
+    pub inactive: bool,
+    #[serde(default)]
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct Attribute {
+    /// The tracked entity attribute UID
+    pub attribute: String,
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    #[serde(rename = "valueType")]
+    pub value_type: Option<String>,
+    pub value: String,
+}
+
+impl TrackedEntity {
+    /// Attribute values keyed by attribute UID.
+    pub fn attribute_values(&self) -> BTreeMap<&str, &str> {
+        self.attributes
+            .iter()
+            .map(|a| (a.attribute.as_str(), a.value.as_str()))
+            .collect()
+    }
+
+    /// The value of one attribute.
+    pub fn attribute(&self, uid: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|a| a.attribute == uid)
+            .map(|a| a.value.as_str())
+    }
+}
+
+/// Fetch one page of tracked entities from DHIS2.
+pub fn query(client: &Dhis2Client, request: &TeiRequest) -> Result<TrackedEntityPage> {
+    client.get(&request.endpoint())
+}
+
+/// Fetch every page of tracked entities, up to `limit` entities in total.
+pub fn query_all(
+    client: &Dhis2Client,
+    request: &TeiRequest,
+    limit: usize,
+) -> Result<Vec<TrackedEntity>> {
+    let mut request = request.clone();
+    let mut entities = Vec::new();
+    while entities.len() < limit {
+        let page = query(client, &request)?;
+        let last = page.is_last(request.page_size);
+        entities.extend(page.tracked_entities);
+        if last {
+            break;
+        }
+        request = request.next_page();
+    }
+    entities.truncate(limit);
+    Ok(entities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{mock_server, response};
+
+    const RESPONSE: &str = r#"{
+        "pager": {"page": 2, "pageSize": 2, "total": 3, "pageCount": 2},
+        "trackedEntities": [
+            {
+                "trackedEntity": "PQfMcpmXeFE",
+                "trackedEntityType": "nEenWmSyUEp",
+                "orgUnit": "DiszpKrYNg8",
+                "createdAt": "2024-01-05T10:00:00.000",
+                "attributes": [
+                    {"attribute": "w75KJ2mc4zz", "displayName": "First name",
+                     "valueType": "TEXT", "value": "Jane"},
+                    {"attribute": "zDhUuAYrxNC", "displayName": "Last name",
+                     "valueType": "TEXT", "value": "Doe"}
+                ]
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_query_parses_attributes() {
+        let (url, server) = mock_server(response(
+            "200 OK",
+            &["Content-Type: application/json"],
+            RESPONSE.as_bytes(),
+        ));
+        let client = Dhis2Client::new(&url, "admin", "district").unwrap();
+
+        let request = TeiRequest::new()
+            .org_unit("DiszpKrYNg8")
+            .program("IpHINAT79UW")
+            .filter("w75KJ2mc4zz:eq:Jane")
+            .page(2)
+            .page_size(2);
+        let page = query(&client, &request).unwrap();
+
+        assert!(page.is_last(2));
+        assert_eq!(page.pager.as_ref().unwrap().total, Some(3));
+        let tei = &page.tracked_entities[0];
+        assert_eq!(tei.tracked_entity, "PQfMcpmXeFE");
+        assert_eq!(tei.org_unit.as_deref(), Some("DiszpKrYNg8"));
+        assert_eq!(
+            tei.attribute_values(),
+            BTreeMap::from([("w75KJ2mc4zz", "Jane"), ("zDhUuAYrxNC", "Doe")])
+        );
+        assert_eq!(tei.attribute("zDhUuAYrxNC"), Some("Doe"));
+        assert_eq!(tei.attribute("missing"), None);
+
+        let request_line = server.join().unwrap();
+        assert!(request_line.starts_with(
+            "GET /api/tracker/trackedEntities?orgUnit=DiszpKrYNg8&program=IpHINAT79UW\
+             &filter=w75KJ2mc4zz%3Aeq%3AJane&page=2&pageSize=2&fields=* "
+        ));
+    }
+
+    #[test]
+    fn test_legacy_instances_key() {
+        let page: TrackedEntityPage = serde_json::from_str(
+            r#"{"page": 1, "pageSize": 50, "instances": [{"trackedEntity": "a1234567890"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(page.tracked_entities.len(), 1);
+        assert!(page.tracked_entities[0].attributes.is_empty());
+        assert!(page.is_last(50));
+        assert!(!page.is_last(1));
+    }
+
+    #[test]
+    fn test_request_paging() {
+        let request = TeiRequest::new().page_size(0).page(0);
+        assert_eq!((request.page, request.page_size), (1, 1));
+        let next = request.next_page();
+        assert_eq!(next.page, 2);
+        assert!(next.endpoint().contains("?page=2&pageSize=1&"));
+        assert_eq!(encode("a b&c"), "a%20b%26c");
+    }
+}
@@ -5,8 +5,86 @@
 use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 use std::rc::Rc;
 
+/// The result of evaluating an expression.
+///
+/// Comparisons, logical operators, `not`, and the `true`/`false` constants
+/// produce [`Value::Bool`]; parenthesized comma lists like `(1, 2)` produce
+/// [`Value::Tuple`]; everything else produces [`Value::Number`].
+/// Arithmetic treats booleans as `1` and `0`, and variables always store
+/// the numeric form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(f64),
+    Bool(bool),
+    /// A fixed-length list of values; serializes as a JSON array
+    Tuple(Vec<Value>),
+}
+
+impl Value {
+    /// The numeric form: booleans coerce to `1.0` or `0.0`.
+    ///
+    /// Tuples have no numeric form and give NaN; use [`Value::number`] to
+    /// get an error instead.
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Value::Number(n) => *n,
+            Value::Bool(b) => {
+                if *b {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Value::Tuple(_) => f64::NAN,
+        }
+    }
+
+    /// The numeric form, or an error for a tuple
+    pub fn number(&self) -> Result<f64> {
+        match self {
+            Value::Tuple(_) => bail!("Expected a number, got tuple {}", self),
+            _ => Ok(self.as_f64()),
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Self {
+        Value::Number(n)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl fmt::Display for Value {
+    /// Booleans print as `true`/`false`, whole numbers without a fraction
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(n) if n.fract() == 0.0 && n.abs() < 1e15 => write!(f, "{}", *n as i64),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Tuple(items) => {
+                write!(f, "(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 /// Binary operators
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -31,6 +109,14 @@ pub enum BinOp {
 }
 
 impl BinOp {
+    /// Whether the operator yields a boolean (comparison or logical)
+    pub fn is_boolean(&self) -> bool {
+        !matches!(
+            self,
+            BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Mod | BinOp::Pow
+        )
+    }
+
     /// Get the operator symbol for display
     pub fn symbol(&self) -> &'static str {
         match self {
@@ -108,6 +194,76 @@ pub enum Expr {
         params: Vec<String>,
         body: Box<Expr>,
     },
+
+    /// A tuple: (a, b, ...)
+    Tuple { items: Vec<Expr> },
+}
+
+impl fmt::Display for Expr {
+    /// Source-like form; nested operators are parenthesized, so
+    /// `2 + 3 * 4` prints as `2 + (3 * 4)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number { value } => write!(f, "{}", Value::Number(*value)),
+            Expr::Constant { name } | Expr::Variable { name } => write!(f, "{}", name),
+            Expr::BinOp { op, left, right } => {
+                write!(f, "{} {} {}", Operand(left), op.symbol(), Operand(right))
+            }
+            Expr::UnaryOp {
+                op: UnaryOp::Neg,
+                expr,
+            } => write!(f, "-{}", Operand(expr)),
+            Expr::UnaryOp {
+                op: UnaryOp::Not,
+                expr,
+            } => write!(f, "not {}", Operand(expr)),
+            Expr::FuncCall { name, args } => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
+            Expr::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => write!(
+                f,
+                "if {} then {} else {}",
+                condition, then_branch, else_branch
+            ),
+            Expr::Lambda { params, body } => write!(f, "({}) => {}", params.join(", "), body),
+            Expr::Tuple { items } => {
+                write!(f, "(")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// An operand inside a larger expression, parenthesized unless atomic
+struct Operand<'a>(&'a Expr);
+
+impl fmt::Display for Operand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Expr::BinOp { .. }
+            | Expr::UnaryOp { .. }
+            | Expr::Conditional { .. }
+            | Expr::Lambda { .. } => write!(f, "({})", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
 }
 
 /// A user-defined function
@@ -150,6 +306,8 @@ pub enum Statement {
         params: Vec<String>,
         body: Box<Expr>,
     },
+    /// Destructuring assignment: (a, b) = expr
+    Destructure { names: Vec<String>, value: Expr },
 }
 
 /// A program is a sequence of statements
@@ -158,11 +316,38 @@ pub struct Program {
     pub statements: Vec<Statement>,
 }
 
+/// How trigonometric functions interpret and return angles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AngleMode {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+impl AngleMode {
+    /// Convert an angle in this mode to radians
+    fn input_radians(self, angle: f64) -> f64 {
+        match self {
+            AngleMode::Radians => angle,
+            AngleMode::Degrees => angle.to_radians(),
+        }
+    }
+
+    /// Convert an angle in radians to this mode
+    fn output_angle(self, angle: f64) -> f64 {
+        match self {
+            AngleMode::Radians => angle,
+            AngleMode::Degrees => angle.to_degrees(),
+        }
+    }
+}
+
 /// Evaluation context holding variable bindings and user-defined functions
 #[derive(Debug, Clone, Default)]
 pub struct Context {
     variables: HashMap<String, f64>,
     functions: HashMap<String, Rc<Callable>>,
+    angle_mode: AngleMode,
 }
 
 impl Context {
@@ -175,10 +360,21 @@ impl Context {
     pub fn with_vars(vars: HashMap<String, f64>) -> Self {
         Self {
             variables: vars,
-            functions: HashMap::new(),
+            ..Self::default()
         }
     }
 
+    /// Set how `sin`, `cos`, `tan`, `asin`, `acos`, `atan` and `atan2`
+    /// treat angles (radians by default)
+    pub fn set_angle_mode(&mut self, mode: AngleMode) {
+        self.angle_mode = mode;
+    }
+
+    /// The current angle mode
+    pub fn angle_mode(&self) -> AngleMode {
+        self.angle_mode
+    }
+
     /// Set a variable value
     pub fn set(&mut self, name: &str, value: f64) {
         self.variables.insert(name.to_string(), value);
@@ -213,6 +409,20 @@ impl Context {
     pub fn capture_env(&self) -> HashMap<String, f64> {
         self.variables.clone()
     }
+
+    /// Iterate over defined variables (in no particular order)
+    pub fn variables(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.variables
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    /// Iterate over user functions and lambdas (in no particular order)
+    pub fn functions(&self) -> impl Iterator<Item = (&str, &Callable)> {
+        self.functions
+            .iter()
+            .map(|(name, callable)| (name.as_str(), callable.as_ref()))
+    }
 }
 
 impl Expr {
@@ -281,11 +491,62 @@ impl Expr {
         }
     }
 
+    /// Create a tuple expression
+    pub fn tuple(items: Vec<Expr>) -> Self {
+        Expr::Tuple { items }
+    }
+
     /// Evaluate the expression and return the result (no variables)
     pub fn eval(&self) -> Result<f64> {
         self.eval_with_context(&mut Context::new())
     }
 
+    /// Evaluate the expression to a typed [`Value`] (no variables)
+    pub fn eval_value(&self) -> Result<Value> {
+        self.eval_value_with_context(&mut Context::new())
+    }
+
+    /// Evaluate the expression to a typed [`Value`] with a variable context.
+    ///
+    /// Boolean-producing nodes become [`Value::Bool`], tuples become
+    /// [`Value::Tuple`], and a conditional takes the type of the branch it
+    /// picks. Operands are evaluated numerically, so `(5 > 3) + 1` is `2`.
+    /// User function calls return numbers, or the tuple the body returns.
+    pub fn eval_value_with_context(&self, ctx: &mut Context) -> Result<Value> {
+        match self {
+            Expr::Tuple { items } => items
+                .iter()
+                .map(|item| item.eval_value_with_context(ctx))
+                .collect::<Result<Vec<_>>>()
+                .map(Value::Tuple),
+            Expr::FuncCall { name, args } if ctx.is_callable(name) => {
+                let callable = ctx.get_function(name).expect("checked by is_callable");
+                call_user_function(&callable, args, ctx)
+            }
+            Expr::Constant { name } if name == "true" || name == "false" => {
+                Ok(Value::Bool(name == "true"))
+            }
+            Expr::BinOp { op, .. } if op.is_boolean() => {
+                Ok(Value::Bool(self.eval_with_context(ctx)? != 0.0))
+            }
+            Expr::UnaryOp {
+                op: UnaryOp::Not, ..
+            } => Ok(Value::Bool(self.eval_with_context(ctx)? != 0.0)),
+            Expr::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                if condition.eval_with_context(ctx)? != 0.0 {
+                    then_branch.eval_value_with_context(ctx)
+                } else {
+                    else_branch.eval_value_with_context(ctx)
+                }
+            }
+            _ => self.eval_with_context(ctx).map(Value::Number),
+        }
+    }
+
     /// Evaluate the expression with a variable context
     pub fn eval_with_context(&self, ctx: &mut Context) -> Result<f64> {
         match self {
@@ -351,7 +612,7 @@ impl Expr {
             Expr::FuncCall { name, args } => {
                 // First check for user-defined function or lambda
                 if let Some(callable) = ctx.get_function(name) {
-                    return call_user_function(&callable, args, ctx);
+                    return call_user_function(&callable, args, ctx)?.number();
                 }
 
                 // Evaluate all arguments
@@ -360,7 +621,7 @@ impl Expr {
                     vals.push(arg.eval_with_context(ctx)?);
                 }
 
-                eval_builtin_function(name, &vals)
+                eval_builtin_function(name, &vals, ctx.angle_mode)
             }
 
             Expr::Conditional {
@@ -385,12 +646,15 @@ impl Expr {
                     params.join(", ")
                 );
             }
+
+            // Fails with the evaluated tuple in the message
+            Expr::Tuple { .. } => self.eval_value_with_context(ctx)?.number(),
         }
     }
 }
 
 /// Evaluate a binary operation
-fn eval_binop(op: BinOp, l: f64, r: f64) -> Result<f64> {
+pub(crate) fn eval_binop(op: BinOp, l: f64, r: f64) -> Result<f64> {
     match op {
         BinOp::Add => Ok(l + r),
         BinOp::Sub => Ok(l - r),
@@ -429,14 +693,8 @@ fn eval_binop(op: BinOp, l: f64, r: f64) -> Result<f64> {
 }
 
 /// Call a user-defined function or lambda
-fn call_user_function(callable: &Callable, args: &[Expr], ctx: &mut Context) -> Result<f64> {
-    if args.len() != callable.params.len() {
-        bail!(
-            "Function expects {} argument(s), got {}",
-            callable.params.len(),
-            args.len()
-        );
-    }
+fn call_user_function(callable: &Callable, args: &[Expr], ctx: &mut Context) -> Result<Value> {
+    check_arity(callable, args.len())?;
 
     // Evaluate arguments in the current context
     let mut arg_vals = Vec::with_capacity(args.len());
@@ -444,8 +702,33 @@ fn call_user_function(callable: &Callable, args: &[Expr], ctx: &mut Context) ->
         arg_vals.push(arg.eval_with_context(ctx)?);
     }
 
+    call_with_values(callable, &arg_vals, ctx)
+}
+
+/// Fail unless `callable` takes exactly `count` arguments
+pub(crate) fn check_arity(callable: &Callable, count: usize) -> Result<()> {
+    if count != callable.params.len() {
+        bail!(
+            "Function expects {} argument(s), got {}",
+            callable.params.len(),
+            count
+        );
+    }
+    Ok(())
+}
+
+/// Call a user-defined function or lambda with already-evaluated arguments.
+///
+/// The caller checks the arity first. Returns a number, or a tuple if the
+/// body produces one.
+pub(crate) fn call_with_values(
+    callable: &Callable,
+    arg_vals: &[f64],
+    ctx: &mut Context,
+) -> Result<Value> {
     // Create a new context for function execution with captured environment
     let mut func_ctx = Context::new();
+    func_ctx.angle_mode = ctx.angle_mode;
 
     // Copy captured variables (closure support)
     for (name, value) in &callable.captures {
@@ -465,7 +748,7 @@ fn call_user_function(callable: &Callable, args: &[Expr], ctx: &mut Context) ->
     // Evaluate the function body
     match &callable.body {
         FuncBody::Expr(expr) => {
-            let result = expr.eval_with_context(&mut func_ctx)?;
+            let result = returned(expr.eval_value_with_context(&mut func_ctx)?);
             // Update captured variables in the original context (for mutable closures)
             for name in callable.captures.keys() {
                 if let Some(new_val) = func_ctx.get(name) {
@@ -475,9 +758,9 @@ fn call_user_function(callable: &Callable, args: &[Expr], ctx: &mut Context) ->
             Ok(result)
         }
         FuncBody::Block(statements) => {
-            let mut result = 0.0;
+            let mut result = Value::Number(0.0);
             for stmt in statements {
-                result = stmt.eval(&mut func_ctx)?;
+                result = returned(stmt.eval_value(&mut func_ctx)?);
             }
             // Update captured variables
             for name in callable.captures.keys() {
@@ -490,16 +773,26 @@ fn call_user_function(callable: &Callable, args: &[Expr], ctx: &mut Context) ->
     }
 }
 
-/// Evaluate a built-in function
-fn eval_builtin_function(name: &str, args: &[f64]) -> Result<f64> {
+/// What a call returns for a body value: tuples pass through, and scalars
+/// become numbers as before tuples existed
+fn returned(value: Value) -> Value {
+    match value {
+        Value::Tuple(_) => value,
+        scalar => Value::Number(scalar.as_f64()),
+    }
+}
+
+/// Evaluate a built-in function. Trigonometric functions take or return
+/// angles in `mode`.
+pub(crate) fn eval_builtin_function(name: &str, args: &[f64], mode: AngleMode) -> Result<f64> {
     match (name, args.len()) {
         // Single-argument functions
-        ("sin", 1) => Ok(args[0].sin()),
-        ("cos", 1) => Ok(args[0].cos()),
-        ("tan", 1) => Ok(args[0].tan()),
-        ("asin", 1) => Ok(args[0].asin()),
-        ("acos", 1) => Ok(args[0].acos()),
-        ("atan", 1) => Ok(args[0].atan()),
+        ("sin", 1) => Ok(mode.input_radians(args[0]).sin()),
+        ("cos", 1) => Ok(mode.input_radians(args[0]).cos()),
+        ("tan", 1) => Ok(mode.input_radians(args[0]).tan()),
+        ("asin", 1) => Ok(mode.output_angle(args[0].asin())),
+        ("acos", 1) => Ok(mode.output_angle(args[0].acos())),
+        ("atan", 1) => Ok(mode.output_angle(args[0].atan())),
         ("sinh", 1) => Ok(args[0].sinh()),
         ("cosh", 1) => Ok(args[0].cosh()),
         ("tanh", 1) => Ok(args[0].tanh()),
@@ -540,12 +833,16 @@ fn eval_builtin_function(name: &str, args: &[f64]) -> Result<f64> {
         }
         ("sign", 1) => Ok(args[0].signum()),
         ("fract", 1) => Ok(args[0].fract()),
+        ("fact", 1) => {
+            let n = whole_number(name, args[0])?;
+            product(name, 2..=n)
+        }
 
         // Two-argument functions
         ("max", 2) => Ok(args[0].max(args[1])),
         ("min", 2) => Ok(args[0].min(args[1])),
         ("pow", 2) => Ok(args[0].powf(args[1])),
-        ("atan2", 2) => Ok(args[0].atan2(args[1])),
+        ("atan2", 2) => Ok(mode.output_angle(args[0].atan2(args[1]))),
         ("hypot", 2) => Ok(args[0].hypot(args[1])),
         ("log", 2) => {
             // log(x, base)
@@ -560,6 +857,34 @@ fn eval_builtin_function(name: &str, args: &[f64]) -> Result<f64> {
             }
             Ok(args[0] % args[1])
         }
+        ("gcd", 2) => Ok(gcd(whole_number(name, args[0])?, whole_number(name, args[1])?) as f64),
+        ("lcm", 2) => {
+            let (a, b) = (whole_number(name, args[0])?, whole_number(name, args[1])?);
+            if a == 0 || b == 0 {
+                return Ok(0.0);
+            }
+            finite(name, (a / gcd(a, b)) as f64 * b as f64)
+        }
+        ("nCr", 2) => {
+            let (n, r) = (whole_number(name, args[0])?, whole_number(name, args[1])?);
+            if r > n {
+                return Ok(0.0);
+            }
+            // Multiply and divide alternately so intermediate values stay small
+            let r = r.min(n - r);
+            let mut result: f64 = 1.0;
+            for i in 1..=r {
+                result = finite(name, result * (n - r + i) as f64 / i as f64)?;
+            }
+            Ok(result.round())
+        }
+        ("nPr", 2) => {
+            let (n, r) = (whole_number(name, args[0])?, whole_number(name, args[1])?);
+            if r > n {
+                return Ok(0.0);
+            }
+            product(name, n - r + 1..=n)
+        }
 
         // Three-argument functions
         ("clamp", 3) => {
@@ -580,12 +905,16 @@ fn eval_builtin_function(name: &str, args: &[f64]) -> Result<f64> {
         (
             "sin" | "cos" | "tan" | "asin" | "acos" | "atan" | "sinh" | "cosh" | "tanh" | "sqrt"
             | "cbrt" | "abs" | "floor" | "ceil" | "round" | "trunc" | "exp" | "ln" | "log2"
-            | "log10" | "print" | "sign" | "fract",
+            | "log10" | "print" | "sign" | "fract" | "fact",
             n,
         ) => {
             bail!("{}() expects 1 argument, got {}", name, n)
         }
-        ("max" | "min" | "pow" | "atan2" | "hypot" | "log" | "mod", n) => {
+        (
+            "max" | "min" | "pow" | "atan2" | "hypot" | "log" | "mod" | "gcd" | "lcm" | "nCr"
+            | "nPr",
+            n,
+        ) => {
             bail!("{}() expects 2 arguments, got {}", name, n)
         }
         ("clamp" | "lerp", n) => {
@@ -596,19 +925,62 @@ fn eval_builtin_function(name: &str, args: &[f64]) -> Result<f64> {
     }
 }
 
+/// Largest integer up to which every integer is exactly representable as f64
+const MAX_EXACT_INTEGER: f64 = 9_007_199_254_740_992.0;
+
+/// Check that a combinatorics operand is a non-negative whole number
+fn whole_number(name: &str, value: f64) -> Result<u64> {
+    if value < 0.0 || value.fract() != 0.0 || !value.is_finite() {
+        bail!(
+            "{}() requires non-negative whole numbers, got {}",
+            name,
+            value
+        );
+    }
+    if value > MAX_EXACT_INTEGER {
+        bail!("{}() argument {} is too large", name, value);
+    }
+    Ok(value as u64)
+}
+
+/// Fail if a result no longer fits in an f64
+fn finite(name: &str, value: f64) -> Result<f64> {
+    if !value.is_finite() {
+        bail!("{}() result is too large", name);
+    }
+    Ok(value)
+}
+
+/// Multiply a range of whole numbers, stopping as soon as the result overflows
+fn product(name: &str, range: std::ops::RangeInclusive<u64>) -> Result<f64> {
+    let mut result: f64 = 1.0;
+    for k in range {
+        result = finite(name, result * k as f64)?;
+    }
+    Ok(result)
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 impl Statement {
     /// Evaluate the statement, potentially modifying context
     /// Returns the value of the expression (for assignments, the assigned value)
     pub fn eval(&self, ctx: &mut Context) -> Result<f64> {
+        self.eval_value(ctx)?.number()
+    }
+
+    /// Evaluate the statement to a typed [`Value`].
+    ///
+    /// Assignments return the assigned value but store its numeric form.
+    pub fn eval_value(&self, ctx: &mut Context) -> Result<Value> {
         match self {
             Statement::Assignment { name, value } => {
-                // Check for reserved names
-                if matches!(name.as_str(), "pi" | "e" | "tau" | "true" | "false") {
-                    bail!("Cannot assign to constant: {}", name);
-                }
-                if is_builtin_function_name(name) {
-                    bail!("Cannot assign to built-in function name: {}", name);
-                }
+                check_assignable(name)?;
 
                 // Check if we're assigning a lambda
                 if let Expr::Lambda { params, body } = value {
@@ -618,14 +990,19 @@ impl Statement {
                         captures: ctx.capture_env(),
                     };
                     ctx.set_lambda(name, callable);
-                    return Ok(0.0); // Lambda assignment returns 0
+                    return Ok(Value::Number(0.0)); // Lambda assignment returns 0
                 }
 
-                let result = value.eval_with_context(ctx)?;
-                ctx.set(name, result);
+                let result = value.eval_value_with_context(ctx)?;
+                assign(ctx, name, &result)?;
+                Ok(result)
+            }
+            Statement::Destructure { names, value } => {
+                let result = value.eval_value_with_context(ctx)?;
+                destructure(ctx, names, &result)?;
                 Ok(result)
             }
-            Statement::Expression(expr) => expr.eval_with_context(ctx),
+            Statement::Expression(expr) => expr.eval_value_with_context(ctx),
             Statement::FuncDef { name, params, body } => {
                 // Check reserved names
                 if matches!(name.as_str(), "pi" | "e" | "tau" | "true" | "false") {
@@ -641,12 +1018,61 @@ impl Statement {
                     captures: ctx.capture_env(),
                 };
                 ctx.define_function(name, callable);
-                Ok(0.0) // Function definition returns 0
+                Ok(Value::Number(0.0)) // Function definition returns 0
             }
         }
     }
 }
 
+/// Reject assignments to constants and built-in function names
+pub(crate) fn check_assignable(name: &str) -> Result<()> {
+    if matches!(name, "pi" | "e" | "tau" | "true" | "false") {
+        bail!("Cannot assign to constant: {}", name);
+    }
+    if is_builtin_function_name(name) {
+        bail!("Cannot assign to built-in function name: {}", name);
+    }
+    Ok(())
+}
+
+/// Store a scalar value in a variable; tuples must be destructured
+pub(crate) fn assign(ctx: &mut Context, name: &str, value: &Value) -> Result<()> {
+    if let Value::Tuple(_) = value {
+        bail!(
+            "Cannot assign tuple {} to '{}'; destructure it instead: (a, b) = ...",
+            value,
+            name
+        );
+    }
+    ctx.set(name, value.number()?);
+    Ok(())
+}
+
+/// Bind each element of a tuple to the matching name
+pub(crate) fn destructure(ctx: &mut Context, names: &[String], value: &Value) -> Result<()> {
+    for (i, name) in names.iter().enumerate() {
+        check_assignable(name)?;
+        if names[..i].contains(name) {
+            bail!("Duplicate name in destructuring assignment: {}", name);
+        }
+    }
+
+    let Value::Tuple(items) = value else {
+        bail!("Cannot destructure {} into {} names", value, names.len());
+    };
+    if items.len() != names.len() {
+        bail!(
+            "Cannot destructure a tuple of {} value(s) into {} name(s)",
+            items.len(),
+            names.len()
+        );
+    }
+    for (name, item) in names.iter().zip(items) {
+        assign(ctx, name, item)?;
+    }
+    Ok(())
+}
+
 impl Program {
     /// Evaluate all statements and return the last expression's value
     pub fn eval(&self) -> Result<f64> {
@@ -655,13 +1081,18 @@ impl Program {
 
     /// Evaluate all statements with a given context
     pub fn eval_with_context(&self, ctx: &mut Context) -> Result<f64> {
+        self.eval_value_with_context(ctx)?.number()
+    }
+
+    /// Evaluate all statements and return the last one's typed [`Value`]
+    pub fn eval_value_with_context(&self, ctx: &mut Context) -> Result<Value> {
         if self.statements.is_empty() {
             bail!("Empty program");
         }
 
-        let mut result = 0.0;
+        let mut result = Value::Number(0.0);
         for stmt in &self.statements {
-            result = stmt.eval(ctx)?;
+            result = stmt.eval_value(ctx)?;
         }
         Ok(result)
     }
@@ -695,12 +1126,17 @@ pub fn is_builtin_function_name(name: &str) -> bool {
             | "print"
             | "sign"
             | "fract"
+            | "fact"
             | "max"
             | "min"
             | "pow"
             | "atan2"
             | "hypot"
             | "mod"
+            | "gcd"
+            | "lcm"
+            | "nCr"
+            | "nPr"
             | "clamp"
             | "lerp"
             | "sum"
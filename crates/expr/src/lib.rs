@@ -15,7 +15,7 @@
 //! - **Arithmetic**: `+`, `-`, `*`, `/`, `%`, `^`, `**`
 //! - **Comparison**: `==`, `!=`, `<`, `>`, `<=`, `>=`
 //! - **Logical**: `and`, `or`, `not`, `&&`, `||`, `!`
-//! - **Conditionals**: `if cond then a else b`
+//! - **Conditionals**: `if cond then a else b` or `cond ? a : b`
 //! - **Variables**: `x = 5`
 //! - **Functions**: `def f(x) = x * 2`
 //! - **Lambdas**: `f = x => x * 2` or `f = (a, b) => a + b`
@@ -27,34 +27,38 @@
 //! - `pi` - π (3.14159...)
 //! - `e` - Euler's number (2.71828...)
 //! - `tau` - τ = 2π (6.28318...)
-//! - `true` - 1.0
-//! - `false` - 0.0
+//! - `true` - 1.0 (`Value::Bool(true)` from [`eval_value`])
+//! - `false` - 0.0 (`Value::Bool(false)` from [`eval_value`])
 //!
 //! ## Built-in Functions
 //!
 //! ### Single Argument
-//! - Trigonometric: `sin`, `cos`, `tan`, `asin`, `acos`, `atan`
+//! - Trigonometric: `sin`, `cos`, `tan`, `asin`, `acos`, `atan` (radians, or
+//!   degrees with [`Context::set_angle_mode`])
 //! - Hyperbolic: `sinh`, `cosh`, `tanh`
 //! - Roots: `sqrt`, `cbrt`
 //! - Rounding: `floor`, `ceil`, `round`, `trunc`
 //! - Other: `abs`, `sign`, `fract`, `exp`, `ln`, `log2`, `log10`, `print`
+//! - Combinatorics: `fact`
 //!
 //! ### Multiple Arguments
-//! - Two args: `max`, `min`, `pow`, `atan2`, `hypot`, `log`, `mod`
+//! - Two args: `max`, `min`, `pow`, `atan2`, `hypot`, `log`, `mod`, `gcd`, `lcm`,
+//!   `nCr`, `nPr`
 //! - Three args: `clamp`, `lerp`
 //! - Variadic: `sum`, `avg`
 //!
 //! ## Operator Precedence (lowest to highest)
 //!
-//! 1. Logical OR (`or`, `||`)
-//! 2. Logical AND (`and`, `&&`)
-//! 3. Equality (`==`, `!=`)
-//! 4. Comparison (`<`, `>`, `<=`, `>=`)
-//! 5. Addition, Subtraction (`+`, `-`)
-//! 6. Multiplication, Division, Modulo (`*`, `/`, `%`)
-//! 7. Power (`^`, `**`) - right-associative
-//! 8. Unary (`-`, `not`, `!`)
-//! 9. Function calls, parentheses
+//! 1. Ternary (`? :`) - right-associative
+//! 2. Logical OR (`or`, `||`)
+//! 3. Logical AND (`and`, `&&`)
+//! 4. Equality (`==`, `!=`)
+//! 5. Comparison (`<`, `>`, `<=`, `>=`)
+//! 6. Addition, Subtraction (`+`, `-`)
+//! 7. Multiplication, Division, Modulo (`*`, `/`, `%`)
+//! 8. Power (`^`, `**`) - right-associative
+//! 9. Unary (`-`, `not`, `!`)
+//! 10. Function calls, parentheses
 //!
 //! ## Examples
 //!
@@ -74,6 +78,7 @@
 //!
 //! // Conditional expression
 //! assert_eq!(eval("if 5 > 3 then 100 else 200").unwrap(), 100.0);
+//! assert_eq!(eval("5 > 3 ? 100 : 200").unwrap(), 100.0);
 //!
 //! // Constants and functions
 //! let result = eval("sin(pi / 2)").unwrap();
@@ -158,11 +163,15 @@
 
 mod ast;
 mod parser;
+mod session;
+mod trace;
 
 pub use ast::{
-    is_builtin_function_name, is_keyword, BinOp, Callable, Context, Expr, FuncBody, FuncDef,
-    Program, Statement, UnaryOp,
+    is_builtin_function_name, is_keyword, AngleMode, BinOp, Callable, Context, Expr, FuncBody,
+    FuncDef, Program, Statement, UnaryOp, Value,
 };
+pub use session::Session;
+pub use trace::TraceEntry;
 
 use anyhow::Result;
 use parser::Parser;
@@ -220,6 +229,37 @@ pub fn eval(input: &str) -> Result<f64> {
     parse(input)?.eval()
 }
 
+/// Parse and evaluate an expression to a typed [`Value`].
+///
+/// Like [`eval`], but comparisons and logical operators return
+/// [`Value::Bool`] instead of `1.0`/`0.0`.
+///
+/// # Examples
+///
+/// ```
+/// use dx_expr::{eval_value, Value};
+///
+/// assert_eq!(eval_value("5 > 3").unwrap(), Value::Bool(true));
+/// assert_eq!(eval_value("(5 > 3) + 1").unwrap(), Value::Number(2.0));
+/// assert_eq!(eval_value("5 > 3").unwrap().to_string(), "true");
+/// ```
+pub fn eval_value(input: &str) -> Result<Value> {
+    parse(input)?.eval_value()
+}
+
+/// Parse and evaluate a multi-line program to a typed [`Value`].
+///
+/// # Examples
+///
+/// ```
+/// use dx_expr::{eval_program_value, Value};
+///
+/// assert_eq!(eval_program_value("x = 4; x >= 4").unwrap(), Value::Bool(true));
+/// ```
+pub fn eval_program_value(input: &str) -> Result<Value> {
+    parse_program(input)?.eval_value_with_context(&mut Context::new())
+}
+
 /// Parse a multi-line program into a Program AST.
 ///
 /// Programs consist of statements separated by newlines or semicolons.
@@ -333,6 +373,44 @@ mod tests {
         assert_eq!(eval("5 != 5").unwrap(), 0.0);
     }
 
+    #[test]
+    fn test_eval_value_booleans() {
+        assert_eq!(eval_value("5>3").unwrap(), Value::Bool(true));
+        assert_eq!(eval("5>3").unwrap(), 1.0);
+        assert_eq!(eval_value("1 and 0").unwrap(), Value::Bool(false));
+        assert_eq!(eval_value("not 2").unwrap(), Value::Bool(false));
+        assert_eq!(eval_value("true").unwrap(), Value::Bool(true));
+        assert_eq!(eval_value("2 * 3").unwrap(), Value::Number(6.0));
+    }
+
+    #[test]
+    fn test_eval_value_coercion() {
+        // Arithmetic on booleans uses 1/0
+        assert_eq!(eval_value("(5 > 3) + (2 > 1)").unwrap(), Value::Number(2.0));
+        assert_eq!(eval_value("true * 7").unwrap(), Value::Number(7.0));
+        // A conditional takes the type of the chosen branch
+        assert_eq!(
+            eval_value("if 1 then 2 > 1 else 5").unwrap(),
+            Value::Bool(true)
+        );
+        assert_eq!(
+            eval_value("if 0 then 2 > 1 else 5").unwrap(),
+            Value::Number(5.0)
+        );
+        assert_eq!(
+            eval_program_value("x = 2 > 1; x + 1").unwrap(),
+            Value::Number(2.0)
+        );
+    }
+
+    #[test]
+    fn test_value_display() {
+        assert_eq!(Value::Bool(true).to_string(), "true");
+        assert_eq!(Value::Number(3.0).to_string(), "3");
+        assert_eq!(Value::Number(2.5).to_string(), "2.5");
+        assert_eq!(Value::Bool(false).as_f64(), 0.0);
+    }
+
     #[test]
     fn test_logical_operators() {
         assert_eq!(eval("1 and 1").unwrap(), 1.0);
@@ -353,6 +431,88 @@ mod tests {
         assert_eq!(eval("clamp(15, 0, 10)").unwrap(), 10.0);
     }
 
+    #[test]
+    fn test_angle_modes() {
+        let mut ctx = Context::new();
+        assert_eq!(ctx.angle_mode(), AngleMode::Radians);
+        assert!((eval_with_context("sin(pi / 2)", &mut ctx).unwrap() - 1.0).abs() < 1e-12);
+
+        ctx.set_angle_mode(AngleMode::Degrees);
+        assert!((eval_with_context("sin(90)", &mut ctx).unwrap() - 1.0).abs() < 1e-12);
+        assert!((eval_with_context("cos(60)", &mut ctx).unwrap() - 0.5).abs() < 1e-12);
+        assert!((eval_with_context("tan(45)", &mut ctx).unwrap() - 1.0).abs() < 1e-12);
+        assert!((eval_with_context("asin(1)", &mut ctx).unwrap() - 90.0).abs() < 1e-9);
+        assert!((eval_with_context("atan2(1, 1)", &mut ctx).unwrap() - 45.0).abs() < 1e-9);
+        // Hyperbolic functions do not take angles
+        assert_eq!(eval_with_context("sinh(0)", &mut ctx).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_angle_mode_in_functions_and_traces() {
+        let mut ctx = Context::new();
+        ctx.set_angle_mode(AngleMode::Degrees);
+        let result = eval_with_context("def s(x) = sin(x)\ns(30)", &mut ctx).unwrap();
+        assert!((result - 0.5).abs() < 1e-12);
+
+        let (traced, _) = parse("acos(0)").unwrap().eval_traced(&mut ctx);
+        assert!((traced.unwrap() - 90.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_combinatorics() {
+        assert_eq!(eval("fact(5)").unwrap(), 120.0);
+        assert_eq!(eval("fact(0)").unwrap(), 1.0);
+        assert_eq!(eval("fact(20)").unwrap(), 2432902008176640000.0);
+        assert_eq!(eval("gcd(12, 18)").unwrap(), 6.0);
+        assert_eq!(eval("gcd(0, 7)").unwrap(), 7.0);
+        assert_eq!(eval("lcm(4, 6)").unwrap(), 12.0);
+        assert_eq!(eval("lcm(0, 6)").unwrap(), 0.0);
+        assert_eq!(eval("nCr(5, 2)").unwrap(), 10.0);
+        assert_eq!(eval("nCr(52, 5)").unwrap(), 2598960.0);
+        assert_eq!(eval("nCr(3, 5)").unwrap(), 0.0);
+        assert_eq!(eval("nPr(5, 2)").unwrap(), 20.0);
+        assert_eq!(eval("nPr(5, 0)").unwrap(), 1.0);
+    }
+
+    #[test]
+    fn test_combinatorics_errors() {
+        for input in [
+            "fact(-1)",
+            "fact(2.5)",
+            "gcd(4, -2)",
+            "nCr(5.5, 2)",
+            "nPr(-3, 1)",
+        ] {
+            let err = eval(input).unwrap_err().to_string();
+            assert!(
+                err.contains("non-negative whole numbers"),
+                "{}: {}",
+                input,
+                err
+            );
+        }
+        assert!(eval("fact(171)")
+            .unwrap_err()
+            .to_string()
+            .contains("too large"));
+        assert!(eval("fact(1000000000000)")
+            .unwrap_err()
+            .to_string()
+            .contains("too large"));
+        assert!(eval("nPr(1000, 500)")
+            .unwrap_err()
+            .to_string()
+            .contains("too large"));
+        assert!(eval("fact(1, 2)")
+            .unwrap_err()
+            .to_string()
+            .contains("expects 1 argument"));
+        assert!(eval("nCr(5)")
+            .unwrap_err()
+            .to_string()
+            .contains("expects 2 arguments"));
+    }
+
     #[test]
     fn test_user_defined_function() {
         let result = eval_program("def double(x) = x * 2\ndouble(5)").unwrap();
@@ -364,4 +524,61 @@ mod tests {
         let result = eval_program("f = x => x * 2\nf(5)").unwrap();
         assert_eq!(result, 10.0);
     }
+
+    #[test]
+    fn test_tuple_destructuring() {
+        let mut ctx = Context::new();
+        let program =
+            parse_program("def minmax(a, b) = (min(a, b), max(a, b))\n(lo, hi) = minmax(7, 3)")
+                .unwrap();
+        let result = program.eval_value_with_context(&mut ctx).unwrap();
+        assert_eq!(
+            result,
+            Value::Tuple(vec![Value::Number(3.0), Value::Number(7.0)])
+        );
+        assert_eq!(ctx.get("lo"), Some(3.0));
+        assert_eq!(ctx.get("hi"), Some(7.0));
+
+        assert_eq!(eval_program("(a, b) = (1, 2); a * 10 + b").unwrap(), 12.0);
+        assert_eq!(
+            eval_program("f = (x, y) => (y, x)\n(p, q) = f(1, 2)\np").unwrap(),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_tuple_errors() {
+        let err = eval_program("(a, b) = (1, 2, 3)").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("tuple of 3 value(s) into 2 name(s)"));
+
+        let err = eval_program("(a, b) = 5").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Cannot destructure 5 into 2 names"));
+
+        let err = eval_program("x = (1, 2)").unwrap_err();
+        assert!(err.to_string().contains("destructure it instead"));
+
+        let err = eval_program("(a, a) = (1, 2)").unwrap_err();
+        assert!(err.to_string().contains("Duplicate name"));
+
+        let err = eval_program("(pi, b) = (1, 2)").unwrap_err();
+        assert!(err.to_string().contains("Cannot assign to constant: pi"));
+
+        assert!(eval("(1, 2) + 1").is_err());
+    }
+
+    #[test]
+    fn test_tuple_value_display_and_json() {
+        let value = eval_value("(1, 2 > 1, 2.5)").unwrap();
+        assert_eq!(value.to_string(), "(1, true, 2.5)");
+        assert_eq!(serde_json::to_string(&value).unwrap(), "[1.0,true,2.5]");
+        assert_eq!(parse("(1, x)").unwrap().to_string(), "(1, x)");
+        // A parenthesized single expression is not a tuple
+        assert_eq!(eval_value("(1)").unwrap(), Value::Number(1.0));
+        // Comparisons and lambdas starting with a tuple still parse
+        assert!(parse_program("(a, b) => a + b").is_ok());
+    }
 }
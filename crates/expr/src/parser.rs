@@ -9,8 +9,9 @@
 //! funcdef     = 'def' identifier '(' params? ')' '=' expr
 //! params      = identifier (',' identifier)*
 //!
-//! statement   = assignment | expr
+//! statement   = assignment | destructure | expr
 //! assignment  = identifier '=' expr
+//! destructure = '(' identifier (',' identifier)+ ')' '=' expr
 //!
 //! expr        = logical_or
 //! logical_or  = logical_and (('or' | '||') logical_and)*
@@ -23,7 +24,8 @@
 //! unary       = ('not' | '!' | '-')* call
 //! call        = identifier '(' args? ')' | primary
 //! args        = expr (',' expr)*
-//! primary     = number | identifier | '(' expr ')' | conditional | lambda
+//! primary     = number | identifier | '(' expr ')' | tuple | conditional | lambda
+//! tuple       = '(' expr (',' expr)+ ')'
 //! conditional = 'if' expr 'then' expr 'else' expr
 //! lambda      = identifier '=>' expr | '(' params ')' '=>' expr
 //! ```
@@ -45,6 +47,7 @@
 //! - Constants: `pi`, `e`, `tau`, `true`, `false`
 //! - Functions: `sin`, `cos`, `tan`, `sqrt`, `abs`, `ln`, `log2`, `log10`, etc.
 //! - Variables and assignment
+//! - Tuples and destructuring: `(lo, hi) = (1, 9)`
 //! - User-defined functions: `def f(x) = x * 2`
 //! - Lambda expressions: `x => x * 2` or `(a, b) => a + b`
 //! - Conditional expressions: `if x > 0 then x else -x`
@@ -148,6 +151,13 @@ impl<'a> Parser<'a> {
             return self.function_def();
         }
 
+        // Check for destructuring assignment: (a, b) = expr
+        if let Some(names) = self.destructure_target() {
+            self.skip_whitespace_not_newline();
+            let value = self.expr()?;
+            return Ok(Statement::Destructure { names, value });
+        }
+
         // Look ahead: if we have identifier followed by '=', it's an assignment
         // But need to distinguish from '==' (equality) and '=>' (lambda)
         let start_pos = self.pos;
@@ -190,6 +200,50 @@ impl<'a> Parser<'a> {
         Ok(Statement::Expression(expr))
     }
 
+    /// Parse `(a, b, ...) =` if present, leaving the position after the `=`.
+    ///
+    /// Returns `None` and rewinds for anything else, including tuple
+    /// expressions, lambdas `(a, b) => ...` and comparisons `(a, b) == ...`.
+    fn destructure_target(&mut self) -> Option<Vec<String>> {
+        let start_pos = self.pos;
+        let names = self.destructure_names();
+        if names.is_some() && self.current_char() == Some('=') {
+            self.advance();
+            if !matches!(self.current_char(), Some('=') | Some('>')) {
+                return names;
+            }
+        }
+        self.pos = start_pos;
+        None
+    }
+
+    /// Parse `(a, b, ...)` with at least two names, stopping before any `=`
+    fn destructure_names(&mut self) -> Option<Vec<String>> {
+        if self.current_char() != Some('(') {
+            return None;
+        }
+        self.advance();
+
+        let mut names = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let name = self.identifier();
+            if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+                return None;
+            }
+            names.push(name);
+            self.skip_whitespace();
+            match self.current_char() {
+                Some(',') => self.advance(),
+                Some(')') => break,
+                _ => return None,
+            }
+        }
+        self.advance();
+        self.skip_whitespace_not_newline();
+        (names.len() >= 2).then_some(names)
+    }
+
     /// Parse function definition: def name(params) = expr
     fn function_def(&mut self) -> Result<Statement> {
         // Skip 'def'
@@ -370,9 +424,33 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parse: expr = logical_or
+    /// Parse: expr = ternary
     fn expr(&mut self) -> Result<Expr> {
-        self.logical_or()
+        self.ternary()
+    }
+
+    /// Parse: ternary = logical_or ('?' expr ':' ternary)?
+    ///
+    /// Sugar for `if cond then a else b`. The else branch is parsed as another
+    /// ternary, so `a ? b : c ? d : e` groups as `a ? b : (c ? d : e)`.
+    fn ternary(&mut self) -> Result<Expr> {
+        let condition = self.logical_or()?;
+
+        self.skip_whitespace();
+        if self.current_char() != Some('?') {
+            return Ok(condition);
+        }
+        self.advance();
+        let then_branch = self.expr()?;
+
+        self.skip_whitespace();
+        if self.current_char() != Some(':') {
+            bail!("Expected ':' in ternary expression");
+        }
+        self.advance();
+        let else_branch = self.ternary()?;
+
+        Ok(Expr::conditional(condition, then_branch, else_branch))
     }
 
     /// Parse: logical_or = logical_and (('or' | '||') logical_and)*
@@ -759,14 +837,25 @@ impl<'a> Parser<'a> {
                 }
 
                 // Not a lambda, rewind and parse as parenthesized expression
+                // or tuple
                 self.pos = start_pos;
-                let result = self.expr()?;
+                let mut items = vec![self.expr()?];
                 self.skip_whitespace();
+                while self.current_char() == Some(',') {
+                    self.advance();
+                    self.skip_whitespace();
+                    items.push(self.expr()?);
+                    self.skip_whitespace();
+                }
                 if self.current_char() != Some(')') {
                     bail!("Expected ')' at position {}", self.pos);
                 }
                 self.advance();
-                Ok(result)
+                Ok(if items.len() == 1 {
+                    items.pop().unwrap()
+                } else {
+                    Expr::tuple(items)
+                })
             }
             Some(c) if c.is_ascii_digit() || c == '.' => self.number(),
             Some(c) => bail!("Unexpected character '{}' at position {}", c, self.pos),
@@ -824,6 +913,7 @@ mod tests {
     // ==================== Basic Numbers ====================
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_basic_numbers() {
         assert_eq!(parse("42").unwrap(), 42.0);
         assert_eq!(parse("3.14").unwrap(), 3.14);
@@ -1040,6 +1130,39 @@ mod tests {
         assert_eq!(parse("if 1 then if 0 then 1 else 2 else 3").unwrap(), 2.0);
     }
 
+    #[test]
+    fn test_ternary_basic() {
+        assert_eq!(parse("1 ? 10 : 20").unwrap(), 10.0);
+        assert_eq!(parse("0 ? 10 : 20").unwrap(), 20.0);
+        assert_eq!(parse("5 > 3 ? 1 + 1 : 0").unwrap(), 2.0);
+        assert_eq!(parse("2 > 1 or 0 ? 7 : 8").unwrap(), 7.0);
+        assert_eq!(parse_program("x = 1 > 2 ? 3 : 4; x").unwrap(), 4.0);
+    }
+
+    #[test]
+    fn test_ternary_right_associative() {
+        let nested = Parser::new("0 ? 1 : 2 ? 3 : 4").parse().unwrap();
+        let grouped = Parser::new("0 ? 1 : (2 ? 3 : 4)").parse().unwrap();
+        assert_eq!(nested, grouped);
+        assert_eq!(nested.eval().unwrap(), 3.0);
+
+        // Same tree as the keyword form
+        let keyword = Parser::new("if 0 then 1 else if 2 then 3 else 4")
+            .parse()
+            .unwrap();
+        assert_eq!(nested, keyword);
+
+        // A ternary in the then-branch needs no parentheses
+        assert_eq!(parse("1 ? 0 ? 5 : 6 : 7").unwrap(), 6.0);
+    }
+
+    #[test]
+    fn test_ternary_errors() {
+        assert!(parse("1 ? 2").is_err());
+        assert!(parse("1 ? : 2").is_err());
+        assert!(parse("1 ? 2 :").is_err());
+    }
+
     #[test]
     fn test_conditional_with_arithmetic() {
         assert_eq!(parse("if 1 then 2 + 3 else 4 * 5").unwrap(), 5.0);
@@ -1150,6 +1273,7 @@ mod tests {
     // ==================== Print Function ====================
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_print_function() {
         // print returns its argument
         assert_eq!(parse("print(42)").unwrap(), 42.0);
@@ -0,0 +1,145 @@
+//! Interactive evaluation sessions.
+//!
+//! A [`Session`] keeps variables and functions alive across separate inputs,
+//! which is what a REPL needs: each line is parsed and evaluated as its own
+//! program, but against the same [`Context`].
+
+use crate::ast::{Context, Expr, Statement, Value};
+use crate::parse_program;
+use anyhow::Result;
+
+/// A stateful evaluator that remembers definitions between inputs.
+///
+/// # Examples
+///
+/// ```
+/// use dx_expr::Session;
+///
+/// let mut session = Session::new();
+/// session.eval("x = 5").unwrap();
+/// assert_eq!(session.eval("x * 2").unwrap(), Some(10.0));
+///
+/// // Definitions produce no value
+/// assert_eq!(session.eval("def sq(n) = n * n").unwrap(), None);
+/// assert_eq!(session.eval("sq(x)").unwrap(), Some(25.0));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    ctx: Context,
+}
+
+impl Session {
+    /// Create a session with no definitions
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluate one input against the session state.
+    ///
+    /// Returns `None` when the input ends with a function or lambda
+    /// definition. If any statement fails, the session is left unchanged.
+    pub fn eval(&mut self, input: &str) -> Result<Option<f64>> {
+        self.eval_value(input)?.map(|v| v.number()).transpose()
+    }
+
+    /// Like [`Session::eval`], but returns a typed [`Value`] so comparisons
+    /// come back as booleans.
+    pub fn eval_value(&mut self, input: &str) -> Result<Option<Value>> {
+        let program = parse_program(input)?;
+        let mut ctx = self.ctx.clone();
+        let result = program.eval_value_with_context(&mut ctx)?;
+        self.ctx = ctx;
+
+        let is_definition = matches!(
+            program.statements.last(),
+            Some(Statement::FuncDef { .. })
+                | Some(Statement::Assignment {
+                    value: Expr::Lambda { .. },
+                    ..
+                })
+        );
+        Ok(if is_definition { None } else { Some(result) })
+    }
+
+    /// Defined variables, sorted by name
+    pub fn variables(&self) -> Vec<(String, f64)> {
+        let mut vars: Vec<(String, f64)> = self
+            .ctx
+            .variables()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        vars.sort_by(|a, b| a.0.cmp(&b.0));
+        vars
+    }
+
+    /// User functions and lambdas with their parameter names, sorted by name
+    pub fn functions(&self) -> Vec<(String, Vec<String>)> {
+        let mut funcs: Vec<(String, Vec<String>)> = self
+            .ctx
+            .functions()
+            .map(|(name, callable)| (name.to_string(), callable.params.clone()))
+            .collect();
+        funcs.sort_by(|a, b| a.0.cmp(&b.0));
+        funcs
+    }
+
+    /// The underlying evaluation context
+    pub fn context(&self) -> &Context {
+        &self.ctx
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variables_persist_across_inputs() {
+        let mut session = Session::new();
+        assert_eq!(session.eval("x = 4").unwrap(), Some(4.0));
+        assert_eq!(session.eval("y = x + 1").unwrap(), Some(5.0));
+        assert_eq!(session.eval("x * y").unwrap(), Some(20.0));
+        assert_eq!(
+            session.variables(),
+            vec![("x".to_string(), 4.0), ("y".to_string(), 5.0)]
+        );
+    }
+
+    #[test]
+    fn test_functions_persist_across_inputs() {
+        let mut session = Session::new();
+        assert_eq!(session.eval("def add(a, b) = a + b").unwrap(), None);
+        assert_eq!(session.eval("double = x => x * 2").unwrap(), None);
+        assert_eq!(session.eval("double(add(1, 2))").unwrap(), Some(6.0));
+        assert_eq!(
+            session.functions(),
+            vec![
+                ("add".to_string(), vec!["a".to_string(), "b".to_string()]),
+                ("double".to_string(), vec!["x".to_string()]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_eval_value_keeps_booleans() {
+        let mut session = Session::new();
+        session.eval("x = 3").unwrap();
+        assert_eq!(
+            session.eval_value("x > 2").unwrap(),
+            Some(Value::Bool(true))
+        );
+        assert_eq!(session.eval("x > 2").unwrap(), Some(1.0));
+        // Stored as a number
+        session.eval("flag = x == 4").unwrap();
+        assert_eq!(session.variables()[0], ("flag".to_string(), 0.0));
+    }
+
+    #[test]
+    fn test_failed_input_leaves_session_unchanged() {
+        let mut session = Session::new();
+        session.eval("x = 1").unwrap();
+        assert!(session.eval("x = 2; y = missing").is_err());
+        assert!(session.eval("1 +").is_err());
+        assert_eq!(session.variables(), vec![("x".to_string(), 1.0)]);
+    }
+}
@@ -0,0 +1,312 @@
+//! Traced evaluation.
+//!
+//! [`Expr::eval_traced`] evaluates like [`Expr::eval_with_context`] but also
+//! records every sub-expression with its value, innermost first. It is a
+//! separate evaluator, so normal evaluation pays nothing for it.
+//!
+//! Calls to user functions are traced as a whole: their arguments appear in
+//! the trace, the function body does not.
+
+use crate::ast::{
+    assign, call_with_values, check_arity, check_assignable, destructure, eval_binop,
+    eval_builtin_function, BinOp, Context, Expr, Program, Statement, UnaryOp, Value,
+};
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+/// One evaluated sub-expression.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TraceEntry {
+    /// Nesting depth; the outermost expression is 0
+    pub depth: usize,
+    /// The sub-expression in source-like form
+    pub expr: String,
+    pub value: Value,
+}
+
+impl Expr {
+    /// Evaluate with a context, recording each sub-expression's value.
+    ///
+    /// The trace lists children before their parent, so `2 + 3 * 4` records
+    /// `3 * 4 = 12` before `2 + (3 * 4) = 14`. If evaluation fails, the trace
+    /// holds everything evaluated up to the error.
+    pub fn eval_traced(&self, ctx: &mut Context) -> (Result<f64>, Vec<TraceEntry>) {
+        let (result, trace) = self.eval_value_traced(ctx);
+        (result.and_then(|v| v.number()), trace)
+    }
+
+    /// Like [`Expr::eval_traced`], but returns a typed [`Value`]
+    pub fn eval_value_traced(&self, ctx: &mut Context) -> (Result<Value>, Vec<TraceEntry>) {
+        let mut tracer = Tracer::default();
+        let result = tracer.eval(self, ctx);
+        (result, tracer.entries)
+    }
+}
+
+impl Program {
+    /// Evaluate all statements, tracing each assigned or evaluated expression
+    pub fn eval_traced(&self, ctx: &mut Context) -> (Result<f64>, Vec<TraceEntry>) {
+        let (result, trace) = self.eval_value_traced(ctx);
+        (result.and_then(|v| v.number()), trace)
+    }
+
+    /// Like [`Program::eval_traced`], but returns the last statement's typed
+    /// [`Value`]
+    pub fn eval_value_traced(&self, ctx: &mut Context) -> (Result<Value>, Vec<TraceEntry>) {
+        let mut tracer = Tracer::default();
+        let result = tracer.eval_program(self, ctx);
+        (result, tracer.entries)
+    }
+}
+
+#[derive(Default)]
+struct Tracer {
+    entries: Vec<TraceEntry>,
+    depth: usize,
+}
+
+impl Tracer {
+    fn eval_program(&mut self, program: &Program, ctx: &mut Context) -> Result<Value> {
+        if program.statements.is_empty() {
+            bail!("Empty program");
+        }
+
+        let mut result = Value::Number(0.0);
+        for stmt in &program.statements {
+            result = match stmt {
+                Statement::Expression(expr) => self.eval(expr, ctx)?,
+                Statement::Assignment { name, value } if !matches!(value, Expr::Lambda { .. }) => {
+                    check_assignable(name)?;
+                    let result = self.eval(value, ctx)?;
+                    assign(ctx, name, &result)?;
+                    result
+                }
+                Statement::Destructure { names, value } => {
+                    let result = self.eval(value, ctx)?;
+                    destructure(ctx, names, &result)?;
+                    result
+                }
+                // Definitions have nothing to trace
+                _ => stmt.eval_value(ctx)?,
+            };
+        }
+        Ok(result)
+    }
+
+    fn eval(&mut self, expr: &Expr, ctx: &mut Context) -> Result<Value> {
+        self.depth += 1;
+        let result = self.eval_node(expr, ctx);
+        self.depth -= 1;
+
+        let value = result?;
+        self.entries.push(TraceEntry {
+            depth: self.depth,
+            expr: expr.to_string(),
+            value: value.clone(),
+        });
+        Ok(value)
+    }
+
+    fn eval_f64(&mut self, expr: &Expr, ctx: &mut Context) -> Result<f64> {
+        self.eval(expr, ctx)?.number()
+    }
+
+    /// Mirrors [`Expr::eval_value_with_context`], recursing through the tracer
+    fn eval_node(&mut self, expr: &Expr, ctx: &mut Context) -> Result<Value> {
+        match expr {
+            Expr::BinOp {
+                op: BinOp::And,
+                left,
+                right,
+            } => {
+                if self.eval_f64(left, ctx)? == 0.0 {
+                    return Ok(Value::Bool(false));
+                }
+                Ok(Value::Bool(self.eval_f64(right, ctx)? != 0.0))
+            }
+            Expr::BinOp {
+                op: BinOp::Or,
+                left,
+                right,
+            } => {
+                if self.eval_f64(left, ctx)? != 0.0 {
+                    return Ok(Value::Bool(true));
+                }
+                Ok(Value::Bool(self.eval_f64(right, ctx)? != 0.0))
+            }
+            Expr::BinOp { op, left, right } => {
+                let l = self.eval_f64(left, ctx)?;
+                let r = self.eval_f64(right, ctx)?;
+                let result = eval_binop(*op, l, r)?;
+                Ok(if op.is_boolean() {
+                    Value::Bool(result != 0.0)
+                } else {
+                    Value::Number(result)
+                })
+            }
+
+            Expr::UnaryOp { op, expr } => {
+                let val = self.eval_f64(expr, ctx)?;
+                Ok(match op {
+                    UnaryOp::Neg => Value::Number(-val),
+                    UnaryOp::Not => Value::Bool(val == 0.0),
+                })
+            }
+
+            Expr::FuncCall { name, args } => {
+                let callable = ctx.get_function(name);
+                if let Some(callable) = &callable {
+                    check_arity(callable, args.len())?;
+                }
+
+                let mut vals = Vec::with_capacity(args.len());
+                for arg in args {
+                    vals.push(self.eval_f64(arg, ctx)?);
+                }
+
+                match callable {
+                    Some(callable) => call_with_values(&callable, &vals, ctx),
+                    None => eval_builtin_function(name, &vals, ctx.angle_mode()).map(Value::Number),
+                }
+            }
+
+            Expr::Conditional {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                if self.eval_f64(condition, ctx)? != 0.0 {
+                    self.eval(then_branch, ctx)
+                } else {
+                    self.eval(else_branch, ctx)
+                }
+            }
+
+            Expr::Tuple { items } => {
+                let mut values = Vec::with_capacity(items.len());
+                for item in items {
+                    values.push(self.eval(item, ctx)?);
+                }
+                Ok(Value::Tuple(values))
+            }
+
+            // Leaves: numbers, constants, variables and lambdas
+            _ => expr.eval_value_with_context(ctx),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parse, parse_program, Context, Value};
+
+    fn trace(input: &str) -> Vec<(String, Value)> {
+        let (result, entries) = parse(input).unwrap().eval_traced(&mut Context::new());
+        result.unwrap();
+        entries.into_iter().map(|e| (e.expr, e.value)).collect()
+    }
+
+    #[test]
+    fn test_multiplication_recorded_before_addition() {
+        let (result, entries) = parse("2 + 3 * 4").unwrap().eval_traced(&mut Context::new());
+        assert_eq!(result.unwrap(), 14.0);
+
+        let values: Vec<(&str, f64, usize)> = entries
+            .iter()
+            .map(|e| (e.expr.as_str(), e.value.as_f64(), e.depth))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                ("2", 2.0, 1),
+                ("3", 3.0, 2),
+                ("4", 4.0, 2),
+                ("3 * 4", 12.0, 1),
+                ("2 + (3 * 4)", 14.0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_booleans_and_short_circuit() {
+        assert_eq!(
+            trace("1 > 2 and missing"),
+            vec![
+                ("1".to_string(), Value::Number(1.0)),
+                ("2".to_string(), Value::Number(2.0)),
+                ("1 > 2".to_string(), Value::Bool(false)),
+                ("(1 > 2) and missing".to_string(), Value::Bool(false)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conditional_traces_taken_branch_only() {
+        let exprs: Vec<String> = trace("if 1 then 10 else 1 / 0")
+            .into_iter()
+            .map(|(expr, _)| expr)
+            .collect();
+        assert_eq!(exprs, ["1", "10", "if 1 then 10 else 1 / 0"]);
+    }
+
+    #[test]
+    fn test_function_calls() {
+        let mut ctx = Context::new();
+        let program = parse_program("def sq(n) = n * n\nx = 3\nsq(x + 1) + sqrt(16)").unwrap();
+        let (result, entries) = program.eval_traced(&mut ctx);
+        assert_eq!(result.unwrap(), 20.0);
+        let exprs: Vec<&str> = entries.iter().map(|e| e.expr.as_str()).collect();
+        assert_eq!(
+            exprs,
+            [
+                "3",
+                "x",
+                "1",
+                "x + 1",
+                "sq(x + 1)",
+                "16",
+                "sqrt(16)",
+                "sq(x + 1) + sqrt(16)"
+            ]
+        );
+        assert_eq!(ctx.get("x"), Some(3.0));
+    }
+
+    #[test]
+    fn test_destructuring() {
+        let mut ctx = Context::new();
+        let program = parse_program("(a, b) = (1, 2 + 3)").unwrap();
+        let (result, entries) = program.eval_value_traced(&mut ctx);
+        assert_eq!(
+            result.unwrap(),
+            Value::Tuple(vec![Value::Number(1.0), Value::Number(5.0)])
+        );
+        let exprs: Vec<&str> = entries.iter().map(|e| e.expr.as_str()).collect();
+        assert_eq!(exprs, ["1", "2", "3", "2 + 3", "(1, 2 + 3)"]);
+        assert_eq!(ctx.get("b"), Some(5.0));
+    }
+
+    #[test]
+    fn test_error_keeps_partial_trace() {
+        let (result, entries) = parse("(1 + 2) / 0")
+            .unwrap()
+            .eval_traced(&mut Context::new());
+        assert!(result.unwrap_err().to_string().contains("Division by zero"));
+        let exprs: Vec<&str> = entries.iter().map(|e| e.expr.as_str()).collect();
+        assert_eq!(exprs, ["1", "2", "1 + 2", "0"]);
+    }
+
+    #[test]
+    fn test_traced_matches_untraced() {
+        for input in [
+            "2 ^ 3 ^ 2",
+            "-abs(-3) + 1",
+            "not (1 == 1) or 5 % 3 >= 2",
+            "max(1, 7)",
+        ] {
+            let expr = parse(input).unwrap();
+            let (traced, _) = expr.eval_value_traced(&mut Context::new());
+            assert_eq!(traced.unwrap(), expr.eval_value().unwrap(), "{}", input);
+        }
+    }
+}
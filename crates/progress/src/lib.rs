@@ -14,6 +14,8 @@
 //! - **OSC 9;4 Progress Reporting**: Native terminal progress indicators
 //! - **Visual Progress Bars**: ANSI-based progress bars
 //! - **Spinners**: Animated spinner support
+//! - **ASCII Fallback**: `-=#` bars and a `|/-\` spinner for terminals
+//!   without Unicode, enabled per reporter or with `DX_ASCII=1`
 //! - **Zero Dependencies**: No external crates required
 //!
 //! ## Quick Start
@@ -48,6 +50,27 @@
 
 use std::io::{self, Write};
 
+/// Environment variable that switches bars and spinners to ASCII.
+///
+/// Any value other than empty or `0` enables ASCII mode.
+pub const ASCII_ENV_VAR: &str = "DX_ASCII";
+
+/// Whether [`ASCII_ENV_VAR`] asks for ASCII output.
+///
+/// This is the default for the `ascii` option of every reporter.
+pub fn ascii_from_env() -> bool {
+    std::env::var(ASCII_ENV_VAR).is_ok_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Characters used to draw bars: (filled, empty, bouncing segment)
+fn bar_chars(ascii: bool) -> (&'static str, &'static str, &'static str) {
+    if ascii {
+        ("#", "-", "=")
+    } else {
+        ("█", "░", "█")
+    }
+}
+
 /// Progress state for OSC 9;4 reporting.
 ///
 /// Different states affect how the terminal displays the progress indicator:
@@ -132,6 +155,7 @@ pub struct TerminalProgress {
     state: ProgressState,
     show_bar: bool,
     bar_width: usize,
+    ascii: bool,
 }
 
 impl TerminalProgress {
@@ -146,6 +170,7 @@ impl TerminalProgress {
             state: ProgressState::Normal,
             show_bar: true,
             bar_width: 30,
+            ascii: ascii_from_env(),
         }
     }
 
@@ -171,13 +196,15 @@ impl TerminalProgress {
         self
     }
 
+    /// Draw the bar with ASCII characters only (default: from `DX_ASCII`).
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
     /// Get the current percentage (0-100).
     pub fn percent(&self) -> u64 {
-        if self.total == 0 {
-            0
-        } else {
-            (self.current * 100) / self.total
-        }
+        (self.current * 100).checked_div(self.total).unwrap_or(0)
     }
 
     /// Get the current progress value.
@@ -221,15 +248,8 @@ impl TerminalProgress {
             return;
         }
 
-        let percent = self.percent() as usize;
-        let filled = (percent * self.bar_width) / 100;
-        let empty = self.bar_width - filled;
-
-        let bar = format!(
-            "\x1b[36m{}\x1b[34m{}\x1b[0m", // cyan filled, blue empty
-            "█".repeat(filled),
-            "░".repeat(empty)
-        );
+        let percent = self.percent();
+        let bar = self.render_bar();
 
         if let Some(m) = msg {
             eprint!("\r\x1b[K[{}] {}% {}", bar, percent, m);
@@ -239,6 +259,19 @@ impl TerminalProgress {
         io::stderr().flush().ok();
     }
 
+    /// Render the bar (without brackets or message) as drawn by [`draw`](Self::draw).
+    pub fn render_bar(&self) -> String {
+        let (filled_char, empty_char, _) = bar_chars(self.ascii);
+        let filled = (self.percent() as usize * self.bar_width) / 100;
+        let empty = self.bar_width - filled;
+
+        format!(
+            "\x1b[36m{}\x1b[34m{}\x1b[0m", // cyan filled, blue empty
+            filled_char.repeat(filled),
+            empty_char.repeat(empty)
+        )
+    }
+
     /// Finish and clear the progress indicator.
     pub fn finish(&self) {
         osc_progress_clear();
@@ -286,6 +319,7 @@ pub struct BouncingBar {
     direction: i8, // 1 = right, -1 = left
     bar_width: usize,
     ball_width: usize,
+    ascii: bool,
 }
 
 impl BouncingBar {
@@ -298,6 +332,7 @@ impl BouncingBar {
             direction: 1,
             bar_width: 30,
             ball_width: 3,
+            ascii: ascii_from_env(),
         }
     }
 
@@ -313,6 +348,12 @@ impl BouncingBar {
         self
     }
 
+    /// Draw the bar with ASCII characters only (default: from `DX_ASCII`).
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
     /// Advance the animation by one frame.
     pub fn tick(&mut self) {
         let max_pos = self.bar_width.saturating_sub(self.ball_width);
@@ -334,17 +375,7 @@ impl BouncingBar {
 
     /// Draw the bouncing bar to stderr.
     pub fn draw(&self, msg: Option<&str>) {
-        let before = self.position;
-        let after = self
-            .bar_width
-            .saturating_sub(self.position + self.ball_width);
-
-        let bar = format!(
-            "\x1b[34m{}\x1b[36m{}\x1b[34m{}\x1b[0m",
-            "░".repeat(before),
-            "█".repeat(self.ball_width),
-            "░".repeat(after)
-        );
+        let bar = self.render_bar();
 
         if let Some(m) = msg {
             eprint!("\r\x1b[K[{}] {}", bar, m);
@@ -354,6 +385,22 @@ impl BouncingBar {
         io::stderr().flush().ok();
     }
 
+    /// Render the bar (without brackets or message) as drawn by [`draw`](Self::draw).
+    pub fn render_bar(&self) -> String {
+        let (_, empty_char, ball_char) = bar_chars(self.ascii);
+        let before = self.position;
+        let after = self
+            .bar_width
+            .saturating_sub(self.position + self.ball_width);
+
+        format!(
+            "\x1b[34m{}\x1b[36m{}\x1b[34m{}\x1b[0m",
+            empty_char.repeat(before),
+            ball_char.repeat(self.ball_width),
+            empty_char.repeat(after)
+        )
+    }
+
     /// Finish and clear the progress indicator.
     pub fn finish(&self) {
         osc_progress_clear();
@@ -385,7 +432,78 @@ impl Drop for BouncingBar {
 /// Braille-based spinner that works well in most terminals.
 pub const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
-/// Draw a spinner frame to stderr.
+/// ASCII spinner frames for terminals without Unicode.
+pub const ASCII_SPINNER_FRAMES: &[&str] = &["|", "/", "-", "\\"];
+
+/// Spinner frames for the given mode.
+pub fn spinner_frames(ascii: bool) -> &'static [&'static str] {
+    if ascii {
+        ASCII_SPINNER_FRAMES
+    } else {
+        SPINNER_FRAMES
+    }
+}
+
+/// Animated spinner for indeterminate work.
+///
+/// # Example
+/// ```no_run
+/// use dx_progress::Spinner;
+///
+/// let mut spinner = Spinner::new().ascii(true);
+/// for _ in 0..50 {
+///     spinner.tick();
+///     spinner.draw("Loading...");
+///     std::thread::sleep(std::time::Duration::from_millis(80));
+/// }
+/// dx_progress::clear_line();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Spinner {
+    frame: usize,
+    ascii: bool,
+}
+
+impl Spinner {
+    /// Create a new spinner.
+    pub fn new() -> Self {
+        Self {
+            frame: 0,
+            ascii: ascii_from_env(),
+        }
+    }
+
+    /// Use ASCII frames only (default: from `DX_ASCII`).
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Advance the animation by one frame.
+    pub fn tick(&mut self) {
+        self.frame = self.frame.wrapping_add(1);
+    }
+
+    /// The current frame.
+    pub fn frame(&self) -> &'static str {
+        let frames = spinner_frames(self.ascii);
+        frames[self.frame % frames.len()]
+    }
+
+    /// Draw the current frame and a message to stderr.
+    pub fn draw(&self, msg: &str) {
+        eprint!("\r\x1b[K\x1b[32m{}\x1b[0m {}", self.frame(), msg); // green spinner
+        io::stderr().flush().ok();
+    }
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Draw a spinner frame to stderr, using ASCII frames if `DX_ASCII` is set.
 ///
 /// # Arguments
 /// * `frame` - Frame index (will wrap around automatically)
@@ -402,9 +520,11 @@ pub const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴",
 /// eprintln!("\r\x1b[KDone!");
 /// ```
 pub fn draw_spinner(frame: usize, msg: &str) {
-    let spinner = SPINNER_FRAMES[frame % SPINNER_FRAMES.len()];
-    eprint!("\r\x1b[K\x1b[32m{}\x1b[0m {}", spinner, msg); // green spinner
-    io::stderr().flush().ok();
+    Spinner {
+        frame,
+        ascii: ascii_from_env(),
+    }
+    .draw(msg);
 }
 
 /// Clear the current line on stderr.
@@ -463,6 +583,42 @@ mod tests {
         assert_eq!(p.percent(), 0);
     }
 
+    #[test]
+    fn test_ascii_bars() {
+        let mut p = TerminalProgress::new(100).bar_width(10).ascii(true);
+        p.set(40);
+        let bar = p.render_bar();
+        assert!(bar.is_ascii(), "{:?}", bar);
+        assert!(bar.contains("####\x1b[34m------"));
+
+        let mut b = BouncingBar::new().bar_width(10).ascii(true);
+        for _ in 0..25 {
+            b.tick();
+            assert!(b.render_bar().is_ascii());
+        }
+        assert!(b.render_bar().contains("==="));
+
+        assert!(!TerminalProgress::new(1)
+            .ascii(false)
+            .render_bar()
+            .is_ascii());
+    }
+
+    #[test]
+    fn test_ascii_spinner() {
+        let mut spinner = Spinner::new().ascii(true);
+        let mut seen = Vec::new();
+        for _ in 0..ASCII_SPINNER_FRAMES.len() {
+            assert!(spinner.frame().is_ascii());
+            seen.push(spinner.frame());
+            spinner.tick();
+        }
+        assert_eq!(seen, ["|", "/", "-", "\\"]);
+        assert_eq!(spinner.frame(), "|");
+
+        assert!(spinner_frames(false).iter().all(|f| !f.is_ascii()));
+    }
+
     #[test]
     fn test_progress_state_values() {
         assert_eq!(ProgressState::Hidden as u8, 0);
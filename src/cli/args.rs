@@ -4,10 +4,10 @@ use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::Shell;
 
 use super::commands::{
-    CalcArgs, ChatArgs, CompressArgs, ConfigArgs, CsvArgs, Dhis2Args, DiffArgs, EguiArgs,
+    BaseArgs, CalcArgs, ChatArgs, CompressArgs, ConfigArgs, CsvArgs, Dhis2Args, DiffArgs, EguiArgs,
     EncodeArgs, EncryptArgs, EnvArgs, ExprArgs, FunArgs, GrepArgs, HashArgs, HttpArgs, JsonArgs,
-    JwtArgs, MarkdownArgs, NetArgs, PolarsArgs, RandArgs, SystemArgs, TemplateArgs, TextArgs,
-    TimeArgs, UiArgs, UuidArgs, WatchArgs, XmlArgs, YamlArgs,
+    JwtArgs, MarkdownArgs, NetArgs, PolarsArgs, RandArgs, RegexArgs, SystemArgs, TemplateArgs,
+    TextArgs, TimeArgs, UiArgs, UuidArgs, WatchArgs, XmlArgs, YamlArgs,
 };
 
 /// dx - Developer Experience CLI
@@ -37,6 +37,10 @@ pub struct Cli {
     #[arg(short, long, global = true, default_value = "text")]
     pub output: OutputFormat,
 
+    /// Seed random generators for reproducible output
+    #[arg(long, global = true, value_name = "SEED")]
+    pub seed: Option<u64>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -123,6 +127,9 @@ pub enum Commands {
     #[command(visible_alias = "c")]
     Calc(CalcArgs),
 
+    /// Number base conversion (binary, octal, hex, any radix up to 36)
+    Base(BaseArgs),
+
     /// Expression evaluator (math, functions, constants)
     #[command(visible_alias = "x")]
     Expr(ExprArgs),
@@ -144,6 +151,10 @@ pub enum Commands {
     #[command(visible_alias = "g")]
     Grep(GrepArgs),
 
+    /// Test regular expressions, extract groups and replace matches
+    #[command(visible_alias = "re")]
+    Regex(RegexArgs),
+
     /// Make HTTP requests
     Http(HttpArgs),
 
@@ -0,0 +1,27 @@
+//! Number base command arguments.
+
+use clap::{Args, Subcommand};
+
+/// Number base conversion
+#[derive(Args, Debug)]
+pub struct BaseArgs {
+    #[command(subcommand)]
+    pub command: BaseCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BaseCommand {
+    /// Convert a number between bases 2-36
+    #[command(visible_alias = "c")]
+    Convert {
+        /// Number to convert (e.g., "ff", "-1010", "0x1f")
+        #[arg(allow_hyphen_values = true)]
+        number: String,
+        /// Source base
+        #[arg(long, default_value = "10", value_parser = clap::value_parser!(u32).range(2..=36))]
+        from: u32,
+        /// Target base (default: show binary, octal, decimal and hex)
+        #[arg(long, value_parser = clap::value_parser!(u32).range(2..=36))]
+        to: Option<u32>,
+    },
+}
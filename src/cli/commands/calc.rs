@@ -31,6 +31,20 @@ pub enum CalcCommand {
         /// Total
         total: f64,
     },
+    /// Convert a value between units of the same dimension
+    #[command(visible_alias = "c")]
+    Convert {
+        /// Value to convert
+        #[arg(allow_hyphen_values = true)]
+        value: f64,
+        /// Source unit (e.g., "km", "lb", "c")
+        from: String,
+        /// Target unit (e.g., "mi", "kg", "f")
+        to: String,
+        /// Show the conversion factor and the calculation
+        #[arg(long)]
+        explain: bool,
+    },
     /// Convert between number bases
     Base {
         /// Number to convert
@@ -5,13 +5,35 @@ use std::path::PathBuf;
 
 /// Compression utilities
 #[derive(Args, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 pub struct CompressArgs {
     #[command(subcommand)]
-    pub command: CompressCommand,
+    pub command: Option<CompressCommand>,
+
+    /// Input file (- for stdin)
+    #[arg(required = true)]
+    pub input: Option<PathBuf>,
+
+    /// Output file (default: derived from the input name; - for stdout)
+    #[arg(value_name = "OUTPUT")]
+    pub out_file: Option<PathBuf>,
+
+    /// Compression algorithm (detected from magic bytes when decompressing)
+    #[arg(short, long, visible_alias = "format")]
+    pub algo: Option<CompressionFormat>,
+
+    /// Compression level (1-9 for gzip, default 6; zstd accepts 1-22 but
+    /// currently falls back to 1 with a warning)
+    #[arg(short, long, conflicts_with = "decompress")]
+    pub level: Option<u32>,
+
+    /// Decompress instead of compress
+    #[arg(short, long)]
+    pub decompress: bool,
 }
 
 /// Compression format
-#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 pub enum CompressionFormat {
     /// Gzip compression
     #[default]
@@ -36,9 +58,10 @@ pub enum CompressCommand {
         #[arg(short, long, default_value = "gzip")]
         format: CompressionFormat,
 
-        /// Compression level (1-9 for gzip, 1-22 for zstd)
-        #[arg(short, long, default_value = "6")]
-        level: u32,
+        /// Compression level (1-9 for gzip, default 6; zstd accepts 1-22 but
+        /// currently falls back to 1 with a warning)
+        #[arg(short, long)]
+        level: Option<u32>,
     },
 
     /// Decompress a file
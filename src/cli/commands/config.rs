@@ -1,10 +1,15 @@
 //! Config command arguments.
 
 use clap::{Args, Subcommand};
+use std::path::PathBuf;
 
 /// Manage application configuration
 #[derive(Args, Debug)]
 pub struct ConfigArgs {
+    /// Profile to read from or write to (e.g. prod)
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+
     #[command(subcommand)]
     pub command: ConfigCommand,
 }
@@ -50,6 +55,12 @@ pub enum ConfigCommand {
         values: bool,
     },
 
+    /// Validate the config file against the known keys and types
+    Check {
+        /// Config file to check (defaults to the active config file)
+        file: Option<PathBuf>,
+    },
+
     /// Show config file path
     Path,
 
@@ -53,7 +53,8 @@ pub enum CsvCommand {
     },
 
     /// Select specific columns from CSV
-    Query {
+    #[command(visible_alias = "query")]
+    Select {
         /// Input file (use - for stdin)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
@@ -66,4 +67,29 @@ pub enum CsvCommand {
         #[arg(short, long, default_value = ",")]
         delimiter: char,
     },
+
+    /// Per-column statistics (count, nulls, min/max/mean for numeric columns)
+    Stats {
+        /// Input file (use - for stdin)
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Delimiter character
+        #[arg(short, long, default_value = ",")]
+        delimiter: char,
+    },
+
+    /// Keep rows matching an expression (e.g., "age > 30 and score >= 50")
+    Filter {
+        /// Predicate evaluated per row; columns are available as variables
+        expression: String,
+
+        /// Input file (use - for stdin)
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Delimiter character
+        #[arg(short, long, default_value = ",")]
+        delimiter: char,
+    },
 }
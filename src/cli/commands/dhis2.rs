@@ -5,27 +5,17 @@ use clap::{Args, Subcommand};
 /// DHIS2 utilities - interact with DHIS2 instances
 #[derive(Args, Debug)]
 pub struct Dhis2Args {
-    /// DHIS2 server URL
-    #[arg(
-        long,
-        env = "DHIS2_SERVER",
-        default_value = "https://play.dhis2.org/demo",
-        global = true
-    )]
-    pub server: String,
-
-    /// DHIS2 username
-    #[arg(long, env = "DHIS2_USER", default_value = "admin", global = true)]
-    pub user: String,
-
-    /// DHIS2 password
-    #[arg(
-        long,
-        env = "DHIS2_PASSWORD",
-        default_value = "district",
-        global = true
-    )]
-    pub password: String,
+    /// DHIS2 server URL [default: config `dhis2.server`, or the demo server]
+    #[arg(long, env = "DHIS2_SERVER", global = true)]
+    pub server: Option<String>,
+
+    /// DHIS2 username [default: config `dhis2.user`, or admin]
+    #[arg(long, env = "DHIS2_USER", global = true)]
+    pub user: Option<String>,
+
+    /// DHIS2 password [default: config `dhis2.password`, or district]
+    #[arg(long, env = "DHIS2_PASSWORD", global = true, hide_env_values = true)]
+    pub password: Option<String>,
 
     #[command(subcommand)]
     pub command: Dhis2Command,
@@ -36,9 +26,13 @@ pub struct Dhis2Args {
 pub enum Dhis2Command {
     /// Generate DHIS2 UIDs (11-character alphanumeric identifiers)
     Uid {
-        /// Number of UIDs to generate
-        #[arg(default_value = "1")]
-        count: usize,
+        /// Number of UIDs to generate [default: 1]
+        #[arg(value_name = "COUNT", conflicts_with = "count")]
+        number: Option<usize>,
+
+        /// Number of UIDs to generate, as an option
+        #[arg(short = 'n', long)]
+        count: Option<usize>,
 
         /// Validate a UID instead of generating
         #[arg(long)]
@@ -54,7 +48,11 @@ pub enum Dhis2Command {
     },
 
     /// Show DHIS2 server information and version
-    Info,
+    Info {
+        /// Output as JSON
+        #[arg(short, long)]
+        json: bool,
+    },
 
     /// List organisation units
     OrgUnits {
@@ -62,7 +60,11 @@ pub enum Dhis2Command {
         #[arg(long)]
         level: Option<i32>,
 
-        /// Maximum number of results
+        /// Page to show, starting at 1
+        #[arg(long, default_value = "1")]
+        page: usize,
+
+        /// Maximum number of results per page
         #[arg(long, default_value = "25")]
         limit: usize,
 
@@ -127,6 +129,30 @@ pub enum Dhis2Command {
         json: bool,
     },
 
+    /// List data element groups
+    #[command(visible_alias = "deg")]
+    DataElementGroups {
+        /// Maximum number of results
+        #[arg(long, default_value = "25")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(short, long)]
+        json: bool,
+    },
+
+    /// List data element group sets
+    #[command(visible_alias = "degs")]
+    DataElementGroupSets {
+        /// Maximum number of results
+        #[arg(long, default_value = "25")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(short, long)]
+        json: bool,
+    },
+
     /// Fetch data values from a data set
     #[command(visible_alias = "dv")]
     DataValues {
@@ -16,9 +16,36 @@ pub struct DiffArgs {
     #[arg(short, long, default_value = "unified")]
     pub format: DiffFormat,
 
-    /// Number of context lines
-    #[arg(short = 'C', long, default_value = "3")]
+    /// Number of context lines around each change
+    #[arg(
+        short = 'U',
+        long = "unified",
+        visible_alias = "context",
+        short_alias = 'C',
+        value_name = "N",
+        default_value = "3"
+    )]
     pub context: usize,
+
+    /// Highlight the changed words within changed lines
+    #[arg(short, long, conflicts_with = "format")]
+    pub word: bool,
+
+    /// When to color the output
+    #[arg(long, value_name = "WHEN", default_value = "auto")]
+    pub color: ColorWhen,
+}
+
+/// When to emit ANSI colors
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum ColorWhen {
+    /// Color when writing to a terminal
+    #[default]
+    Auto,
+    /// Always color, even when piped
+    Always,
+    /// Never color
+    Never,
 }
 
 /// Diff output format
@@ -26,7 +26,7 @@ pub struct EncodeArgs {
     #[arg(long)]
     pub url_safe: bool,
 
-    /// Don't add padding (for base64)
+    /// Don't add padding (for base64 and base32)
     #[arg(long)]
     pub no_padding: bool,
 }
@@ -39,4 +39,8 @@ pub enum EncodingFormat {
     Base64,
     /// Hexadecimal encoding
     Hex,
+    /// Base32 encoding (RFC 4648)
+    Base32,
+    /// Base58 encoding (Bitcoin alphabet)
+    Base58,
 }
@@ -11,7 +11,7 @@ pub struct EncryptArgs {
 }
 
 /// Encryption algorithm
-#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 pub enum EncryptAlgorithm {
     /// ChaCha20-Poly1305 (recommended, fast in software)
     #[default]
@@ -20,6 +20,25 @@ pub enum EncryptAlgorithm {
     AesGcm,
 }
 
+/// Where the password or key comes from.
+///
+/// At most one option may be given. Without any, `DX_ENCRYPT_PASSWORD` is
+/// used, and failing that the password is read from the terminal.
+#[derive(Args, Debug, Clone, Default)]
+pub struct PasswordOptions {
+    /// Password for key derivation (visible in shell history; prefer the others)
+    #[arg(short, long)]
+    pub password: Option<String>,
+
+    /// Read the password from a file (one trailing newline is ignored)
+    #[arg(long, value_name = "FILE", conflicts_with = "password")]
+    pub password_file: Option<PathBuf>,
+
+    /// Use the raw bytes of a file as key material instead of a password
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["password", "password_file"])]
+    pub keyfile: Option<PathBuf>,
+}
+
 /// Encrypt subcommands
 #[derive(Subcommand, Debug)]
 pub enum EncryptCommand {
@@ -37,9 +56,8 @@ pub enum EncryptCommand {
         #[arg(long, name = "out")]
         out_file: Option<PathBuf>,
 
-        /// Password/key for encryption
-        #[arg(short, long)]
-        password: String,
+        #[command(flatten)]
+        secret: PasswordOptions,
 
         /// Algorithm to use
         #[arg(short, long, default_value = "chacha20")]
@@ -60,12 +78,12 @@ pub enum EncryptCommand {
         #[arg(long, name = "out")]
         out_file: Option<PathBuf>,
 
-        /// Password/key for decryption
-        #[arg(short, long)]
-        password: String,
+        #[command(flatten)]
+        secret: PasswordOptions,
 
-        /// Algorithm to use
-        #[arg(short, long, default_value = "chacha20")]
-        algorithm: EncryptAlgorithm,
+        /// Expected algorithm (read from the header; needed only for data
+        /// from older dx versions, which default to chacha20)
+        #[arg(short, long)]
+        algorithm: Option<EncryptAlgorithm>,
     },
 }
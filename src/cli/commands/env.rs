@@ -1,6 +1,7 @@
 //! Environment command arguments.
 
 use clap::{Args, Subcommand};
+use std::path::PathBuf;
 
 /// Manage environment variables
 #[derive(Args, Debug)]
@@ -57,15 +58,34 @@ pub enum EnvCommand {
         #[arg(short, long)]
         prefix: Option<String>,
     },
+
+    /// Load a dotenv file: print export lines, or run a command with it
+    Load {
+        /// Dotenv file to load
+        file: PathBuf,
+
+        /// Command to run with the merged environment (after --)
+        #[arg(last = true)]
+        command: Vec<String>,
+    },
+
+    /// Compare two dotenv files
+    Diff {
+        /// Original file
+        old: PathBuf,
+
+        /// Changed file
+        new: PathBuf,
+    },
 }
 
 /// Export format
 #[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
 pub enum ExportFormat {
-    /// Shell export format (export KEY=VALUE)
+    /// Shell export format (export KEY='VALUE')
     #[default]
     Shell,
-    /// Docker format (KEY=VALUE per line)
+    /// Docker run flags (--env KEY=VALUE per line)
     Docker,
     /// JSON object
     Json,
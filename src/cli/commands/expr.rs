@@ -17,6 +17,10 @@ pub enum ExprCommand {
         /// Math expression (e.g., "2+2", "sqrt(16)", "2^10", "sin(pi/2)")
         /// If omitted, reads multi-line input from stdin (end with Ctrl+D)
         expression: Option<String>,
+
+        /// Print every sub-expression and its value to stderr
+        #[arg(long)]
+        trace: bool,
     },
 
     /// Run a program from file (supports variables and multi-line)
@@ -26,6 +30,12 @@ pub enum ExprCommand {
         file: String,
     },
 
+    /// Evaluate each non-comment line of a file, printing `input => result`
+    EvalFile {
+        /// File path (use "-" for stdin)
+        file: String,
+    },
+
     /// Parse expression and show AST as JSON
     Ast {
         /// Math expression to parse
@@ -39,4 +49,7 @@ pub enum ExprCommand {
     /// Show available functions and constants
     #[command(visible_alias = "l")]
     List,
+
+    /// Start an interactive session (:vars, :funcs, :help, :quit)
+    Repl,
 }
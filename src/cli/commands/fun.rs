@@ -1,10 +1,14 @@
 //! Fun terminal UI command arguments.
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 
 /// Fun terminal UI effects
 #[derive(Args, Debug)]
 pub struct FunArgs {
+    /// Draw bars and spinners with ASCII only (also enabled by DX_ASCII=1)
+    #[arg(long, global = true)]
+    pub ascii: bool,
+
     #[command(subcommand)]
     pub command: FunCommand,
 }
@@ -128,10 +132,44 @@ pub enum FunCommand {
         /// Column density (1-10)
         #[arg(long, default_value = "5")]
         density: u8,
+        /// Frames per second (1-120)
+        #[arg(long, default_value = "20", value_parser = clap::value_parser!(u32).range(1..=120))]
+        fps: u32,
+        /// Rain color
+        #[arg(short, long, default_value = "green")]
+        color: MatrixColor,
     },
     /// Big ASCII text banner (figlet-style)
     Banner {
         /// Text to display
         text: String,
+        /// Font to render with
+        #[arg(short, long, default_value = "block")]
+        font: BannerFont,
     },
 }
+
+/// Matrix rain color
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum MatrixColor {
+    #[default]
+    Green,
+    Red,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+/// Banner font
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum BannerFont {
+    /// Solid blocks, 5 rows
+    #[default]
+    Block,
+    /// `#` characters, 5 rows (for terminals without Unicode)
+    Ascii,
+    /// Half blocks, 3 rows
+    Compact,
+}
@@ -27,15 +27,18 @@ pub struct GrepArgs {
     pub recursive: bool,
 
     /// Only show filenames with matches
-    #[arg(short = 'l', long)]
+    #[arg(short = 'l', long = "files-with-matches", visible_alias = "files-only")]
     pub files_only: bool,
 
-    /// Show count of matches per file
+    /// Show count of matching lines per file
     #[arg(short, long)]
     pub count: bool,
 
-    /// Invert match (show non-matching lines)
-    #[arg(long)]
+    /// Invert match (select non-matching lines)
+    ///
+    /// `-v` is taken by the global `--verbose` flag, so only the long form
+    /// is available.
+    #[arg(long, visible_alias = "invert-match")]
     pub invert: bool,
 
     /// Show N lines before match
@@ -50,6 +53,10 @@ pub struct GrepArgs {
     #[arg(short = 'C', long, value_name = "N")]
     pub context: Option<usize>,
 
+    /// Match across line boundaries (`.` also matches newlines)
+    #[arg(short = 'U', long)]
+    pub multiline: bool,
+
     /// Include hidden files
     #[arg(long)]
     pub hidden: bool,
@@ -22,10 +22,18 @@ pub struct HashArgs {
     #[arg(short = 'q', long)]
     pub quiet: bool,
 
-    /// Verify hash against expected value
-    #[arg(long, value_name = "HASH")]
+    /// Verify hash against expected value (exits 1 on mismatch)
+    #[arg(long, visible_alias = "check", value_name = "HASH")]
     pub verify: Option<String>,
 
+    /// Compute an HMAC with the selected digest (requires --key)
+    #[arg(long, requires = "key")]
+    pub hmac: bool,
+
+    /// Secret key for --hmac
+    #[arg(long, value_name = "KEY", requires = "hmac")]
+    pub key: Option<String>,
+
     /// Cost factor for bcrypt (4-31) or Argon2 iterations
     #[arg(long, default_value = "12")]
     pub cost: u32,
@@ -41,18 +49,33 @@ pub enum Algorithm {
     Sha256,
     /// SHA-512 (512-bit, more secure)
     Sha512,
+    /// BLAKE3 (256-bit, very fast and secure)
+    Blake3,
     /// Bcrypt password hash (includes salt, configurable cost)
     Bcrypt,
     /// Argon2id password hash (includes salt, memory-hard)
     Argon2,
 }
 
+impl Algorithm {
+    /// Whether this is a plain digest (as opposed to a salted password hash).
+    ///
+    /// Digests can be computed incrementally over a stream of input.
+    pub fn is_digest(self) -> bool {
+        matches!(
+            self,
+            Algorithm::Md5 | Algorithm::Sha256 | Algorithm::Sha512 | Algorithm::Blake3
+        )
+    }
+}
+
 impl std::fmt::Display for Algorithm {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Algorithm::Md5 => write!(f, "MD5"),
             Algorithm::Sha256 => write!(f, "SHA256"),
             Algorithm::Sha512 => write!(f, "SHA512"),
+            Algorithm::Blake3 => write!(f, "BLAKE3"),
             Algorithm::Bcrypt => write!(f, "BCRYPT"),
             Algorithm::Argon2 => write!(f, "ARGON2"),
         }
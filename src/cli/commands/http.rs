@@ -1,6 +1,7 @@
 //! HTTP command arguments.
 
 use clap::{Args, Subcommand, ValueEnum};
+use std::path::PathBuf;
 
 /// Make HTTP requests
 #[derive(Args, Debug)]
@@ -24,13 +25,16 @@ pub enum HttpCommand {
         #[arg(short, long, default_value = "body")]
         format: OutputFormat,
 
-        /// Follow redirects
-        #[arg(short = 'L', long)]
-        follow: bool,
+        /// Stream the response body to a file, showing download progress
+        #[arg(short = 'O', long = "out", value_name = "FILE")]
+        out_file: Option<PathBuf>,
 
-        /// Request timeout in seconds
-        #[arg(short, long, default_value = "30")]
-        timeout: u64,
+        /// Continue a partial download of --out with an HTTP Range request
+        #[arg(long, requires = "out_file")]
+        resume: bool,
+
+        #[command(flatten)]
+        options: RequestOptions,
     },
 
     /// Send POST request
@@ -38,33 +42,19 @@ pub enum HttpCommand {
         /// URL to request
         url: String,
 
-        /// Request body (JSON or raw text)
-        #[arg(short, long)]
-        data: Option<String>,
-
-        /// Read body from file
-        #[arg(short = 'F', long, conflicts_with = "data")]
-        file: Option<String>,
+        #[command(flatten)]
+        body: BodyOptions,
 
         /// Request headers (can be repeated)
         #[arg(short = 'H', long = "header", value_name = "KEY:VALUE")]
         headers: Vec<String>,
 
-        /// Content type
-        #[arg(long, default_value = "application/json")]
-        content_type: String,
-
         /// Output format
         #[arg(short, long, default_value = "body")]
         format: OutputFormat,
 
-        /// Follow redirects
-        #[arg(short = 'L', long)]
-        follow: bool,
-
-        /// Request timeout in seconds
-        #[arg(short, long, default_value = "30")]
-        timeout: u64,
+        #[command(flatten)]
+        options: RequestOptions,
     },
 
     /// Send PUT request
@@ -72,25 +62,19 @@ pub enum HttpCommand {
         /// URL to request
         url: String,
 
-        /// Request body
-        #[arg(short, long)]
-        data: Option<String>,
+        #[command(flatten)]
+        body: BodyOptions,
 
         /// Request headers
         #[arg(short = 'H', long = "header", value_name = "KEY:VALUE")]
         headers: Vec<String>,
 
-        /// Content type
-        #[arg(long, default_value = "application/json")]
-        content_type: String,
-
         /// Output format
         #[arg(short, long, default_value = "body")]
         format: OutputFormat,
 
-        /// Request timeout in seconds
-        #[arg(short, long, default_value = "30")]
-        timeout: u64,
+        #[command(flatten)]
+        options: RequestOptions,
     },
 
     /// Send DELETE request
@@ -106,9 +90,8 @@ pub enum HttpCommand {
         #[arg(short, long, default_value = "body")]
         format: OutputFormat,
 
-        /// Request timeout in seconds
-        #[arg(short, long, default_value = "30")]
-        timeout: u64,
+        #[command(flatten)]
+        options: RequestOptions,
     },
 
     /// Send HEAD request (headers only)
@@ -120,12 +103,55 @@ pub enum HttpCommand {
         #[arg(short = 'H', long = "header", value_name = "KEY:VALUE")]
         headers: Vec<String>,
 
-        /// Request timeout in seconds
-        #[arg(short, long, default_value = "30")]
-        timeout: u64,
+        #[command(flatten)]
+        options: RequestOptions,
     },
 }
 
+/// Request body options for POST and PUT
+#[derive(Args, Debug, Clone)]
+pub struct BodyOptions {
+    /// Request body (JSON or raw text, `@-` reads stdin, `@FILE` reads a file)
+    #[arg(short, long)]
+    pub data: Option<String>,
+
+    /// Read body from file
+    #[arg(short = 'F', long, conflicts_with = "data")]
+    pub file: Option<String>,
+
+    /// JSON body; validated and sent as application/json (`@-` reads stdin)
+    #[arg(long, value_name = "JSON", conflicts_with_all = ["data", "file"])]
+    pub json: Option<String>,
+
+    /// Form field sent as application/x-www-form-urlencoded (can be repeated)
+    #[arg(long, value_name = "KEY=VALUE", conflicts_with_all = ["data", "file", "json"])]
+    pub form: Vec<String>,
+
+    /// Content type (overridden by --json and --form)
+    #[arg(long, default_value = "application/json")]
+    pub content_type: String,
+}
+
+/// Connection options shared by all request subcommands
+#[derive(Args, Debug, Clone)]
+pub struct RequestOptions {
+    /// Request timeout in seconds
+    #[arg(short, long, default_value = "30")]
+    pub timeout: u64,
+
+    /// Retry idempotent requests on 5xx responses and connection errors
+    #[arg(long, default_value = "0", value_name = "N")]
+    pub retries: u32,
+
+    /// Maximum number of redirects to follow (0 disables redirects)
+    #[arg(long, default_value = "10", value_name = "N")]
+    pub max_redirects: u32,
+
+    /// Deprecated no-op: redirects are followed by default, see --max-redirects
+    #[arg(short = 'L', long, hide = true)]
+    pub follow: bool,
+}
+
 /// Output format for HTTP responses
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
 pub enum OutputFormat {
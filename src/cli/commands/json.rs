@@ -55,14 +55,30 @@ pub enum JsonCommand {
         input: Option<PathBuf>,
     },
 
-    /// Query JSON with a path expression
+    /// Query JSON with a jq-like filter
     Query {
+        /// Filter expression (e.g., ".users\[0\].name", ".items\[\] | .id");
+        /// with --path this is the input file instead
+        #[arg(value_name = "FILTER", required_unless_present = "path")]
+        filter: Option<String>,
+
         /// Input file (use - for stdin)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
 
-        /// JSON path query (e.g., ".foo.bar\[0\]")
-        #[arg(short, long)]
-        path: String,
+        /// Filter expression given as an option, followed by an optional FILE
+        #[arg(short, long, value_name = "FILTER", conflicts_with = "input")]
+        path: Option<String>,
+    },
+
+    /// Convert an array of objects to CSV
+    ToCsv {
+        /// Input file (use - for stdin)
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Expand nested objects into dotted columns (e.g., addr.city)
+        #[arg(long)]
+        flatten: bool,
     },
 }
@@ -10,7 +10,7 @@ pub struct JwtArgs {
 }
 
 /// JWT algorithm
-#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 pub enum JwtAlgorithm {
     /// HMAC SHA-256
     #[default]
@@ -32,17 +32,34 @@ pub enum JwtCommand {
         /// Output format
         #[arg(short, long, default_value = "pretty")]
         format: DecodeFormat,
+
+        /// Verify the signature and expiry before printing (requires --secret)
+        #[arg(long, requires = "secret")]
+        verify: bool,
+
+        /// Secret key for --verify
+        #[arg(short, long)]
+        secret: Option<String>,
+
+        /// Algorithm expected by --verify
+        #[arg(short, long, default_value = "hs256")]
+        algorithm: JwtAlgorithm,
     },
 
-    /// Create a JWT token
-    Encode {
+    /// Create a signed JWT token
+    #[command(visible_alias = "encode")]
+    Sign {
         /// Secret key for signing
         #[arg(short, long)]
         secret: String,
 
-        /// JSON payload (claims)
+        /// JSON object of base claims
         #[arg(short, long)]
-        payload: String,
+        payload: Option<String>,
+
+        /// Extra claim as KEY=VALUE (repeatable; JSON values like 3 or true keep their type)
+        #[arg(short, long = "claim", value_name = "KEY=VALUE")]
+        claims: Vec<String>,
 
         /// Algorithm to use
         #[arg(short, long, default_value = "hs256")]
@@ -13,11 +13,15 @@ pub struct MarkdownArgs {
 /// Markdown subcommands
 #[derive(Subcommand, Debug)]
 pub enum MarkdownCommand {
-    /// Render markdown to HTML
+    /// Render markdown as styled terminal text
     Render {
         /// Input file (use - for stdin)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
+
+        /// Render to HTML instead
+        #[arg(long)]
+        html: bool,
     },
 
     /// Extract table of contents
@@ -30,4 +34,11 @@ pub enum MarkdownCommand {
         #[arg(short, long, default_value = "3")]
         depth: u8,
     },
+
+    /// List link and image targets
+    Links {
+        /// Input file (use - for stdin)
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+    },
 }
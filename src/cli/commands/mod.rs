@@ -1,5 +1,6 @@
 //! Subcommand argument definitions.
 
+pub mod base;
 pub mod calc;
 pub mod chat;
 pub mod compress;
@@ -22,6 +23,7 @@ pub mod markdown;
 pub mod net;
 pub mod polars;
 pub mod rand;
+pub mod regex;
 pub mod system;
 pub mod template;
 pub mod text;
@@ -32,6 +34,7 @@ pub mod watch;
 pub mod xml;
 pub mod yaml;
 
+pub use base::{BaseArgs, BaseCommand};
 pub use calc::{CalcArgs, CalcCommand};
 pub use chat::{ChatArgs, ChatCommand};
 pub use compress::{CompressArgs, CompressCommand};
@@ -54,6 +57,7 @@ pub use markdown::{MarkdownArgs, MarkdownCommand};
 pub use net::{NetArgs, NetCommand};
 pub use polars::{PolarsArgs, PolarsCommand};
 pub use rand::{RandArgs, RandCommand};
+pub use regex::{RegexArgs, RegexCommand};
 pub use system::{SystemArgs, SystemCommand};
 pub use template::{TemplateArgs, TemplateCommand};
 pub use text::{TextArgs, TextCommand};
@@ -1,6 +1,6 @@
 //! Network utilities command arguments.
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
 
 /// Network utilities
 #[derive(Args, Debug)]
@@ -35,4 +35,38 @@ pub enum NetCommand {
         /// Domain name to lookup
         domain: String,
     },
+    /// Subnet calculator (CIDR breakdown)
+    Subnet {
+        /// Network in CIDR notation (e.g., 192.168.1.0/26, 2001:db8::/48)
+        cidr: String,
+    },
+    /// DNS query for a specific record type
+    Dns {
+        /// Domain name to query
+        domain: String,
+        /// Record type
+        #[arg(short = 't', long = "type", default_value = "a", ignore_case = true)]
+        record_type: DnsRecordType,
+        /// DNS server (default: first nameserver in /etc/resolv.conf, else 1.1.1.1)
+        #[arg(short, long)]
+        server: Option<String>,
+        /// Timeout in seconds
+        #[arg(long, default_value = "5", value_parser = clap::value_parser!(u64).range(1..))]
+        timeout: u64,
+    },
+}
+
+/// DNS record types supported by `net dns`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DnsRecordType {
+    /// IPv4 address
+    A,
+    /// IPv6 address
+    Aaaa,
+    /// Mail exchanger
+    Mx,
+    /// Text record
+    Txt,
+    /// Canonical name (alias)
+    Cname,
 }
@@ -131,10 +131,6 @@ pub enum PolarsCommand {
         #[arg(long, default_value = "0.0")]
         null_prob: f64,
 
-        /// Random seed for reproducibility
-        #[arg(long)]
-        seed: Option<u64>,
-
         /// Output format (table, json, jsonl)
         #[arg(short, long, default_value = "table")]
         format: PolarsOutputFormat,
@@ -1,6 +1,22 @@
 //! Random generation command arguments.
 
-use clap::{Args, Subcommand};
+use clap::builder::PossibleValuesParser;
+use clap::{Args, Subcommand, ValueEnum};
+use dx_datagen::categories;
+
+/// Output format for generated records.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum RecordFormat {
+    /// One JSON object per line (default)
+    #[default]
+    Ndjson,
+    /// JSON array of objects
+    Json,
+    /// CSV with a header row
+    Csv,
+    /// Aligned columns
+    Table,
+}
 
 /// Random data generation
 #[derive(Args, Debug)]
@@ -92,4 +108,25 @@ pub enum RandCommand {
         #[arg(short, long, default_value = "1")]
         count: usize,
     },
+    /// Pick random values from a predefined category
+    Category {
+        /// Category name, such as fruits or currencies
+        #[arg(short, long, value_parser = PossibleValuesParser::new(categories::names()))]
+        name: String,
+        /// Number of values to pick
+        #[arg(short, long, default_value = "1")]
+        count: usize,
+    },
+    /// Generate random records from a shorthand schema
+    Record {
+        /// Schema such as '{name:string, age:int(18,90)}'
+        #[arg(short, long)]
+        schema: String,
+        /// Number of records to generate
+        #[arg(short, long, default_value = "1")]
+        count: usize,
+        /// Output format
+        #[arg(short, long, value_enum, default_value = "ndjson")]
+        format: RecordFormat,
+    },
 }
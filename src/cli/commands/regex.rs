@@ -0,0 +1,56 @@
+//! Regex command arguments.
+
+use clap::{Args, Subcommand};
+
+/// Regular expression testing and replacement
+#[derive(Args, Debug)]
+pub struct RegexArgs {
+    #[command(subcommand)]
+    pub command: RegexCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RegexCommand {
+    /// Print every match, one per line (exit 1 if nothing matches)
+    #[command(visible_alias = "m")]
+    Match {
+        /// Regular expression
+        #[arg(allow_hyphen_values = true)]
+        pattern: String,
+        /// Text to search (or use stdin)
+        input: Option<String>,
+        /// Case-insensitive matching
+        #[arg(short, long)]
+        ignore_case: bool,
+    },
+    /// Print the numbered and named groups of every match
+    #[command(visible_alias = "c")]
+    Captures {
+        /// Regular expression
+        #[arg(allow_hyphen_values = true)]
+        pattern: String,
+        /// Text to search (or use stdin)
+        input: Option<String>,
+        /// Case-insensitive matching
+        #[arg(short, long)]
+        ignore_case: bool,
+    },
+    /// Replace matches; `$1` and `${name}` refer to groups
+    #[command(visible_alias = "r")]
+    Replace {
+        /// Regular expression
+        #[arg(allow_hyphen_values = true)]
+        pattern: String,
+        /// Replacement text (use `$$` for a literal `$`)
+        #[arg(allow_hyphen_values = true)]
+        replacement: String,
+        /// Text to transform (or use stdin)
+        input: Option<String>,
+        /// Case-insensitive matching
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// Replace only the first match
+        #[arg(long)]
+        first: bool,
+    },
+}
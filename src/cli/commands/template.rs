@@ -16,15 +16,27 @@ pub enum TemplateCommand {
     /// Render a template with data
     Render {
         /// Template file
-        template: PathBuf,
+        #[arg(
+            required_unless_present = "template_file",
+            conflicts_with = "template_file"
+        )]
+        template: Option<PathBuf>,
+
+        /// Template file (same as the positional argument)
+        #[arg(short, long = "template", value_name = "FILE")]
+        template_file: Option<PathBuf>,
 
-        /// JSON data file (or use --json for inline)
+        /// Data file: JSON, or YAML for .yaml/.yml (or use --json for inline)
         #[arg(short, long)]
         data: Option<PathBuf>,
 
         /// Inline JSON data
-        #[arg(long)]
+        #[arg(long, conflicts_with = "data")]
         json: Option<String>,
+
+        /// Render variables missing from the data as empty instead of failing
+        #[arg(long)]
+        allow_missing: bool,
     },
 
     /// Validate template syntax
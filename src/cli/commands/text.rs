@@ -1,6 +1,21 @@
 //! Text transformation command arguments.
 
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum};
+
+/// Target style for `text case`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CaseStyle {
+    /// snake_case
+    Snake,
+    /// camelCase
+    Camel,
+    /// kebab-case
+    Kebab,
+    /// PascalCase
+    Pascal,
+    /// Title Case
+    Title,
+}
 
 /// Text transformation utilities
 #[derive(Args, Debug)]
@@ -51,6 +66,14 @@ pub enum TextCommand {
         /// Text to transform (or use stdin)
         text: Option<String>,
     },
+    /// Convert between naming styles, splitting mixed-style input into words
+    Case {
+        /// Target case
+        #[arg(long, value_enum)]
+        to: CaseStyle,
+        /// Text to transform (or use stdin)
+        text: Option<String>,
+    },
     /// Convert to url-safe-slug
     Slug {
         /// Text to transform (or use stdin)
@@ -61,10 +84,15 @@ pub enum TextCommand {
         /// Text to reverse (or use stdin)
         text: Option<String>,
     },
-    /// Count characters, words, lines
+    /// Count lines, words, characters, and bytes
     Count {
-        /// Text to count (or use stdin)
-        text: Option<String>,
+        /// File to count, or text to count if no such file exists
+        /// (or use stdin, "-" for stdin)
+        #[arg(value_name = "FILE|TEXT", conflicts_with = "string")]
+        input: Option<String>,
+        /// Count a string instead of a file
+        #[arg(short, long)]
+        string: Option<String>,
     },
     /// Generate lorem ipsum text
     Lorem {
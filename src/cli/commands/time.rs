@@ -25,7 +25,7 @@ pub enum TimeCommand {
 
     /// Parse a timestamp and show in different formats
     Parse {
-        /// Timestamp to parse
+        /// Timestamp to parse (or a relative phrase like "2 hours ago")
         timestamp: String,
 
         /// Input format hint
@@ -51,6 +51,21 @@ pub enum TimeCommand {
         /// End timestamp (defaults to now)
         end: Option<String>,
     },
+
+    /// Add a duration to a timestamp
+    Add {
+        /// Base timestamp (defaults to now)
+        #[arg(short, long)]
+        base: Option<String>,
+
+        /// Duration to add, e.g. "3d12h", "90m", "-2h"
+        #[arg(short, long, allow_hyphen_values = true)]
+        delta: String,
+
+        /// Output format
+        #[arg(short, long, default_value = "iso")]
+        format: TimeFormat,
+    },
 }
 
 /// Time output formats
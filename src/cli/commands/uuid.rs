@@ -1,10 +1,14 @@
 //! UUID command arguments.
 
-use clap::{Args, ValueEnum};
+use clap::{Args, Subcommand, ValueEnum};
 
-/// Generate UUIDs
+/// Generate UUIDs (v4, v7, v5) and inspect existing ones
 #[derive(Args, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct UuidArgs {
+    #[command(subcommand)]
+    pub command: Option<UuidCommand>,
+
     /// UUID version to generate (v4 = random, v7 = time-ordered)
     #[arg(
         short = 'T',
@@ -27,6 +31,42 @@ pub struct UuidArgs {
     pub uppercase: bool,
 }
 
+/// UUID subcommands
+#[derive(Subcommand, Debug)]
+pub enum UuidCommand {
+    /// Show the version, variant, and embedded timestamp of a UUID
+    Inspect {
+        /// UUID in standard, simple, URN, or braced form
+        uuid: String,
+    },
+
+    /// Generate a name-based (SHA-1) version 5 UUID
+    V5 {
+        /// Namespace: dns, url, oid, x500, or a namespace UUID
+        #[arg(short, long)]
+        namespace: String,
+
+        /// Name to hash within the namespace
+        #[arg(long)]
+        name: String,
+
+        /// Output format
+        #[arg(short, long, default_value = "standard")]
+        format: UuidFormat,
+
+        /// Uppercase output
+        #[arg(short = 'U', long)]
+        uppercase: bool,
+    },
+
+    /// Check that UUIDs are well formed (exits 1 if any are not)
+    Validate {
+        /// UUIDs to check
+        #[arg(required = true)]
+        uuids: Vec<String>,
+    },
+}
+
 /// UUID version
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
 pub enum UuidVersion {
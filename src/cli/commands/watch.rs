@@ -14,7 +14,7 @@ pub struct WatchArgs {
     #[arg(last = true, required = true)]
     pub command: Vec<String>,
 
-    /// Debounce delay in milliseconds
+    /// Coalesce events arriving within this many milliseconds into one run
     #[arg(short, long, default_value = "500")]
     pub debounce: u64,
 
@@ -26,13 +26,13 @@ pub struct WatchArgs {
     #[arg(short, long)]
     pub initial: bool,
 
-    /// File patterns to include (e.g., "*.rs")
+    /// Only trigger on files matching this glob (repeatable, e.g. "*.rs", "*.{rs,toml}")
     #[arg(long, value_name = "GLOB")]
-    pub include: Option<String>,
+    pub include: Vec<String>,
 
-    /// File patterns to exclude
+    /// Never trigger on files matching this glob (repeatable; wins over --include)
     #[arg(long, value_name = "GLOB")]
-    pub exclude: Option<String>,
+    pub exclude: Vec<String>,
 
     /// Watch recursively (default: true)
     #[arg(long, default_value = "true")]
@@ -3,7 +3,7 @@
 use clap::{Args, Subcommand};
 use std::path::PathBuf;
 
-/// XML utilities (format, validate, convert)
+/// XML utilities (format, validate, query, convert)
 #[derive(Args, Debug)]
 pub struct XmlArgs {
     #[command(subcommand)]
@@ -35,6 +35,16 @@ pub enum XmlCommand {
         quiet: bool,
     },
 
+    /// Select elements, attributes, or text with a path like /root/item/@id
+    Query {
+        /// Path: /a/b, //b, *, [n], [@attr], [@attr='v'], ending in @attr or text()
+        path: String,
+
+        /// Input file (use - for stdin)
+        #[arg(value_name = "FILE")]
+        input: Option<PathBuf>,
+    },
+
     /// Convert XML to JSON
     #[command(name = "to-json")]
     ToJson {
@@ -31,7 +31,7 @@ pub enum YamlCommand {
         quiet: bool,
     },
 
-    /// Convert YAML to JSON
+    /// Convert YAML to JSON (multi-document streams become an array)
     #[command(name = "to-json")]
     ToJson {
         /// Input file (use - for stdin)
@@ -49,5 +49,9 @@ pub enum YamlCommand {
         /// Input file (use - for stdin)
         #[arg(value_name = "FILE")]
         input: Option<PathBuf>,
+
+        /// Write each element of a top-level array as its own document
+        #[arg(short, long)]
+        documents: bool,
     },
 }
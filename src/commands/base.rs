@@ -0,0 +1,165 @@
+//! # Number Base Command
+//!
+//! Convert integers between bases 2 through 36.
+//!
+//! ## Examples
+//! ```bash
+//! dx base convert 255 --to 16          # ff
+//! dx base convert FF --from 16 --to 2  # 11111111
+//! dx base convert -z --from 36         # bin, oct, dec and hex of -35
+//! ```
+//!
+//! Digits above 9 are letters in either case. A `0b`, `0o` or `0x` prefix
+//! matching the source base is accepted, and a leading `-` makes the number
+//! negative.
+
+use crate::cli::commands::base::{BaseArgs, BaseCommand};
+use anyhow::{bail, Result};
+use colored::Colorize;
+
+pub fn run(args: BaseArgs) -> Result<()> {
+    match args.command {
+        BaseCommand::Convert { number, from, to } => cmd_convert(&number, from, to),
+    }
+}
+
+/// Bases shown when no target base is given.
+const COMMON_BASES: &[(&str, u32)] = &[("bin", 2), ("oct", 8), ("dec", 10), ("hex", 16)];
+
+/// Convert a number, printing one base or all common ones
+fn cmd_convert(number: &str, from: u32, to: Option<u32>) -> Result<()> {
+    let value = parse_radix(number, from)?;
+    match to {
+        Some(to) => println!("{}", format_radix(value, to)),
+        None => {
+            for (name, radix) in COMMON_BASES {
+                println!("{}  {}", name.cyan(), format_radix(value, *radix));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parse a signed integer written in `radix` (2-36).
+///
+/// # Errors
+/// Empty input, digits outside the radix, and values beyond the `i128`
+/// range are errors.
+pub(crate) fn parse_radix(number: &str, radix: u32) -> Result<i128> {
+    if !(2..=36).contains(&radix) {
+        bail!("Base must be between 2 and 36");
+    }
+
+    let trimmed = number.trim();
+    let (negative, digits) = match trimmed.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, trimmed.strip_prefix('+').unwrap_or(trimmed)),
+    };
+    let prefix = match radix {
+        2 => Some("0b"),
+        8 => Some("0o"),
+        16 => Some("0x"),
+        _ => None,
+    };
+    let digits = prefix
+        .and_then(|p| {
+            digits
+                .strip_prefix(p)
+                .or_else(|| digits.strip_prefix(&p.to_uppercase()))
+        })
+        .unwrap_or(digits);
+    if digits.is_empty() {
+        bail!("Invalid number '{}': no digits", number);
+    }
+
+    // Accumulate negatively so i128::MIN still fits
+    let mut value: i128 = 0;
+    for c in digits.chars() {
+        let Some(digit) = c.to_digit(radix) else {
+            bail!("Invalid digit '{}' for base {} in '{}'", c, radix, number);
+        };
+        value = value
+            .checked_mul(radix as i128)
+            .and_then(|v| v.checked_sub(digit as i128))
+            .ok_or_else(|| anyhow::anyhow!("Number '{}' is too large", number))?;
+    }
+
+    if negative {
+        Ok(value)
+    } else {
+        value
+            .checked_neg()
+            .ok_or_else(|| anyhow::anyhow!("Number '{}' is too large", number))
+    }
+}
+
+/// Format a signed integer in `radix` (2-36) with lowercase digits.
+pub(crate) fn format_radix(n: i128, radix: u32) -> String {
+    if n == 0 {
+        return "0".to_string();
+    }
+
+    let mut magnitude = n.unsigned_abs();
+    let mut digits = Vec::new();
+    while magnitude > 0 {
+        let digit = (magnitude % radix as u128) as u32;
+        digits.push(char::from_digit(digit, radix).unwrap_or('?'));
+        magnitude /= radix as u128;
+    }
+    if n < 0 {
+        digits.push('-');
+    }
+    digits.into_iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dec_to_hex_to_bin() {
+        let value = parse_radix("255", 10).unwrap();
+        let hex = format_radix(value, 16);
+        assert_eq!(hex, "ff");
+        let bin = format_radix(parse_radix(&hex, 16).unwrap(), 2);
+        assert_eq!(bin, "11111111");
+        assert_eq!(parse_radix(&bin, 2).unwrap(), 255);
+    }
+
+    #[test]
+    fn test_format_radix() {
+        assert_eq!(format_radix(255, 16), "ff");
+        assert_eq!(format_radix(10, 2), "1010");
+        assert_eq!(format_radix(0, 7), "0");
+        assert_eq!(format_radix(35, 36), "z");
+        assert_eq!(
+            format_radix(i128::MIN, 16),
+            "-80000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn test_negative_numbers_and_prefixes() {
+        assert_eq!(parse_radix("-FF", 16).unwrap(), -255);
+        assert_eq!(format_radix(-255, 2), "-11111111");
+        assert_eq!(parse_radix("0x1F", 16).unwrap(), 31);
+        assert_eq!(parse_radix("-0b101", 2).unwrap(), -5);
+        assert_eq!(parse_radix("+0o17", 8).unwrap(), 15);
+        assert_eq!(
+            parse_radix("-80000000000000000000000000000000", 16).unwrap(),
+            i128::MIN
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let err = parse_radix("G", 16).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid digit 'G' for base 16 in 'G'");
+        assert!(parse_radix("102", 2).is_err());
+        assert!(parse_radix("-", 10).is_err());
+        assert!(parse_radix("0x", 16).is_err());
+        assert!(parse_radix("1", 37).is_err());
+        let err = parse_radix("80000000000000000000000000000000", 16).unwrap_err();
+        assert!(err.to_string().contains("too large"));
+    }
+}
@@ -8,9 +8,22 @@
 //! dx calc time 3665s             # 1h 1m 5s
 //! dx calc percent 15 of 200      # 7.5%
 //! dx calc base 255 10 16         # ff
+//! dx calc convert 10 km mi       # 6.213712 mi
+//! dx calc convert 100 c f        # 212 f
+//! dx calc convert 100 km/h m/s   # 27.777778 m/s
+//! dx calc convert 5 km mi --explain  # 5 km × 0.621371 = 3.106856 mi
 //! ```
+//!
+//! ## Dimensions
+//! Every unit has dimensions expressed as exponents of five base dimensions
+//! (length, mass, time, temperature, data), and conversions only succeed when
+//! the dimensions match, so `km -> kg` is an error rather than a meaningless
+//! number. Compound units like `km/h` or `m*m` combine their parts' factors
+//! and exponents. Temperature also needs an offset, since 0 °C is not 0 K,
+//! so temperature units can't be part of a compound.
 
 use crate::cli::commands::calc::{CalcArgs, CalcCommand};
+use crate::commands::base::{format_radix, parse_radix};
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
 
@@ -19,6 +32,12 @@ pub fn run(args: CalcArgs) -> Result<()> {
         CalcCommand::Bytes { value } => cmd_bytes(&value),
         CalcCommand::Time { value } => cmd_time(&value),
         CalcCommand::Percent { value, total } => cmd_percent(value, total),
+        CalcCommand::Convert {
+            value,
+            from,
+            to,
+            explain,
+        } => cmd_convert(value, &from, &to, explain),
         CalcCommand::Base { number, from, to } => cmd_base(&number, from, to),
     }
 }
@@ -169,55 +188,373 @@ fn cmd_percent(value: f64, total: f64) -> Result<()> {
     Ok(())
 }
 
-/// Convert between number bases
-fn cmd_base(number: &str, from: u32, to: u32) -> Result<()> {
-    if !(2..=36).contains(&from) || !(2..=36).contains(&to) {
-        bail!("Base must be between 2 and 36");
+/// Number of base dimensions tracked in [`Dims`].
+const BASE_DIMENSIONS: usize = 5;
+
+/// Names of the base dimensions, in [`Dims`] index order.
+const DIMENSION_NAMES: [&str; BASE_DIMENSIONS] = ["length", "mass", "time", "temperature", "data"];
+
+/// Exponents of each base dimension, e.g. speed is length^1 * time^-1.
+type Dims = [i32; BASE_DIMENSIONS];
+
+const LENGTH: Dims = [1, 0, 0, 0, 0];
+const MASS: Dims = [0, 1, 0, 0, 0];
+const TIME: Dims = [0, 0, 1, 0, 0];
+const TEMPERATURE: Dims = [0, 0, 0, 1, 0];
+const DATA: Dims = [0, 0, 0, 0, 1];
+const SPEED: Dims = [1, 0, -1, 0, 0];
+const VOLUME: Dims = [3, 0, 0, 0, 0];
+const AREA: Dims = [2, 0, 0, 0, 0];
+
+/// Describe dimensions for error messages (e.g., "length", "length/time").
+fn describe_dims(dims: &Dims) -> String {
+    let term = |i: usize, exp: i32| {
+        if exp == 1 {
+            DIMENSION_NAMES[i].to_string()
+        } else {
+            format!("{}^{}", DIMENSION_NAMES[i], exp)
+        }
+    };
+    let numerator: Vec<String> = (0..BASE_DIMENSIONS)
+        .filter(|&i| dims[i] > 0)
+        .map(|i| term(i, dims[i]))
+        .collect();
+    let denominator: Vec<String> = (0..BASE_DIMENSIONS)
+        .filter(|&i| dims[i] < 0)
+        .map(|i| term(i, -dims[i]))
+        .collect();
+
+    let top = if numerator.is_empty() {
+        "1".to_string()
+    } else {
+        numerator.join("*")
+    };
+    match denominator.len() {
+        0 if numerator.is_empty() => "dimensionless".to_string(),
+        0 => top,
+        _ => format!("{}/{}", top, denominator.join("/")),
+    }
+}
+
+/// A unit of measurement.
+///
+/// A value in this unit converts to SI base units (meter, kilogram, second,
+/// kelvin, byte) as `value * factor + offset`.
+struct Unit {
+    names: &'static [&'static str],
+    dims: Dims,
+    factor: f64,
+    offset: f64,
+}
+
+/// Shorthand for units without an offset.
+const fn unit(names: &'static [&'static str], dims: Dims, factor: f64) -> Unit {
+    Unit {
+        names,
+        dims,
+        factor,
+        offset: 0.0,
     }
+}
 
-    // Parse from source base
-    let decimal = i64::from_str_radix(number, from)
-        .with_context(|| format!("Invalid number '{}' for base {}", number, from))?;
+/// All known units. See [`find_unit`] for how names are matched.
+const UNITS: &[Unit] = &[
+    // Length (base: meter)
+    unit(&["m", "meter", "meters", "metre", "metres"], LENGTH, 1.0),
+    unit(&["km", "kilometer", "kilometers"], LENGTH, 1000.0),
+    unit(&["cm", "centimeter", "centimeters"], LENGTH, 0.01),
+    unit(&["mm", "millimeter", "millimeters"], LENGTH, 0.001),
+    unit(&["mi", "mile", "miles"], LENGTH, 1609.344),
+    unit(&["yd", "yard", "yards"], LENGTH, 0.9144),
+    unit(&["ft", "foot", "feet"], LENGTH, 0.3048),
+    unit(&["in", "inch", "inches"], LENGTH, 0.0254),
+    unit(&["nmi", "nautical-mile"], LENGTH, 1852.0),
+    // Mass (base: kilogram)
+    unit(&["kg", "kilogram", "kilograms"], MASS, 1.0),
+    unit(&["g", "gram", "grams"], MASS, 0.001),
+    unit(&["mg", "milligram", "milligrams"], MASS, 1e-6),
+    unit(&["t", "tonne", "tonnes"], MASS, 1000.0),
+    unit(&["lb", "lbs", "pound", "pounds"], MASS, 0.453_592_37),
+    unit(&["oz", "ounce", "ounces"], MASS, 0.028_349_523_125),
+    unit(&["st", "stone"], MASS, 6.350_293_18),
+    // Time (base: second)
+    unit(&["s", "sec", "second", "seconds"], TIME, 1.0),
+    unit(&["ms", "millisecond", "milliseconds"], TIME, 0.001),
+    unit(&["min", "minute", "minutes"], TIME, 60.0),
+    unit(&["h", "hr", "hour", "hours"], TIME, 3600.0),
+    unit(&["d", "day", "days"], TIME, 86_400.0),
+    unit(&["wk", "week", "weeks"], TIME, 604_800.0),
+    // Temperature (base: kelvin)
+    unit(&["k", "kelvin"], TEMPERATURE, 1.0),
+    Unit {
+        names: &["c", "celsius"],
+        dims: TEMPERATURE,
+        factor: 1.0,
+        offset: 273.15,
+    },
+    Unit {
+        names: &["f", "fahrenheit"],
+        dims: TEMPERATURE,
+        factor: 5.0 / 9.0,
+        offset: 459.67 * 5.0 / 9.0,
+    },
+    // Data (base: byte)
+    unit(&["b", "byte", "bytes"], DATA, 1.0),
+    unit(&["bit", "bits"], DATA, 0.125),
+    unit(&["kb", "kilobyte", "kilobytes"], DATA, 1e3),
+    unit(&["mb", "megabyte", "megabytes"], DATA, 1e6),
+    unit(&["gb", "gigabyte", "gigabytes"], DATA, 1e9),
+    unit(&["tb", "terabyte", "terabytes"], DATA, 1e12),
+    unit(&["kib", "kibibyte"], DATA, 1024.0),
+    unit(&["mib", "mebibyte"], DATA, 1_048_576.0),
+    unit(&["gib", "gibibyte"], DATA, 1_073_741_824.0),
+    unit(&["tib", "tebibyte"], DATA, 1_099_511_627_776.0),
+    // Bits come after bytes so case-insensitive lookups prefer bytes
+    unit(&["Kb", "kbit", "kilobit", "kilobits"], DATA, 125.0),
+    unit(&["Mb", "mbit", "megabit", "megabits"], DATA, 1.25e5),
+    unit(&["Gb", "gbit", "gigabit", "gigabits"], DATA, 1.25e8),
+    unit(&["Tb", "tbit", "terabit", "terabits"], DATA, 1.25e11),
+    // Common derived units
+    unit(&["mph"], SPEED, 0.447_04),
+    unit(&["kph", "kmh"], SPEED, 1000.0 / 3600.0),
+    unit(&["kn", "knot", "knots"], SPEED, 1852.0 / 3600.0),
+    unit(&["l", "liter", "liters", "litre", "litres"], VOLUME, 0.001),
+    unit(&["ml", "milliliter", "milliliters"], VOLUME, 1e-6),
+    unit(&["ha", "hectare", "hectares"], AREA, 10_000.0),
+];
+
+/// Look up a unit by name.
+///
+/// An exact, case-sensitive match wins, so the bit units `Kb`, `Mb`, `Gb`,
+/// and `Tb` are not folded into the byte units `kb`, `mb`, and so on.
+/// Anything else is matched case-insensitively against the table in order,
+/// which keeps `MB` and `mb` meaning megabytes.
+fn find_unit(name: &str) -> Result<&'static Unit> {
+    let name = name.trim();
+    let lower = name.to_lowercase();
+    UNITS
+        .iter()
+        .find(|u| u.names.contains(&name))
+        .or_else(|| {
+            UNITS
+                .iter()
+                .find(|u| u.names.iter().any(|n| n.to_lowercase() == lower))
+        })
+        .with_context(|| format!("Unknown unit '{}'", name))
+}
 
-    // Convert to target base
-    let result = format_radix(decimal, to);
-    println!("{}", result);
+/// A parsed unit expression such as `km/h` or `ft^2`.
+struct CompoundUnit {
+    dims: Dims,
+    factor: f64,
+    offset: f64,
+}
 
-    Ok(())
+/// Parse a unit expression: units joined by `*` or `/`, each with an
+/// optional integer exponent (`m/s^2`, `kg*m`, `ft^2`).
+///
+/// Operators apply to the unit that follows them, left to right, so
+/// `kg*m/s^2` is kilograms times meters divided by seconds squared.
+///
+/// # Errors
+/// Unknown units, empty factors, malformed exponents, and offset temperature
+/// scales (°C, °F) inside compounds are errors, since their offsets make
+/// products meaningless. Kelvin is absolute and combines like any unit.
+fn parse_unit(expr: &str) -> Result<CompoundUnit> {
+    let mut dims = [0; BASE_DIMENSIONS];
+    let mut factor = 1.0;
+    let mut offset = 0.0;
+    let mut terms = 0;
+
+    let mut rest = expr.trim();
+    let mut sign = 1;
+    loop {
+        let end = rest.find(['*', '/']).unwrap_or(rest.len());
+        let term = rest[..end].trim();
+        if term.is_empty() {
+            bail!("Invalid unit expression '{}': missing unit", expr);
+        }
+
+        let (name, exp) = match term.split_once('^') {
+            Some((name, exp)) => {
+                let exp: i32 = exp.trim().parse().with_context(|| {
+                    format!(
+                        "Invalid unit expression '{}': bad exponent in '{}'",
+                        expr, term
+                    )
+                })?;
+                (name.trim(), exp)
+            }
+            None => (term, 1),
+        };
+        let unit = find_unit(name)?;
+        let exp = exp * sign;
+
+        if unit.offset != 0.0 {
+            if terms > 0 || end < rest.len() || exp != 1 {
+                bail!(
+                    "Invalid unit expression '{}': {} has an offset and cannot be combined (use k)",
+                    expr,
+                    name
+                );
+            }
+            offset = unit.offset;
+        }
+
+        for (d, u) in dims.iter_mut().zip(unit.dims) {
+            *d += u * exp;
+        }
+        factor *= unit.factor.powi(exp);
+        terms += 1;
+
+        if end == rest.len() {
+            break;
+        }
+        sign = if rest[end..].starts_with('/') { -1 } else { 1 };
+        rest = &rest[end + 1..];
+    }
+
+    Ok(CompoundUnit {
+        dims,
+        factor,
+        offset,
+    })
+}
+
+/// A conversion between two units of the same dimensions.
+///
+/// It is affine: `result = value * factor + offset`, where the offset is only
+/// non-zero between temperature scales.
+struct Conversion {
+    from: CompoundUnit,
+    to: CompoundUnit,
 }
 
-/// Format number in given radix (base)
-fn format_radix(mut n: i64, radix: u32) -> String {
-    if n == 0 {
-        return "0".to_string();
+impl Conversion {
+    /// How many target units one source unit is
+    fn factor(&self) -> f64 {
+        self.from.factor / self.to.factor
+    }
+
+    /// What the target value is for a source value of zero
+    fn offset(&self) -> f64 {
+        (self.from.offset - self.to.offset) / self.to.factor
+    }
+
+    /// Convert through SI base units, as precisely as the unit table allows.
+    fn apply(&self, value: f64) -> f64 {
+        let base = value * self.from.factor + self.from.offset;
+        (base - self.to.offset) / self.to.factor
     }
 
-    let negative = n < 0;
-    if negative {
-        n = -n;
+    /// The factor and the calculation, one line each.
+    ///
+    /// For `5 km` to `mi` that is `1 km = 0.621371 mi` and
+    /// `5 km × 0.621371 = 3.106856 mi`. Temperature scales show the formula
+    /// instead, such as `f = c × 1.8 + 32`.
+    fn explain(&self, value: f64, from: &str, to: &str) -> Vec<String> {
+        let factor = format_value(self.factor());
+        let result = format_value(self.apply(value));
+        let value = format_value(value);
+        let offset = self.offset();
+        if offset.abs() < 1e-9 {
+            return vec![
+                format!("1 {} = {} {}", from, factor, to),
+                format!("{} {} × {} = {} {}", value, from, factor, result, to),
+            ];
+        }
+
+        let sign = if offset < 0.0 { '-' } else { '+' };
+        let offset = format_value(offset.abs());
+        vec![
+            format!("{} = {} × {} {} {}", to, from, factor, sign, offset),
+            format!(
+                "{} {} × {} {} {} = {} {}",
+                value, from, factor, sign, offset, result, to
+            ),
+        ]
     }
+}
 
-    let mut digits = Vec::new();
-    while n > 0 {
-        let digit = (n % radix as i64) as u32;
-        digits.push(char::from_digit(digit, radix).unwrap_or('?'));
-        n /= radix as i64;
+/// Look up the conversion from one unit expression to another.
+fn conversion(from: &str, to: &str) -> Result<Conversion> {
+    let from_unit = parse_unit(from)?;
+    let to_unit = parse_unit(to)?;
+
+    if from_unit.dims != to_unit.dims {
+        bail!(
+            "Cannot convert {} ({}) to {} ({})",
+            from,
+            describe_dims(&from_unit.dims),
+            to,
+            describe_dims(&to_unit.dims)
+        );
     }
 
-    digits.reverse();
-    let result: String = digits.into_iter().collect();
+    Ok(Conversion {
+        from: from_unit,
+        to: to_unit,
+    })
+}
+
+/// Convert a value between units, optionally explaining the calculation
+fn cmd_convert(value: f64, from: &str, to: &str, explain: bool) -> Result<()> {
+    let conversion = conversion(from, to)?;
+    let result = conversion.apply(value);
+    println!(
+        "{} {} = {} {}",
+        format_value(value),
+        from,
+        format_value(result).green(),
+        to
+    );
+    if explain {
+        for line in conversion.explain(value, from, to) {
+            println!("  {}", line.dimmed());
+        }
+    }
+    Ok(())
+}
 
-    if negative {
-        format!("-{}", result)
+/// Format a value with up to 6 decimals, dropping trailing zeros.
+///
+/// Values too small to show that way switch to scientific notation with
+/// six significant digits.
+fn format_value(value: f64) -> String {
+    if value != 0.0 && value.abs() < 5e-7 {
+        let s = format!("{:.5e}", value);
+        let (mantissa, exponent) = s.split_once('e').unwrap_or((&s, "0"));
+        let mantissa = mantissa.trim_end_matches('0').trim_end_matches('.');
+        return format!("{}e{}", mantissa, exponent);
+    }
+    let s = format!("{:.6}", value);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
     } else {
-        result
+        s.to_string()
+    }
+}
+
+/// Convert between number bases
+fn cmd_base(number: &str, from: u32, to: u32) -> Result<()> {
+    if !(2..=36).contains(&to) {
+        bail!("Base must be between 2 and 36");
     }
+    let value = parse_radix(number, from)?;
+    println!("{}", format_radix(value, to));
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Convert a value between two units of the same dimensions.
+    fn convert(value: f64, from: &str, to: &str) -> Result<f64> {
+        Ok(conversion(from, to)?.apply(value))
+    }
+
     #[test]
     fn test_parse_bytes() {
         assert_eq!(parse_bytes("1024").unwrap(), 1024);
@@ -227,9 +564,111 @@ mod tests {
     }
 
     #[test]
-    fn test_format_radix() {
-        assert_eq!(format_radix(255, 16), "ff");
-        assert_eq!(format_radix(10, 2), "1010");
+    fn test_convert_within_dimension() {
+        let mi = convert(10.0, "km", "mi").unwrap();
+        assert!((mi - 6.213_711_922).abs() < 1e-6);
+
+        let lb = convert(1.0, "kg", "lb").unwrap();
+        assert!((lb - 2.204_622_621_8).abs() < 1e-6);
+
+        assert_eq!(convert(2.0, "KiB", "B").unwrap(), 2048.0);
+        assert_eq!(convert(90.0, "min", "h").unwrap(), 1.5);
+    }
+
+    #[test]
+    fn test_convert_temperature() {
+        assert!((convert(100.0, "c", "f").unwrap() - 212.0).abs() < 1e-9);
+        assert!((convert(-40.0, "f", "c").unwrap() + 40.0).abs() < 1e-9);
+        assert!((convert(0.0, "celsius", "kelvin").unwrap() - 273.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_convert_incompatible_dimensions() {
+        let err = convert(1.0, "km", "kg").unwrap_err();
+        assert_eq!(err.to_string(), "Cannot convert km (length) to kg (mass)");
+        assert!(convert(1.0, "c", "m").is_err());
+        assert!(convert(1.0, "furlong", "m").is_err());
+    }
+
+    #[test]
+    fn test_convert_compound_speed() {
+        let ms = convert(100.0, "km/h", "m/s").unwrap();
+        assert!((ms - 27.777_777_778).abs() < 1e-6);
+        let mph = convert(100.0, "kph", "mi/h").unwrap();
+        assert!((mph - 62.137_119_224).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_convert_compound_area() {
+        let sq_ft = convert(1.0, "m*m", "ft^2").unwrap();
+        assert!((sq_ft - 10.763_910_417).abs() < 1e-6);
+        assert!((convert(1.0, "ha", "m^2").unwrap() - 10_000.0).abs() < 1e-6);
+        assert!((convert(1.0, "l", "cm^3").unwrap() - 1000.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_convert_compound_errors() {
+        let err = convert(1.0, "km/h", "m^2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Cannot convert km/h (length/time) to m^2 (length^2)"
+        );
+        assert!(parse_unit("m/").is_err());
+        assert!(parse_unit("*m").is_err());
+        assert!(parse_unit("m^x").is_err());
+        assert!(parse_unit("c/s").is_err());
+        assert!(parse_unit("f^2").is_err());
+        assert!(parse_unit("kg*m/s^2").is_ok());
+    }
+
+    #[test]
+    fn test_convert_kelvin_in_compound() {
+        assert!((convert(1.0, "K/s", "K/min").unwrap() - 60.0).abs() < 1e-9);
+        assert!(parse_unit("kg*K").is_ok());
+        assert!((convert(300.0, "k", "c").unwrap() - 26.85).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_conversion_explain() {
+        let km_mi = conversion("km", "mi").unwrap();
+        assert!((km_mi.factor() - 0.621_371_192).abs() < 1e-9);
+        assert_eq!(
+            km_mi.explain(5.0, "km", "mi"),
+            ["1 km = 0.621371 mi", "5 km × 0.621371 = 3.106856 mi"]
+        );
+
+        let c_f = conversion("c", "f").unwrap();
+        assert!((c_f.factor() - 1.8).abs() < 1e-12);
+        assert!((c_f.offset() - 32.0).abs() < 1e-9);
+        assert_eq!(
+            c_f.explain(100.0, "c", "f"),
+            ["f = c × 1.8 + 32", "100 c × 1.8 + 32 = 212 f"]
+        );
+
+        let f_c = conversion("f", "c").unwrap();
+        assert_eq!(
+            f_c.explain(212.0, "f", "c")[0],
+            "c = f × 0.555556 - 17.777778"
+        );
+    }
+
+    #[test]
+    fn test_find_unit_bits_vs_bytes() {
+        assert!((convert(1.0, "MB", "Mb").unwrap() - 8.0).abs() < 1e-9);
+        assert!((convert(1.0, "mb", "Mb").unwrap() - 8.0).abs() < 1e-9);
+        assert!((convert(1.0, "Gb", "MB").unwrap() - 125.0).abs() < 1e-9);
+        assert!((convert(8.0, "bit", "B").unwrap() - 1.0).abs() < 1e-9);
+        assert!((convert(1.0, "KB", "kb").unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_format_value_small() {
+        assert_eq!(format_value(2.5), "2.5");
+        assert_eq!(format_value(0.000001), "0.000001");
+        assert_eq!(format_value(1e-9), "1e-9");
+        assert_eq!(format_value(-1.234_567_89e-8), "-1.23457e-8");
+        assert_eq!(format_value(0.0), "0");
+        assert_eq!(format_value(convert(1.0, "mg", "t").unwrap()), "1e-9");
     }
 
     // Parser tests are now in src/expr/parser.rs
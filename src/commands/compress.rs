@@ -1,35 +1,74 @@
 //! Compress command - compression utilities.
+//!
+//! Data is streamed through the encoders in chunks, so large files never
+//! need to fit in memory. When decompressing, the format is taken from
+//! `--algo` if given, otherwise from the magic bytes at the start of the
+//! input, and finally from the file extension.
 
 use crate::cli::commands::compress::{CompressArgs, CompressCommand, CompressionFormat};
-use anyhow::{Context, Result};
+use crate::utils::{print_warning, write_atomic};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
-use flate2::read::GzDecoder;
+use flate2::bufread::MultiGzDecoder;
 use flate2::write::GzEncoder;
 use flate2::Compression;
+use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::CompressionLevel;
 use std::fs::File;
-use std::io::{BufReader, BufWriter, Read, Write};
-use std::path::PathBuf;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Gzip member header (RFC 1952).
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Zstandard frame header (RFC 8878).
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// The only zstd level the pure-Rust encoder implements so far.
+const ZSTD_FASTEST_LEVEL: u32 = 1;
 
 /// Run the compress command
 pub fn run(args: CompressArgs) -> Result<()> {
     match args.command {
-        CompressCommand::Compress {
+        Some(CompressCommand::Compress {
             input,
             out_file,
             format,
             level,
-        } => cmd_compress(&input, out_file, format, level),
-        CompressCommand::Decompress { input, out_file } => cmd_decompress(&input, out_file),
+        }) => cmd_compress(&input, out_file, format, level),
+        Some(CompressCommand::Decompress { input, out_file }) => {
+            cmd_decompress(&input, out_file, None)
+        }
+        None => {
+            let input = args.input.context("An input file is required")?;
+            if args.decompress {
+                cmd_decompress(&input, args.out_file, args.algo)
+            } else {
+                let format = args.algo.unwrap_or_default();
+                cmd_compress(&input, args.out_file, format, args.level)
+            }
+        }
     }
 }
 
 fn cmd_compress(
-    input: &PathBuf,
+    input: &Path,
     out_file: Option<PathBuf>,
     format: CompressionFormat,
-    level: u32,
+    level: Option<u32>,
 ) -> Result<()> {
+    let level = check_level(format, level.unwrap_or_else(|| default_level(format)))?;
+    if format == CompressionFormat::Zstd && level != ZSTD_FASTEST_LEVEL {
+        print_warning(&format!(
+            "zstd level {} is not supported by the built-in encoder yet; using level {}",
+            level, ZSTD_FASTEST_LEVEL
+        ));
+    }
+
     let output_path = out_file.unwrap_or_else(|| {
+        if is_stdio(input) {
+            return PathBuf::from("-");
+        }
         let ext = match format {
             CompressionFormat::Gzip => "gz",
             CompressionFormat::Zstd => "zst",
@@ -37,30 +76,27 @@ fn cmd_compress(
         PathBuf::from(format!("{}.{}", input.display(), ext))
     });
 
-    let input_file =
-        File::open(input).with_context(|| format!("Failed to open input: {}", input.display()))?;
-    let mut reader = BufReader::new(input_file);
+    let mut reader = open_input(input)?;
+    write_output(&output_path, |mut writer| {
+        compress_stream(&mut reader, &mut writer, format, level)
+    })?;
 
-    let output_file = File::create(&output_path)
-        .with_context(|| format!("Failed to create output: {}", output_path.display()))?;
-    let writer = BufWriter::new(output_file);
+    if is_stdio(&output_path) {
+        return Ok(());
+    }
 
-    match format {
-        CompressionFormat::Gzip => {
-            let level = level.min(9);
-            let mut encoder = GzEncoder::new(writer, Compression::new(level));
-            std::io::copy(&mut reader, &mut encoder)?;
-            encoder.finish()?;
-        }
-        CompressionFormat::Zstd => {
-            // ruzstd is decode-only, so we use flate2 for zstd-like compression
-            // In a real implementation, you'd use the `zstd` crate with vendored feature
-            anyhow::bail!("Zstd compression requires the 'zstd' crate. Use gzip for now.");
-        }
+    let output_size = std::fs::metadata(&output_path)?.len();
+    if is_stdio(input) {
+        println!(
+            "{} {} ({} bytes)",
+            "Compressed:".green(),
+            output_path.display(),
+            output_size
+        );
+        return Ok(());
     }
 
     let input_size = std::fs::metadata(input)?.len();
-    let output_size = std::fs::metadata(&output_path)?.len();
     let ratio = if input_size > 0 {
         (output_size as f64 / input_size as f64) * 100.0
     } else {
@@ -77,48 +113,382 @@ fn cmd_compress(
     Ok(())
 }
 
-fn cmd_decompress(input: &PathBuf, out_file: Option<PathBuf>) -> Result<()> {
-    let ext = input.extension().and_then(|e| e.to_str()).unwrap_or("");
+fn cmd_decompress(
+    input: &Path,
+    out_file: Option<PathBuf>,
+    format: Option<CompressionFormat>,
+) -> Result<()> {
+    let output_path = match out_file {
+        Some(path) => path,
+        None if is_stdio(input) => PathBuf::from("-"),
+        None => strip_compressed_extension(input).with_context(|| {
+            format!(
+                "Cannot derive an output name from {}; pass an output path",
+                input.display()
+            )
+        })?,
+    };
 
-    let output_path = out_file.unwrap_or_else(|| {
-        let stem = input.file_stem().unwrap_or_default();
-        input.with_file_name(stem)
-    });
+    let (header, reader) = peek_magic(open_input(input)?)?;
+    let format = match format {
+        Some(format) => format,
+        None => detect_format(&header)
+            .or_else(|| format_from_extension(input))
+            .context(
+                "Unknown compression format: expected gzip or zstd data (use --algo to choose)",
+            )?,
+    };
 
-    let input_file =
-        File::open(input).with_context(|| format!("Failed to open input: {}", input.display()))?;
-    let reader = BufReader::new(input_file);
+    write_output(&output_path, |mut writer| {
+        decompress_stream(reader, &mut writer, format)
+    })?;
 
-    let output_file = File::create(&output_path)
-        .with_context(|| format!("Failed to create output: {}", output_path.display()))?;
-    let mut writer = BufWriter::new(output_file);
+    if !is_stdio(&output_path) {
+        println!(
+            "{} {} -> {}",
+            "Decompressed:".green(),
+            input.display(),
+            output_path.display()
+        );
+    }
+    Ok(())
+}
 
-    match ext {
-        "gz" | "gzip" => {
-            let mut decoder = GzDecoder::new(reader);
-            std::io::copy(&mut decoder, &mut writer)?;
+/// Compress everything from `reader` into `writer`.
+pub fn compress_stream<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    format: CompressionFormat,
+    level: u32,
+) -> Result<()> {
+    match format {
+        CompressionFormat::Gzip => {
+            let mut encoder = GzEncoder::new(writer, Compression::new(level));
+            io::copy(reader, &mut encoder).context("Failed to compress gzip")?;
+            encoder.finish()?;
+        }
+        CompressionFormat::Zstd => {
+            // The encoder panics on I/O errors, so park them and report afterwards
+            let mut source = ErrorSlot::new(reader);
+            let mut drain = ErrorSlot::new(writer);
+            ruzstd::encoding::compress(&mut source, &mut drain, CompressionLevel::Fastest);
+            source.finish().context("Failed to read input")?;
+            drain.finish().context("Failed to compress zstd")?;
         }
-        "zst" | "zstd" => {
-            let mut decoder = ruzstd::decoding::StreamingDecoder::new(reader)
-                .map_err(|e| anyhow::anyhow!("Failed to create zstd decoder: {}", e))?;
-            let mut buffer = Vec::new();
-            decoder
-                .read_to_end(&mut buffer)
-                .context("Failed to decompress zstd")?;
-            writer.write_all(&buffer)?;
+    }
+    Ok(())
+}
+
+/// Decompress everything from `reader` into `writer`.
+pub fn decompress_stream<R: BufRead, W: Write>(
+    reader: R,
+    writer: &mut W,
+    format: CompressionFormat,
+) -> Result<()> {
+    match format {
+        CompressionFormat::Gzip => {
+            let mut decoder = MultiGzDecoder::new(reader);
+            io::copy(&mut decoder, writer).context("Failed to decompress gzip")?;
         }
-        _ => {
-            anyhow::bail!("Unknown compression format. Supported: .gz, .gzip, .zst, .zstd");
+        CompressionFormat::Zstd => {
+            let mut reader = reader;
+            // A zstd stream may hold several concatenated frames
+            while !reader
+                .fill_buf()
+                .context("Failed to read input")?
+                .is_empty()
+            {
+                let mut decoder =
+                    StreamingDecoder::new(&mut reader).context("Failed to decompress zstd")?;
+                io::copy(&mut decoder, writer).context("Failed to decompress zstd")?;
+            }
         }
     }
+    Ok(())
+}
+
+/// Read enough of `reader` to recognise any supported magic number.
+///
+/// A single `fill_buf` may return fewer bytes (e.g. from a pipe), so this
+/// keeps reading until the header is complete or the input ends. The
+/// returned reader replays the header before the rest of the input.
+fn peek_magic<R: BufRead>(mut reader: R) -> Result<(Vec<u8>, impl BufRead)> {
+    let mut header = Vec::with_capacity(ZSTD_MAGIC.len());
+    reader
+        .by_ref()
+        .take(ZSTD_MAGIC.len() as u64)
+        .read_to_end(&mut header)
+        .context("Failed to read input")?;
+    Ok((header.clone(), io::Cursor::new(header).chain(reader)))
+}
+
+/// Wraps a reader or writer so that the first I/O error is recorded
+/// instead of returned, letting callers that unwrap I/O results finish
+/// cleanly. Reads stop at the error; writes after it are discarded.
+struct ErrorSlot<T> {
+    inner: T,
+    error: Option<io::Error>,
+}
+
+impl<T> ErrorSlot<T> {
+    fn new(inner: T) -> Self {
+        Self { inner, error: None }
+    }
 
-    writer.flush()?;
+    fn finish(self) -> io::Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
 
-    println!(
-        "{} {} -> {}",
-        "Decompressed:".green(),
-        input.display(),
-        output_path.display()
-    );
-    Ok(())
+impl<R: Read> Read for ErrorSlot<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.error.is_some() {
+            return Ok(0);
+        }
+        loop {
+            match self.inner.read(buf) {
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.error = Some(e);
+                    return Ok(0);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<W: Write> Write for ErrorSlot<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.error.is_none() {
+            if let Err(e) = self.inner.write_all(buf) {
+                self.error = Some(e);
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.error.is_none() {
+            if let Err(e) = self.inner.flush() {
+                self.error = Some(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Identify a compression format from the first bytes of a stream.
+pub fn detect_format(header: &[u8]) -> Option<CompressionFormat> {
+    if header.starts_with(&ZSTD_MAGIC) {
+        Some(CompressionFormat::Zstd)
+    } else if header.starts_with(&GZIP_MAGIC) {
+        Some(CompressionFormat::Gzip)
+    } else {
+        None
+    }
+}
+
+fn format_from_extension(path: &Path) -> Option<CompressionFormat> {
+    match path.extension()?.to_str()? {
+        "gz" | "gzip" => Some(CompressionFormat::Gzip),
+        "zst" | "zstd" => Some(CompressionFormat::Zstd),
+        _ => None,
+    }
+}
+
+fn strip_compressed_extension(path: &Path) -> Option<PathBuf> {
+    format_from_extension(path)?;
+    Some(path.with_extension(""))
+}
+
+fn default_level(format: CompressionFormat) -> u32 {
+    match format {
+        CompressionFormat::Gzip => 6,
+        CompressionFormat::Zstd => ZSTD_FASTEST_LEVEL,
+    }
+}
+
+fn check_level(format: CompressionFormat, level: u32) -> Result<u32> {
+    let (name, max) = match format {
+        CompressionFormat::Gzip => ("Gzip", 9),
+        CompressionFormat::Zstd => ("Zstd", 22),
+    };
+    if !(1..=max).contains(&level) {
+        bail!(
+            "{} level must be between 1 and {}, got {}",
+            name,
+            max,
+            level
+        );
+    }
+    Ok(level)
+}
+
+fn is_stdio(path: &Path) -> bool {
+    path.as_os_str() == "-"
+}
+
+fn open_input(path: &Path) -> Result<Box<dyn BufRead>> {
+    if is_stdio(path) {
+        return Ok(Box::new(BufReader::new(io::stdin())));
+    }
+    let file =
+        File::open(path).with_context(|| format!("Failed to open input: {}", path.display()))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Run `write` against stdout for `-`, otherwise against a file that only
+/// replaces `path` once `write` has succeeded.
+fn write_output(path: &Path, write: impl FnOnce(&mut dyn Write) -> Result<()>) -> Result<()> {
+    if is_stdio(path) {
+        let mut writer = BufWriter::new(io::stdout().lock());
+        write(&mut writer)?;
+        return Ok(writer.flush()?);
+    }
+    write_atomic(path, |writer| write(writer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<u8> {
+        (0..200_000u32)
+            .flat_map(|i| format!("line {} {}\n", i, i % 7).into_bytes())
+            .collect()
+    }
+
+    fn round_trip(format: CompressionFormat, level: u32) {
+        let original = sample();
+
+        let mut compressed = Vec::new();
+        compress_stream(&mut original.as_slice(), &mut compressed, format, level).unwrap();
+        assert!(compressed.len() < original.len());
+        assert_eq!(detect_format(&compressed), Some(format));
+
+        let mut restored = Vec::new();
+        decompress_stream(compressed.as_slice(), &mut restored, format).unwrap();
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        round_trip(CompressionFormat::Gzip, 1);
+        round_trip(CompressionFormat::Gzip, 9);
+    }
+
+    #[test]
+    fn test_zstd_round_trip() {
+        round_trip(CompressionFormat::Zstd, 1);
+    }
+
+    #[test]
+    fn test_zstd_concatenated_frames() {
+        let mut compressed = Vec::new();
+        for part in [&b"first "[..], b"second"] {
+            compress_stream(&mut &part[..], &mut compressed, CompressionFormat::Zstd, 1).unwrap();
+        }
+        let mut restored = Vec::new();
+        decompress_stream(
+            compressed.as_slice(),
+            &mut restored,
+            CompressionFormat::Zstd,
+        )
+        .unwrap();
+        assert_eq!(restored, b"first second");
+    }
+
+    #[test]
+    fn test_peek_magic_short_reads() {
+        let original = sample();
+        let mut compressed = Vec::new();
+        compress_stream(
+            &mut original.as_slice(),
+            &mut compressed,
+            CompressionFormat::Zstd,
+            1,
+        )
+        .unwrap();
+
+        // A one-byte buffer hands out the header one byte at a time
+        let trickle = BufReader::with_capacity(1, compressed.as_slice());
+        let (header, reader) = peek_magic(trickle).unwrap();
+        assert_eq!(detect_format(&header), Some(CompressionFormat::Zstd));
+
+        let mut restored = Vec::new();
+        decompress_stream(reader, &mut restored, CompressionFormat::Zstd).unwrap();
+        assert_eq!(restored, original);
+    }
+
+    #[test]
+    fn test_peek_magic_short_input() {
+        let (header, mut reader) = peek_magic(&b"ab"[..]).unwrap();
+        assert_eq!(header, b"ab");
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "ab");
+    }
+
+    #[test]
+    fn test_zstd_write_error_is_reported() {
+        struct Broken;
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let err = compress_stream(&mut &b"data"[..], &mut Broken, CompressionFormat::Zstd, 1)
+            .unwrap_err();
+        assert!(format!("{:#}", err).contains("closed"));
+    }
+
+    #[test]
+    fn test_empty_round_trip() {
+        for format in [CompressionFormat::Gzip, CompressionFormat::Zstd] {
+            let mut compressed = Vec::new();
+            compress_stream(&mut io::empty(), &mut compressed, format, 3).unwrap();
+            let mut restored = Vec::new();
+            decompress_stream(compressed.as_slice(), &mut restored, format).unwrap();
+            assert!(restored.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(
+            detect_format(&[0x1f, 0x8b, 0x08]),
+            Some(CompressionFormat::Gzip)
+        );
+        assert_eq!(
+            detect_format(&[0x28, 0xb5, 0x2f, 0xfd, 0x00]),
+            Some(CompressionFormat::Zstd)
+        );
+        assert_eq!(detect_format(b"plain text"), None);
+        assert_eq!(detect_format(&[]), None);
+    }
+
+    #[test]
+    fn test_check_level() {
+        assert!(check_level(CompressionFormat::Gzip, 9).is_ok());
+        assert!(check_level(CompressionFormat::Gzip, 10).is_err());
+        assert!(check_level(CompressionFormat::Zstd, 22).is_ok());
+        assert!(check_level(CompressionFormat::Zstd, 0).is_err());
+    }
+
+    #[test]
+    fn test_strip_compressed_extension() {
+        assert_eq!(
+            strip_compressed_extension(Path::new("data.txt.gz")),
+            Some(PathBuf::from("data.txt"))
+        );
+        assert_eq!(
+            strip_compressed_extension(Path::new("data.zst")),
+            Some(PathBuf::from("data"))
+        );
+        assert_eq!(strip_compressed_extension(Path::new("data.txt")), None);
+    }
 }
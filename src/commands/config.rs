@@ -36,19 +36,28 @@
 //! dx config unset editor            # Remove a value
 //! dx config list                    # List all keys
 //! dx config list --values           # List keys with values
+//! dx config check                   # Validate keys and value types
 //! dx config path                    # Show config file location
 //! dx config edit                    # Open in $EDITOR
 //! dx config reset                   # Reset to defaults
+//! dx config set --profile prod hash.algorithm sha512
+//! dx config get --profile prod hash.algorithm
 //! ```
 //!
+//! ### Profiles and Environment Overrides
+//! Reads resolve `DX_<KEY>` environment variables first, then the
+//! `--profile` selected, then the base settings. Writes with `--profile` go
+//! into `[profiles.<name>]` instead of the base settings.
+//!
 //! ## External Documentation
 //! - TOML: <https://toml.io/>
 //! - directories crate: <https://docs.rs/directories>
 
 use crate::cli::commands::config::{ConfigArgs, ConfigCommand, ConfigFormat};
-use crate::config::Settings;
+use crate::config::{schema, Settings};
 use anyhow::{Context, Result};
 use colored::Colorize;
+use std::path::Path;
 
 /// Run the config command, dispatching to the appropriate subcommand.
 ///
@@ -60,12 +69,14 @@ use colored::Colorize;
 /// - `edit`: Open in editor
 /// - `reset`: Restore defaults
 pub fn run(args: ConfigArgs) -> Result<()> {
+    let profile = args.profile.as_deref();
     match args.command {
-        ConfigCommand::Show { key, format } => cmd_show(key.as_deref(), format),
-        ConfigCommand::Get { key } => cmd_get(&key),
-        ConfigCommand::Set { key, value } => cmd_set(&key, &value),
-        ConfigCommand::Unset { key } => cmd_unset(&key),
-        ConfigCommand::List { values } => cmd_list(values),
+        ConfigCommand::Show { key, format } => cmd_show(key.as_deref(), format, profile),
+        ConfigCommand::Get { key } => cmd_get(&key, profile),
+        ConfigCommand::Set { key, value } => cmd_set(&key, &value, profile),
+        ConfigCommand::Unset { key } => cmd_unset(&key, profile),
+        ConfigCommand::List { values } => cmd_list(values, profile),
+        ConfigCommand::Check { file } => cmd_check(file.as_deref()),
         ConfigCommand::Path => cmd_path(),
         ConfigCommand::Edit => cmd_edit(),
         ConfigCommand::Reset { force } => cmd_reset(force),
@@ -81,13 +92,13 @@ pub fn run(args: ConfigArgs) -> Result<()> {
 /// # Key Lookup
 /// If a key is provided, only that value is printed (raw, no formatting).
 /// This is useful for shell scripts: `editor=$(dx config show editor)`
-fn cmd_show(key: Option<&str>, format: ConfigFormat) -> Result<()> {
+fn cmd_show(key: Option<&str>, format: ConfigFormat, profile: Option<&str>) -> Result<()> {
     // Load settings from disk (or defaults if no file exists)
     let settings = Settings::load()?;
 
     if let Some(k) = key {
         // Single key lookup
-        if let Some(value) = settings.get(k) {
+        if let Some(value) = settings.resolve(k, profile)? {
             println!("{}", value);
         } else {
             eprintln!("{} Key '{}' not found", "✗".red().bold(), k);
@@ -112,11 +123,12 @@ fn cmd_show(key: Option<&str>, format: ConfigFormat) -> Result<()> {
 /// Get a specific configuration value by key.
 ///
 /// Similar to `show <key>` but more explicit in intent.
+/// The value is resolved as env (`DX_<KEY>`) > profile > base settings.
 /// Exits with code 1 if the key doesn't exist.
-fn cmd_get(key: &str) -> Result<()> {
+fn cmd_get(key: &str, profile: Option<&str>) -> Result<()> {
     let settings = Settings::load()?;
 
-    if let Some(value) = settings.get(key) {
+    if let Some(value) = settings.resolve(key, profile)? {
         println!("{}", value);
     } else {
         eprintln!("{} Key '{}' not found", "✗".red().bold(), key);
@@ -135,22 +147,29 @@ fn cmd_get(key: &str) -> Result<()> {
 /// # Creating Nested Keys
 /// Dot-notation creates nested structures automatically:
 /// `dx config set output.colors true` creates `[output]` section.
-fn cmd_set(key: &str, value: &str) -> Result<()> {
+fn cmd_set(key: &str, value: &str, profile: Option<&str>) -> Result<()> {
     // Load existing settings (or defaults)
     let mut settings = Settings::load()?;
 
     // Set the value (handles dot-notation internally)
-    settings.set(key, value)?;
+    match profile {
+        Some(name) => settings.set_in_profile(name, key, value)?,
+        None => settings.set(key, value)?,
+    }
 
     // Persist to disk
     settings.save()?;
 
     // Confirm the change to the user
+    let scope = profile
+        .map(|name| format!(" (profile {})", name))
+        .unwrap_or_default();
     println!(
-        "{} Set {} = {}",
+        "{} Set {} = {}{}",
         "✓".green().bold(),
         key.cyan(),
-        value.yellow()
+        value.yellow(),
+        scope
     );
 
     Ok(())
@@ -160,9 +179,12 @@ fn cmd_set(key: &str, value: &str) -> Result<()> {
 ///
 /// After unsetting, the key will return to its default value
 /// (if one exists) or be absent from the config.
-fn cmd_unset(key: &str) -> Result<()> {
+fn cmd_unset(key: &str, profile: Option<&str>) -> Result<()> {
     let mut settings = Settings::load()?;
-    settings.unset(key)?;
+    match profile {
+        Some(name) => settings.unset_in_profile(name, key)?,
+        None => settings.unset(key)?,
+    }
     settings.save()?;
 
     println!("{} Removed {}", "✓".green().bold(), key.cyan());
@@ -175,11 +197,11 @@ fn cmd_unset(key: &str) -> Result<()> {
 /// # With Values
 /// The `--values` flag shows `key = value` pairs instead of just keys.
 /// Useful for quickly reviewing the entire configuration.
-fn cmd_list(show_values: bool) -> Result<()> {
+fn cmd_list(show_values: bool, profile: Option<&str>) -> Result<()> {
     let settings = Settings::load()?;
 
-    // list() returns an iterator over (key, value) pairs
-    for (key, value) in settings.list() {
+    // Values are resolved the same way as `get`
+    for (key, value) in settings.list_resolved(profile)? {
         if show_values {
             println!("{} = {}", key.cyan(), value);
         } else {
@@ -190,6 +212,54 @@ fn cmd_list(show_values: bool) -> Result<()> {
     Ok(())
 }
 
+/// Validate a config file against the typed schema.
+///
+/// Every problem is printed with its key path (for example
+/// `profiles.prod.hash.algorithm`), then the command exits with code 1.
+/// A missing file is valid: the defaults apply.
+fn cmd_check(file: Option<&Path>) -> Result<()> {
+    let path = match file {
+        Some(path) => path.to_path_buf(),
+        None => Settings::config_path()?,
+    };
+
+    if !path.exists() {
+        if file.is_some() {
+            anyhow::bail!("{} does not exist", path.display());
+        }
+        println!(
+            "{} No config file at {}, using defaults",
+            "✓".green().bold(),
+            path.display()
+        );
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let problems = schema::validate_str(&content);
+    if problems.is_empty() {
+        println!("{} {} is valid", "✓".green().bold(), path.display());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        eprintln!(
+            "{} {}: {}",
+            "✗".red().bold(),
+            problem.path.cyan(),
+            problem.message
+        );
+    }
+    eprintln!(
+        "{} problem{} in {}",
+        problems.len(),
+        if problems.len() == 1 { "" } else { "s" },
+        path.display()
+    );
+    std::process::exit(1);
+}
+
 /// Show the path to the configuration file.
 ///
 /// Useful for:
@@ -1,7 +1,9 @@
 //! CSV command - CSV utilities.
 
 use crate::cli::commands::csv::{CsvArgs, CsvCommand};
-use anyhow::{Context, Result};
+use crate::utils::Table;
+use anyhow::{bail, Context, Result};
+use dx_expr::{Context as ExprContext, Expr};
 use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Read};
@@ -21,11 +23,17 @@ pub fn run(args: CsvArgs) -> Result<()> {
             pretty,
         } => cmd_to_json(input, delimiter, pretty),
         CsvCommand::FromJson { input } => cmd_from_json(input),
-        CsvCommand::Query {
+        CsvCommand::Select {
             input,
             columns,
             delimiter,
-        } => cmd_query(input, columns, delimiter),
+        } => cmd_select(input, columns, delimiter),
+        CsvCommand::Stats { input, delimiter } => cmd_stats(input, delimiter),
+        CsvCommand::Filter {
+            expression,
+            input,
+            delimiter,
+        } => cmd_filter(&expression, input, delimiter),
     }
 }
 
@@ -77,56 +85,11 @@ fn cmd_format(input: Option<PathBuf>, delimiter: char, no_header: bool) -> Resul
         })
         .collect::<Result<Vec<_>>>()?;
 
-    // Calculate column widths
-    let num_cols = if !headers.is_empty() {
-        headers.len()
-    } else if let Some(first) = records.first() {
-        first.len()
-    } else {
-        return Ok(());
-    };
-
-    let mut widths = vec![0usize; num_cols];
-
-    if !headers.is_empty() {
-        for (i, h) in headers.iter().enumerate() {
-            widths[i] = widths[i].max(h.len());
-        }
-    }
-
-    for record in &records {
-        for (i, field) in record.iter().enumerate() {
-            if i < widths.len() {
-                widths[i] = widths[i].max(field.len());
-            }
-        }
-    }
-
-    // Print formatted table
-    if !headers.is_empty() {
-        let header_line: Vec<String> = headers
-            .iter()
-            .enumerate()
-            .map(|(i, h)| format!("{:width$}", h, width = widths[i]))
-            .collect();
-        println!("{}", header_line.join(" | "));
-
-        // Print separator
-        let sep: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
-        println!("{}", sep.join("-+-"));
-    }
-
-    for record in &records {
-        let line: Vec<String> = record
-            .iter()
-            .enumerate()
-            .map(|(i, f)| {
-                let width = widths.get(i).copied().unwrap_or(0);
-                format!("{:width$}", f, width = width)
-            })
-            .collect();
-        println!("{}", line.join(" | "));
+    let mut table = Table::new(headers);
+    for record in records {
+        table.push_row(record);
     }
+    table.print();
 
     Ok(())
 }
@@ -231,9 +194,84 @@ fn cmd_from_json(input: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
-fn cmd_query(input: Option<PathBuf>, columns: Vec<String>, delimiter: char) -> Result<()> {
+fn cmd_select(input: Option<PathBuf>, columns: Vec<String>, delimiter: char) -> Result<()> {
     let content = read_input(input)?;
+    let (headers, records) = read_records(&content, delimiter)?;
+    let column_indices = resolve_columns(&headers, &columns)?;
 
+    let mut wtr = csv::Writer::from_writer(io::stdout());
+
+    // Write selected headers
+    let selected_headers: Vec<&str> = column_indices
+        .iter()
+        .map(|&i| headers[i].as_str())
+        .collect();
+    wtr.write_record(&selected_headers)?;
+
+    // Write selected columns from each record
+    for record in &records {
+        wtr.write_record(select_fields(record, &column_indices))?;
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+fn cmd_stats(input: Option<PathBuf>, delimiter: char) -> Result<()> {
+    let content = read_input(input)?;
+    let (headers, records) = read_records(&content, delimiter)?;
+    let stats = column_stats(&headers, &records);
+
+    let table: Vec<[String; 6]> = stats
+        .iter()
+        .map(|s| {
+            let numeric = |v: Option<f64>| v.map(format_stat).unwrap_or_else(|| "-".to_string());
+            [
+                s.name.clone(),
+                s.count.to_string(),
+                s.nulls.to_string(),
+                numeric(s.min),
+                numeric(s.max),
+                numeric(s.mean),
+            ]
+        })
+        .collect();
+
+    let mut output = Table::new(["column", "count", "nulls", "min", "max", "mean"]);
+    for row in table {
+        output.push_row(row);
+    }
+    output.print();
+
+    Ok(())
+}
+
+fn cmd_filter(expression: &str, input: Option<PathBuf>, delimiter: char) -> Result<()> {
+    let content = read_input(input)?;
+    let (headers, records) = read_records(&content, delimiter)?;
+    let filter = RowFilter::new(expression, &headers)?;
+
+    let mut wtr = csv::Writer::from_writer(io::stdout());
+    wtr.write_record(&headers)?;
+    for (row, record) in records.iter().enumerate() {
+        // Row numbers count the header as line 1
+        if filter
+            .matches(record)
+            .with_context(|| format!("Failed to evaluate row {}", row + 2))?
+        {
+            wtr.write_record(record)?;
+        }
+    }
+
+    wtr.flush()?;
+    Ok(())
+}
+
+/// Parse CSV content into its header row and records.
+///
+/// Quoting follows RFC 4180: quoted fields may contain delimiters, newlines,
+/// and doubled quotes.
+fn read_records(content: &str, delimiter: char) -> Result<(Vec<String>, Vec<csv::StringRecord>)> {
     let mut rdr = csv::ReaderBuilder::new()
         .delimiter(delimiter as u8)
         .has_headers(true)
@@ -246,44 +284,254 @@ fn cmd_query(input: Option<PathBuf>, columns: Vec<String>, delimiter: char) -> R
         .map(|s| s.to_string())
         .collect();
 
-    // Resolve column names/indices to indices
-    let column_indices: Vec<usize> = columns
+    let records = rdr
+        .records()
+        .map(|r| r.context("Failed to read CSV record"))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((headers, records))
+}
+
+/// Resolve column names or indices to indices, in the order given.
+fn resolve_columns(headers: &[String], columns: &[String]) -> Result<Vec<usize>> {
+    if columns.is_empty() {
+        bail!("No columns given (use -c name,age)");
+    }
+    columns
         .iter()
-        .filter_map(|c| {
-            // Try as index first
-            if let Ok(idx) = c.parse::<usize>() {
-                if idx < headers.len() {
-                    return Some(idx);
-                }
+        .map(|c| {
+            // Names take precedence over indices, so a column called "2" still works
+            if let Some(idx) = headers.iter().position(|h| h == c) {
+                return Ok(idx);
+            }
+            match c.parse::<usize>() {
+                Ok(idx) if idx < headers.len() => Ok(idx),
+                _ => bail!("Unknown column: {} (available: {})", c, headers.join(", ")),
             }
-            // Try as column name
-            headers.iter().position(|h| h == c)
         })
-        .collect();
+        .collect()
+}
 
-    if column_indices.is_empty() {
-        anyhow::bail!("No valid columns found");
-    }
+fn select_fields<'a>(record: &'a csv::StringRecord, indices: &[usize]) -> Vec<&'a str> {
+    indices
+        .iter()
+        .map(|&i| record.get(i).unwrap_or(""))
+        .collect()
+}
 
-    let mut wtr = csv::Writer::from_writer(io::stdout());
+/// Summary statistics for one column.
+#[derive(Debug, Clone, PartialEq)]
+struct ColumnStats {
+    name: String,
+    /// Non-empty values
+    count: usize,
+    /// Empty or missing values
+    nulls: usize,
+    /// Numeric statistics, present only when every non-empty value is a number
+    min: Option<f64>,
+    max: Option<f64>,
+    mean: Option<f64>,
+}
 
-    // Write selected headers
-    let selected_headers: Vec<&str> = column_indices
+fn column_stats(headers: &[String], records: &[csv::StringRecord]) -> Vec<ColumnStats> {
+    headers
         .iter()
-        .map(|&i| headers[i].as_str())
-        .collect();
-    wtr.write_record(&selected_headers)?;
+        .enumerate()
+        .map(|(i, name)| {
+            let values: Vec<&str> = records
+                .iter()
+                .map(|r| r.get(i).unwrap_or("").trim())
+                .filter(|v| !v.is_empty())
+                .collect();
+            let numbers: Option<Vec<f64>> = values.iter().map(|v| v.parse().ok()).collect();
+            let numbers = numbers.filter(|n| !n.is_empty());
+
+            ColumnStats {
+                name: name.clone(),
+                count: values.len(),
+                nulls: records.len() - values.len(),
+                min: numbers
+                    .as_ref()
+                    .map(|n| n.iter().copied().fold(f64::INFINITY, f64::min)),
+                max: numbers
+                    .as_ref()
+                    .map(|n| n.iter().copied().fold(f64::NEG_INFINITY, f64::max)),
+                mean: numbers
+                    .as_ref()
+                    .map(|n| n.iter().sum::<f64>() / n.len() as f64),
+            }
+        })
+        .collect()
+}
 
-    // Write selected columns from each record
-    for record in rdr.records() {
-        let record = record.context("Failed to read CSV record")?;
-        let selected: Vec<&str> = column_indices
+/// Format a statistic with up to 4 decimals, dropping trailing zeros.
+fn format_stat(value: f64) -> String {
+    let s = format!("{:.4}", value);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+/// A row predicate compiled from an `expr` expression.
+///
+/// Columns referenced by the expression are bound as variables for each
+/// row. A row whose referenced cell is empty or not numeric does not match.
+struct RowFilter {
+    expr: Expr,
+    /// (variable name, column index) for each referenced column
+    bindings: Vec<(String, usize)>,
+}
+
+impl RowFilter {
+    fn new(expression: &str, headers: &[String]) -> Result<Self> {
+        let expr = dx_expr::parse(expression)
+            .with_context(|| format!("Invalid filter expression: {}", expression))?;
+
+        let mut names = Vec::new();
+        collect_variables(&expr, &mut names);
+        let bindings = names
+            .into_iter()
+            .map(|name| match headers.iter().position(|h| *h == name) {
+                Some(idx) => Ok((name, idx)),
+                None => bail!(
+                    "Unknown column in filter: {} (available: {})",
+                    name,
+                    headers.join(", ")
+                ),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self { expr, bindings })
+    }
+
+    fn matches(&self, record: &csv::StringRecord) -> Result<bool> {
+        let mut ctx = ExprContext::new();
+        for (name, idx) in &self.bindings {
+            match record.get(*idx).and_then(|v| v.trim().parse::<f64>().ok()) {
+                Some(value) => ctx.set(name, value),
+                None => return Ok(false),
+            }
+        }
+        Ok(self.expr.eval_with_context(&mut ctx)? != 0.0)
+    }
+}
+
+/// Collect the distinct variable names an expression reads.
+fn collect_variables(expr: &Expr, names: &mut Vec<String>) {
+    match expr {
+        Expr::Variable { name } => {
+            if !names.contains(name) {
+                names.push(name.clone());
+            }
+        }
+        Expr::Number { .. } | Expr::Constant { .. } | Expr::Lambda { .. } => {}
+        Expr::BinOp { left, right, .. } => {
+            collect_variables(left, names);
+            collect_variables(right, names);
+        }
+        Expr::UnaryOp { expr, .. } => collect_variables(expr, names),
+        Expr::FuncCall { args, .. } | Expr::Tuple { items: args } => {
+            for arg in args {
+                collect_variables(arg, names);
+            }
+        }
+        Expr::Conditional {
+            condition,
+            then_branch,
+            else_branch,
+        } => {
+            collect_variables(condition, names);
+            collect_variables(then_branch, names);
+            collect_variables(else_branch, names);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PEOPLE: &str = "name,age,city,score\n\
+        Alice,30,\"Oslo, Norway\",88.5\n\
+        Bob,25,Bergen,\n\
+        \"Carol \"\"CJ\"\"\",41,\"Trondheim\nNorth\",70\n";
+
+    fn people() -> (Vec<String>, Vec<csv::StringRecord>) {
+        read_records(PEOPLE, ',').unwrap()
+    }
+
+    #[test]
+    fn test_read_records_rfc4180_quoting() {
+        let (headers, records) = people();
+        assert_eq!(headers, vec!["name", "age", "city", "score"]);
+        assert_eq!(records.len(), 3);
+        assert_eq!(&records[0][2], "Oslo, Norway");
+        assert_eq!(&records[2][0], "Carol \"CJ\"");
+        assert_eq!(&records[2][2], "Trondheim\nNorth");
+    }
+
+    #[test]
+    fn test_column_stats_mean() {
+        let (headers, records) = people();
+        let stats = column_stats(&headers, &records);
+
+        let age = &stats[1];
+        assert_eq!(age.count, 3);
+        assert_eq!(age.nulls, 0);
+        assert_eq!(age.min, Some(25.0));
+        assert_eq!(age.max, Some(41.0));
+        assert_eq!(age.mean, Some(32.0));
+
+        let score = &stats[3];
+        assert_eq!(score.count, 2);
+        assert_eq!(score.nulls, 1);
+        assert_eq!(score.mean, Some(79.25));
+
+        // Text columns have no numeric statistics
+        assert_eq!(stats[0].mean, None);
+        assert_eq!(stats[2].min, None);
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let (headers, records) = people();
+        let indices = resolve_columns(&headers, &["name".to_string(), "age".to_string()]).unwrap();
+        assert_eq!(indices, vec![0, 1]);
+        assert_eq!(select_fields(&records[1], &indices), vec!["Bob", "25"]);
+
+        // Reordering and indices
+        let indices = resolve_columns(&headers, &["3".to_string(), "name".to_string()]).unwrap();
+        assert_eq!(select_fields(&records[0], &indices), vec!["88.5", "Alice"]);
+
+        assert!(resolve_columns(&headers, &["missing".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_row_filter() {
+        let (headers, records) = people();
+        let filter = RowFilter::new("age > 28", &headers).unwrap();
+        let kept: Vec<&str> = records
             .iter()
-            .filter_map(|&i| record.get(i))
+            .filter(|r| filter.matches(r).unwrap())
+            .map(|r| &r[0])
             .collect();
-        wtr.write_record(&selected)?;
+        assert_eq!(kept, vec!["Alice", "Carol \"CJ\""]);
+
+        // Empty cells never match
+        let filter = RowFilter::new("score >= 0", &headers).unwrap();
+        assert!(!filter.matches(&records[1]).unwrap());
+
+        assert!(RowFilter::new("salary > 10", &headers).is_err());
+        assert!(RowFilter::new("age >", &headers).is_err());
     }
 
-    wtr.flush()?;
-    Ok(())
+    #[test]
+    fn test_format_stat() {
+        assert_eq!(format_stat(32.0), "32");
+        assert_eq!(format_stat(79.25), "79.25");
+        assert_eq!(format_stat(1.0 / 3.0), "0.3333");
+    }
 }
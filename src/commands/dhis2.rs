@@ -1,27 +1,48 @@
 //! DHIS2 command implementation.
 
 use crate::cli::commands::dhis2::{Dhis2Args, Dhis2Command};
-use anyhow::Result;
+use crate::config::Settings;
+use anyhow::{Context, Result};
 
 pub fn run(args: Dhis2Args) -> Result<()> {
-    let client = dx_dhis2::Dhis2Client::new(&args.server, &args.user, &args.password)?;
+    // UIDs are generated locally, without a server
+    if let Dhis2Command::Uid {
+        number,
+        count,
+        ref validate,
+        json,
+        plain,
+    } = args.command
+    {
+        let count = number.or(count).unwrap_or(1);
+        return dx_dhis2::uid::run(count, validate.clone(), json, plain);
+    }
+
+    let settings = if args.server.is_none() || args.user.is_none() || args.password.is_none() {
+        Some(Settings::load().context("Failed to load config for DHIS2 connection settings")?)
+    } else {
+        None
+    };
+    let setting = |flag: Option<String>, key: &str, default: &str| -> Result<String> {
+        connection_setting(flag, settings.as_ref(), key, default)
+    };
+    let server = setting(args.server, "dhis2.server", dx_dhis2::DEFAULT_SERVER)?;
+    let user = setting(args.user, "dhis2.user", dx_dhis2::DEFAULT_USER)?;
+    let password = setting(args.password, "dhis2.password", dx_dhis2::DEFAULT_PASSWORD)?;
+    let client = dx_dhis2::Dhis2Client::new(&server, &user, &password)?;
 
     match args.command {
-        Dhis2Command::Uid {
-            count,
-            validate,
-            json,
-            plain,
-        } => dx_dhis2::uid::run(count, validate, json, plain),
+        Dhis2Command::Uid { .. } => unreachable!("handled above"),
 
-        Dhis2Command::Info => dx_dhis2::info::run(&client),
+        Dhis2Command::Info { json } => dx_dhis2::info::run(&client, json),
 
         Dhis2Command::OrgUnits {
             level,
+            page,
             limit,
             geometry,
             json,
-        } => dx_dhis2::org_units::run(&client, level, limit, geometry, json),
+        } => dx_dhis2::org_units::run(&client, level, page, limit, geometry, json),
 
         Dhis2Command::DataElements {
             limit,
@@ -39,6 +60,14 @@ pub fn run(args: Dhis2Args) -> Result<()> {
             dx_dhis2::org_unit_group_sets::run(&client, limit, json)
         }
 
+        Dhis2Command::DataElementGroups { limit, json } => {
+            dx_dhis2::data_element_groups::run(&client, limit, json)
+        }
+
+        Dhis2Command::DataElementGroupSets { limit, json } => {
+            dx_dhis2::data_element_group_sets::run(&client, limit, json)
+        }
+
         Dhis2Command::DataValues {
             data_set,
             org_unit,
@@ -54,3 +83,52 @@ pub fn run(args: Dhis2Args) -> Result<()> {
         Dhis2Command::Tui => dx_dhis2::tui::run(client),
     }
 }
+
+/// A connection setting: the flag (or its `DHIS2_*` variable), then `key` in
+/// the config file (or its `DX_*` variable), then `default`.
+fn connection_setting(
+    flag: Option<String>,
+    settings: Option<&Settings>,
+    key: &str,
+    default: &str,
+) -> Result<String> {
+    if let Some(value) = flag {
+        return Ok(value);
+    }
+    let configured = match settings {
+        Some(settings) => settings.resolve(key, None)?,
+        None => None,
+    };
+    Ok(configured.unwrap_or_else(|| default.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_setting_precedence() {
+        let mut settings = Settings::default();
+        settings
+            .set("dhis2.test_server", "https://dhis2.example.org")
+            .unwrap();
+
+        let flag = Some("http://localhost:8080".to_string());
+        assert_eq!(
+            connection_setting(flag, Some(&settings), "dhis2.test_server", "default").unwrap(),
+            "http://localhost:8080"
+        );
+        assert_eq!(
+            connection_setting(None, Some(&settings), "dhis2.test_server", "default").unwrap(),
+            "https://dhis2.example.org"
+        );
+        assert_eq!(
+            connection_setting(None, Some(&settings), "dhis2.test_user", "admin").unwrap(),
+            "admin"
+        );
+        assert_eq!(
+            connection_setting(None, None, "dhis2.test_user", "admin").unwrap(),
+            "admin"
+        );
+    }
+}
@@ -4,6 +4,7 @@
 //! - First character: a-zA-Z (letter)
 //! - Remaining 10: a-zA-Z0-9 (alphanumeric)
 
+use crate::utils::command_rng;
 use anyhow::Result;
 use colored::Colorize;
 use rand::Rng;
@@ -16,7 +17,7 @@ const OTHER_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXY
 
 /// Generate a single DHIS2 UID.
 pub fn generate_uid() -> String {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     let mut uid = String::with_capacity(11);
 
     // First character must be a letter
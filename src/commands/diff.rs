@@ -1,9 +1,18 @@
 //! Diff command - text diffing utilities.
+//!
+//! Lines are compared with a longest-common-subsequence (LCS) table. The
+//! common prefix and suffix are stripped first, so the quadratic table only
+//! covers the region that actually changed. Unified output follows GNU
+//! `diff -u`: hunks are `@@ -l,s +l,s @@`, a length of 1 is written as just
+//! the line number, and an empty range points at the line before it.
+//!
+//! With `--word`, changed lines are paired up and diffed again at the word
+//! level, and each pair is printed as one line with the differing words
+//! marked `[-old-]{+new+}` (or colored when color is on).
 
-use crate::cli::commands::diff::{DiffArgs, DiffFormat};
+use crate::cli::commands::diff::{ColorWhen, DiffArgs, DiffFormat};
 use anyhow::{Context, Result};
-use colored::Colorize;
-use similar::{ChangeTag, TextDiff};
+use colored::{Color, Colorize};
 use std::fs;
 
 /// Run the diff command
@@ -13,74 +22,701 @@ pub fn run(args: DiffArgs) -> Result<()> {
     let text2 = fs::read_to_string(&args.file2)
         .with_context(|| format!("Failed to read file: {}", args.file2.display()))?;
 
-    let diff = TextDiff::from_lines(&text1, &text2);
-
-    match args.format {
-        DiffFormat::Unified => {
-            println!("{}", format!("--- {}", args.file1.display()).red());
-            println!("{}", format!("+++ {}", args.file2.display()).green());
-
-            for hunk in diff
-                .unified_diff()
-                .context_radius(args.context)
-                .iter_hunks()
-            {
-                println!("{}", format!("{}", hunk.header()).cyan());
-                for change in hunk.iter_changes() {
-                    let line = match change.tag() {
-                        ChangeTag::Delete => format!("-{}", change).red().to_string(),
-                        ChangeTag::Insert => format!("+{}", change).green().to_string(),
-                        ChangeTag::Equal => format!(" {}", change).to_string(),
-                    };
-                    print!("{}", line);
+    let color = match args.color {
+        ColorWhen::Always => {
+            colored::control::set_override(true);
+            true
+        }
+        ColorWhen::Never => false,
+        ColorWhen::Auto => colored::control::SHOULD_COLORIZE.should_colorize(),
+    };
+
+    let output = match args.format {
+        _ if args.word => word_diff(
+            &args.file1.display().to_string(),
+            &args.file2.display().to_string(),
+            &text1,
+            &text2,
+            args.context,
+            color,
+        ),
+        DiffFormat::Unified => unified_diff(
+            &args.file1.display().to_string(),
+            &args.file2.display().to_string(),
+            &text1,
+            &text2,
+            args.context,
+            color,
+        ),
+        DiffFormat::Inline => inline_diff(&text1, &text2, color),
+        DiffFormat::Compact => compact_diff(&text1, &text2, color),
+    };
+    print!("{}", output);
+
+    Ok(())
+}
+
+/// One step of an edit script, holding indices into the old and new sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// Item present in both sequences
+    Equal { old: usize, new: usize },
+    /// Item only in the old sequence
+    Delete { old: usize },
+    /// Item only in the new sequence
+    Insert { new: usize },
+}
+
+/// Compute a minimal edit script turning `old` into `new`.
+///
+/// Uses Hirschberg's divide-and-conquer LCS, so memory stays linear in the
+/// input size. Within a change, deletions are emitted before insertions.
+pub fn lcs_diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut edits: Vec<Edit> = (0..prefix)
+        .map(|k| Edit::Equal { old: k, new: k })
+        .collect();
+
+    hirschberg(
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+        prefix,
+        prefix,
+        &mut edits,
+    );
+
+    edits.extend((0..suffix).map(|k| Edit::Equal {
+        old: old.len() - suffix + k,
+        new: new.len() - suffix + k,
+    }));
+
+    // Inside each run of changes, list deletions before insertions
+    let mut start = 0;
+    while start < edits.len() {
+        let end = edits[start..]
+            .iter()
+            .position(|e| matches!(e, Edit::Equal { .. }))
+            .map_or(edits.len(), |p| start + p);
+        edits[start..end].sort_by_key(|e| matches!(e, Edit::Insert { .. }));
+        start = end + 1;
+    }
+    edits
+}
+
+/// Append the edits aligning `a` and `b`, offset by their positions in the
+/// full sequences.
+fn hirschberg<T: PartialEq>(a: &[T], b: &[T], a_off: usize, b_off: usize, edits: &mut Vec<Edit>) {
+    if a.is_empty() {
+        edits.extend((0..b.len()).map(|j| Edit::Insert { new: b_off + j }));
+        return;
+    }
+    if b.is_empty() {
+        edits.extend((0..a.len()).map(|i| Edit::Delete { old: a_off + i }));
+        return;
+    }
+    if a.len() == 1 {
+        match b.iter().position(|x| *x == a[0]) {
+            Some(j) => {
+                edits.extend((0..j).map(|k| Edit::Insert { new: b_off + k }));
+                edits.push(Edit::Equal {
+                    old: a_off,
+                    new: b_off + j,
+                });
+                edits.extend((j + 1..b.len()).map(|k| Edit::Insert { new: b_off + k }));
+            }
+            None => {
+                edits.push(Edit::Delete { old: a_off });
+                edits.extend((0..b.len()).map(|k| Edit::Insert { new: b_off + k }));
+            }
+        }
+        return;
+    }
+
+    let mid = a.len() / 2;
+    let forward = lcs_prefix_lengths(&a[..mid], b);
+    let backward = lcs_suffix_lengths(&a[mid..], b);
+    let split = (0..=b.len())
+        .max_by_key(|&j| (forward[j] + backward[j], std::cmp::Reverse(j)))
+        .unwrap_or(0);
+
+    hirschberg(&a[..mid], &b[..split], a_off, b_off, edits);
+    hirschberg(&a[mid..], &b[split..], a_off + mid, b_off + split, edits);
+}
+
+/// `row[j]` = length of the LCS of `a` and `b[..j]`.
+fn lcs_prefix_lengths<T: PartialEq>(a: &[T], b: &[T]) -> Vec<usize> {
+    let mut row = vec![0; b.len() + 1];
+    for x in a {
+        let mut diag = 0;
+        for j in 0..b.len() {
+            let up = row[j + 1];
+            row[j + 1] = if *x == b[j] { diag + 1 } else { up.max(row[j]) };
+            diag = up;
+        }
+    }
+    row
+}
+
+/// `row[j]` = length of the LCS of `a` and `b[j..]`.
+fn lcs_suffix_lengths<T: PartialEq>(a: &[T], b: &[T]) -> Vec<usize> {
+    let mut row = vec![0; b.len() + 1];
+    for x in a.iter().rev() {
+        let mut diag = 0;
+        for j in (0..b.len()).rev() {
+            let up = row[j];
+            row[j] = if *x == b[j] {
+                diag + 1
+            } else {
+                up.max(row[j + 1])
+            };
+            diag = up;
+        }
+    }
+    row
+}
+
+/// A group of nearby changes with surrounding context.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// First old line (1-based), or the line before an empty range
+    pub old_start: usize,
+    /// Number of old lines covered
+    pub old_len: usize,
+    /// First new line (1-based), or the line before an empty range
+    pub new_start: usize,
+    /// Number of new lines covered
+    pub new_len: usize,
+    /// Edits in this hunk, including context
+    pub edits: Vec<Edit>,
+}
+
+impl Hunk {
+    /// The `@@ -l,s +l,s @@` header line.
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{} +{} @@",
+            format_range(self.old_start, self.old_len),
+            format_range(self.new_start, self.new_len)
+        )
+    }
+}
+
+fn format_range(start: usize, len: usize) -> String {
+    if len == 1 {
+        start.to_string()
+    } else {
+        format!("{},{}", start, len)
+    }
+}
+
+/// Group an edit script into hunks with `context` lines around each change.
+///
+/// Changes separated by at most `2 * context` unchanged lines share a hunk.
+pub fn hunks(edits: &[Edit], context: usize) -> Vec<Hunk> {
+    let changes: Vec<usize> = edits
+        .iter()
+        .enumerate()
+        .filter(|(_, e)| !matches!(e, Edit::Equal { .. }))
+        .map(|(k, _)| k)
+        .collect();
+
+    // Old/new lines consumed before each edit position
+    let mut old_before = Vec::with_capacity(edits.len() + 1);
+    let mut new_before = Vec::with_capacity(edits.len() + 1);
+    let (mut old_pos, mut new_pos) = (0, 0);
+    for edit in edits {
+        old_before.push(old_pos);
+        new_before.push(new_pos);
+        match edit {
+            Edit::Equal { .. } => {
+                old_pos += 1;
+                new_pos += 1;
+            }
+            Edit::Delete { .. } => old_pos += 1,
+            Edit::Insert { .. } => new_pos += 1,
+        }
+    }
+    old_before.push(old_pos);
+    new_before.push(new_pos);
+
+    let mut result = Vec::new();
+    let mut k = 0;
+    while k < changes.len() {
+        let first = changes[k];
+        let mut last = first;
+        while k + 1 < changes.len() && changes[k + 1] - last - 1 <= 2 * context {
+            k += 1;
+            last = changes[k];
+        }
+        k += 1;
+
+        let lo = first.saturating_sub(context);
+        let hi = (last + 1 + context).min(edits.len());
+        let old_len = old_before[hi] - old_before[lo];
+        let new_len = new_before[hi] - new_before[lo];
+
+        result.push(Hunk {
+            old_start: old_before[lo] + usize::from(old_len > 0),
+            old_len,
+            new_start: new_before[lo] + usize::from(new_len > 0),
+            new_len,
+            edits: edits[lo..hi].to_vec(),
+        });
+    }
+    result
+}
+
+/// Render a unified diff. Returns an empty string when the texts are equal.
+pub fn unified_diff(
+    old_name: &str,
+    new_name: &str,
+    old: &str,
+    new: &str,
+    context: usize,
+    color: bool,
+) -> String {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let edits = lcs_diff(&old_lines, &new_lines);
+    let hunks = hunks(&edits, context);
+
+    let mut out = String::new();
+    if hunks.is_empty() {
+        return out;
+    }
+
+    out.push_str(&paint(format!("--- {}", old_name), color, Color::Red));
+    out.push('\n');
+    out.push_str(&paint(format!("+++ {}", new_name), color, Color::Green));
+    out.push('\n');
+
+    for hunk in &hunks {
+        out.push_str(&paint(hunk.header(), color, Color::Cyan));
+        out.push('\n');
+        for edit in &hunk.edits {
+            match *edit {
+                Edit::Equal { old, .. } => push_line(&mut out, ' ', old_lines[old], color, None),
+                Edit::Delete { old } => {
+                    push_line(&mut out, '-', old_lines[old], color, Some(Color::Red))
+                }
+                Edit::Insert { new } => {
+                    push_line(&mut out, '+', new_lines[new], color, Some(Color::Green))
                 }
             }
         }
-        DiffFormat::Inline => {
-            for change in diff.iter_all_changes() {
-                match change.tag() {
-                    ChangeTag::Delete => {
-                        print!("{}", format!("[-{}]", change.value().trim_end()).red());
-                    }
-                    ChangeTag::Insert => {
-                        print!("{}", format!("[+{}]", change.value().trim_end()).green());
-                    }
-                    ChangeTag::Equal => {
-                        print!("{}", change.value());
-                    }
+    }
+    out
+}
+
+/// Render a unified diff with word-level highlighting inside changed lines.
+///
+/// Within each run of changed lines, the n-th deleted line is paired with the
+/// n-th inserted line and printed once with its word changes marked; lines
+/// left without a partner are marked whole.
+pub fn word_diff(
+    old_name: &str,
+    new_name: &str,
+    old: &str,
+    new: &str,
+    context: usize,
+    color: bool,
+) -> String {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+    let edits = lcs_diff(&old_lines, &new_lines);
+    let hunks = hunks(&edits, context);
+
+    let mut out = String::new();
+    if hunks.is_empty() {
+        return out;
+    }
+
+    out.push_str(&paint(format!("--- {}", old_name), color, Color::Red));
+    out.push('\n');
+    out.push_str(&paint(format!("+++ {}", new_name), color, Color::Green));
+    out.push('\n');
+
+    for hunk in &hunks {
+        out.push_str(&paint(hunk.header(), color, Color::Cyan));
+        out.push('\n');
+
+        let mut k = 0;
+        while k < hunk.edits.len() {
+            if let Edit::Equal { old, .. } = hunk.edits[k] {
+                out.push_str(trim_newline(old_lines[old]));
+                out.push('\n');
+                k += 1;
+                continue;
+            }
+
+            // Collect the run of changed lines
+            let (mut deleted, mut inserted) = (Vec::new(), Vec::new());
+            while let Some(edit) = hunk.edits.get(k) {
+                match *edit {
+                    Edit::Delete { old } => deleted.push(trim_newline(old_lines[old])),
+                    Edit::Insert { new } => inserted.push(trim_newline(new_lines[new])),
+                    Edit::Equal { .. } => break,
                 }
+                k += 1;
+            }
+
+            for pair in 0..deleted.len().max(inserted.len()) {
+                let line = match (deleted.get(pair), inserted.get(pair)) {
+                    (Some(old), Some(new)) => word_diff_line(old, new, color),
+                    (Some(old), None) => mark_deleted(old, color),
+                    (None, Some(new)) => mark_inserted(new, color),
+                    (None, None) => unreachable!(),
+                };
+                out.push_str(&line);
+                out.push('\n');
             }
         }
-        DiffFormat::Compact => {
-            let mut line_num_old = 0usize;
-            let mut line_num_new = 0usize;
-
-            for change in diff.iter_all_changes() {
-                match change.tag() {
-                    ChangeTag::Delete => {
-                        line_num_old += 1;
-                        println!(
-                            "{}",
-                            format!("{:4} -    | {}", line_num_old, change.value().trim_end())
-                                .red()
-                        );
-                    }
-                    ChangeTag::Insert => {
-                        line_num_new += 1;
-                        println!(
-                            "{}",
-                            format!("     + {:3}| {}", line_num_new, change.value().trim_end())
-                                .green()
-                        );
-                    }
-                    ChangeTag::Equal => {
-                        line_num_old += 1;
-                        line_num_new += 1;
-                    }
+    }
+    out
+}
+
+/// Diff two lines word by word, marking removed and added words.
+pub fn word_diff_line(old: &str, new: &str, color: bool) -> String {
+    let old_words = tokenize_words(old);
+    let new_words = tokenize_words(new);
+
+    let mut out = String::new();
+    let (mut deleted, mut inserted) = (String::new(), String::new());
+    for edit in lcs_diff(&old_words, &new_words) {
+        match edit {
+            Edit::Equal { old, .. } => {
+                flush_words(&mut out, &mut deleted, &mut inserted, color);
+                out.push_str(old_words[old]);
+            }
+            Edit::Delete { old } => deleted.push_str(old_words[old]),
+            Edit::Insert { new } => inserted.push_str(new_words[new]),
+        }
+    }
+    flush_words(&mut out, &mut deleted, &mut inserted, color);
+    out
+}
+
+/// Emit and clear the pending removed/added words.
+fn flush_words(out: &mut String, deleted: &mut String, inserted: &mut String, color: bool) {
+    if !deleted.is_empty() {
+        out.push_str(&mark_deleted(deleted, color));
+        deleted.clear();
+    }
+    if !inserted.is_empty() {
+        out.push_str(&mark_inserted(inserted, color));
+        inserted.clear();
+    }
+}
+
+/// Split a line into words, whitespace runs, and single punctuation marks.
+///
+/// Concatenating the tokens gives back the original line.
+pub fn tokenize_words(line: &str) -> Vec<&str> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Space,
+        Other,
+    }
+    let class = |c: char| {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Space
+        } else {
+            Class::Other
+        }
+    };
+
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let current = class(c);
+        let joins = current != Class::Other
+            && chars
+                .peek()
+                .is_some_and(|&(_, next)| class(next) == current);
+        if !joins {
+            let end = i + c.len_utf8();
+            tokens.push(&line[start..end]);
+            start = end;
+        }
+    }
+    tokens
+}
+
+fn mark_deleted(text: &str, color: bool) -> String {
+    if color {
+        paint(text.to_string(), true, Color::Red)
+    } else {
+        format!("[-{}-]", text)
+    }
+}
+
+fn mark_inserted(text: &str, color: bool) -> String {
+    if color {
+        paint(text.to_string(), true, Color::Green)
+    } else {
+        format!("{{+{}+}}", text)
+    }
+}
+
+fn trim_newline(line: &str) -> &str {
+    line.strip_suffix('\n').unwrap_or(line)
+}
+
+/// Render changes inline as `[-old]` and `[+new]` markers within the text.
+pub fn inline_diff(old: &str, new: &str, color: bool) -> String {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+
+    let mut out = String::new();
+    for edit in lcs_diff(&old_lines, &new_lines) {
+        match edit {
+            Edit::Equal { old, .. } => out.push_str(old_lines[old]),
+            Edit::Delete { old } => out.push_str(&paint(
+                format!("[-{}]", old_lines[old].trim_end()),
+                color,
+                Color::Red,
+            )),
+            Edit::Insert { new } => out.push_str(&paint(
+                format!("[+{}]", new_lines[new].trim_end()),
+                color,
+                Color::Green,
+            )),
+        }
+    }
+    out
+}
+
+/// Render only the changed lines, numbered by their position in each file.
+pub fn compact_diff(old: &str, new: &str, color: bool) -> String {
+    let old_lines = split_lines(old);
+    let new_lines = split_lines(new);
+
+    let mut out = String::new();
+    for edit in lcs_diff(&old_lines, &new_lines) {
+        let line = match edit {
+            Edit::Equal { .. } => continue,
+            Edit::Delete { old } => paint(
+                format!("{:4} -    | {}", old + 1, old_lines[old].trim_end()),
+                color,
+                Color::Red,
+            ),
+            Edit::Insert { new } => paint(
+                format!("     + {:3}| {}", new + 1, new_lines[new].trim_end()),
+                color,
+                Color::Green,
+            ),
+        };
+        out.push_str(&line);
+        out.push('\n');
+    }
+    out
+}
+
+/// Split text into lines, keeping each line's terminator so that a missing
+/// final newline counts as a difference.
+fn split_lines(text: &str) -> Vec<&str> {
+    text.split_inclusive('\n').collect()
+}
+
+/// Append a prefixed diff line, noting a missing final newline like GNU diff.
+fn push_line(out: &mut String, prefix: char, line: &str, color: bool, tint: Option<Color>) {
+    let body = format!("{}{}", prefix, line.strip_suffix('\n').unwrap_or(line));
+    match tint {
+        Some(c) => out.push_str(&paint(body, color, c)),
+        None => out.push_str(&body),
+    }
+    out.push('\n');
+    if !line.ends_with('\n') {
+        out.push_str("\\ No newline at end of file\n");
+    }
+}
+
+fn paint(text: String, color: bool, c: Color) -> String {
+    if color {
+        text.color(c).to_string()
+    } else {
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numbered(n: usize) -> String {
+        (1..=n).map(|i| format!("line {}\n", i)).collect()
+    }
+
+    #[test]
+    fn test_lcs_diff() {
+        let edits = lcs_diff(&["a", "b", "c"], &["a", "x", "c", "d"]);
+        assert_eq!(
+            edits,
+            vec![
+                Edit::Equal { old: 0, new: 0 },
+                Edit::Delete { old: 1 },
+                Edit::Insert { new: 1 },
+                Edit::Equal { old: 2, new: 2 },
+                Edit::Insert { new: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lcs_diff_identical_and_empty() {
+        assert!(lcs_diff(&["a", "b"], &["a", "b"])
+            .iter()
+            .all(|e| matches!(e, Edit::Equal { .. })));
+        assert_eq!(lcs_diff::<&str>(&[], &["a"]), vec![Edit::Insert { new: 0 }]);
+        assert_eq!(lcs_diff::<&str>(&["a"], &[]), vec![Edit::Delete { old: 0 }]);
+    }
+
+    #[test]
+    fn test_lcs_diff_is_minimal_and_complete() {
+        let old: Vec<char> = "ABCBDABXYZQRS".chars().collect();
+        let new: Vec<char> = "BDCABAQXRSZY".chars().collect();
+        let edits = lcs_diff(&old, &new);
+
+        let mut rebuilt = Vec::new();
+        let (mut seen_old, mut seen_new) = (0, 0);
+        for edit in &edits {
+            match *edit {
+                Edit::Equal { old: i, new: j } => {
+                    assert_eq!(old[i], new[j]);
+                    assert_eq!((i, j), (seen_old, seen_new));
+                    rebuilt.push(new[j]);
+                    seen_old += 1;
+                    seen_new += 1;
+                }
+                Edit::Delete { old: i } => {
+                    assert_eq!(i, seen_old);
+                    seen_old += 1;
+                }
+                Edit::Insert { new: j } => {
+                    assert_eq!(j, seen_new);
+                    rebuilt.push(new[j]);
+                    seen_new += 1;
                 }
             }
         }
+        assert_eq!(rebuilt, new);
+        assert_eq!((seen_old, seen_new), (old.len(), new.len()));
+
+        let common = edits
+            .iter()
+            .filter(|e| matches!(e, Edit::Equal { .. }))
+            .count();
+        assert_eq!(common, lcs_prefix_lengths(&old, &new)[new.len()]);
     }
 
-    Ok(())
+    #[test]
+    fn test_unified_single_change() {
+        let old = "one\ntwo\nthree\n";
+        let new = "one\n2\nthree\n";
+        assert_eq!(
+            unified_diff("a.txt", "b.txt", old, new, 3, false),
+            "--- a.txt\n+++ b.txt\n@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n"
+        );
+    }
+
+    #[test]
+    fn test_unified_context_and_separate_hunks() {
+        let old = numbered(20);
+        let new = old
+            .replace("line 2\n", "line two\n")
+            .replace("line 18\n", "line eighteen\n");
+
+        let diff = unified_diff("a", "b", &old, &new, 3, false);
+        let headers: Vec<&str> = diff.lines().filter(|l| l.starts_with("@@")).collect();
+        assert_eq!(headers, vec!["@@ -1,5 +1,5 @@", "@@ -15,6 +15,6 @@"]);
+        assert!(diff.contains("\n-line 2\n+line two\n"));
+        assert!(diff.contains("\n-line 18\n+line eighteen\n"));
+
+        // Wide context merges both changes into one hunk
+        let merged = unified_diff("a", "b", &old, &new, 8, false);
+        assert_eq!(merged.matches("@@ -").count(), 1);
+        assert!(merged.contains("@@ -1,20 +1,20 @@"));
+    }
+
+    #[test]
+    fn test_unified_zero_context() {
+        let old = "a\nb\nc\n";
+        let new = "a\nc\n";
+        assert_eq!(
+            unified_diff("a", "b", old, new, 0, false),
+            "--- a\n+++ b\n@@ -2 +1,0 @@\n-b\n"
+        );
+
+        let added = unified_diff("a", "b", "a\n", "a\nb\n", 0, false);
+        assert!(added.contains("@@ -1,0 +2 @@\n+b\n"));
+    }
+
+    #[test]
+    fn test_unified_missing_newline() {
+        let diff = unified_diff("a", "b", "a\nb", "a\nb\n", 3, false);
+        assert!(diff.contains("-b\n\\ No newline at end of file\n+b\n"));
+    }
+
+    #[test]
+    fn test_unified_identical() {
+        assert_eq!(unified_diff("a", "b", "same\n", "same\n", 3, false), "");
+    }
+
+    #[test]
+    fn test_tokenize_words() {
+        assert_eq!(
+            tokenize_words("let x = foo(1, 2);"),
+            vec!["let", " ", "x", " ", "=", " ", "foo", "(", "1", ",", " ", "2", ")", ";"]
+        );
+        assert_eq!(tokenize_words("a  b").concat(), "a  b");
+        assert!(tokenize_words("").is_empty());
+    }
+
+    #[test]
+    fn test_word_diff_line_single_word() {
+        assert_eq!(
+            word_diff_line("the quick brown fox", "the quick red fox", false),
+            "the quick [-brown-]{+red+} fox"
+        );
+    }
+
+    #[test]
+    fn test_word_diff_line_insert_and_delete() {
+        assert_eq!(word_diff_line("a b c", "a c d", false), "a [-b -]c{+ d+}");
+        assert_eq!(word_diff_line("same", "same", false), "same");
+    }
+
+    #[test]
+    fn test_word_diff() {
+        let old = "one\nthe quick brown fox\nthree\n";
+        let new = "one\nthe quick red fox\nthree\nfour\n";
+        assert_eq!(
+            word_diff("a", "b", old, new, 3, false),
+            "--- a\n+++ b\n@@ -1,3 +1,4 @@\none\nthe quick [-brown-]{+red+} fox\nthree\n{+four+}\n"
+        );
+    }
+
+    #[test]
+    fn test_compact_diff() {
+        assert_eq!(
+            compact_diff("a\nb\n", "a\nc\n", false),
+            "   2 -    | b\n     +   2| c\n"
+        );
+    }
+
+    #[test]
+    fn test_inline_diff() {
+        assert_eq!(inline_diff("a\nb\n", "a\nc\n", false), "a\n[-b][+c]");
+    }
 }
@@ -3313,7 +3313,7 @@ impl eframe::App for TextStatsApp {
                                 }
                             }
                             let mut freq_vec: Vec<_> = word_freq.into_iter().collect();
-                            freq_vec.sort_by(|a, b| b.1.cmp(&a.1));
+                            freq_vec.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
 
                             egui::ScrollArea::vertical()
                                 .max_height(SCROLL_HEIGHT_SMALL)
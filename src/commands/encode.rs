@@ -7,6 +7,15 @@
 //!
 //! - **Base64**: Binary-to-text encoding using 64 ASCII characters
 //! - **Hex**: Binary-to-text encoding using hexadecimal (0-9, a-f)
+//! - **Base32**: RFC 4648 encoding using A-Z and 2-7 (case-insensitive)
+//! - **Base58**: Bitcoin alphabet, which drops the look-alikes 0, O, I, and l
+//!
+//! ## Streaming
+//!
+//! Encoding reads input in fixed-size chunks aligned to the base64 and
+//! base32 group sizes and writes each chunk as it goes, so multi-gigabyte
+//! files can be encoded in constant memory. Base58 is the exception: it is
+//! a base conversion of the whole input and must read it fully.
 //!
 //! ## Base64 Variants
 //!
@@ -29,6 +38,8 @@
 //! dx encode --decode "aGVsbG8="        # Decode base64
 //! dx encode --url-safe "hello"         # URL-safe base64
 //! dx encode --no-padding "hello"       # No padding
+//! dx encode -f base32 "hello"          # Base32 encode
+//! dx encode -f base58 -d "Cn8eVZg"     # Base58 decode
 //! ```
 //!
 //! ## External Documentation
@@ -40,7 +51,7 @@ use crate::cli::commands::encode::{EncodeArgs, EncodingFormat};
 use anyhow::{bail, Context, Result};
 use base64::{engine::general_purpose, Engine};
 use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, BufWriter, Read, Write};
 use std::path::Path;
 
 /// Run the encode/decode command with the provided arguments.
@@ -55,23 +66,45 @@ use std::path::Path;
 /// * `Ok(())` on success, prints result to stdout
 /// * `Err` if input cannot be read or decoded data is invalid
 pub fn run(args: EncodeArgs) -> Result<()> {
-    // Get input data from string, file, or stdin
-    let data = get_input(&args)?;
-
-    // Encode or decode based on the --decode flag
-    let result = if args.decode {
+    if args.decode {
         // Decoding: convert encoded text back to original
-        decode(&data, args.format)?
-    } else {
-        // Encoding: convert binary data to text representation
-        encode(&data, args.format, args.url_safe, args.no_padding)
-    };
+        let data = get_input(&args)?;
+        println!("{}", decode(&data, args.format)?);
+        return Ok(());
+    }
 
-    // Print result (no trailing newline issues since println adds one)
-    println!("{}", result);
+    // Encoding: stream binary data to its text representation so large
+    // files never have to be held in memory
+    let reader = open_input(&args)?;
+    let stdout = io::stdout();
+    let mut writer = BufWriter::new(stdout.lock());
+    encode_stream(
+        reader,
+        &mut writer,
+        args.format,
+        args.url_safe,
+        args.no_padding,
+    )?;
+    writeln!(writer)?;
+    writer.flush()?;
     Ok(())
 }
 
+/// Open the input as a reader, following the same priority as [`get_input`].
+fn open_input(args: &EncodeArgs) -> Result<Box<dyn Read>> {
+    if let Some(s) = &args.string {
+        return Ok(Box::new(io::Cursor::new(s.clone().into_bytes())));
+    }
+    match &args.input {
+        Some(path) if path.to_string_lossy() != "-" => {
+            let file =
+                File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+            Ok(Box::new(file))
+        }
+        _ => Ok(Box::new(io::stdin())),
+    }
+}
+
 /// Get input data from one of three sources.
 ///
 /// Priority order:
@@ -136,9 +169,9 @@ fn read_file(path: &Path) -> Result<Vec<u8>> {
 ///
 /// # Arguments
 /// * `data` - Raw bytes to encode
-/// * `format` - Base64 or Hex
+/// * `format` - Output encoding
 /// * `url_safe` - Use URL-safe alphabet (- and _ instead of + and /)
-/// * `no_padding` - Omit trailing = padding characters
+/// * `no_padding` - Omit trailing = padding characters (base64 and base32)
 ///
 /// # Returns
 /// The encoded string
@@ -161,7 +194,71 @@ fn encode(data: &[u8], format: EncodingFormat, url_safe: bool, no_padding: bool)
             // 0x68 = 'h', 0x65 = 'e', etc.
             hex::encode(data)
         }
+        EncodingFormat::Base32 => base32_encode(data, !no_padding),
+        EncodingFormat::Base58 => base58_encode(data),
+    }
+}
+
+/// Bytes read per chunk when streaming.
+///
+/// A multiple of 15 so every full chunk ends on both a base64 (3-byte) and a
+/// base32 (5-byte) group boundary, meaning padding can only ever appear
+/// after the final chunk.
+const STREAM_CHUNK_SIZE: usize = 15 * 4096;
+
+/// Encode everything from `reader` to `writer` in fixed-size chunks.
+///
+/// Base64, Base32, and hex are encoded chunk by chunk, so memory use stays
+/// constant regardless of input size. Base58 treats the whole input as one
+/// number and cannot be chunked, so it reads the input fully first.
+///
+/// No trailing newline is written.
+fn encode_stream<R: Read, W: Write>(
+    mut reader: R,
+    writer: &mut W,
+    format: EncodingFormat,
+    url_safe: bool,
+    no_padding: bool,
+) -> Result<()> {
+    if matches!(format, EncodingFormat::Base58) {
+        let mut data = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .context("Failed to read input")?;
+        writer.write_all(encode(&data, format, url_safe, no_padding).as_bytes())?;
+        return Ok(());
+    }
+
+    let mut buf = vec![0u8; STREAM_CHUNK_SIZE];
+    loop {
+        let n = read_full(&mut reader, &mut buf)?;
+        if n == 0 {
+            break;
+        }
+        writer.write_all(encode(&buf[..n], format, url_safe, no_padding).as_bytes())?;
+        if n < buf.len() {
+            // A short read only happens at end of input
+            break;
+        }
     }
+    Ok(())
+}
+
+/// Fill `buf` as far as possible, returning fewer bytes only at end of input.
+///
+/// A plain `read` may return short counts (e.g., from a pipe), which would
+/// break group alignment mid-stream.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e).context("Failed to read input"),
+        }
+    }
+    Ok(filled)
 }
 
 /// Decode text back to its original form.
@@ -189,7 +286,7 @@ fn encode(data: &[u8], format: EncodingFormat, url_safe: bool, no_padding: bool)
 ///
 /// # Arguments
 /// * `data` - Encoded text as bytes
-/// * `format` - Base64 or Hex
+/// * `format` - Input encoding
 ///
 /// # Returns
 /// * `Ok(String)` - The decoded text
@@ -216,6 +313,8 @@ fn decode(data: &[u8], format: EncodingFormat) -> Result<String> {
             // Hex decoding: "68656c6c6f" -> [0x68, 0x65, 0x6c, 0x6c, 0x6f]
             hex::decode(input).context("Invalid hex input")?
         }
+        EncodingFormat::Base32 => base32_decode(input).context("Invalid base32 input")?,
+        EncodingFormat::Base58 => base58_decode(input).context("Invalid base58 input")?,
     };
 
     // Try to convert decoded bytes to a UTF-8 string
@@ -228,6 +327,165 @@ fn decode(data: &[u8], format: EncodingFormat) -> Result<String> {
         })
 }
 
+/// RFC 4648 Base32 alphabet.
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Bitcoin Base58 alphabet (no 0, O, I, or l).
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encode bytes as RFC 4648 Base32.
+///
+/// Every 5 input bytes become 8 output characters of 5 bits each. When
+/// `padding` is set, the output is padded with `=` to a multiple of 8.
+fn base32_encode(data: &[u8], padding: bool) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(5) * 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    for &byte in data {
+        buffer = (buffer << 8) | u16::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[usize::from((buffer >> bits) & 0x1f)] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[usize::from((buffer << (5 - bits)) & 0x1f)] as char);
+    }
+
+    if padding {
+        while !out.len().is_multiple_of(8) {
+            out.push('=');
+        }
+    }
+    out
+}
+
+/// Decode RFC 4648 Base32, with or without padding.
+///
+/// Lowercase letters are accepted. Errors report the 0-based character
+/// position of the first invalid character. Padding, when present, must be
+/// exactly what the encoder would write, and the unused low bits of the last
+/// character must be zero, so every byte string has one accepted encoding
+/// (apart from case and optional padding).
+fn base32_decode(input: &str) -> Result<Vec<u8>> {
+    let trimmed = input.trim_end_matches('=');
+    let data_len = trimmed.chars().count();
+
+    // Only these remainders can be produced by whole bytes (RFC 4648 section 6)
+    let expected_padding = match data_len % 8 {
+        0 => 0,
+        2 => 6,
+        4 => 4,
+        5 => 3,
+        7 => 1,
+        _ => bail!(
+            "Invalid length: {} characters cannot encode whole bytes",
+            data_len
+        ),
+    };
+    let padding = input.len() - trimmed.len();
+    if padding != 0 && padding != expected_padding {
+        bail!(
+            "Invalid padding: expected {} '=' after {} characters, found {}",
+            expected_padding,
+            data_len,
+            padding
+        );
+    }
+
+    let mut out = Vec::with_capacity(data_len * 5 / 8);
+    let mut buffer: u16 = 0;
+    let mut bits = 0;
+
+    for (pos, c) in input.chars().enumerate() {
+        if pos >= data_len {
+            // Only trailing padding remains
+            break;
+        }
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| c.is_ascii() && a == c.to_ascii_uppercase() as u8)
+            .with_context(|| format!("Invalid character '{}' at position {}", c, pos))?;
+        buffer = (buffer << 5) | value as u16;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+
+    if buffer & ((1 << bits) - 1) != 0 {
+        bail!("Invalid trailing bits: the last character has non-zero unused bits");
+    }
+    Ok(out)
+}
+
+/// Encode bytes as Base58 using the Bitcoin alphabet.
+///
+/// The input is treated as one big-endian number and repeatedly divided by
+/// 58. Each leading zero byte is kept as a leading `1`.
+fn base58_encode(data: &[u8]) -> String {
+    let zeros = data.iter().take_while(|&&b| b == 0).count();
+
+    // Base-58 digits, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(data.len() * 138 / 100 + 1);
+    for &byte in &data[zeros..] {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out = String::with_capacity(zeros + digits.len());
+    out.extend(std::iter::repeat_n('1', zeros));
+    out.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| BASE58_ALPHABET[usize::from(d)] as char),
+    );
+    out
+}
+
+/// Decode Base58 using the Bitcoin alphabet.
+///
+/// Errors report the 0-based character position of the first invalid
+/// character.
+fn base58_decode(input: &str) -> Result<Vec<u8>> {
+    let zeros = input.chars().take_while(|&c| c == '1').count();
+
+    // Bytes, least significant first
+    let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+    for (pos, c) in input.chars().enumerate().skip(zeros) {
+        let value = BASE58_ALPHABET
+            .iter()
+            .position(|&a| c.is_ascii() && a == c as u8)
+            .with_context(|| format!("Invalid character '{}' at position {}", c, pos))?;
+        let mut carry = value as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let mut out = vec![0u8; zeros];
+    out.extend(bytes.iter().rev());
+    Ok(out)
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -269,4 +527,153 @@ mod tests {
         let decoded = decode(data, EncodingFormat::Hex).unwrap();
         assert_eq!(decoded, "hello");
     }
+
+    /// Test Base32 against the RFC 4648 test vectors.
+    #[test]
+    fn test_base32_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "MY======"),
+            ("fo", "MZXQ===="),
+            ("foo", "MZXW6==="),
+            ("foob", "MZXW6YQ="),
+            ("fooba", "MZXW6YTB"),
+            ("foobar", "MZXW6YTBOI======"),
+        ];
+        for (plain, encoded) in vectors {
+            assert_eq!(base32_encode(plain.as_bytes(), true), encoded);
+            assert_eq!(base32_decode(encoded).unwrap(), plain.as_bytes());
+            let unpadded = encoded.trim_end_matches('=');
+            assert_eq!(base32_encode(plain.as_bytes(), false), unpadded);
+            assert_eq!(base32_decode(unpadded).unwrap(), plain.as_bytes());
+        }
+        assert_eq!(base32_decode("mzxw6ytb").unwrap(), b"fooba");
+    }
+
+    /// Test Base58 against known Bitcoin-alphabet values.
+    #[test]
+    fn test_base58_vectors() {
+        assert_eq!(base58_encode(b""), "");
+        assert_eq!(base58_encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(base58_encode(&[0, 0, 1]), "112");
+        assert_eq!(base58_decode("StV1DL6CwTryKyV").unwrap(), b"hello world");
+        assert_eq!(base58_decode("112").unwrap(), vec![0, 0, 1]);
+    }
+
+    /// Test that arbitrary bytes survive a Base32 and Base58 round trip.
+    #[test]
+    fn test_round_trip_arbitrary_bytes() {
+        let samples: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0],
+            vec![0, 0, 0, 255],
+            vec![255; 17],
+            (0..=255).collect(),
+            (0..100).map(|i| (i * 37 % 256) as u8).collect(),
+        ];
+        for data in samples {
+            assert_eq!(base32_decode(&base32_encode(&data, true)).unwrap(), data);
+            assert_eq!(base32_decode(&base32_encode(&data, false)).unwrap(), data);
+            assert_eq!(base58_decode(&base58_encode(&data)).unwrap(), data);
+        }
+    }
+
+    /// Test that invalid characters are reported with their position.
+    #[test]
+    fn test_decode_invalid_position() {
+        let err = base32_decode("MZX1").unwrap_err();
+        assert!(err.to_string().contains("'1' at position 3"), "{}", err);
+
+        let err = base58_decode("St0V").unwrap_err();
+        assert!(err.to_string().contains("'0' at position 2"), "{}", err);
+
+        assert!(base32_decode("MZXW6Y").is_err());
+    }
+
+    /// Test that non-ASCII characters are rejected instead of truncated.
+    #[test]
+    fn test_base32_rejects_non_ascii() {
+        // 'Ł' is U+0141; truncating it to a byte would give 'A'
+        let err = base32_decode("MŁ").unwrap_err();
+        assert!(err.to_string().contains("'Ł' at position 1"), "{}", err);
+        assert!(base32_decode("MZXW6YÄB").is_err());
+    }
+
+    /// Test that padding must match what the encoder would produce.
+    #[test]
+    fn test_base32_rejects_non_canonical_padding() {
+        assert_eq!(base32_decode("MY======").unwrap(), b"f");
+        assert!(base32_decode("MY=").is_err());
+        assert!(base32_decode("MY=======").is_err());
+        assert!(base32_decode("MZXW6===").is_ok());
+        assert!(base32_decode("MZXW6=").is_err());
+        assert!(base32_decode("MZXW6YTB=").is_err());
+    }
+
+    /// Test that the unused low bits of the final character must be zero.
+    #[test]
+    fn test_base32_rejects_trailing_bits() {
+        // "MY" is 'f'; "MZ" sets one of the two unused bits
+        assert!(base32_decode("MY").is_ok());
+        let err = base32_decode("MZ").unwrap_err();
+        assert!(err.to_string().contains("trailing bits"), "{}", err);
+        assert!(base32_decode("MZXW7").is_err());
+    }
+
+    /// Test that streaming a multi-megabyte file matches a one-shot encode.
+    #[test]
+    fn test_encode_stream_large_file() {
+        use std::io::Write as _;
+
+        // Not a multiple of the chunk size, so the last chunk is partial
+        let data: Vec<u8> = (0..3 * 1024 * 1024 + 7)
+            .map(|i: u32| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(&data).unwrap();
+
+        for format in [
+            EncodingFormat::Base64,
+            EncodingFormat::Base32,
+            EncodingFormat::Hex,
+        ] {
+            for no_padding in [false, true] {
+                let reader = File::open(file.path()).unwrap();
+                let mut streamed = Vec::new();
+                encode_stream(reader, &mut streamed, format, false, no_padding).unwrap();
+                let one_shot = encode(&data, format, false, no_padding);
+                assert_eq!(String::from_utf8(streamed).unwrap(), one_shot);
+            }
+        }
+    }
+
+    /// Test that short reads from the source don't break group alignment.
+    #[test]
+    fn test_encode_stream_short_reads() {
+        /// Reader that returns at most 7 bytes per call.
+        struct Trickle<'a>(&'a [u8]);
+        impl Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let n = buf.len().min(7).min(self.0.len());
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+
+        let data = vec![0xabu8; STREAM_CHUNK_SIZE + 1];
+        let mut streamed = Vec::new();
+        encode_stream(
+            Trickle(&data),
+            &mut streamed,
+            EncodingFormat::Base64,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(streamed).unwrap(),
+            encode(&data, EncodingFormat::Base64, false, false)
+        );
+    }
 }
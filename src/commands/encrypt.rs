@@ -1,18 +1,57 @@
 //! Encrypt command - encryption utilities.
+//!
+//! Keys are derived from the password with Argon2id and a random salt. The
+//! output starts with a header recording the algorithm, the Argon2 cost
+//! parameters, the salt and the nonce:
+//!
+//! ```text
+//! "dxe1" | algorithm (1) | m_cost, t_cost, p_cost (u32 LE each) | salt (16) | nonce (12)
+//! ```
+//!
+//! followed by the ciphertext and its 16-byte tag, all base64 encoded. The
+//! header is passed to the AEAD as associated data, so changing any of it
+//! makes decryption fail just like a wrong password does.
+//!
+//! The password comes from `--password`, `--password-file` or `--keyfile`,
+//! then `$DX_ENCRYPT_PASSWORD`, then a prompt on the terminal. A keyfile's
+//! bytes go through the same key derivation as a password.
+//!
+//! Data without the header comes from older versions (nonce and ciphertext
+//! under a SHA-256 key of the password) and can still be decrypted.
 
-use crate::cli::commands::encrypt::{EncryptAlgorithm, EncryptArgs, EncryptCommand};
+use crate::cli::commands::encrypt::{
+    EncryptAlgorithm, EncryptArgs, EncryptCommand, PasswordOptions,
+};
+use crate::utils::write_atomic;
 use aes_gcm::{
-    aead::{Aead, KeyInit},
-    Aes256Gcm, Nonce as AesNonce,
+    aead::{Aead, KeyInit, Nonce, Payload},
+    Aes256Gcm,
 };
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use argon2::{Argon2, Params, Version};
 use base64::Engine;
-use chacha20poly1305::{ChaCha20Poly1305, Nonce as ChachaNonce};
+use chacha20poly1305::ChaCha20Poly1305;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
 use rand::Rng;
 use sha2::{Digest, Sha256};
 use std::fs;
-use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Start of the current format; the digit is the format version.
+const MAGIC: &[u8; 4] = b"dxe1";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const HEADER_LEN: usize = MAGIC.len() + 1 + 3 * 4 + SALT_LEN + NONCE_LEN;
+/// Largest Argon2 memory cost accepted from a header (1 GiB, in KiB)
+const MAX_M_COST: u32 = 1 << 20;
+
+/// Password used when no password option is given
+const PASSWORD_ENV: &str = "DX_ENCRYPT_PASSWORD";
+
+const DECRYPT_FAILED: &str = "Decryption failed: invalid password or corrupted data";
 
 /// Run the encrypt command
 pub fn run(args: EncryptArgs) -> Result<()> {
@@ -21,16 +60,22 @@ pub fn run(args: EncryptArgs) -> Result<()> {
             input,
             string,
             out_file,
-            password,
+            secret,
             algorithm,
-        } => cmd_encrypt(input, string, out_file, &password, algorithm),
+        } => {
+            let password = resolve_secret(&secret, std::env::var(PASSWORD_ENV).ok(), true)?;
+            cmd_encrypt(input, string, out_file, &password, algorithm)
+        }
         EncryptCommand::Decrypt {
             input,
             string,
             out_file,
-            password,
+            secret,
             algorithm,
-        } => cmd_decrypt(input, string, out_file, &password, algorithm),
+        } => {
+            let password = resolve_secret(&secret, std::env::var(PASSWORD_ENV).ok(), false)?;
+            cmd_decrypt(input, string, out_file, &password, algorithm)
+        }
     }
 }
 
@@ -63,7 +108,7 @@ fn read_input(input: Option<PathBuf>, string: Option<String>) -> Result<Vec<u8>>
 fn write_output(output: Option<PathBuf>, data: &[u8]) -> Result<()> {
     match output {
         Some(path) => {
-            fs::write(&path, data)
+            write_atomic(&path, |writer| Ok(writer.write_all(data)?))
                 .with_context(|| format!("Failed to write file: {}", path.display()))?;
         }
         None => {
@@ -76,63 +121,330 @@ fn write_output(output: Option<PathBuf>, data: &[u8]) -> Result<()> {
     Ok(())
 }
 
-fn derive_key(password: &str) -> [u8; 32] {
-    let mut hasher = Sha256::new();
-    hasher.update(password.as_bytes());
-    hasher.finalize().into()
+/// The password or key material from the first source that has one:
+/// `--keyfile`, `--password` or `--password-file` (at most one is allowed),
+/// then `env_password`, then a terminal prompt, confirmed when `confirm`.
+fn resolve_secret(
+    options: &PasswordOptions,
+    env_password: Option<String>,
+    confirm: bool,
+) -> Result<Vec<u8>> {
+    if let Some(path) = &options.keyfile {
+        let key = fs::read(path)
+            .with_context(|| format!("Failed to read keyfile: {}", path.display()))?;
+        if key.is_empty() {
+            bail!("Keyfile is empty: {}", path.display());
+        }
+        return Ok(key);
+    }
+    if let Some(password) = &options.password {
+        return Ok(password.clone().into_bytes());
+    }
+    if let Some(path) = &options.password_file {
+        return read_password_file(path);
+    }
+    if let Some(password) = env_password.filter(|p| !p.is_empty()) {
+        return Ok(password.into_bytes());
+    }
+
+    if !io::stderr().is_terminal() {
+        bail!(
+            "No password given: use --password, --password-file, --keyfile or ${}",
+            PASSWORD_ENV
+        );
+    }
+    let password = read_hidden("Password: ")?;
+    if password.is_empty() {
+        bail!("Empty password");
+    }
+    if confirm && read_hidden("Confirm password: ")? != password {
+        bail!("Passwords do not match");
+    }
+    Ok(password.into_bytes())
 }
 
-fn cmd_encrypt(
-    input: Option<PathBuf>,
-    string: Option<String>,
-    output: Option<PathBuf>,
-    password: &str,
-    algorithm: EncryptAlgorithm,
-) -> Result<()> {
-    let plaintext = read_input(input, string)?;
-    let key = derive_key(password);
+/// Read a password file, dropping one trailing `\n` or `\r\n`
+fn read_password_file(path: &Path) -> Result<Vec<u8>> {
+    let mut password = fs::read(path)
+        .with_context(|| format!("Failed to read password file: {}", path.display()))?;
+    if password.last() == Some(&b'\n') {
+        password.pop();
+        if password.last() == Some(&b'\r') {
+            password.pop();
+        }
+    }
+    if password.is_empty() {
+        bail!("Password file is empty: {}", path.display());
+    }
+    Ok(password)
+}
 
-    let ciphertext = match algorithm {
-        EncryptAlgorithm::Chacha20 => {
-            let cipher = ChaCha20Poly1305::new_from_slice(&key)
-                .map_err(|e| anyhow::anyhow!("Failed to create cipher: {}", e))?;
+/// Restores cooked terminal mode when dropped
+struct RawModeGuard;
 
-            // Generate random nonce (12 bytes for ChaCha20-Poly1305)
-            let mut nonce_bytes = [0u8; 12];
-            rand::rng().fill(&mut nonce_bytes);
-            let nonce = ChachaNonce::from_slice(&nonce_bytes);
+impl RawModeGuard {
+    fn enable() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
 
-            let encrypted = cipher
-                .encrypt(nonce, plaintext.as_ref())
-                .map_err(|e| anyhow::anyhow!("Encryption failed: {}", e))?;
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Read a line from the terminal without echoing it.
+/// Ctrl+C or Ctrl+D cancels.
+fn read_hidden(prompt: &str) -> Result<String> {
+    let mut stderr = io::stderr();
+    write!(stderr, "{}", prompt)?;
+    stderr.flush()?;
 
-            // Prepend nonce to ciphertext
-            let mut result = nonce_bytes.to_vec();
-            result.extend(encrypted);
-            result
+    let _raw = RawModeGuard::enable()?;
+    let mut password = String::new();
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
         }
-        EncryptAlgorithm::AesGcm => {
-            let cipher = Aes256Gcm::new_from_slice(&key)
-                .map_err(|e| anyhow::anyhow!("Failed to create cipher: {}", e))?;
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Enter => break,
+            KeyCode::Char('c') | KeyCode::Char('d') if ctrl => {
+                write!(stderr, "\r\n")?;
+                bail!("Password entry cancelled");
+            }
+            KeyCode::Char(_) if ctrl => {}
+            KeyCode::Char(c) => password.push(c),
+            KeyCode::Backspace => {
+                password.pop();
+            }
+            _ => {}
+        }
+    }
+    write!(stderr, "\r\n")?;
+    Ok(password)
+}
+
+/// Argon2id cost parameters, stored in the header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct KdfParams {
+    /// Memory in KiB
+    m_cost: u32,
+    /// Iterations
+    t_cost: u32,
+    /// Parallelism
+    p_cost: u32,
+}
 
-            // Generate random nonce (12 bytes for AES-GCM)
-            let mut nonce_bytes = [0u8; 12];
-            rand::rng().fill(&mut nonce_bytes);
-            let nonce = AesNonce::from_slice(&nonce_bytes);
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            m_cost: Params::DEFAULT_M_COST,
+            t_cost: Params::DEFAULT_T_COST,
+            p_cost: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+/// Everything needed to derive the key and decrypt, besides the password.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Header {
+    algorithm: EncryptAlgorithm,
+    kdf: KdfParams,
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+}
+
+impl Header {
+    /// A header with a fresh random salt and nonce
+    fn generate(algorithm: EncryptAlgorithm, kdf: KdfParams) -> Self {
+        let mut rng = rand::rng();
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        rng.fill(&mut salt);
+        rng.fill(&mut nonce);
+        Self {
+            algorithm,
+            kdf,
+            salt,
+            nonce,
+        }
+    }
 
-            let encrypted = cipher
-                .encrypt(nonce, plaintext.as_ref())
-                .map_err(|e| anyhow::anyhow!("Encryption failed: {}", e))?;
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(match self.algorithm {
+            EncryptAlgorithm::Chacha20 => 0,
+            EncryptAlgorithm::AesGcm => 1,
+        });
+        for cost in [self.kdf.m_cost, self.kdf.t_cost, self.kdf.p_cost] {
+            bytes.extend_from_slice(&cost.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.salt);
+        bytes.extend_from_slice(&self.nonce);
+        bytes
+    }
 
-            // Prepend nonce to ciphertext
-            let mut result = nonce_bytes.to_vec();
-            result.extend(encrypted);
-            result
+    /// Parse the first [`HEADER_LEN`] bytes of `bytes`
+    fn parse(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HEADER_LEN || !bytes.starts_with(MAGIC) {
+            bail!("Ciphertext too short");
         }
+        let algorithm = match bytes[4] {
+            0 => EncryptAlgorithm::Chacha20,
+            1 => EncryptAlgorithm::AesGcm,
+            id => bail!("Unknown encryption algorithm id {}", id),
+        };
+        let cost = |i: usize| {
+            let start = 5 + 4 * i;
+            u32::from_le_bytes(bytes[start..start + 4].try_into().unwrap())
+        };
+        let salt_start = 5 + 3 * 4;
+        let nonce_start = salt_start + SALT_LEN;
+        Ok(Self {
+            algorithm,
+            kdf: KdfParams {
+                m_cost: cost(0),
+                t_cost: cost(1),
+                p_cost: cost(2),
+            },
+            salt: bytes[salt_start..nonce_start].try_into().unwrap(),
+            nonce: bytes[nonce_start..HEADER_LEN].try_into().unwrap(),
+        })
+    }
+}
+
+/// Derive a 256-bit key from a password with Argon2id.
+fn derive_key(password: &[u8], salt: &[u8], kdf: KdfParams) -> Result<[u8; 32]> {
+    if kdf.m_cost > MAX_M_COST {
+        bail!(
+            "Key derivation memory cost of {} KiB exceeds the 1 GiB limit",
+            kdf.m_cost
+        );
+    }
+    let params = Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32))
+        .map_err(|e| anyhow!("Invalid key derivation parameters: {}", e))?;
+    let mut key = [0u8; 32];
+    Argon2::new(argon2::Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password, salt, &mut key)
+        .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+    Ok(key)
+}
+
+/// Key used by the headerless format of older versions.
+fn derive_legacy_key(password: &[u8]) -> [u8; 32] {
+    Sha256::digest(password).into()
+}
+
+fn seal<C: Aead + KeyInit>(
+    key: &[u8; 32],
+    nonce: &[u8],
+    msg: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    let cipher = C::new_from_slice(key).map_err(|e| anyhow!("Failed to create cipher: {}", e))?;
+    cipher
+        .encrypt(Nonce::<C>::from_slice(nonce), Payload { msg, aad })
+        .map_err(|e| anyhow!("Encryption failed: {}", e))
+}
+
+/// Decrypt and verify the tag; any mismatch is reported as [`DECRYPT_FAILED`].
+fn open<C: Aead + KeyInit>(
+    key: &[u8; 32],
+    nonce: &[u8],
+    msg: &[u8],
+    aad: &[u8],
+) -> Result<Vec<u8>> {
+    let cipher = C::new_from_slice(key).map_err(|e| anyhow!("Failed to create cipher: {}", e))?;
+    cipher
+        .decrypt(Nonce::<C>::from_slice(nonce), Payload { msg, aad })
+        .map_err(|_| anyhow!(DECRYPT_FAILED))
+}
+
+/// Encrypt `plaintext` into header, ciphertext and tag.
+fn encrypt_bytes(
+    plaintext: &[u8],
+    password: &[u8],
+    algorithm: EncryptAlgorithm,
+    kdf: KdfParams,
+) -> Result<Vec<u8>> {
+    let header = Header::generate(algorithm, kdf);
+    let key = derive_key(password, &header.salt, kdf)?;
+    let mut data = header.to_bytes();
+    let ciphertext = match algorithm {
+        EncryptAlgorithm::Chacha20 => {
+            seal::<ChaCha20Poly1305>(&key, &header.nonce, plaintext, &data)?
+        }
+        EncryptAlgorithm::AesGcm => seal::<Aes256Gcm>(&key, &header.nonce, plaintext, &data)?,
     };
+    data.extend(ciphertext);
+    Ok(data)
+}
+
+/// Decrypt data from [`encrypt_bytes`], or headerless data from older
+/// versions. `algorithm`, if given, must match the header.
+fn decrypt_bytes(
+    data: &[u8],
+    password: &[u8],
+    algorithm: Option<EncryptAlgorithm>,
+) -> Result<Vec<u8>> {
+    if !data.starts_with(MAGIC) {
+        return decrypt_legacy(data, password, algorithm.unwrap_or_default());
+    }
+    if data.len() < HEADER_LEN + TAG_LEN {
+        bail!("Ciphertext too short");
+    }
+
+    let header = Header::parse(data)?;
+    if let Some(expected) = algorithm {
+        if expected != header.algorithm {
+            bail!(
+                "Data was encrypted with {:?}, not {:?}",
+                header.algorithm,
+                expected
+            );
+        }
+    }
+    let key = derive_key(password, &header.salt, header.kdf)?;
+    let (aad, ciphertext) = data.split_at(HEADER_LEN);
+    match header.algorithm {
+        EncryptAlgorithm::Chacha20 => {
+            open::<ChaCha20Poly1305>(&key, &header.nonce, ciphertext, aad)
+        }
+        EncryptAlgorithm::AesGcm => open::<Aes256Gcm>(&key, &header.nonce, ciphertext, aad),
+    }
+}
+
+fn decrypt_legacy(data: &[u8], password: &[u8], algorithm: EncryptAlgorithm) -> Result<Vec<u8>> {
+    if data.len() < NONCE_LEN + TAG_LEN {
+        bail!("Ciphertext too short");
+    }
+    let key = derive_legacy_key(password);
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+    match algorithm {
+        EncryptAlgorithm::Chacha20 => open::<ChaCha20Poly1305>(&key, nonce, ciphertext, &[]),
+        EncryptAlgorithm::AesGcm => open::<Aes256Gcm>(&key, nonce, ciphertext, &[]),
+    }
+}
+
+fn cmd_encrypt(
+    input: Option<PathBuf>,
+    string: Option<String>,
+    output: Option<PathBuf>,
+    password: &[u8],
+    algorithm: EncryptAlgorithm,
+) -> Result<()> {
+    let plaintext = read_input(input, string)?;
+    let data = encrypt_bytes(&plaintext, password, algorithm, KdfParams::default())?;
 
     // Base64 encode for text output
-    let encoded = base64::engine::general_purpose::STANDARD.encode(&ciphertext);
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
     write_output(output, encoded.as_bytes())
 }
 
@@ -140,47 +452,170 @@ fn cmd_decrypt(
     input: Option<PathBuf>,
     string: Option<String>,
     output: Option<PathBuf>,
-    password: &str,
-    algorithm: EncryptAlgorithm,
+    password: &[u8],
+    algorithm: Option<EncryptAlgorithm>,
 ) -> Result<()> {
     let encoded = read_input(input, string)?;
     let encoded_str = String::from_utf8(encoded).context("Invalid UTF-8 in input")?;
 
     // Base64 decode
-    let ciphertext = base64::engine::general_purpose::STANDARD
+    let data = base64::engine::general_purpose::STANDARD
         .decode(encoded_str.trim())
         .context("Failed to decode base64")?;
 
-    if ciphertext.len() < 12 {
-        anyhow::bail!("Ciphertext too short");
+    // Nothing is written unless the tag verifies
+    let plaintext = decrypt_bytes(&data, password, algorithm)?;
+    write_output(output, &plaintext)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cheap parameters so tests don't spend seconds in Argon2
+    const FAST: KdfParams = KdfParams {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+
+    #[test]
+    fn test_round_trip() {
+        for algorithm in [EncryptAlgorithm::Chacha20, EncryptAlgorithm::AesGcm] {
+            let data = encrypt_bytes(b"attack at dawn", b"hunter2", algorithm, FAST).unwrap();
+            assert!(data.starts_with(MAGIC));
+            assert_eq!(data.len(), HEADER_LEN + 14 + TAG_LEN);
+            assert_eq!(
+                decrypt_bytes(&data, b"hunter2", None).unwrap(),
+                b"attack at dawn"
+            );
+            assert_eq!(
+                decrypt_bytes(&data, b"hunter2", Some(algorithm)).unwrap(),
+                b"attack at dawn"
+            );
+        }
     }
 
-    let key = derive_key(password);
+    #[test]
+    fn test_wrong_password_fails() {
+        let data = encrypt_bytes(b"secret", b"right", EncryptAlgorithm::Chacha20, FAST).unwrap();
+        let err = decrypt_bytes(&data, b"wrong", None).unwrap_err();
+        assert_eq!(err.to_string(), DECRYPT_FAILED);
+    }
 
-    let plaintext = match algorithm {
-        EncryptAlgorithm::Chacha20 => {
-            let cipher = ChaCha20Poly1305::new_from_slice(&key)
-                .map_err(|e| anyhow::anyhow!("Failed to create cipher: {}", e))?;
+    #[test]
+    fn test_tampering_fails() {
+        let data = encrypt_bytes(b"secret", b"pw", EncryptAlgorithm::AesGcm, FAST).unwrap();
 
-            let nonce = ChachaNonce::from_slice(&ciphertext[..12]);
-            let encrypted = &ciphertext[12..];
+        // Salt byte: a different key
+        let mut salt = data.clone();
+        salt[5 + 12] ^= 1;
+        assert_eq!(
+            decrypt_bytes(&salt, b"pw", None).unwrap_err().to_string(),
+            DECRYPT_FAILED
+        );
 
-            cipher.decrypt(nonce, encrypted).map_err(|_| {
-                anyhow::anyhow!("Decryption failed: invalid password or corrupted data")
-            })?
-        }
-        EncryptAlgorithm::AesGcm => {
-            let cipher = Aes256Gcm::new_from_slice(&key)
-                .map_err(|e| anyhow::anyhow!("Failed to create cipher: {}", e))?;
+        // Ciphertext byte: the tag no longer matches
+        let mut body = data.clone();
+        body[HEADER_LEN] ^= 1;
+        assert!(decrypt_bytes(&body, b"pw", None).is_err());
 
-            let nonce = AesNonce::from_slice(&ciphertext[..12]);
-            let encrypted = &ciphertext[12..];
+        assert!(decrypt_bytes(&data[..HEADER_LEN + 4], b"pw", None)
+            .unwrap_err()
+            .to_string()
+            .contains("too short"));
+    }
 
-            cipher.decrypt(nonce, encrypted).map_err(|_| {
-                anyhow::anyhow!("Decryption failed: invalid password or corrupted data")
-            })?
-        }
-    };
+    #[test]
+    fn test_salt_and_nonce_are_fresh() {
+        let a = encrypt_bytes(b"same", b"pw", EncryptAlgorithm::Chacha20, FAST).unwrap();
+        let b = encrypt_bytes(b"same", b"pw", EncryptAlgorithm::Chacha20, FAST).unwrap();
+        let (ha, hb) = (Header::parse(&a).unwrap(), Header::parse(&b).unwrap());
+        assert_ne!(ha.salt, hb.salt);
+        assert_ne!(ha.nonce, hb.nonce);
+        assert_eq!(ha.kdf, FAST);
+    }
 
-    write_output(output, &plaintext)
+    #[test]
+    fn test_header_round_trip_and_limits() {
+        let header = Header::generate(EncryptAlgorithm::AesGcm, KdfParams::default());
+        let bytes = header.to_bytes();
+        assert_eq!(bytes.len(), HEADER_LEN);
+        assert_eq!(Header::parse(&bytes).unwrap(), header);
+
+        let mut unknown = bytes.clone();
+        unknown[4] = 9;
+        assert!(Header::parse(&unknown).is_err());
+
+        let huge = KdfParams {
+            m_cost: MAX_M_COST + 1,
+            ..FAST
+        };
+        assert!(derive_key(b"pw", &[0; SALT_LEN], huge).is_err());
+    }
+
+    #[test]
+    fn test_algorithm_mismatch() {
+        let data = encrypt_bytes(b"x", b"pw", EncryptAlgorithm::Chacha20, FAST).unwrap();
+        let err = decrypt_bytes(&data, b"pw", Some(EncryptAlgorithm::AesGcm)).unwrap_err();
+        assert!(err.to_string().contains("encrypted with Chacha20"));
+    }
+
+    #[test]
+    fn test_password_file_and_keyfile() {
+        let dir = tempfile::tempdir().unwrap();
+        let password_file = dir.path().join("password.txt");
+        let keyfile = dir.path().join("key.bin");
+        fs::write(&password_file, "s3cret\r\n").unwrap();
+        fs::write(&keyfile, [0u8, 10, 13, 10]).unwrap();
+
+        let from_file = PasswordOptions {
+            password_file: Some(password_file.clone()),
+            ..Default::default()
+        };
+        assert_eq!(resolve_secret(&from_file, None, true).unwrap(), b"s3cret");
+
+        // Raw bytes, trailing newline included
+        let from_keyfile = PasswordOptions {
+            keyfile: Some(keyfile),
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve_secret(&from_keyfile, None, true).unwrap(),
+            [0u8, 10, 13, 10]
+        );
+
+        fs::write(&password_file, "\n").unwrap();
+        let err = resolve_secret(&from_file, None, true).unwrap_err();
+        assert!(err.to_string().contains("Password file is empty"));
+    }
+
+    #[test]
+    fn test_options_take_precedence_over_env() {
+        let explicit = PasswordOptions {
+            password: Some("flag".to_string()),
+            ..Default::default()
+        };
+        let env = Some("env".to_string());
+        assert_eq!(
+            resolve_secret(&explicit, env.clone(), true).unwrap(),
+            b"flag"
+        );
+        assert_eq!(
+            resolve_secret(&PasswordOptions::default(), env, true).unwrap(),
+            b"env"
+        );
+    }
+
+    #[test]
+    fn test_legacy_format_still_decrypts() {
+        let key = derive_legacy_key(b"old");
+        let nonce = [7u8; NONCE_LEN];
+        let mut data = nonce.to_vec();
+        data.extend(seal::<ChaCha20Poly1305>(&key, &nonce, b"from before", &[]).unwrap());
+
+        assert_eq!(decrypt_bytes(&data, b"old", None).unwrap(), b"from before");
+        assert!(decrypt_bytes(&data, b"new", None).is_err());
+        assert!(decrypt_bytes(&data, b"old", Some(EncryptAlgorithm::AesGcm)).is_err());
+    }
 }
@@ -36,16 +36,29 @@
 //! dx env get MISSING --default x   # With fallback
 //! dx env check PATH HOME           # Verify variables exist
 //! dx env export --format docker    # Export for Docker
+//! dx env load .env                 # Print export lines for the file
+//! dx env load .env -- cargo run    # Run a command with the file loaded
+//! dx env diff .env.a .env.b        # Added/removed/changed keys
 //! ```
 //!
+//! ## Dotenv Files
+//!
+//! One `KEY=VALUE` per line. Blank lines and `#` comments are skipped, and a
+//! leading `export ` is allowed. Values may be:
+//! - unquoted: trimmed, with a trailing ` # comment` removed
+//! - single-quoted: taken literally
+//! - double-quoted: `\n`, `\t`, `\"`, and `\\` escapes are expanded
+//!
 //! ## External Documentation
 //! - std::env: <https://doc.rust-lang.org/std/env/>
 
 use crate::cli::commands::env::{EnvArgs, EnvCommand, ExportFormat};
-use anyhow::Result;
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
 use std::collections::BTreeMap;
 use std::env;
+use std::fs;
+use std::path::Path;
 
 /// Run the environment command, dispatching to the appropriate subcommand.
 ///
@@ -54,6 +67,8 @@ use std::env;
 /// - `get`: Get a specific variable
 /// - `check`: Verify variables are set
 /// - `export`: Output in various formats
+/// - `load`: Apply a dotenv file
+/// - `diff`: Compare dotenv files
 pub fn run(args: EnvArgs) -> Result<()> {
     match args.command {
         EnvCommand::List {
@@ -64,6 +79,8 @@ pub fn run(args: EnvArgs) -> Result<()> {
         EnvCommand::Get { name, default } => cmd_get(&name, default.as_deref()),
         EnvCommand::Check { names, strict } => cmd_check(&names, strict),
         EnvCommand::Export { format, prefix } => cmd_export(format, prefix.as_deref()),
+        EnvCommand::Load { file, command } => cmd_load(&file, &command),
+        EnvCommand::Diff { old, new } => cmd_diff(&old, &new),
     }
 }
 
@@ -222,39 +239,229 @@ fn cmd_export(format: ExportFormat, prefix: Option<&str>) -> Result<()> {
         })
         .collect();
 
+    print!("{}", format_export(&vars, format)?);
+    Ok(())
+}
+
+/// Render variables in an export format, one entry per line.
+fn format_export(vars: &BTreeMap<String, String>, format: ExportFormat) -> Result<String> {
+    let mut out = String::new();
     match format {
         ExportFormat::Shell => {
-            for (key, value) in &vars {
-                // Escape single quotes in value using the shell idiom:
-                // 'don'\''t' = 'don' + \' + 't'
-                // This ends the string, adds a literal ', then continues
-                let escaped = value.replace('\'', "'\\''");
-                println!("export {}='{}'", key, escaped);
+            for (key, value) in vars {
+                out.push_str(&format!("export {}={}\n", key, shell_quote(value)));
             }
         }
         ExportFormat::Docker => {
-            // Docker .env file format: simple KEY=VALUE
-            // No quoting needed for Docker's parser
-            for (key, value) in &vars {
-                println!("{}={}", key, value);
+            // Flags for `docker run`; values are quoted only when the shell
+            // would otherwise split or expand them
+            for (key, value) in vars {
+                let value = if is_shell_safe(value) {
+                    value.clone()
+                } else {
+                    shell_quote(value)
+                };
+                out.push_str(&format!("--env {}={}\n", key, value));
             }
         }
         ExportFormat::Json => {
             // serde_json handles all escaping automatically
-            println!("{}", serde_json::to_string_pretty(&vars)?);
+            out.push_str(&serde_json::to_string_pretty(vars)?);
+            out.push('\n');
         }
         ExportFormat::Toml => {
-            for (key, value) in &vars {
+            for (key, value) in vars {
                 // TOML string escaping: backslash and double-quote
                 let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
-                println!("{} = \"{}\"", key, escaped);
+                out.push_str(&format!("{} = \"{}\"\n", key, escaped));
             }
         }
     }
+    Ok(out)
+}
+
+/// Whether a value can appear unquoted in a shell command.
+fn is_shell_safe(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-.,:/@%+=".contains(c))
+}
+
+/// Quote a value for POSIX shells.
+///
+/// Single quotes prevent expansion; internal single quotes use the
+/// `'\''` idiom: `don't` becomes `'don'\''t'`.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Load a dotenv file.
+///
+/// Without a command, prints `export KEY='value'` lines so the file can be
+/// applied to the current shell with `eval "$(dx env load .env)"`. With a
+/// command, runs it with the file's variables merged over the current
+/// environment and exits with its status.
+fn cmd_load(file: &Path, command: &[String]) -> Result<()> {
+    let vars = read_dotenv(file)?;
+
+    let Some((program, args)) = command.split_first() else {
+        for (key, value) in &vars {
+            println!("export {}={}", key, shell_quote(value));
+        }
+        return Ok(());
+    };
 
+    let status = std::process::Command::new(program)
+        .args(args)
+        .envs(vars)
+        .status()
+        .with_context(|| format!("Failed to run {}", program))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
     Ok(())
 }
 
+/// Show keys added, removed, or changed between two dotenv files.
+fn cmd_diff(old: &Path, new: &Path) -> Result<()> {
+    let old_vars: BTreeMap<String, String> = read_dotenv(old)?.into_iter().collect();
+    let new_vars: BTreeMap<String, String> = read_dotenv(new)?.into_iter().collect();
+    let diff = diff_env(&old_vars, &new_vars);
+
+    if diff.is_empty() {
+        println!("{} No differences", "✓".green().bold());
+        return Ok(());
+    }
+    for (key, value) in &diff.added {
+        println!("{}", format!("+ {}={}", key, value).green());
+    }
+    for (key, value) in &diff.removed {
+        println!("{}", format!("- {}={}", key, value).red());
+    }
+    for (key, before, after) in &diff.changed {
+        println!("{}", format!("~ {}: {} -> {}", key, before, after).yellow());
+    }
+    Ok(())
+}
+
+fn read_dotenv(path: &Path) -> Result<Vec<(String, String)>> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    parse_dotenv(&content).with_context(|| format!("Invalid dotenv file {}", path.display()))
+}
+
+/// Parse dotenv content into `(key, value)` pairs in file order.
+fn parse_dotenv(content: &str) -> Result<Vec<(String, String)>> {
+    let mut vars = Vec::new();
+    for (index, raw) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line).trim_start();
+        let (key, value) = line
+            .split_once('=')
+            .with_context(|| format!("line {}: expected KEY=VALUE", line_no))?;
+        let key = key.trim();
+        let valid_key = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_key {
+            bail!("line {}: invalid variable name '{}'", line_no, key);
+        }
+        let value =
+            parse_dotenv_value(value.trim()).with_context(|| format!("line {}", line_no))?;
+        vars.push((key.to_string(), value));
+    }
+    Ok(vars)
+}
+
+fn parse_dotenv_value(value: &str) -> Result<String> {
+    if let Some(rest) = value.strip_prefix('\'') {
+        let end = rest.find('\'').context("unterminated single quote")?;
+        check_trailing(&rest[end + 1..])?;
+        return Ok(rest[..end].to_string());
+    }
+
+    if let Some(rest) = value.strip_prefix('"') {
+        let mut out = String::new();
+        let mut chars = rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    check_trailing(&rest[i + 1..])?;
+                    return Ok(out);
+                }
+                '\\' => match chars.next().map(|(_, c)| c) {
+                    Some('n') => out.push('\n'),
+                    Some('t') => out.push('\t'),
+                    Some('r') => out.push('\r'),
+                    Some(other) => out.push(other),
+                    None => break,
+                },
+                c => out.push(c),
+            }
+        }
+        bail!("unterminated double quote");
+    }
+
+    // Unquoted: an inline comment needs whitespace before the '#'
+    let value = match value.find(" #") {
+        Some(pos) => &value[..pos],
+        None => value,
+    };
+    Ok(value.trim_end().to_string())
+}
+
+/// Only whitespace or a comment may follow a closing quote.
+fn check_trailing(rest: &str) -> Result<()> {
+    let rest = rest.trim_start();
+    if rest.is_empty() || rest.starts_with('#') {
+        Ok(())
+    } else {
+        bail!("unexpected text after closing quote: {}", rest)
+    }
+}
+
+/// Differences between two sets of variables, each list sorted by key.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct EnvDiff {
+    added: Vec<(String, String)>,
+    removed: Vec<(String, String)>,
+    /// `(key, old value, new value)`
+    changed: Vec<(String, String, String)>,
+}
+
+impl EnvDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn diff_env(old: &BTreeMap<String, String>, new: &BTreeMap<String, String>) -> EnvDiff {
+    let mut diff = EnvDiff::default();
+    for (key, value) in new {
+        match old.get(key) {
+            None => diff.added.push((key.clone(), value.clone())),
+            Some(before) if before != value => {
+                diff.changed
+                    .push((key.clone(), before.clone(), value.clone()))
+            }
+            Some(_) => {}
+        }
+    }
+    for (key, value) in old {
+        if !new.contains_key(key) {
+            diff.removed.push((key.clone(), value.clone()));
+        }
+    }
+    diff
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -268,4 +475,94 @@ mod tests {
     fn test_get_existing_var() {
         assert!(env::var("PATH").is_ok());
     }
+
+    fn pairs(items: &[(&str, &str)]) -> Vec<(String, String)> {
+        items
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_dotenv_quoting() {
+        let content = r#"
+# database settings
+export DB_HOST=localhost
+DB_PASS='p@ss "word" # not a comment'
+GREETING="hello\n\"world\""   # trailing comment
+PLAIN = some value # comment
+EMPTY=
+URL=http://x/#anchor
+"#;
+        assert_eq!(
+            parse_dotenv(content).unwrap(),
+            pairs(&[
+                ("DB_HOST", "localhost"),
+                ("DB_PASS", "p@ss \"word\" # not a comment"),
+                ("GREETING", "hello\n\"world\""),
+                ("PLAIN", "some value"),
+                ("EMPTY", ""),
+                ("URL", "http://x/#anchor"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_dotenv_errors() {
+        let err = parse_dotenv("A=1\nnot a pair\n").unwrap_err();
+        assert!(err.to_string().contains("line 2"));
+        assert!(parse_dotenv("1BAD=x").is_err());
+        assert!(parse_dotenv("A='open").is_err());
+        assert!(parse_dotenv("A=\"open").is_err());
+        assert!(parse_dotenv("A='x' trailing").is_err());
+    }
+
+    #[test]
+    fn test_shell_quote_escapes_single_quotes() {
+        assert_eq!(shell_quote("plain"), "'plain'");
+        assert_eq!(shell_quote("don't"), "'don'\\''t'");
+        assert_eq!(shell_quote("$HOME `x`"), "'$HOME `x`'");
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    fn test_format_export() {
+        let vars: BTreeMap<String, String> =
+            pairs(&[("A", "it's"), ("B", "simple"), ("C", "two words")])
+                .into_iter()
+                .collect();
+
+        assert_eq!(
+            format_export(&vars, ExportFormat::Shell).unwrap(),
+            "export A='it'\\''s'\nexport B='simple'\nexport C='two words'\n"
+        );
+        assert_eq!(
+            format_export(&vars, ExportFormat::Docker).unwrap(),
+            "--env A='it'\\''s'\n--env B=simple\n--env C='two words'\n"
+        );
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_export(&vars, ExportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["A"], "it's");
+        assert_eq!(json.as_object().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_diff_env() {
+        let old: BTreeMap<String, String> = pairs(&[("A", "1"), ("B", "2"), ("C", "3")])
+            .into_iter()
+            .collect();
+        let new: BTreeMap<String, String> = pairs(&[("A", "1"), ("B", "20"), ("D", "4")])
+            .into_iter()
+            .collect();
+        assert_eq!(
+            diff_env(&old, &new),
+            EnvDiff {
+                added: pairs(&[("D", "4")]),
+                removed: pairs(&[("C", "3")]),
+                changed: vec![("B".to_string(), "2".to_string(), "20".to_string())],
+            }
+        );
+        assert!(diff_env(&old, &old).is_empty());
+    }
 }
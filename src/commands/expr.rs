@@ -8,31 +8,39 @@
 //! dx expr eval "2^10"            # 1024
 //! dx expr eval "sqrt(16) + pi"   # 7.14159...
 //! dx expr eval "sin(pi/2)"       # 1
+//! dx expr eval --trace "2+3*4"   # Show each intermediate value
 //! dx expr eval                   # Multi-line input mode (Ctrl+D to eval)
 //! dx expr run script.dx          # Run a script file
 //! dx expr run -                  # Run from stdin
+//! dx expr eval-file exprs.txt    # Evaluate line by line
 //! dx expr ast "2 + 3"            # Show AST as JSON
 //! dx expr list                   # Show available functions
+//! dx expr repl                   # Interactive session with history
 //! ```
 
 use crate::cli::commands::expr::{ExprArgs, ExprCommand};
 use crate::expr;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use colored::Colorize;
+use crossterm::cursor::MoveToColumn;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType};
 use std::fs;
-use std::io::{self, Read};
+use std::io::{self, BufRead, IsTerminal, Read, Write};
 
 pub fn run(args: ExprArgs) -> Result<()> {
     match args.command {
-        ExprCommand::Eval { expression } => cmd_eval(expression),
+        ExprCommand::Eval { expression, trace } => cmd_eval(expression, trace),
         ExprCommand::Run { file } => cmd_run(&file),
+        ExprCommand::EvalFile { file } => cmd_eval_file(&file),
         ExprCommand::Ast { expression, pretty } => cmd_ast(&expression, pretty),
         ExprCommand::List => cmd_list(),
+        ExprCommand::Repl => cmd_repl(),
     }
 }
 
 /// Evaluate a math expression
-fn cmd_eval(expression: Option<String>) -> Result<()> {
+fn cmd_eval(expression: Option<String>, trace: bool) -> Result<()> {
     let input = match expression {
         Some(expr) => expr,
         None => {
@@ -47,12 +55,37 @@ fn cmd_eval(expression: Option<String>) -> Result<()> {
         }
     };
 
+    if trace {
+        let program = expr::parse_program(&input)?;
+        let (result, entries) = program.eval_value_traced(&mut expr::Context::new());
+        for line in format_trace(&entries) {
+            eprintln!("{}", line);
+        }
+        println!("{}", result?);
+        return Ok(());
+    }
+
     // Use eval_program to support variables even in single expressions
-    let result = expr::eval_program(&input)?;
-    print_result(result);
+    let result = expr::eval_program_value(&input)?;
+    println!("{}", result);
     Ok(())
 }
 
+/// One line per trace entry, indented by nesting depth
+fn format_trace(entries: &[expr::TraceEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .map(|entry| {
+            format!(
+                "{}{} = {}",
+                "  ".repeat(entry.depth),
+                entry.expr,
+                entry.value
+            )
+        })
+        .collect()
+}
+
 /// Run a program from a file
 fn cmd_run(file: &str) -> Result<()> {
     let input = if file == "-" {
@@ -63,17 +96,263 @@ fn cmd_run(file: &str) -> Result<()> {
         fs::read_to_string(file)?
     };
 
-    let result = expr::eval_program(&input)?;
-    print_result(result);
+    let result = expr::eval_program_value(&input)?;
+    println!("{}", result);
     Ok(())
 }
 
-/// Print a result, formatting integers nicely
-fn print_result(result: f64) {
-    if result.fract() == 0.0 && result.abs() < 1e15 {
-        println!("{}", result as i64);
+/// Evaluate a file line by line, continuing past errors
+fn cmd_eval_file(file: &str) -> Result<()> {
+    let input = if file == "-" {
+        let mut buffer = String::new();
+        io::stdin().read_to_string(&mut buffer)?;
+        buffer
     } else {
-        println!("{}", result);
+        fs::read_to_string(file).with_context(|| format!("Failed to read {}", file))?
+    };
+
+    let results = eval_lines(&input);
+    let failed = results.iter().filter(|(_, result)| result.is_err()).count();
+    for (line, result) in &results {
+        match result {
+            Ok(Some(value)) => println!("{} => {}", line, value),
+            Ok(None) => println!("{} => {}", line, "defined".dimmed()),
+            Err(e) => println!("{} => {} {}", line, "error:".red(), e),
+        }
+    }
+
+    if failed > 0 {
+        eprintln!(
+            "{} {} of {} expressions failed",
+            "✗".red().bold(),
+            failed,
+            results.len()
+        );
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Evaluate each non-empty, non-comment line in one session, so earlier
+/// assignments are visible to later lines.
+fn eval_lines(input: &str) -> Vec<(&str, Result<Option<expr::Value>>)> {
+    let mut session = expr::Session::new();
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| (line, session.eval_value(line)))
+        .collect()
+}
+
+fn format_number(value: f64) -> String {
+    expr::Value::Number(value).to_string()
+}
+
+const REPL_PROMPT: &str = "expr> ";
+
+/// What the REPL should do after handling one line
+#[derive(Debug, PartialEq)]
+enum ReplAction {
+    Print(String),
+    Nothing,
+    Quit,
+}
+
+/// Run an interactive session. Definitions persist between lines.
+fn cmd_repl() -> Result<()> {
+    let interactive = io::stdin().is_terminal();
+    if interactive {
+        eprintln!(
+            "{}",
+            "dx expr repl - :vars, :funcs, :help, :quit (Ctrl+D to exit)".dimmed()
+        );
+    }
+
+    let mut session = expr::Session::new();
+    let mut history: Vec<String> = Vec::new();
+    loop {
+        let line = if interactive {
+            read_line_with_history(REPL_PROMPT, &history)?
+        } else {
+            read_plain_line()?
+        };
+        let Some(line) = line else { break };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if interactive && history.last().map(String::as_str) != Some(line) {
+            history.push(line.to_string());
+        }
+
+        match repl_step(&mut session, line) {
+            Ok(ReplAction::Print(output)) => println!("{}", output),
+            Ok(ReplAction::Nothing) => {}
+            Ok(ReplAction::Quit) => break,
+            Err(e) => eprintln!("{} {}", "error:".red(), e),
+        }
+    }
+    Ok(())
+}
+
+/// Handle one REPL line: either a `:command` or an expression
+fn repl_step(session: &mut expr::Session, line: &str) -> Result<ReplAction> {
+    let action = match line {
+        ":quit" | ":q" | ":exit" => ReplAction::Quit,
+        ":help" | ":h" => ReplAction::Print(
+            [
+                ":vars   List defined variables",
+                ":funcs  List user functions and lambdas",
+                ":help   Show this help",
+                ":quit   Leave the session",
+            ]
+            .join("\n"),
+        ),
+        ":vars" => {
+            let vars = session.variables();
+            if vars.is_empty() {
+                ReplAction::Print("(no variables)".to_string())
+            } else {
+                let lines: Vec<String> = vars
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, format_number(*value)))
+                    .collect();
+                ReplAction::Print(lines.join("\n"))
+            }
+        }
+        ":funcs" => {
+            let funcs = session.functions();
+            if funcs.is_empty() {
+                ReplAction::Print("(no functions)".to_string())
+            } else {
+                let lines: Vec<String> = funcs
+                    .iter()
+                    .map(|(name, params)| format!("{}({})", name, params.join(", ")))
+                    .collect();
+                ReplAction::Print(lines.join("\n"))
+            }
+        }
+        cmd if cmd.starts_with(':') => {
+            anyhow::bail!("Unknown command '{}' (try :help)", cmd)
+        }
+        input => match session.eval_value(input)? {
+            Some(value) => ReplAction::Print(value.to_string()),
+            None => ReplAction::Nothing,
+        },
+    };
+    Ok(action)
+}
+
+/// Read a line from non-interactive stdin, `None` at EOF
+fn read_plain_line() -> Result<Option<String>> {
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    Ok(Some(line))
+}
+
+/// Restores cooked terminal mode when dropped
+struct RawModeGuard;
+
+impl RawModeGuard {
+    fn enable() -> Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for RawModeGuard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Minimal line editor: cursor movement, backspace/delete, Up/Down history.
+/// Returns `None` on Ctrl+D at an empty line; Ctrl+C discards the line.
+fn read_line_with_history(prompt: &str, history: &[String]) -> Result<Option<String>> {
+    let _raw = RawModeGuard::enable()?;
+    let mut stdout = io::stdout();
+    let mut buffer: Vec<char> = Vec::new();
+    let mut cursor = 0;
+    // Index into history while browsing; history.len() is the line being edited
+    let mut browse = history.len();
+    let mut draft: Vec<char> = Vec::new();
+
+    let redraw = |stdout: &mut io::Stdout, buffer: &[char], cursor: usize| -> Result<()> {
+        let line: String = buffer.iter().collect();
+        crossterm::execute!(stdout, MoveToColumn(0), Clear(ClearType::UntilNewLine))?;
+        write!(stdout, "{}{}", prompt, line)?;
+        let column = prompt.chars().count() + cursor;
+        crossterm::execute!(stdout, MoveToColumn(column as u16))?;
+        Ok(())
+    };
+    redraw(&mut stdout, &buffer, cursor)?;
+
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Enter => {
+                write!(stdout, "\r\n")?;
+                stdout.flush()?;
+                return Ok(Some(buffer.iter().collect()));
+            }
+            KeyCode::Char('d') if ctrl && buffer.is_empty() => {
+                write!(stdout, "\r\n")?;
+                stdout.flush()?;
+                return Ok(None);
+            }
+            KeyCode::Char('c') if ctrl => {
+                write!(stdout, "^C\r\n")?;
+                buffer.clear();
+                cursor = 0;
+                browse = history.len();
+            }
+            KeyCode::Char('a') if ctrl => cursor = 0,
+            KeyCode::Char('e') if ctrl => cursor = buffer.len(),
+            KeyCode::Char(_) if ctrl => {}
+            KeyCode::Char(c) => {
+                buffer.insert(cursor, c);
+                cursor += 1;
+            }
+            KeyCode::Backspace if cursor > 0 => {
+                cursor -= 1;
+                buffer.remove(cursor);
+            }
+            KeyCode::Delete if cursor < buffer.len() => {
+                buffer.remove(cursor);
+            }
+            KeyCode::Left => cursor = cursor.saturating_sub(1),
+            KeyCode::Right => cursor = (cursor + 1).min(buffer.len()),
+            KeyCode::Home => cursor = 0,
+            KeyCode::End => cursor = buffer.len(),
+            KeyCode::Up if browse > 0 => {
+                if browse == history.len() {
+                    draft = buffer.clone();
+                }
+                browse -= 1;
+                buffer = history[browse].chars().collect();
+                cursor = buffer.len();
+            }
+            KeyCode::Down if browse < history.len() => {
+                browse += 1;
+                buffer = if browse == history.len() {
+                    draft.clone()
+                } else {
+                    history[browse].chars().collect()
+                };
+                cursor = buffer.len();
+            }
+            _ => {}
+        }
+        redraw(&mut stdout, &buffer, cursor)?;
     }
 }
 
@@ -180,6 +459,14 @@ fn cmd_list() -> Result<()> {
     println!("    lerp(a, b, t)  Interpolate   lerp(0, 10, 0.5) = 5");
     println!();
 
+    println!("  {}", "Combinatorics (whole numbers)".cyan());
+    println!("    fact(n)    Factorial       fact(5) = 120");
+    println!("    gcd(a, b)  Greatest common divisor  gcd(12, 18) = 6");
+    println!("    lcm(a, b)  Least common multiple    lcm(4, 6) = 12");
+    println!("    nCr(n, r)  Combinations    nCr(5, 2) = 10");
+    println!("    nPr(n, r)  Permutations    nPr(5, 2) = 20");
+    println!();
+
     println!("  {}", "Variadic".cyan());
     println!("    sum(...)   Sum all args    sum(1, 2, 3) = 6");
     println!("    avg(...)   Average         avg(2, 4, 6) = 4");
@@ -196,11 +483,13 @@ fn cmd_list() -> Result<()> {
     println!("{}", "CONDITIONALS".yellow());
     println!("  if-then-else expression:");
     println!("    if condition then expr else expr");
+    println!("    condition ? expr : expr           # same, right-associative");
     println!();
     println!("  Examples:");
     println!("    if x > 0 then x else -x           # absolute value");
     println!("    if a > b then a else b            # max");
     println!("    if n <= 1 then 1 else n * f(n-1)  # factorial");
+    println!("    x < 0 ? -1 : x > 0 ? 1 : 0        # sign");
     println!();
 
     // ========== VARIABLES ==========
@@ -287,6 +576,7 @@ fn cmd_list() -> Result<()> {
     println!("  {}", "Comparisons and logic".cyan());
     println!("    dx expr eval \"5 > 3 and 2 < 4\"");
     println!("    dx expr eval \"if 5 > 3 then 100 else 200\"");
+    println!("    dx expr eval \"5 > 3 ? 100 : 200\"");
     println!();
 
     println!("  {}", "With variables".cyan());
@@ -313,6 +603,7 @@ fn cmd_list() -> Result<()> {
 
     println!("  {}", "Debug with print".cyan());
     println!("    dx expr eval \"x=5; print(x); x*2\"");
+    println!("    dx expr eval --trace \"2 + 3 * 4\"  # Every intermediate value");
     println!();
 
     println!("  {}", "AST output".cyan());
@@ -320,3 +611,83 @@ fn cmd_list() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_variable_across_inputs() {
+        let mut session = expr::Session::new();
+        assert_eq!(session.eval("rate = 1.5").unwrap(), Some(1.5));
+        assert_eq!(session.eval("hours = 8").unwrap(), Some(8.0));
+        assert_eq!(session.eval("rate * hours").unwrap(), Some(12.0));
+    }
+
+    #[test]
+    fn test_format_trace_indents_by_depth() {
+        let program = expr::parse_program("x = 2; x > 1").unwrap();
+        let (_, entries) = program.eval_value_traced(&mut expr::Context::new());
+        assert_eq!(
+            format_trace(&entries),
+            ["2 = 2", "  x = 2", "  1 = 1", "x > 1 = true"]
+        );
+    }
+
+    #[test]
+    fn test_eval_lines_reports_each_line() {
+        let input = "# comment\n1 + 1\n\nsqrt(-1)\nx = 3\nx * 2\nx > 2\n";
+        let results = eval_lines(input);
+        let lines: Vec<&str> = results.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, ["1 + 1", "sqrt(-1)", "x = 3", "x * 2", "x > 2"]);
+        assert_eq!(
+            results[0].1.as_ref().unwrap(),
+            &Some(expr::Value::Number(2.0))
+        );
+        assert!(results[1].1.is_err());
+        assert_eq!(
+            results[3].1.as_ref().unwrap(),
+            &Some(expr::Value::Number(6.0))
+        );
+        assert_eq!(
+            results[4].1.as_ref().unwrap(),
+            &Some(expr::Value::Bool(true))
+        );
+    }
+
+    #[test]
+    fn test_repl_step_commands() {
+        let mut session = expr::Session::new();
+        assert_eq!(
+            repl_step(&mut session, ":vars").unwrap(),
+            ReplAction::Print("(no variables)".to_string())
+        );
+        assert_eq!(
+            repl_step(&mut session, "x = 2").unwrap(),
+            ReplAction::Print("2".to_string())
+        );
+        assert_eq!(
+            repl_step(&mut session, "def sq(n) = n * n").unwrap(),
+            ReplAction::Nothing
+        );
+        assert_eq!(
+            repl_step(&mut session, "sq(x) + 0.5").unwrap(),
+            ReplAction::Print("4.5".to_string())
+        );
+        assert_eq!(
+            repl_step(&mut session, "sq(x) == 4").unwrap(),
+            ReplAction::Print("true".to_string())
+        );
+        assert_eq!(
+            repl_step(&mut session, ":vars").unwrap(),
+            ReplAction::Print("x = 2".to_string())
+        );
+        assert_eq!(
+            repl_step(&mut session, ":funcs").unwrap(),
+            ReplAction::Print("sq(n)".to_string())
+        );
+        assert_eq!(repl_step(&mut session, ":q").unwrap(), ReplAction::Quit);
+        assert!(repl_step(&mut session, ":bogus").is_err());
+        assert!(repl_step(&mut session, "undefined_var").is_err());
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Fun terminal effects like fake progress bars, hacker mode, countdown timers, and spinners.
 
-use crate::cli::commands::fun::{FunArgs, FunCommand};
+use crate::cli::commands::fun::{BannerFont, FunArgs, FunCommand, MatrixColor};
+use crate::utils::command_rng;
 use crate::utils::progress::{
-    self, osc_progress, osc_progress_clear, BouncingBar, ProgressState, SPINNER_FRAMES,
+    ascii_from_env, osc_progress, osc_progress_clear, spinner_frames, BouncingBar, ProgressState,
+    Spinner, ASCII_SPINNER_FRAMES,
 };
 use anyhow::Result;
 use colored::Colorize;
@@ -14,8 +16,9 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 pub fn run(args: FunArgs) -> Result<()> {
+    let ascii = args.ascii || ascii_from_env();
     match args.command {
-        FunCommand::Progress { duration, style } => cmd_progress(duration, &style),
+        FunCommand::Progress { duration, style } => cmd_progress(duration, &style, ascii),
         FunCommand::Hacker {
             duration,
             intensity,
@@ -24,16 +27,16 @@ pub fn run(args: FunArgs) -> Result<()> {
             seconds,
             message,
             simple,
-        } => cmd_countdown(seconds, message, simple),
-        FunCommand::Spinners { duration, name } => cmd_spinners(duration, name),
+        } => cmd_countdown(seconds, message, simple, ascii),
+        FunCommand::Spinners { duration, name } => cmd_spinners(duration, name, ascii),
         FunCommand::Work {
             duration,
             tasks,
             style,
             list_styles,
-        } => cmd_work(duration, tasks, &style, list_styles),
+        } => cmd_work(duration, tasks, &style, list_styles, ascii),
         FunCommand::Fortune { animal, say, list } => cmd_fortune(animal, say, list),
-        FunCommand::Bounce { duration, message } => cmd_bounce(duration, message),
+        FunCommand::Bounce { duration, message } => cmd_bounce(duration, message, ascii),
         FunCommand::Clock {
             duration,
             twelve_hour,
@@ -46,8 +49,13 @@ pub fn run(args: FunArgs) -> Result<()> {
             width,
             height,
         } => cmd_life(duration, &pattern, width, height),
-        FunCommand::Matrix { duration, density } => cmd_matrix(duration, density),
-        FunCommand::Banner { text } => cmd_banner(&text),
+        FunCommand::Matrix {
+            duration,
+            density,
+            fps,
+            color,
+        } => cmd_matrix(duration, density, fps, color),
+        FunCommand::Banner { text, font } => cmd_banner(&text, font),
     }
 }
 
@@ -80,23 +88,37 @@ const LOADING_MESSAGES: &[&str] = &[
     "Proving P = NP...",
 ];
 
-/// Manual progress bar that bypasses terminal detection
-fn draw_progress(progress: u64, width: usize, msg: &str) {
+/// Check mark for finished work
+fn check_mark(ascii: bool) -> &'static str {
+    if ascii {
+        "+"
+    } else {
+        "✓"
+    }
+}
+
+/// Cyan filled, blue empty bar at `progress` percent
+fn simple_bar(progress: u64, width: usize, ascii: bool) -> String {
+    let (filled_char, empty_char) = if ascii { ("#", "-") } else { ("█", "░") };
     let filled = (progress as usize * width) / 100;
     let empty = width - filled;
-    let bar = format!("{}{}", "█".repeat(filled).cyan(), "░".repeat(empty).blue());
-    eprint!("\r\x1b[K[{}] {}% {}", bar, progress, msg);
-    io::stderr().flush().ok();
+    format!(
+        "{}{}",
+        filled_char.repeat(filled).cyan(),
+        empty_char.repeat(empty).blue()
+    )
 }
 
-/// Manual spinner that bypasses terminal detection
-fn draw_spinner(frame: usize, msg: &str) {
-    progress::draw_spinner(frame, msg);
+/// Manual progress bar that bypasses terminal detection
+fn draw_progress(progress: u64, width: usize, msg: &str, ascii: bool) {
+    let bar = simple_bar(progress, width, ascii);
+    eprint!("\r\x1b[K[{}] {}% {}", bar, progress, msg);
+    io::stderr().flush().ok();
 }
 
 /// Fake progress bar with funny messages
-fn cmd_progress(duration: u64, style: &str) -> Result<()> {
-    let mut rng = rand::rng();
+fn cmd_progress(duration: u64, style: &str, ascii: bool) -> Result<()> {
+    let mut rng = command_rng();
     let total_ms = duration * 1000;
     let start = Instant::now();
 
@@ -105,7 +127,7 @@ fn cmd_progress(duration: u64, style: &str) -> Result<()> {
 
     match style {
         "spinner" => {
-            let mut frame = 0usize;
+            let mut spinner = Spinner::new().ascii(ascii);
             let mut elapsed_percent;
             while start.elapsed().as_millis() < total_ms as u128 {
                 let msg = LOADING_MESSAGES[rng.random_range(0..LOADING_MESSAGES.len())];
@@ -116,13 +138,13 @@ fn cmd_progress(duration: u64, style: &str) -> Result<()> {
                 while msg_start.elapsed().as_millis() < msg_duration {
                     elapsed_percent = (start.elapsed().as_millis() * 100 / total_ms as u128) as u64;
                     osc_progress(elapsed_percent, ProgressState::Normal);
-                    draw_spinner(frame, msg);
-                    frame += 1;
+                    spinner.draw(msg);
+                    spinner.tick();
                     thread::sleep(Duration::from_millis(80));
                 }
             }
             osc_progress_clear(); // Clear terminal progress
-            eprintln!("\r\x1b[K{} {}", "✓".green(), "Done!".green());
+            eprintln!("\r\x1b[K{} {}", check_mark(ascii).green(), "Done!".green());
         }
         "bar" => {
             let mut progress = 0u64;
@@ -140,7 +162,7 @@ fn cmd_progress(duration: u64, style: &str) -> Result<()> {
                 progress = (progress + increment).min(100);
                 osc_progress(progress, ProgressState::Normal);
                 let display_msg = stall_msg.as_deref().unwrap_or(msg);
-                draw_progress(progress, 30, display_msg);
+                draw_progress(progress, 30, display_msg, ascii);
 
                 let delay = total_ms / 100 * increment;
                 thread::sleep(Duration::from_millis(delay.max(50)));
@@ -148,14 +170,14 @@ fn cmd_progress(duration: u64, style: &str) -> Result<()> {
             osc_progress_clear(); // Clear terminal progress
             eprintln!(
                 "\r\x1b[K[{}] 100% {}",
-                "█".repeat(30).cyan(),
+                simple_bar(100, 30, ascii),
                 "Complete!".green()
             );
         }
         _ => {
             // "both" style - spinner + progress bar
             let mut progress = 0u64;
-            let mut frame = 0usize;
+            let mut spinner = Spinner::new().ascii(ascii);
 
             while progress < 100 {
                 let msg = LOADING_MESSAGES[rng.random_range(0..LOADING_MESSAGES.len())];
@@ -184,24 +206,23 @@ fn cmd_progress(duration: u64, style: &str) -> Result<()> {
                 };
 
                 while step_start.elapsed().as_millis() < step_ms as u128 {
-                    let spinner = SPINNER_FRAMES[frame % SPINNER_FRAMES.len()].green();
-                    let filled = (progress as usize * 30) / 100;
-                    let empty = 30 - filled;
-                    let bar = format!("{}{}", "█".repeat(filled).cyan(), "░".repeat(empty).blue());
                     eprint!(
                         "\r\x1b[K{} [{}] {}% {}",
-                        spinner, bar, progress, display_msg
+                        spinner.frame().green(),
+                        simple_bar(progress, 30, ascii),
+                        progress,
+                        display_msg
                     );
                     io::stderr().flush().ok();
-                    frame += 1;
+                    spinner.tick();
                     thread::sleep(Duration::from_millis(80));
                 }
             }
             osc_progress_clear(); // Clear terminal progress
             eprintln!(
                 "\r\x1b[K{} [{}] 100% {}",
-                "✓".green(),
-                "█".repeat(30).cyan(),
+                check_mark(ascii).green(),
+                simple_bar(100, 30, ascii),
                 "All done!".green().bold()
             );
         }
@@ -259,7 +280,7 @@ const HACKER_COMMANDS: &[&str] = &[
 
 /// Fake hacker terminal output
 fn cmd_hacker(duration: u64, intensity: u8) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     let start = Instant::now();
     let total_ms = duration * 1000;
 
@@ -374,7 +395,7 @@ fn generate_filename(rng: &mut impl Rng) -> String {
 }
 
 /// Countdown timer with visual effects
-fn cmd_countdown(seconds: u64, message: Option<String>, simple: bool) -> Result<()> {
+fn cmd_countdown(seconds: u64, message: Option<String>, simple: bool, ascii: bool) -> Result<()> {
     for remaining in (1..=seconds).rev() {
         let mins = remaining / 60;
         let secs = remaining % 60;
@@ -396,12 +417,10 @@ fn cmd_countdown(seconds: u64, message: Option<String>, simple: bool) -> Result<
         if simple {
             eprint!("\r\x1b[K{} remaining", colored_time);
         } else {
-            let elapsed = seconds - remaining;
-            let filled = (elapsed as usize * 30) / seconds as usize;
-            let empty = 30 - filled;
-            let bar = format!("{}{}", "█".repeat(filled).cyan(), "░".repeat(empty).blue());
+            let bar = simple_bar(percent, 30, ascii);
+            let frames = spinner_frames(ascii);
             let frame = (seconds - remaining) as usize;
-            let spinner = SPINNER_FRAMES[frame % SPINNER_FRAMES.len()].green();
+            let spinner = frames[frame % frames.len()].green();
             eprint!("\r\x1b[K{} [{}] {}", spinner, bar, colored_time);
         }
         io::stderr().flush().ok();
@@ -441,6 +460,7 @@ const SPINNER_STYLES: &[(&str, &str, &[&str])] = &[
         &["▁", "▃", "▄", "▅", "▆", "▇", "█", "▇", "▆", "▅", "▄", "▃"],
     ),
     ("circle", "Rotating quadrants", &["◐", "◓", "◑", "◒"]),
+    ("ascii", "Plain ASCII line", ASCII_SPINNER_FRAMES),
     (
         "arrow",
         "Rotating arrow",
@@ -492,15 +512,29 @@ const SPINNER_STYLES: &[(&str, &str, &[&str])] = &[
     ),
 ];
 
+/// Whether every frame is plain ASCII
+fn is_ascii_frames(frames: &[&str]) -> bool {
+    frames.iter().all(|f| f.is_ascii())
+}
+
+/// The frames to draw for a style, falling back to ASCII when asked
+fn style_frames<'a>(frames: &'a [&'a str], ascii: bool) -> &'a [&'a str] {
+    if ascii && !is_ascii_frames(frames) {
+        ASCII_SPINNER_FRAMES
+    } else {
+        frames
+    }
+}
+
 /// Showcase spinner styles
-fn cmd_spinners(duration: u64, name: Option<String>) -> Result<()> {
+fn cmd_spinners(duration: u64, name: Option<String>, ascii: bool) -> Result<()> {
     if let Some(ref spinner_name) = name {
         // Show single spinner
         if let Some((name, desc, frames)) =
             SPINNER_STYLES.iter().find(|(n, _, _)| *n == spinner_name)
         {
             println!("{}: {}\n", name.cyan().bold(), desc);
-            run_spinner_manual(frames, duration * 1000)?;
+            run_spinner_manual(style_frames(frames, ascii), duration * 1000, ascii)?;
         } else {
             println!("Unknown spinner: {}", spinner_name);
             println!("\nAvailable spinners:");
@@ -512,7 +546,11 @@ fn cmd_spinners(duration: u64, name: Option<String>) -> Result<()> {
         // Showcase all spinners
         println!("{}\n", "Spinner Showcase".cyan().bold());
 
-        for (name, desc, frames) in SPINNER_STYLES {
+        // In ASCII mode, only styles that are ASCII already are worth showing
+        let styles = SPINNER_STYLES
+            .iter()
+            .filter(|(_, _, frames)| !ascii || is_ascii_frames(frames));
+        for (name, desc, frames) in styles {
             print!("{:12} ", name.yellow());
             io::stdout().flush().ok();
 
@@ -541,7 +579,7 @@ fn cmd_spinners(duration: u64, name: Option<String>) -> Result<()> {
     Ok(())
 }
 
-fn run_spinner_manual(frames: &[&str], duration_ms: u64) -> Result<()> {
+fn run_spinner_manual(frames: &[&str], duration_ms: u64, ascii: bool) -> Result<()> {
     let start = Instant::now();
     let mut frame_idx = 0usize;
     while start.elapsed().as_millis() < duration_ms as u128 {
@@ -553,7 +591,7 @@ fn run_spinner_manual(frames: &[&str], duration_ms: u64) -> Result<()> {
         frame_idx += 1;
         thread::sleep(Duration::from_millis(80));
     }
-    eprintln!("\r\x1b[K{} Done!", "✓".green());
+    eprintln!("\r\x1b[K{} Done!", check_mark(ascii).green());
     Ok(())
 }
 
@@ -604,6 +642,19 @@ const BAR_STYLES: &[(&str, &str, &[&str], &[&str])] = &[
     ("fade", "Fade effect", &["█", "▓", "▒", "░"], &[" "]),
 ];
 
+/// The bar style to draw: non-ASCII styles become "classic" in ASCII mode
+fn bar_style(style: &str, ascii: bool) -> &str {
+    let is_ascii = BAR_STYLES
+        .iter()
+        .find(|(name, _, _, _)| *name == style)
+        .is_some_and(|(_, _, f, e)| is_ascii_frames(f) && is_ascii_frames(e));
+    if ascii && !is_ascii {
+        "classic"
+    } else {
+        style
+    }
+}
+
 /// Render a progress bar with the given style
 fn render_bar(progress: u64, width: usize, style: &str, colored: bool) -> String {
     let (filled_chars, empty_chars) = BAR_STYLES
@@ -673,13 +724,19 @@ fn render_bar_complete(width: usize, style: &str) -> String {
 }
 
 /// Simulate doing fake work with progress bars
-fn cmd_work(duration: u64, num_tasks: usize, style: &str, list_styles: bool) -> Result<()> {
+fn cmd_work(
+    duration: u64,
+    num_tasks: usize,
+    style: &str,
+    list_styles: bool,
+    ascii: bool,
+) -> Result<()> {
     // Handle --list-styles flag
     if list_styles {
         println!("{}", "Available progress bar styles:".cyan().bold());
         println!();
         for (name, desc, _, _) in BAR_STYLES {
-            let preview = render_bar(60, 20, name, false);
+            let preview = render_bar(60, 20, bar_style(name, ascii), false);
             println!("  {:10} [{}] {}", name.yellow(), preview, desc.dimmed());
         }
         return Ok(());
@@ -692,7 +749,9 @@ fn cmd_work(duration: u64, num_tasks: usize, style: &str, list_styles: bool) ->
         return Ok(());
     }
 
-    let mut rng = rand::rng();
+    let style = bar_style(style, ascii);
+    let frames = spinner_frames(ascii);
+    let mut rng = command_rng();
     let total_ms = duration * 1000;
     let time_per_task = total_ms / num_tasks as u64;
 
@@ -752,7 +811,7 @@ fn cmd_work(duration: u64, num_tasks: usize, style: &str, list_styles: bool) ->
             progress = progress.max(target_progress);
 
             // Draw inline progress - spinner on right side
-            let spinner = SPINNER_FRAMES[frame % SPINNER_FRAMES.len()];
+            let spinner = frames[frame % frames.len()];
             let bar = render_bar(progress, 20, style, true);
 
             print!(
@@ -780,7 +839,7 @@ fn cmd_work(duration: u64, num_tasks: usize, style: &str, list_styles: bool) ->
             format!("[{}/{}]", task_idx + 1, num_tasks).dimmed(),
             padded_task.cyan(),
             render_bar_complete(20, style),
-            "✓".green(),
+            check_mark(ascii).green(),
             "Done!".green()
         );
     }
@@ -789,7 +848,7 @@ fn cmd_work(duration: u64, num_tasks: usize, style: &str, list_styles: bool) ->
     println!();
     println!(
         "{} {} tasks completed successfully!",
-        "✓".green().bold(),
+        check_mark(ascii).green().bold(),
         num_tasks
     );
 
@@ -925,7 +984,7 @@ fn cmd_fortune(animal: Option<String>, say: Option<String>, list: bool) -> Resul
     let message = if let Some(custom) = say {
         custom
     } else {
-        let mut rng = rand::rng();
+        let mut rng = command_rng();
         FORTUNES[rng.random_range(0..FORTUNES.len())].to_string()
     };
 
@@ -936,7 +995,7 @@ fn cmd_fortune(animal: Option<String>, say: Option<String>, list: bool) -> Resul
             .find(|(n, _)| *n == name.as_str())
             .map(|(_, art)| *art)
     } else {
-        let mut rng = rand::rng();
+        let mut rng = command_rng();
         Some(ANIMALS[rng.random_range(0..ANIMALS.len())].1)
     };
 
@@ -1001,12 +1060,12 @@ fn cmd_fortune(animal: Option<String>, say: Option<String>, list: bool) -> Resul
 }
 
 /// Bouncing indeterminate progress bar
-fn cmd_bounce(duration: u64, message: Option<String>) -> Result<()> {
+fn cmd_bounce(duration: u64, message: Option<String>, ascii: bool) -> Result<()> {
     let msg = message.as_deref().unwrap_or("Loading...");
     let total_ms = duration * 1000;
     let start = Instant::now();
 
-    let mut bar = BouncingBar::new();
+    let mut bar = BouncingBar::new().ascii(ascii);
 
     while start.elapsed().as_millis() < total_ms as u128 {
         bar.tick();
@@ -1014,7 +1073,7 @@ fn cmd_bounce(duration: u64, message: Option<String>) -> Result<()> {
         thread::sleep(Duration::from_millis(50));
     }
 
-    bar.finish_with_message(&format!("{} Done!", "✓".green()));
+    bar.finish_with_message(&format!("{} Done!", check_mark(ascii).green()));
 
     Ok(())
 }
@@ -1233,7 +1292,7 @@ fn cmd_qr(text: &str, invert: bool) -> Result<()> {
 
 /// Conway's Game of Life
 fn cmd_life(duration: u64, pattern: &str, width: usize, height: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     let start = Instant::now();
 
     // Enable raw mode for keyboard input (q/Esc to quit)
@@ -1377,43 +1436,163 @@ fn count_neighbors(grid: &[Vec<bool>], x: usize, y: usize, width: usize, height:
 // MATRIX RAIN
 // ============================================================================
 
+/// Katakana-like characters + digits
+const MATRIX_CHARS: &str = "ｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝ0123456789";
+
+/// One falling stream of characters
+#[derive(Debug, Clone, PartialEq)]
+struct RainColumn {
+    /// Row of the head (negative while still above the screen)
+    pos: f32,
+    /// Rows advanced per frame
+    speed: f32,
+    /// Trail length in rows
+    len: usize,
+    active: bool,
+}
+
+/// Column states plus the character grid they draw into
+struct MatrixRain {
+    columns: Vec<RainColumn>,
+    screen: Vec<Vec<char>>,
+    chars: Vec<char>,
+    density: u8,
+}
+
+impl MatrixRain {
+    fn new(width: usize, height: usize, density: u8, rng: &mut impl Rng) -> Self {
+        let columns = (0..width)
+            .map(|_| RainColumn {
+                pos: rng.random_range(-(height as f32)..0.0),
+                speed: rng.random_range(0.3..1.0),
+                len: rng.random_range(5..15),
+                active: rng.random_bool(density as f64 / 10.0),
+            })
+            .collect();
+        Self {
+            columns,
+            screen: vec![vec![' '; width]; height],
+            chars: MATRIX_CHARS.chars().collect(),
+            density,
+        }
+    }
+
+    /// Advance every column by one frame.
+    ///
+    /// Active columns move their head down by `speed`, write a character at
+    /// the head, and erase the cell `len` rows behind it. A column goes
+    /// inactive once its tail leaves the screen and may restart at the top.
+    fn step(&mut self, rng: &mut impl Rng) {
+        let height = self.screen.len() as i32;
+        let restart_chance = (0.02 * self.density as f64).min(1.0);
+
+        for (x, column) in self.columns.iter_mut().enumerate() {
+            if !column.active {
+                if rng.random_bool(restart_chance) {
+                    column.active = true;
+                    column.pos = 0.0;
+                    column.len = rng.random_range(5..15);
+                    column.speed = rng.random_range(0.3..1.0);
+                }
+                continue;
+            }
+
+            column.pos += column.speed;
+
+            let head_y = column.pos as i32;
+            if (0..height).contains(&head_y) {
+                self.screen[head_y as usize][x] = self.chars[rng.random_range(0..self.chars.len())];
+            }
+
+            let tail_y = head_y - column.len as i32;
+            if (0..height).contains(&tail_y) {
+                self.screen[tail_y as usize][x] = ' ';
+            }
+
+            if tail_y > height {
+                column.active = false;
+            }
+        }
+    }
+
+    /// Render the grid as ANSI text: a white head, a bright trail near the
+    /// head, and the normal shade further back.
+    fn render(&self, color: MatrixColor) -> String {
+        let (normal, bright) = matrix_palette(color);
+        let mut out = String::from("\x1b[H");
+        for (y, row) in self.screen.iter().enumerate() {
+            for (x, &ch) in row.iter().enumerate() {
+                if ch == ' ' {
+                    out.push(' ');
+                    continue;
+                }
+                let head_y = self.columns[x].pos as i32;
+                let code = if y as i32 == head_y {
+                    97
+                } else if y as i32 > head_y - 3 {
+                    bright
+                } else {
+                    normal
+                };
+                out.push_str(&format!("\x1b[{}m{}\x1b[0m", code, ch));
+            }
+            out.push_str("\r\n");
+        }
+        out
+    }
+}
+
+/// ANSI foreground codes (normal, bright) for a rain color
+fn matrix_palette(color: MatrixColor) -> (u8, u8) {
+    let normal = match color {
+        MatrixColor::Red => 31,
+        MatrixColor::Green => 32,
+        MatrixColor::Yellow => 33,
+        MatrixColor::Blue => 34,
+        MatrixColor::Magenta => 35,
+        MatrixColor::Cyan => 36,
+        MatrixColor::White => 37,
+    };
+    (normal, normal + 60)
+}
+
+/// Puts the terminal into animation mode and restores it when dropped.
+///
+/// Saves the cursor, switches to the alternate screen, hides the cursor, and
+/// enables raw mode. Dropping reverses all of it, so the terminal is restored
+/// on every exit path: duration elapsed, q/Esc, Ctrl+C, or an early return.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> Self {
+        enable_raw_mode();
+        print!("\x1b7\x1b[?1049h\x1b[?25l\x1b[2J");
+        io::stdout().flush().ok();
+        TerminalGuard
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        disable_raw_mode();
+        print!("\x1b[0m\x1b[?25h\x1b[?1049l\x1b8");
+        io::stdout().flush().ok();
+    }
+}
+
 /// Matrix-style falling code rain
-fn cmd_matrix(duration: u64, density: u8) -> Result<()> {
-    let mut rng = rand::rng();
+fn cmd_matrix(duration: u64, density: u8, fps: u32, color: MatrixColor) -> Result<()> {
+    let mut rng = command_rng();
     let start = Instant::now();
-
-    // Enable raw mode for keyboard input (q/Esc to quit)
-    enable_raw_mode();
+    let frame_time = Duration::from_secs(1) / fps.max(1);
 
     // Get terminal size
     let (width, height) = terminal_size::terminal_size()
         .map(|(w, h)| (w.0 as usize, h.0 as usize))
         .unwrap_or((80, 24));
 
-    // Katakana-like characters + digits
-    let chars: Vec<char> = "ｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝ0123456789"
-        .chars()
-        .collect();
-
-    // Column states: (position, speed, length, active)
-    let num_columns = width / 2;
-    let mut columns: Vec<(f32, f32, usize, bool)> = (0..num_columns)
-        .map(|_| {
-            (
-                rng.random_range(-(height as f32)..0.0),
-                rng.random_range(0.3..1.0),
-                rng.random_range(5..15),
-                rng.random_bool(density as f64 / 10.0),
-            )
-        })
-        .collect();
-
-    // Screen buffer
-    let mut screen: Vec<Vec<char>> = vec![vec![' '; num_columns]; height];
-
-    // Hide cursor
-    print!("\x1b[?25l");
-    io::stdout().flush().ok();
+    // Every other cell, so wide glyphs don't collide
+    let mut rain = MatrixRain::new(width / 2, height, density, &mut rng);
 
     let running = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(true));
     let r = running.clone();
@@ -1421,7 +1600,10 @@ fn cmd_matrix(duration: u64, density: u8) -> Result<()> {
         r.store(false, std::sync::atomic::Ordering::SeqCst);
     });
 
+    let _terminal = TerminalGuard::enter();
+
     while running.load(std::sync::atomic::Ordering::SeqCst) {
+        let frame_start = Instant::now();
         if duration > 0 && start.elapsed().as_secs() >= duration {
             break;
         }
@@ -1431,70 +1613,15 @@ fn cmd_matrix(duration: u64, density: u8) -> Result<()> {
             break;
         }
 
-        // Update columns
-        for (i, (pos, speed, len, active)) in columns.iter_mut().enumerate() {
-            if !*active {
-                if rng.random_bool(0.02 * density as f64) {
-                    *active = true;
-                    *pos = 0.0;
-                    *len = rng.random_range(5..15);
-                    *speed = rng.random_range(0.3..1.0);
-                }
-                continue;
-            }
-
-            *pos += *speed;
-
-            // Add character at head position
-            let head_y = *pos as i32;
-            if head_y >= 0 && (head_y as usize) < height {
-                screen[head_y as usize][i] = chars[rng.random_range(0..chars.len())];
-            }
-
-            // Clear tail
-            let tail_y = head_y - (*len as i32);
-            if tail_y >= 0 && (tail_y as usize) < height {
-                screen[tail_y as usize][i] = ' ';
-            }
+        rain.step(&mut rng);
+        print!("{}", rain.render(color));
+        io::stdout().flush().ok();
 
-            // Reset if off screen
-            if tail_y > height as i32 {
-                *active = false;
-            }
+        if let Some(remaining) = frame_time.checked_sub(frame_start.elapsed()) {
+            thread::sleep(remaining);
         }
-
-        // Render
-        print!("\x1b[H"); // Move to top
-        for (y, row) in screen.iter().enumerate() {
-            for (x, &ch) in row.iter().enumerate() {
-                let head_y = columns[x].0 as i32;
-                if ch != ' ' {
-                    if y as i32 == head_y {
-                        // Bright head
-                        print!("\x1b[97m{}\x1b[0m", ch); // White
-                    } else if y as i32 > head_y - 3 {
-                        // Bright green near head
-                        print!("\x1b[92m{}\x1b[0m", ch);
-                    } else {
-                        // Dark green
-                        print!("\x1b[32m{}\x1b[0m", ch);
-                    }
-                } else {
-                    print!(" ");
-                }
-            }
-            println!();
-        }
-
-        io::stdout().flush().ok();
-        thread::sleep(Duration::from_millis(50));
     }
 
-    // Disable raw mode, restore cursor and clear
-    disable_raw_mode();
-    print!("\x1b[?25h\x1b[2J\x1b[H");
-    io::stdout().flush().ok();
-
     Ok(())
 }
 
@@ -1546,31 +1673,67 @@ const BANNER_FONT: &[(char, &[&str])] = &[
     ('-', &["     ", "     ", "█████", "     ", "     "]),
 ];
 
-/// Big ASCII text banner
-fn cmd_banner(text: &str) -> Result<()> {
-    let text = text.to_uppercase();
-
-    // Build 5 rows
-    let mut rows: Vec<String> = vec![String::new(); 5];
+/// Rows in a `BANNER_FONT` glyph
+const BANNER_HEIGHT: usize = 5;
+
+/// Render text as banner rows in the given font.
+///
+/// Letters are matched case-insensitively and separated by one column;
+/// characters missing from the font render as a blank glyph. Every font is
+/// derived from `BANNER_FONT`: `ascii` swaps the block for `#`, and
+/// `compact` packs each pair of rows into one with half blocks.
+fn render_banner(text: &str, font: BannerFont) -> Vec<String> {
+    let blank = BANNER_FONT
+        .iter()
+        .find(|(c, _)| *c == ' ')
+        .map(|(_, glyph)| *glyph)
+        .unwrap_or(&[]);
 
-    for ch in text.chars() {
-        if let Some((_, pattern)) = BANNER_FONT.iter().find(|(c, _)| *c == ch) {
-            for (i, line) in pattern.iter().enumerate() {
-                rows[i].push_str(line);
-                rows[i].push(' '); // spacing between letters
-            }
-        } else {
-            // Unknown char - add space
-            for row in &mut rows {
-                row.push_str("     ");
+    let mut rows = vec![String::new(); BANNER_HEIGHT];
+    for (i, ch) in text.to_uppercase().chars().enumerate() {
+        let glyph = BANNER_FONT
+            .iter()
+            .find(|(c, _)| *c == ch)
+            .map(|(_, glyph)| *glyph)
+            .unwrap_or(blank);
+        for (row, line) in rows.iter_mut().zip(glyph) {
+            if i > 0 {
                 row.push(' ');
             }
+            row.push_str(line);
         }
     }
 
+    match font {
+        BannerFont::Block => rows,
+        BannerFont::Ascii => rows.iter().map(|row| row.replace('█', "#")).collect(),
+        BannerFont::Compact => rows
+            .chunks(2)
+            .map(|pair| {
+                let top: Vec<char> = pair[0].chars().collect();
+                let bottom: Vec<char> =
+                    pair.get(1).map(|r| r.chars().collect()).unwrap_or_default();
+                top.iter()
+                    .enumerate()
+                    .map(
+                        |(x, &t)| match (t != ' ', bottom.get(x).is_some_and(|&b| b != ' ')) {
+                            (true, true) => '█',
+                            (true, false) => '▀',
+                            (false, true) => '▄',
+                            (false, false) => ' ',
+                        },
+                    )
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+/// Big ASCII text banner
+fn cmd_banner(text: &str, font: BannerFont) -> Result<()> {
     // Print with color
     println!();
-    for row in &rows {
+    for row in render_banner(text, font) {
         println!("  {}", row.cyan());
     }
     println!();
@@ -1656,3 +1819,141 @@ fn disable_raw_mode() {
 
 #[cfg(not(unix))]
 fn disable_raw_mode() {}
+
+// =============================================================================
+// TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn single_column(height: usize, speed: f32, len: usize) -> MatrixRain {
+        MatrixRain {
+            columns: vec![RainColumn {
+                pos: 0.0,
+                speed,
+                len,
+                active: true,
+            }],
+            screen: vec![vec![' '; 1]; height],
+            chars: vec!['x'],
+            density: 0,
+        }
+    }
+
+    fn lit_rows(rain: &MatrixRain) -> Vec<usize> {
+        (0..rain.screen.len())
+            .filter(|&y| rain.screen[y][0] != ' ')
+            .collect()
+    }
+
+    #[test]
+    fn test_ascii_bars_and_spinners() {
+        for (name, _, _, _) in BAR_STYLES {
+            let style = bar_style(name, true);
+            for progress in [0, 40, 100] {
+                let bar = render_bar(progress, 20, style, true);
+                assert!(bar.is_ascii(), "{}: {:?}", name, bar);
+            }
+            assert!(render_bar_complete(20, style).is_ascii());
+        }
+        assert_eq!(bar_style("arrow", true), "arrow");
+        assert_eq!(bar_style("gradient", false), "gradient");
+
+        for (name, _, frames) in SPINNER_STYLES {
+            assert!(is_ascii_frames(style_frames(frames, true)), "{}", name);
+        }
+        assert!(simple_bar(50, 10, true).is_ascii());
+        assert!(check_mark(true).is_ascii());
+    }
+
+    #[test]
+    fn test_banner_single_char() {
+        assert_eq!(
+            render_banner("a", BannerFont::Block),
+            vec!["  █  ", " █ █ ", "█████", "█   █", "█   █"]
+        );
+        assert_eq!(
+            render_banner("A", BannerFont::Ascii),
+            vec!["  #  ", " # # ", "#####", "#   #", "#   #"]
+        );
+        assert_eq!(
+            render_banner("A", BannerFont::Compact),
+            vec![" ▄▀▄ ", "█▀▀▀█", "▀   ▀"]
+        );
+    }
+
+    #[test]
+    fn test_banner_spacing_and_unknown_chars() {
+        let rows = render_banner("I~1", BannerFont::Block);
+        assert_eq!(rows.len(), BANNER_HEIGHT);
+        // Three 5-wide glyphs plus two separators
+        assert!(rows.iter().all(|row| row.chars().count() == 17));
+        assert_eq!(rows[0], "█████        █   ");
+    }
+
+    #[test]
+    fn test_matrix_column_advance() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut rain = single_column(10, 1.0, 3);
+
+        for _ in 0..5 {
+            rain.step(&mut rng);
+        }
+        // Head at row 5; rows 1..=5 drawn, row 2 and behind erased by the tail
+        assert_eq!(rain.columns[0].pos, 5.0);
+        assert_eq!(lit_rows(&rain), vec![3, 4, 5]);
+
+        // Tail passes the bottom (row 11 > height 10) after 14 frames
+        for _ in 0..8 {
+            rain.step(&mut rng);
+        }
+        assert_eq!(rain.columns[0].pos, 13.0);
+        assert!(rain.columns[0].active);
+        assert!(lit_rows(&rain).is_empty());
+        rain.step(&mut rng);
+        assert!(!rain.columns[0].active);
+    }
+
+    #[test]
+    fn test_matrix_fractional_speed() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut rain = single_column(20, 0.5, 4);
+        for _ in 0..8 {
+            rain.step(&mut rng);
+        }
+        // Moves one row every two frames
+        assert_eq!(rain.columns[0].pos, 4.0);
+        assert_eq!(lit_rows(&rain), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_matrix_inactive_column_stays_put_without_density() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut rain = single_column(10, 1.0, 3);
+        rain.columns[0].active = false;
+        for _ in 0..50 {
+            rain.step(&mut rng);
+        }
+        assert_eq!(rain.columns[0].pos, 0.0);
+        assert!(lit_rows(&rain).is_empty());
+    }
+
+    #[test]
+    fn test_matrix_render_colors() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut rain = single_column(6, 1.0, 5);
+        for _ in 0..4 {
+            rain.step(&mut rng);
+        }
+        let rendered = rain.render(MatrixColor::Blue);
+        assert!(rendered.starts_with("\x1b[H"));
+        assert!(rendered.contains("\x1b[97mx"), "white head");
+        assert!(rendered.contains("\x1b[94mx"), "bright trail");
+        assert!(rendered.contains("\x1b[34mx"), "normal trail");
+        assert_eq!(rendered.matches("\r\n").count(), 6);
+    }
+}
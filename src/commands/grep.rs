@@ -4,8 +4,6 @@ use crate::cli::commands::grep::GrepArgs;
 use anyhow::{Context, Result};
 use colored::Colorize;
 use regex::{Regex, RegexBuilder};
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 use std::path::Path;
 use walkdir::WalkDir;
 
@@ -13,6 +11,8 @@ use walkdir::WalkDir;
 pub fn run(args: GrepArgs) -> Result<()> {
     let regex = RegexBuilder::new(&args.pattern)
         .case_insensitive(args.ignore_case)
+        .multi_line(args.multiline)
+        .dot_matches_new_line(args.multiline)
         .build()
         .context("Invalid regex pattern")?;
 
@@ -22,21 +22,24 @@ pub fn run(args: GrepArgs) -> Result<()> {
         args.paths.clone()
     };
 
+    // Prefix output with filenames whenever more than one file can be searched
+    let show_filename = args.recursive || paths.len() > 1 || paths.iter().any(|p| p.is_dir());
+
     let mut total_matches = 0;
 
     for path in &paths {
         if path.is_file() {
-            total_matches += search_file(path, &regex, &args)?;
+            total_matches += search_file(path, &regex, &args, show_filename)?;
         } else if path.is_dir() {
             if args.recursive {
-                total_matches += search_dir(path, &regex, &args)?;
+                total_matches += search_dir(path, &regex, &args, show_filename)?;
             } else {
                 // Non-recursive: only search files in this directory
                 for entry in std::fs::read_dir(path)? {
                     let entry = entry?;
                     let path = entry.path();
                     if path.is_file() && should_include(&path, &args) {
-                        total_matches += search_file(&path, &regex, &args)?;
+                        total_matches += search_file(&path, &regex, &args, show_filename)?;
                     }
                 }
             }
@@ -45,17 +48,15 @@ pub fn run(args: GrepArgs) -> Result<()> {
         }
     }
 
-    if args.count && paths.len() == 1 && paths[0].is_file() {
-        // Already printed in search_file
-    } else if total_matches == 0 && !args.files_only && !args.count {
-        // No matches found, exit with non-zero status
+    if total_matches == 0 {
+        // No matches found, exit with non-zero status like grep
         std::process::exit(1);
     }
 
     Ok(())
 }
 
-fn search_dir(dir: &Path, regex: &Regex, args: &GrepArgs) -> Result<usize> {
+fn search_dir(dir: &Path, regex: &Regex, args: &GrepArgs, show_filename: bool) -> Result<usize> {
     let mut total = 0;
     let walker = WalkDir::new(dir).follow_links(true);
 
@@ -77,7 +78,7 @@ fn search_dir(dir: &Path, regex: &Regex, args: &GrepArgs) -> Result<usize> {
         }
 
         if path.is_file() && should_include(path, args) {
-            total += search_file(path, regex, args)?;
+            total += search_file(path, regex, args, show_filename)?;
         }
     }
 
@@ -130,87 +131,182 @@ fn glob_match(pattern: &str, name: &str) -> bool {
     }
 }
 
-fn search_file(path: &Path, regex: &Regex, args: &GrepArgs) -> Result<usize> {
-    let file = match File::open(path) {
-        Ok(f) => f,
+/// Search a single file and print results, returning the number of selected lines.
+///
+/// With `--files-with-matches` the search stops at the first selected line,
+/// so the returned count is at most 1.
+fn search_file(path: &Path, regex: &Regex, args: &GrepArgs, show_filename: bool) -> Result<usize> {
+    let bytes = match std::fs::read(path) {
+        Ok(b) => b,
         Err(e) => {
             eprintln!("{}: {}", path.display(), e);
             return Ok(0);
         }
     };
 
-    let reader = BufReader::new(file);
-    let lines: Vec<String> = reader.lines().map_while(Result::ok).collect();
+    let content = String::from_utf8_lossy(&bytes);
 
-    let mut match_count = 0;
-    let mut matched_lines: Vec<(usize, String)> = Vec::new();
+    // Listing files only needs to know whether any line is selected
+    if args.files_only && !args.multiline {
+        let found = content
+            .lines()
+            .any(|line| regex.is_match(line) != args.invert);
+        if !found {
+            return Ok(0);
+        }
+        println!("{}", path.display());
+        return Ok(1);
+    }
 
-    for (line_num, line) in lines.iter().enumerate() {
-        let is_match = regex.is_match(line);
-        let should_show = if args.invert { !is_match } else { is_match };
+    let lines: Vec<&str> = content.lines().collect();
+    let matches = find_matching_lines(&content, regex, args.multiline, args.invert);
+    let match_count = matches.len();
 
-        if should_show {
-            match_count += 1;
-            matched_lines.push((line_num + 1, line.clone()));
+    // Counts are printed for every file, including those with no matches
+    if args.count && !args.files_only {
+        if show_filename {
+            println!("{}:{}", path.display(), match_count);
+        } else {
+            println!("{}", match_count);
         }
+        return Ok(match_count);
     }
 
     if match_count == 0 {
         return Ok(0);
     }
 
-    // Output based on mode
     if args.files_only {
         println!("{}", path.display());
         return Ok(match_count);
     }
 
-    if args.count {
-        println!("{}:{}", path.display(), match_count);
-        return Ok(match_count);
-    }
-
     // Determine context lines
     let before = args.context.or(args.before).unwrap_or(0);
     let after = args.context.or(args.after).unwrap_or(0);
 
-    let show_filename = args.recursive || args.paths.len() > 1;
+    let groups = select_with_context(&matches, lines.len(), before, after);
+    for (i, group) in groups.iter().enumerate() {
+        // Separate non-adjacent context regions like grep does
+        if i > 0 && (before > 0 || after > 0) {
+            println!("{}", "--".dimmed());
+        }
 
-    for (line_num, line) in &matched_lines {
-        // Show context before
-        if before > 0 {
-            let start = line_num.saturating_sub(before + 1);
-            for i in start..(*line_num - 1) {
-                if i < lines.len() {
-                    print_context_line(path, i + 1, &lines[i], show_filename, args.line_number);
-                }
+        for selected in group {
+            let line = lines[selected.index];
+            if selected.is_match {
+                print_match_line(
+                    path,
+                    selected.index + 1,
+                    line,
+                    regex,
+                    show_filename,
+                    args.line_number,
+                );
+            } else {
+                print_context_line(
+                    path,
+                    selected.index + 1,
+                    line,
+                    show_filename,
+                    args.line_number,
+                );
             }
         }
+    }
+
+    Ok(match_count)
+}
+
+/// A line chosen for output, either a match or surrounding context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SelectedLine {
+    /// Zero-based line index
+    index: usize,
+    /// Whether the line matched (false for context lines)
+    is_match: bool,
+}
+
+/// Find the zero-based indices of lines that match the pattern.
+///
+/// In multiline mode the regex runs over the whole content and every line
+/// touched by a match counts as matching. With `invert`, the non-matching
+/// lines are returned instead.
+fn find_matching_lines(content: &str, regex: &Regex, multiline: bool, invert: bool) -> Vec<usize> {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.is_empty() {
+        return Vec::new();
+    }
 
-        // Show matching line
-        print_match_line(
-            path,
-            *line_num,
-            line,
-            regex,
-            show_filename,
-            args.line_number,
-        );
-
-        // Show context after
-        if after > 0 {
-            for (i, line) in lines
-                .iter()
-                .enumerate()
-                .take((*line_num + after).min(lines.len()))
-                .skip(*line_num)
-            {
-                print_context_line(path, i + 1, line, show_filename, args.line_number);
+    let mut matched = vec![false; lines.len()];
+    if multiline {
+        let starts = line_starts(content);
+        let last_line = lines.len() - 1;
+        for m in regex.find_iter(content) {
+            let first = line_index(&starts, m.start()).min(last_line);
+            let last = line_index(&starts, m.end().saturating_sub(1).max(m.start())).min(last_line);
+            for flag in &mut matched[first..=last] {
+                *flag = true;
             }
         }
+    } else {
+        for (flag, line) in matched.iter_mut().zip(&lines) {
+            *flag = regex.is_match(line);
+        }
     }
 
-    Ok(match_count)
+    matched
+        .iter()
+        .enumerate()
+        .filter(|(_, &is_match)| is_match != invert)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Byte offsets at which each line of `content` starts.
+fn line_starts(content: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+        .collect()
+}
+
+/// Zero-based line containing the byte offset `pos`.
+fn line_index(starts: &[usize], pos: usize) -> usize {
+    starts.partition_point(|&s| s <= pos).saturating_sub(1)
+}
+
+/// Expand matches with context lines, merging overlapping or adjacent regions.
+///
+/// Each returned group is a contiguous run of lines; groups never overlap, so
+/// no line is printed twice.
+fn select_with_context(
+    matches: &[usize],
+    total_lines: usize,
+    before: usize,
+    after: usize,
+) -> Vec<Vec<SelectedLine>> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+
+    for &m in matches {
+        let start = m.saturating_sub(before);
+        let end = (m + after).min(total_lines.saturating_sub(1));
+        match ranges.last_mut() {
+            Some((_, last_end)) if start <= *last_end + 1 => *last_end = (*last_end).max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| {
+            (start..=end)
+                .map(|index| SelectedLine {
+                    index,
+                    is_match: matches.binary_search(&index).is_ok(),
+                })
+                .collect()
+        })
+        .collect()
 }
 
 fn print_match_line(
@@ -260,3 +356,74 @@ fn print_context_line(
     output.push_str(line);
     println!("{}", output.dimmed());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BUFFER: &str =
+        "one\ntwo\nthree match\nfour\nfive\nsix match\nseven\neight\nnine match\nten";
+
+    fn indices(group: &[SelectedLine]) -> Vec<usize> {
+        group.iter().map(|l| l.index).collect()
+    }
+
+    #[test]
+    fn test_context_one_line_each_side() {
+        let regex = Regex::new("three").unwrap();
+        let matches = find_matching_lines(BUFFER, &regex, false, false);
+        assert_eq!(matches, vec![2]);
+
+        let groups = select_with_context(&matches, 10, 1, 1);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(indices(&groups[0]), vec![1, 2, 3]);
+        assert!(!groups[0][0].is_match);
+        assert!(groups[0][1].is_match);
+        assert!(!groups[0][2].is_match);
+    }
+
+    #[test]
+    fn test_overlapping_context_merges() {
+        let regex = Regex::new("match").unwrap();
+        let matches = find_matching_lines(BUFFER, &regex, false, false);
+        assert_eq!(matches, vec![2, 5, 8]);
+
+        // Lines 3 and 4 are shared context between the first two matches
+        let groups = select_with_context(&matches, 10, 2, 2);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(indices(&groups[0]), (0..10).collect::<Vec<_>>());
+
+        // Adjacent regions are joined as well
+        let groups = select_with_context(&matches, 10, 1, 1);
+        assert_eq!(groups.len(), 1);
+
+        // With a gap between them the regions stay separate
+        let groups = select_with_context(&matches, 10, 1, 0);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(indices(&groups[1]), vec![4, 5]);
+    }
+
+    #[test]
+    fn test_context_clamped_at_edges() {
+        let groups = select_with_context(&[0, 9], 10, 2, 2);
+        assert_eq!(indices(&groups[0]), vec![0, 1, 2]);
+        assert_eq!(indices(&groups[1]), vec![7, 8, 9]);
+    }
+
+    #[test]
+    fn test_multiline_match_spans_lines() {
+        let regex = RegexBuilder::new("three.*four")
+            .dot_matches_new_line(true)
+            .build()
+            .unwrap();
+        assert_eq!(find_matching_lines(BUFFER, &regex, true, false), vec![2, 3]);
+        assert!(find_matching_lines(BUFFER, &regex, false, false).is_empty());
+    }
+
+    #[test]
+    fn test_invert() {
+        let regex = Regex::new("match").unwrap();
+        let matches = find_matching_lines(BUFFER, &regex, false, true);
+        assert_eq!(matches, vec![0, 1, 3, 4, 6, 7, 9]);
+    }
+}
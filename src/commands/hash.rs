@@ -8,6 +8,17 @@
 //! - **MD5**: 128-bit hash, fast but cryptographically broken. Use only for checksums.
 //! - **SHA-256**: 256-bit hash from the SHA-2 family. Recommended for most use cases.
 //! - **SHA-512**: 512-bit hash, more secure but slower than SHA-256.
+//! - **BLAKE3**: 256-bit hash, much faster than SHA-2 and still secure.
+//!
+//! ## HMAC
+//! `--hmac --key <k>` computes a keyed HMAC (RFC 2104) with the selected
+//! digest, e.g. HMAC-SHA256 by default. BLAKE3 has its own keyed mode with a
+//! fixed 32-byte key and is not offered as an HMAC.
+//!
+//! ## Streaming
+//! Digest algorithms read their input in fixed-size chunks via [`hash_reader`],
+//! so hashing a huge file or a stdin pipe uses constant memory. Password hashes
+//! (bcrypt, Argon2) operate on short secrets and read the input fully.
 //!
 //! ## Key Concepts
 //!
@@ -23,27 +34,34 @@
 //! ```bash
 //! dx hash file.txt                    # SHA-256 (default)
 //! dx hash -a md5 file.txt             # MD5
+//! dx hash -a blake3 big.iso           # BLAKE3, streamed
 //! dx hash -s "hello world"            # Hash a string
 //! echo "data" | dx hash -             # Hash from stdin
 //! dx hash --verify abc123 file.txt    # Verify against expected hash
+//! dx hash --hmac --key s3cret file    # HMAC-SHA256
+//! dx hash --hmac --key k --check ab12 # Verify an HMAC
 //! ```
 //!
 //! ## External Documentation
 //! - SHA-2: <https://docs.rs/sha2>
 //! - MD5: <https://docs.rs/md5>
+//! - BLAKE3: <https://docs.rs/blake3>
 //! - Digest trait: <https://docs.rs/digest>
 
-use crate::cli::commands::hash::{Algorithm, HashArgs};
+pub use crate::cli::commands::hash::Algorithm;
+use crate::cli::commands::hash::HashArgs;
 use anyhow::{Context, Result};
 use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
 use argon2::Argon2;
 use colored::Colorize;
+use hmac::{Hmac, Mac};
 use md5::Md5;
 use rand::Rng;
 use sha2::{Digest, Sha256, Sha512};
 use std::fs::File;
 use std::io::{self, BufReader, Read};
 use std::path::Path;
+use subtle::ConstantTimeEq;
 
 /// Run the hash command with the provided arguments.
 ///
@@ -59,6 +77,29 @@ use std::path::Path;
 /// * `Ok(())` on success
 /// * `Err` if reading input fails or verification fails
 pub fn run(args: HashArgs) -> Result<()> {
+    if let Some(key) = args.key.as_deref().filter(|_| args.hmac) {
+        let (reader, source) = open_input(&args)?;
+        let mac = hmac_reader(reader, args.algorithm, key.as_bytes())?;
+        let label = format!("HMAC-{}", args.algorithm);
+        if let Some(expected) = &args.verify {
+            return report_verification(compare_digest(&mac, expected, false), &label);
+        }
+        print_hash(&args, &source, &label, &mac);
+        return Ok(());
+    }
+
+    if args.algorithm.is_digest() {
+        // Digests are computed as the input streams in
+        let (reader, source) = open_input(&args)?;
+        let hash = hash_reader(reader, args.algorithm)?;
+        let label = args.algorithm.to_string();
+        if let Some(expected) = &args.verify {
+            return report_verification(compare_digest(&hash, expected, true), &label);
+        }
+        print_hash(&args, &source, &label, &hash);
+        return Ok(());
+    }
+
     // Step 1: Get input data and its source description (for display)
     let (data, source) = get_input(&args)?;
 
@@ -69,20 +110,42 @@ pub fn run(args: HashArgs) -> Result<()> {
     } else {
         // Compute the hash using the selected algorithm
         let hash = compute_hash(&data, args.algorithm, args.cost)?;
-
-        if args.quiet {
-            // Quiet mode: just print the hash (useful for scripts)
-            println!("{}", hash);
-        } else {
-            // Normal mode: print with colors and source info
-            // Format: "source (algorithm) = hash"
-            println!("{} ({}) = {}", source.cyan(), args.algorithm, hash.green());
-        }
+        print_hash(&args, &source, &args.algorithm.to_string(), &hash);
     }
 
     Ok(())
 }
 
+/// Print a computed hash in quiet or decorated form.
+fn print_hash(args: &HashArgs, source: &str, label: &str, hash: &str) {
+    if args.quiet {
+        // Quiet mode: just print the hash (useful for scripts)
+        println!("{}", hash);
+    } else {
+        // Normal mode: print with colors and source info
+        // Format: "source (algorithm) = hash"
+        println!("{} ({}) = {}", source.cyan(), label, hash.green());
+    }
+}
+
+/// Open the input as a reader, with the same priority as [`get_input`].
+fn open_input(args: &HashArgs) -> Result<(Box<dyn Read>, String)> {
+    if let Some(s) = &args.string {
+        return Ok((
+            Box::new(io::Cursor::new(s.clone().into_bytes())),
+            "string".to_string(),
+        ));
+    }
+    match &args.input {
+        Some(path) if path.to_string_lossy() != "-" => {
+            let file =
+                File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+            Ok((Box::new(file), path.display().to_string()))
+        }
+        _ => Ok((Box::new(io::stdin()), "stdin".to_string())),
+    }
+}
+
 /// Get input data from one of three sources: string, file, or stdin.
 ///
 /// The priority order is:
@@ -134,9 +197,8 @@ fn get_input(args: &HashArgs) -> Result<(Vec<u8>, String)> {
 /// files it improves performance significantly.
 ///
 /// # Memory Considerations
-/// This reads the entire file into memory. For very large files (multiple GB),
-/// you might want to use streaming hash computation instead. See the `Digest`
-/// trait's `update()` method which can be called multiple times with chunks.
+/// This reads the entire file into memory, so it is only used for password
+/// hashes. Digest algorithms stream the file through [`hash_reader`] instead.
 ///
 /// # Arguments
 /// * `path` - Path to the file to read
@@ -163,6 +225,91 @@ fn read_file(path: &Path) -> Result<Vec<u8>> {
     Ok(data)
 }
 
+/// Bytes read per chunk when streaming input into a digest.
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Hash a file with a digest algorithm, streaming its contents.
+///
+/// # Errors
+/// Fails if the file cannot be read or `algorithm` is a password hash.
+pub fn hash_file(path: impl AsRef<Path>, algorithm: Algorithm) -> Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    hash_reader(file, algorithm).with_context(|| format!("Failed to hash {}", path.display()))
+}
+
+/// Hash everything from a reader with a digest algorithm.
+///
+/// Input is consumed in fixed-size chunks and fed to the hasher with
+/// `update()`, so memory use is constant regardless of input size.
+///
+/// # Errors
+/// Fails on read errors, or if `algorithm` is bcrypt or Argon2, which are
+/// salted password hashes rather than digests (use [`compute_hash`]).
+pub fn hash_reader<R: Read>(reader: R, algorithm: Algorithm) -> Result<String> {
+    match algorithm {
+        Algorithm::Md5 => digest_reader::<Md5, _>(reader),
+        Algorithm::Sha256 => digest_reader::<Sha256, _>(reader),
+        Algorithm::Sha512 => digest_reader::<Sha512, _>(reader),
+        Algorithm::Blake3 => {
+            let mut hasher = blake3::Hasher::new();
+            for_each_chunk(reader, |chunk| {
+                hasher.update(chunk);
+            })?;
+            Ok(hasher.finalize().to_hex().to_string())
+        }
+        Algorithm::Bcrypt | Algorithm::Argon2 => anyhow::bail!(
+            "{} is a password hash and cannot be computed from a stream",
+            algorithm
+        ),
+    }
+}
+
+/// Compute a hex HMAC of everything from a reader.
+///
+/// # Errors
+/// Fails on read errors, or if `algorithm` has no HMAC construction here
+/// (BLAKE3, bcrypt, Argon2).
+pub fn hmac_reader<R: Read>(reader: R, algorithm: Algorithm, key: &[u8]) -> Result<String> {
+    match algorithm {
+        Algorithm::Md5 => mac_reader::<Hmac<Md5>, _>(reader, key),
+        Algorithm::Sha256 => mac_reader::<Hmac<Sha256>, _>(reader, key),
+        Algorithm::Sha512 => mac_reader::<Hmac<Sha512>, _>(reader, key),
+        Algorithm::Blake3 | Algorithm::Bcrypt | Algorithm::Argon2 => anyhow::bail!(
+            "HMAC is not supported with {} (use md5, sha256, or sha512)",
+            algorithm
+        ),
+    }
+}
+
+/// Stream a reader through any keyed `Mac` implementation.
+fn mac_reader<M: Mac + hmac::digest::KeyInit, R: Read>(reader: R, key: &[u8]) -> Result<String> {
+    // HMAC accepts keys of any length, so this cannot fail
+    let mut mac = <M as Mac>::new_from_slice(key).context("Invalid HMAC key")?;
+    for_each_chunk(reader, |chunk| mac.update(chunk))?;
+    Ok(hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Stream a reader through any `Digest` implementation.
+fn digest_reader<D: Digest, R: Read>(reader: R) -> Result<String> {
+    let mut hasher = D::new();
+    for_each_chunk(reader, |chunk| hasher.update(chunk))?;
+    Ok(hex::encode(hasher.finalize()))
+}
+
+/// Call `f` with successive chunks read from `reader` until end of input.
+fn for_each_chunk<R: Read>(mut reader: R, mut f: impl FnMut(&[u8])) -> Result<()> {
+    let mut buf = vec![0u8; HASH_CHUNK_SIZE];
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => f(&buf[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e).context("Failed to read input"),
+        }
+    }
+}
+
 /// Compute the cryptographic hash of data using the specified algorithm.
 ///
 /// # How Hashing Works
@@ -179,6 +326,7 @@ fn read_file(path: &Path) -> Result<Vec<u8>> {
 /// | MD5       | 128 bits    | Fastest  | Broken (collisions found) |
 /// | SHA-256   | 256 bits    | Fast     | Secure            |
 /// | SHA-512   | 512 bits    | Slower   | Most secure       |
+/// | BLAKE3    | 256 bits    | Fastest  | Secure            |
 /// | Bcrypt    | Variable    | Slow     | Password hashing  |
 /// | Argon2    | Variable    | Slow     | Modern password hash |
 ///
@@ -187,6 +335,7 @@ fn read_file(path: &Path) -> Result<Vec<u8>> {
 /// - **MD5**: Only for non-security checksums (file integrity, cache keys)
 /// - **SHA-256**: General purpose, file verification, most applications
 /// - **SHA-512**: When you need extra security margin
+/// - **BLAKE3**: Fast hashing of large files
 /// - **Bcrypt**: Password hashing with configurable cost
 /// - **Argon2**: Modern password hashing, memory-hard
 ///
@@ -223,6 +372,12 @@ pub fn compute_hash(data: &[u8], algorithm: Algorithm, cost: u32) -> Result<Stri
             hasher.update(data);
             Ok(hex::encode(hasher.finalize()))
         }
+        Algorithm::Blake3 => {
+            // BLAKE3 produces a 256-bit (32 byte) hash by default
+            // Built on a Merkle tree, so it parallelizes and is very fast
+            // See: https://github.com/BLAKE3-team/BLAKE3
+            Ok(blake3::hash(data).to_hex().to_string())
+        }
         Algorithm::Bcrypt => {
             // Bcrypt password hash with configurable cost (4-31)
             // Note: bcrypt has a 72 byte password limit
@@ -267,18 +422,10 @@ pub fn compute_hash(data: &[u8], algorithm: Algorithm, cost: u32) -> Result<Stri
 /// * Exits with code 1 if verification fails
 fn verify_hash(data: &[u8], expected: &str, algorithm: Algorithm) -> Result<()> {
     let verified = match algorithm {
-        Algorithm::Md5 | Algorithm::Sha256 | Algorithm::Sha512 => {
+        Algorithm::Md5 | Algorithm::Sha256 | Algorithm::Sha512 | Algorithm::Blake3 => {
             // For standard hash algorithms, compute and compare
             let computed = compute_hash(data, algorithm, 0)?;
-            let expected_lower = expected.to_lowercase();
-            if computed == expected_lower {
-                true
-            } else {
-                eprintln!("{} Hash mismatch!", "✗".red().bold());
-                eprintln!("  Expected: {}", expected_lower.yellow());
-                eprintln!("  Got:      {}", computed.red());
-                false
-            }
+            compare_digest(&computed, expected, true)
         }
         Algorithm::Bcrypt => {
             // Bcrypt has built-in verification
@@ -316,12 +463,35 @@ fn verify_hash(data: &[u8], expected: &str, algorithm: Algorithm) -> Result<()>
         }
     };
 
+    report_verification(verified, &algorithm.to_string())
+}
+
+/// Compare a computed hex digest to an expected one (case-insensitive),
+/// printing both on mismatch when `reveal` is set.
+///
+/// The comparison is constant-time so that checking a MAC doesn't leak how
+/// many leading characters matched. MAC checks pass `reveal: false`: the
+/// computed value is the correct MAC for the data, so printing it would
+/// hand it to whoever supplied the wrong one.
+fn compare_digest(computed: &str, expected: &str, reveal: bool) -> bool {
+    let expected_lower = expected.trim().to_lowercase();
+    if bool::from(computed.as_bytes().ct_eq(expected_lower.as_bytes())) {
+        true
+    } else if reveal {
+        eprintln!("{} Hash mismatch!", "✗".red().bold());
+        eprintln!("  Expected: {}", expected_lower.yellow());
+        eprintln!("  Got:      {}", computed.red());
+        false
+    } else {
+        eprintln!("{} HMAC mismatch!", "✗".red().bold());
+        false
+    }
+}
+
+/// Print the verification outcome, exiting with code 1 on failure.
+fn report_verification(verified: bool, label: &str) -> Result<()> {
     if verified {
-        println!(
-            "{} {} hash verified",
-            "✓".green().bold(),
-            algorithm.to_string().cyan()
-        );
+        println!("{} {} hash verified", "✓".green().bold(), label.cyan());
         Ok(())
     } else {
         std::process::exit(1);
@@ -389,4 +559,93 @@ mod tests {
         let parsed = PasswordHash::new(&hash).unwrap();
         assert!(Argon2::default().verify_password(data, &parsed).is_ok());
     }
+
+    /// Test BLAKE3 hash computation against the reference value.
+    /// You can verify with: echo -n "hello world" | b3sum
+    #[test]
+    fn test_blake3_hash() {
+        let hash = compute_hash(b"hello world", Algorithm::Blake3, 0).unwrap();
+        assert_eq!(
+            hash,
+            "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24"
+        );
+    }
+
+    /// Test that hash_reader over a chunked reader matches the known digest.
+    #[test]
+    fn test_hash_reader_chunked_blake3() {
+        /// Reader that hands out at most 3 bytes per call.
+        struct Chunked<'a>(&'a [u8]);
+        impl Read for Chunked<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let n = buf.len().min(3).min(self.0.len());
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+
+        let hash = hash_reader(Chunked(b"hello world"), Algorithm::Blake3).unwrap();
+        assert_eq!(
+            hash,
+            "d74981efa70a0c880b8d8c1985d075dbcbf679b99a5f9914e5aaf96b831a9e24"
+        );
+    }
+
+    /// Test that streaming matches one-shot hashing across chunk boundaries.
+    #[test]
+    fn test_hash_reader_matches_compute_hash() {
+        let data: Vec<u8> = (0..HASH_CHUNK_SIZE * 3 + 17)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        for algorithm in [
+            Algorithm::Md5,
+            Algorithm::Sha256,
+            Algorithm::Sha512,
+            Algorithm::Blake3,
+        ] {
+            assert_eq!(
+                hash_reader(data.as_slice(), algorithm).unwrap(),
+                compute_hash(&data, algorithm, 0).unwrap()
+            );
+        }
+    }
+
+    /// Test hash_file and that password hashes are rejected for streams.
+    #[test]
+    fn test_hash_file() {
+        use std::io::Write as _;
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        file.write_all(b"hello world").unwrap();
+        assert_eq!(
+            hash_file(file.path(), Algorithm::Md5).unwrap(),
+            "5eb63bbbe01eeed093cb22bb8f5acdc3"
+        );
+        assert!(hash_reader(&b"secret"[..], Algorithm::Bcrypt).is_err());
+    }
+
+    /// Test HMAC-SHA256 against RFC 4231 test case 2.
+    #[test]
+    fn test_hmac_sha256_vector() {
+        let mac = hmac_reader(
+            &b"what do ya want for nothing?"[..],
+            Algorithm::Sha256,
+            b"Jefe",
+        )
+        .unwrap();
+        assert_eq!(
+            mac,
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        assert!(hmac_reader(&b""[..], Algorithm::Blake3, b"k").is_err());
+    }
+
+    /// Test constant-time digest comparison, including case and length.
+    #[test]
+    fn test_compare_digest() {
+        assert!(compare_digest("abc123", "ABC123", true));
+        assert!(!compare_digest("abc123", "abc124", true));
+        assert!(!compare_digest("abc123", "abc12", false));
+    }
 }
@@ -1,12 +1,32 @@
 //! HTTP command - make HTTP requests.
 
-use crate::cli::commands::http::{HttpArgs, HttpCommand, OutputFormat};
+use crate::cli::commands::http::{
+    BodyOptions, HttpArgs, HttpCommand, OutputFormat, RequestOptions,
+};
+use crate::utils::progress::TerminalProgress;
+use crate::utils::{print_warning, write_atomic};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use serde_json::{json, Value};
 use std::fs;
-use std::time::Duration;
-use ureq::Agent;
+use std::io::{self, BufWriter, IsTerminal, Read, Write};
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+use ureq::http::Response;
+use ureq::{Agent, Body};
+
+/// Delay before the first retry; doubled after every further attempt.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Upper bound for the delay between retries.
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Read size when streaming a download to disk.
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Minimum time between progress redraws.
+const PROGRESS_REDRAW_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Run the http command
 pub fn run(args: HttpArgs) -> Result<()> {
@@ -15,56 +35,109 @@ pub fn run(args: HttpArgs) -> Result<()> {
             url,
             headers,
             format,
-            follow: _,
-            timeout,
-        } => cmd_get(&url, &headers, format, timeout),
+            out_file,
+            resume,
+            options,
+        } => match out_file {
+            Some(path) => cmd_download(&url, &headers, &path, resume, &options),
+            None => cmd_get(&url, &headers, format, &options),
+        },
 
         HttpCommand::Post {
             url,
-            data,
-            file,
+            body,
             headers,
-            content_type,
             format,
-            follow: _,
-            timeout,
-        } => cmd_post(&url, data, file, &headers, &content_type, format, timeout),
+            options,
+        } => cmd_post(&url, &body, &headers, format, &options),
 
         HttpCommand::Put {
             url,
-            data,
+            body,
             headers,
-            content_type,
             format,
-            timeout,
-        } => cmd_put(&url, data, &headers, &content_type, format, timeout),
+            options,
+        } => cmd_put(&url, &body, &headers, format, &options),
 
         HttpCommand::Delete {
             url,
             headers,
             format,
-            timeout,
-        } => cmd_delete(&url, &headers, format, timeout),
+            options,
+        } => cmd_delete(&url, &headers, format, &options),
 
         HttpCommand::Head {
             url,
             headers,
-            timeout,
-        } => cmd_head(&url, &headers, timeout),
+            options,
+        } => cmd_head(&url, &headers, &options),
     }
 }
 
-fn create_agent(timeout: u64) -> Agent {
+fn create_agent(options: &RequestOptions) -> Agent {
     let tls_config = ureq::tls::TlsConfig::builder()
         .provider(ureq::tls::TlsProvider::NativeTls)
         .build();
     Agent::config_builder()
-        .timeout_global(Some(Duration::from_secs(timeout)))
+        .timeout_global(Some(Duration::from_secs(options.timeout)))
+        .max_redirects(options.max_redirects)
         .tls_config(tls_config)
         .build()
         .into()
 }
 
+/// Whether a failed request is worth retrying (server errors and transient
+/// connection problems).
+fn is_retryable(err: &ureq::Error) -> bool {
+    matches!(
+        err,
+        ureq::Error::StatusCode(500..=599)
+            | ureq::Error::Io(_)
+            | ureq::Error::Timeout(_)
+            | ureq::Error::ConnectionFailed
+    )
+}
+
+/// Send a request, retrying with exponential backoff when allowed.
+///
+/// `send` builds and sends a fresh request on every attempt. Only idempotent
+/// methods should pass `retries > 0`, since a retried POST may be applied
+/// twice by the server.
+fn send_with_retry<F>(retries: u32, mut send: F) -> Result<Response<Body>, ureq::Error>
+where
+    F: FnMut() -> Result<Response<Body>, ureq::Error>,
+{
+    let mut attempt = 0;
+    loop {
+        match send() {
+            Err(err) if attempt < retries && is_retryable(&err) => {
+                let delay = retry_delay(attempt);
+                attempt += 1;
+                print_warning(&format!(
+                    "Request failed ({}), retrying in {}ms ({}/{})",
+                    err,
+                    delay.as_millis(),
+                    attempt,
+                    retries
+                ));
+                thread::sleep(delay);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Backoff before retry number `attempt + 1`, capped at [`RETRY_MAX_DELAY`].
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY)
+}
+
+fn parse_headers(headers: &[String]) -> Result<Vec<(String, String)>> {
+    headers.iter().map(|h| parse_header(h)).collect()
+}
+
 fn parse_header(header: &str) -> Result<(String, String)> {
     let parts: Vec<&str> = header.splitn(2, ':').collect();
     if parts.len() != 2 {
@@ -73,6 +146,83 @@ fn parse_header(header: &str) -> Result<(String, String)> {
     Ok((parts[0].trim().to_string(), parts[1].trim().to_string()))
 }
 
+/// Add a `Content-Type` header unless the user already set one with `-H`.
+fn with_content_type(
+    mut headers: Vec<(String, String)>,
+    content_type: &str,
+) -> Vec<(String, String)> {
+    let present = headers
+        .iter()
+        .any(|(key, _)| key.eq_ignore_ascii_case("content-type"));
+    if !present {
+        headers.insert(0, ("Content-Type".to_string(), content_type.to_string()));
+    }
+    headers
+}
+
+/// A request body and the content type it should be sent with.
+#[derive(Debug, PartialEq)]
+struct RequestBody {
+    content: String,
+    content_type: String,
+}
+
+/// Build the request body from `--data`, `--file`, `--json`, or `--form`.
+///
+/// `@-` reads the value from `stdin` and `@FILE` reads it from a file.
+fn build_body(opts: &BodyOptions, stdin: &mut impl Read) -> Result<RequestBody> {
+    if let Some(json) = &opts.json {
+        let raw = resolve_value(json, stdin)?;
+        let value: Value = serde_json::from_str(&raw).context("Invalid JSON for --json")?;
+        return Ok(RequestBody {
+            content: serde_json::to_string(&value)?,
+            content_type: "application/json".to_string(),
+        });
+    }
+
+    if !opts.form.is_empty() {
+        let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+        for field in &opts.form {
+            let (key, value) = field.split_once('=').with_context(|| {
+                format!("Invalid form field: '{}'. Expected 'key=value'", field)
+            })?;
+            serializer.append_pair(key, value);
+        }
+        return Ok(RequestBody {
+            content: serializer.finish(),
+            content_type: "application/x-www-form-urlencoded".to_string(),
+        });
+    }
+
+    let content = if let Some(data) = &opts.data {
+        resolve_value(data, stdin)?
+    } else if let Some(file_path) = &opts.file {
+        fs::read_to_string(file_path).context(format!("Failed to read file: {}", file_path))?
+    } else {
+        String::new()
+    };
+
+    Ok(RequestBody {
+        content,
+        content_type: opts.content_type.clone(),
+    })
+}
+
+/// Resolve curl-style `@-` (stdin) and `@FILE` references; other values are used as-is.
+fn resolve_value(value: &str, stdin: &mut impl Read) -> Result<String> {
+    match value.strip_prefix('@') {
+        Some("-") => {
+            let mut buf = String::new();
+            stdin
+                .read_to_string(&mut buf)
+                .context("Failed to read body from stdin")?;
+            Ok(buf)
+        }
+        Some(path) => fs::read_to_string(path).context(format!("Failed to read file: {}", path)),
+        None => Ok(value.to_string()),
+    }
+}
+
 fn print_status(status: u16, status_text: &str) {
     let status_str = format!("HTTP {} {}", status, status_text);
     if (200..300).contains(&status) {
@@ -96,16 +246,23 @@ fn print_body(body: &str) {
     }
 }
 
-fn cmd_get(url: &str, headers: &[String], format: OutputFormat, timeout: u64) -> Result<()> {
-    let agent = create_agent(timeout);
-    let mut request = agent.get(url);
-
-    for header in headers {
-        let (key, value) = parse_header(header)?;
-        request = request.header(&key, &value);
-    }
+fn cmd_get(
+    url: &str,
+    headers: &[String],
+    format: OutputFormat,
+    options: &RequestOptions,
+) -> Result<()> {
+    let agent = create_agent(options);
+    let headers = parse_headers(headers)?;
 
-    let response = request.call().context("Failed to send GET request")?;
+    let response = send_with_retry(options.retries, || {
+        let mut request = agent.get(url);
+        for (key, value) in &headers {
+            request = request.header(key, value);
+        }
+        request.call()
+    })
+    .context("Failed to send GET request")?;
 
     let status = response.status().as_u16();
     let status_text = response.status().canonical_reason().unwrap_or("Unknown");
@@ -147,32 +304,242 @@ fn cmd_get(url: &str, headers: &[String], format: OutputFormat, timeout: u64) ->
     Ok(())
 }
 
-fn cmd_post(
+/// GET a URL and stream the body to `path`.
+///
+/// Progress is shown on stderr when it is a terminal: a percentage bar when
+/// the server sends `Content-Length`, otherwise a running byte count.
+///
+/// With `resume`, an existing partial file is continued by requesting
+/// `Range: bytes=<size>-`. A `206 Partial Content` reply is appended; a `200`
+/// means the server ignored the range, and the file is downloaded again from
+/// the start. Without `resume`, the body goes to a temporary file that
+/// replaces `path` only once the download is complete.
+fn cmd_download(
     url: &str,
-    data: Option<String>,
-    file: Option<String>,
     headers: &[String],
-    content_type: &str,
-    format: OutputFormat,
-    timeout: u64,
+    path: &Path,
+    resume: bool,
+    options: &RequestOptions,
 ) -> Result<()> {
-    let agent = create_agent(timeout);
-    let mut request = agent.post(url).header("Content-Type", content_type);
+    let agent = create_agent(options);
+    let mut headers = parse_headers(headers)?;
 
-    for header in headers {
-        let (key, value) = parse_header(header)?;
-        request = request.header(&key, &value);
+    let existing = if resume {
+        fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+    } else {
+        0
+    };
+    if existing > 0 {
+        headers.push(("Range".to_string(), format!("bytes={}-", existing)));
     }
 
-    let body = if let Some(data) = data {
-        data
-    } else if let Some(file_path) = file {
-        fs::read_to_string(&file_path).context(format!("Failed to read file: {}", file_path))?
+    let response = send_with_retry(options.retries, || {
+        let mut request = agent.get(url);
+        for (key, value) in &headers {
+            request = request.header(key, value);
+        }
+        request.call()
+    })
+    .map_err(|err| match err {
+        ureq::Error::StatusCode(416) if existing > 0 => anyhow::anyhow!(
+            "Server rejected the range request (416); {} may already be complete",
+            path.display()
+        ),
+        err => anyhow::Error::new(err).context("Failed to send GET request"),
+    })?;
+
+    let offset = match response.status().as_u16() {
+        206 if existing > 0 => {
+            let content_range = response
+                .headers()
+                .get("content-range")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or("");
+            match content_range_start(content_range) {
+                Some(start) if start == existing => existing,
+                _ => anyhow::bail!(
+                    "Unexpected Content-Range '{}' when resuming from byte {}",
+                    content_range,
+                    existing
+                ),
+            }
+        }
+        _ => {
+            if existing > 0 {
+                print_warning("Server ignored the range request; downloading the whole file");
+            }
+            0
+        }
+    };
+
+    let body = response.into_body();
+    let content_length = body.content_length();
+    // With --resume, write in place so an interrupted download can be
+    // continued; otherwise only a complete download replaces the file
+    let in_place = if resume {
+        let file = if offset > 0 {
+            fs::OpenOptions::new().append(true).open(path)
+        } else {
+            fs::File::create(path)
+        }
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+        Some(BufWriter::new(file))
     } else {
-        String::new()
+        None
     };
 
-    let response = request.send(&body).context("Failed to send POST request")?;
+    let mut progress =
+        (io::stderr().is_terminal() && io::stdout().is_terminal()).then(|| match content_length {
+            Some(len) => {
+                let mut progress = TerminalProgress::new(offset + len);
+                progress.set(offset);
+                progress
+            }
+            None => TerminalProgress::indeterminate().show_bar(false),
+        });
+
+    let mut reader = body.into_reader();
+    let mut copy = |mut writer: &mut dyn Write, progress: Option<&mut TerminalProgress>| {
+        let written = download(&mut reader, &mut writer, progress)
+            .with_context(|| format!("Failed to download to {}", path.display()))?;
+        if let Some(expected) = content_length {
+            if written != expected {
+                anyhow::bail!(
+                    "Download incomplete: received {} of {} bytes",
+                    written,
+                    expected
+                );
+            }
+        }
+        Ok(written)
+    };
+    let result = match in_place {
+        Some(mut writer) => copy(&mut writer, progress.as_mut()).and_then(|written| {
+            writer
+                .flush()
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+            Ok(written)
+        }),
+        None => write_atomic(path, |writer| copy(writer, progress.as_mut())),
+    };
+
+    if let Some(progress) = &progress {
+        progress.finish();
+    }
+    let written = result?;
+    if offset > 0 {
+        eprintln!(
+            "{} Resumed at {}, saved {} more to {}",
+            "✓".green().bold(),
+            format_bytes(offset),
+            format_bytes(written),
+            path.display()
+        );
+    } else {
+        eprintln!(
+            "{} Saved {} to {}",
+            "✓".green().bold(),
+            format_bytes(written),
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// First byte offset of a `Content-Range: bytes <start>-<end>/<total>` value.
+fn content_range_start(value: &str) -> Option<u64> {
+    let range = value.trim().strip_prefix("bytes")?.trim_start();
+    let (start, _) = range.split_once('-')?;
+    start.trim().parse().ok()
+}
+
+/// Copy `reader` to `writer` in chunks, advancing `progress` as bytes arrive.
+///
+/// Returns the number of bytes written. A progress with a zero total is
+/// indeterminate and shows the byte count instead of a percentage.
+fn download(
+    reader: &mut impl Read,
+    writer: &mut impl Write,
+    mut progress: Option<&mut TerminalProgress>,
+) -> Result<u64> {
+    let mut buf = vec![0u8; DOWNLOAD_CHUNK_SIZE];
+    let mut written = 0u64;
+    let mut last_draw: Option<Instant> = None;
+
+    loop {
+        let n = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+        writer.write_all(&buf[..n])?;
+        written += n as u64;
+
+        let Some(progress) = progress.as_deref_mut() else {
+            continue;
+        };
+        progress.inc(n as u64);
+        if last_draw.is_some_and(|t| t.elapsed() < PROGRESS_REDRAW_INTERVAL) {
+            continue;
+        }
+        last_draw = Some(Instant::now());
+        if progress.total() > 0 {
+            let message = format!(
+                "{} / {}",
+                format_bytes(progress.current()),
+                format_bytes(progress.total())
+            );
+            progress.draw(Some(&message));
+        } else {
+            eprint!("\r\x1b[K{} downloaded", format_bytes(written));
+            io::stderr().flush().ok();
+        }
+    }
+    Ok(written)
+}
+
+/// Format bytes in human readable form
+fn format_bytes(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    if bytes >= GB {
+        format!("{:.1} GB", bytes as f64 / GB as f64)
+    } else if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else if bytes >= KB {
+        format!("{:.1} KB", bytes as f64 / KB as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn cmd_post(
+    url: &str,
+    body: &BodyOptions,
+    headers: &[String],
+    format: OutputFormat,
+    options: &RequestOptions,
+) -> Result<()> {
+    let body = build_body(body, &mut io::stdin())?;
+    let agent = create_agent(options);
+    let headers = with_content_type(parse_headers(headers)?, &body.content_type);
+    let mut request = agent.post(url);
+
+    for (key, value) in &headers {
+        request = request.header(key, value);
+    }
+
+    // POST is not idempotent, so it is never retried
+    if options.retries > 0 {
+        print_warning("--retries is ignored for POST requests");
+    }
+
+    let response = request
+        .send(&body.content)
+        .context("Failed to send POST request")?;
 
     let status = response.status().as_u16();
     let status_text = response.status().canonical_reason().unwrap_or("Unknown");
@@ -214,23 +581,23 @@ fn cmd_post(
 
 fn cmd_put(
     url: &str,
-    data: Option<String>,
+    body: &BodyOptions,
     headers: &[String],
-    content_type: &str,
     format: OutputFormat,
-    timeout: u64,
+    options: &RequestOptions,
 ) -> Result<()> {
-    let agent = create_agent(timeout);
-    let mut request = agent.put(url).header("Content-Type", content_type);
-
-    for header in headers {
-        let (key, value) = parse_header(header)?;
-        request = request.header(&key, &value);
-    }
-
-    let body = data.unwrap_or_default();
-
-    let response = request.send(&body).context("Failed to send PUT request")?;
+    let body = build_body(body, &mut io::stdin())?;
+    let agent = create_agent(options);
+    let headers = with_content_type(parse_headers(headers)?, &body.content_type);
+
+    let response = send_with_retry(options.retries, || {
+        let mut request = agent.put(url);
+        for (key, value) in &headers {
+            request = request.header(key, value);
+        }
+        request.send(&body.content)
+    })
+    .context("Failed to send PUT request")?;
 
     let status = response.status().as_u16();
     let status_text = response.status().canonical_reason().unwrap_or("Unknown");
@@ -260,16 +627,23 @@ fn cmd_put(
     Ok(())
 }
 
-fn cmd_delete(url: &str, headers: &[String], format: OutputFormat, timeout: u64) -> Result<()> {
-    let agent = create_agent(timeout);
-    let mut request = agent.delete(url);
-
-    for header in headers {
-        let (key, value) = parse_header(header)?;
-        request = request.header(&key, &value);
-    }
+fn cmd_delete(
+    url: &str,
+    headers: &[String],
+    format: OutputFormat,
+    options: &RequestOptions,
+) -> Result<()> {
+    let agent = create_agent(options);
+    let headers = parse_headers(headers)?;
 
-    let response = request.call().context("Failed to send DELETE request")?;
+    let response = send_with_retry(options.retries, || {
+        let mut request = agent.delete(url);
+        for (key, value) in &headers {
+            request = request.header(key, value);
+        }
+        request.call()
+    })
+    .context("Failed to send DELETE request")?;
 
     let status = response.status().as_u16();
     let status_text = response.status().canonical_reason().unwrap_or("Unknown");
@@ -299,16 +673,18 @@ fn cmd_delete(url: &str, headers: &[String], format: OutputFormat, timeout: u64)
     Ok(())
 }
 
-fn cmd_head(url: &str, headers: &[String], timeout: u64) -> Result<()> {
-    let agent = create_agent(timeout);
-    let mut request = agent.head(url);
+fn cmd_head(url: &str, headers: &[String], options: &RequestOptions) -> Result<()> {
+    let agent = create_agent(options);
+    let headers = parse_headers(headers)?;
 
-    for header in headers {
-        let (key, value) = parse_header(header)?;
-        request = request.header(&key, &value);
-    }
-
-    let response = request.call().context("Failed to send HEAD request")?;
+    let response = send_with_retry(options.retries, || {
+        let mut request = agent.head(url);
+        for (key, value) in &headers {
+            request = request.header(key, value);
+        }
+        request.call()
+    })
+    .context("Failed to send HEAD request")?;
 
     let status = response.status().as_u16();
     let status_text = response.status().canonical_reason().unwrap_or("Unknown");
@@ -321,3 +697,141 @@ fn cmd_head(url: &str, headers: &[String], timeout: u64) -> Result<()> {
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body_options() -> BodyOptions {
+        BodyOptions {
+            data: None,
+            file: None,
+            json: None,
+            form: Vec::new(),
+            content_type: "application/json".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_up_to_cap() {
+        assert_eq!(retry_delay(0), Duration::from_millis(250));
+        assert_eq!(retry_delay(1), Duration::from_millis(500));
+        assert_eq!(retry_delay(3), Duration::from_secs(2));
+        assert_eq!(retry_delay(7), RETRY_MAX_DELAY);
+    }
+
+    #[test]
+    fn test_retry_delay_large_attempts() {
+        for attempt in [31, 32, 33, 100, u32::MAX] {
+            assert_eq!(retry_delay(attempt), RETRY_MAX_DELAY);
+        }
+    }
+
+    #[test]
+    fn test_default_content_type_added() {
+        let headers = vec![("Accept".to_string(), "text/plain".to_string())];
+        assert_eq!(
+            with_content_type(headers, "application/json"),
+            vec![
+                ("Content-Type".to_string(), "application/json".to_string()),
+                ("Accept".to_string(), "text/plain".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_user_content_type_wins() {
+        let headers = vec![("content-type".to_string(), "text/csv".to_string())];
+        assert_eq!(
+            with_content_type(headers.clone(), "application/json"),
+            headers
+        );
+    }
+
+    #[test]
+    fn test_json_body_sets_content_type() {
+        let opts = BodyOptions {
+            json: Some(r#"{ "a": 1, "b": [true, null] }"#.to_string()),
+            content_type: "text/plain".to_string(),
+            ..body_options()
+        };
+        let body = build_body(&opts, &mut io::empty()).unwrap();
+        assert_eq!(body.content_type, "application/json");
+        assert_eq!(body.content, r#"{"a":1,"b":[true,null]}"#);
+    }
+
+    #[test]
+    fn test_json_body_rejects_invalid() {
+        let opts = BodyOptions {
+            json: Some("{not json".to_string()),
+            ..body_options()
+        };
+        assert!(build_body(&opts, &mut io::empty()).is_err());
+    }
+
+    #[test]
+    fn test_form_body_encodes_fields() {
+        let opts = BodyOptions {
+            form: vec![
+                "name=Jane Doe".to_string(),
+                "tags=a&b".to_string(),
+                "empty=".to_string(),
+            ],
+            ..body_options()
+        };
+        let body = build_body(&opts, &mut io::empty()).unwrap();
+        assert_eq!(body.content_type, "application/x-www-form-urlencoded");
+        assert_eq!(body.content, "name=Jane+Doe&tags=a%26b&empty=");
+    }
+
+    #[test]
+    fn test_form_body_requires_key_value() {
+        let opts = BodyOptions {
+            form: vec!["novalue".to_string()],
+            ..body_options()
+        };
+        assert!(build_body(&opts, &mut io::empty()).is_err());
+    }
+
+    #[test]
+    fn test_download_known_length_reaches_100_percent() {
+        let data: Vec<u8> = (0..DOWNLOAD_CHUNK_SIZE * 3 + 123)
+            .map(|i| (i % 251) as u8)
+            .collect();
+        let mut progress = TerminalProgress::new(data.len() as u64).show_bar(false);
+        let mut out = Vec::new();
+
+        let written = download(&mut data.as_slice(), &mut out, Some(&mut progress)).unwrap();
+        assert_eq!(written, data.len() as u64);
+        assert_eq!(out, data);
+        assert_eq!(progress.current(), data.len() as u64);
+        assert_eq!(progress.percent(), 100);
+    }
+
+    #[test]
+    fn test_content_range_start() {
+        assert_eq!(content_range_start("bytes 100-199/200"), Some(100));
+        assert_eq!(content_range_start("bytes 0-0/*"), Some(0));
+        assert_eq!(content_range_start("bytes */200"), None);
+        assert_eq!(content_range_start("items 1-2/3"), None);
+        assert_eq!(content_range_start(""), None);
+    }
+
+    #[test]
+    fn test_download_without_progress() {
+        let mut out = Vec::new();
+        assert_eq!(download(&mut "abc".as_bytes(), &mut out, None).unwrap(), 3);
+        assert_eq!(out, b"abc");
+    }
+
+    #[test]
+    fn test_data_from_stdin() {
+        let opts = BodyOptions {
+            data: Some("@-".to_string()),
+            ..body_options()
+        };
+        let body = build_body(&opts, &mut "from stdin".as_bytes()).unwrap();
+        assert_eq!(body.content, "from stdin");
+        assert_eq!(body.content_type, "application/json");
+    }
+}
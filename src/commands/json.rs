@@ -1,7 +1,7 @@
 //! # JSON Command Implementation
 //!
 //! This module provides JSON utilities: formatting, validation, minification,
-//! and path-based querying.
+//! path-based querying, and CSV conversion.
 //!
 //! ## Key Concepts
 //!
@@ -28,7 +28,9 @@
 //! dx json format --sort-keys data.json  # Sort object keys
 //! dx json validate data.json            # Check if valid JSON
 //! dx json minify data.json              # Remove whitespace
-//! dx json query data.json ".foo.bar[0]" # Extract value at path
+//! dx json query ".foo.bar[0]" data.json # Extract value at path
+//! dx json query ".items[] | .id"        # Iterate and pipe (stdin)
+//! dx json to-csv --flatten users.json   # Array of objects to CSV
 //! ```
 //!
 //! ## External Documentation
@@ -40,6 +42,7 @@ use crate::cli::commands::json::{JsonArgs, JsonCommand};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{self, Read};
 use std::path::Path;
@@ -50,7 +53,8 @@ use std::path::Path;
 /// - `format`: Pretty-print with customizable indentation
 /// - `validate`: Check if input is valid JSON
 /// - `minify`: Remove all unnecessary whitespace
-/// - `query`: Extract a value using a path expression
+/// - `query`: Extract values using a jq-like filter
+/// - `to-csv`: Convert an array of objects to CSV
 pub fn run(args: JsonArgs) -> Result<()> {
     match args.command {
         JsonCommand::Format {
@@ -62,7 +66,17 @@ pub fn run(args: JsonArgs) -> Result<()> {
         } => cmd_format(input.as_deref(), indent, tabs, sort_keys, compact),
         JsonCommand::Validate { input, quiet } => cmd_validate(input.as_deref(), quiet),
         JsonCommand::Minify { input } => cmd_minify(input.as_deref()),
-        JsonCommand::Query { input, path } => cmd_query(input.as_deref(), &path),
+        JsonCommand::Query {
+            filter,
+            input,
+            path,
+        } => match (path, filter) {
+            // `query FILE --path FILTER`: the positional is the input
+            (Some(path), file) => cmd_query(file.as_deref().map(Path::new), &path),
+            (None, Some(filter)) => cmd_query(input.as_deref(), &filter),
+            (None, None) => unreachable!("clap requires FILTER without --path"),
+        },
+        JsonCommand::ToCsv { input, flatten } => cmd_to_csv(input.as_deref(), flatten),
     }
 }
 
@@ -224,92 +238,360 @@ fn cmd_minify(input: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
-/// Query JSON using a simple path expression.
+/// Query JSON using a small subset of jq.
 ///
-/// # Path Syntax
-/// - `.foo`: Access object key "foo"
+/// # Filter Syntax
+/// - `.`: The whole input
+/// - `.foo`, `."foo bar"`, `.["foo"]`: Object key access
 /// - `.foo.bar`: Nested object access
-/// - `[0]`: Array index access
-/// - `.foo[0]`: Combined object and array access
-/// - `.foo.bar[0].baz`: Deep nesting
+/// - `[0]`, `[-1]`: Array index access (negative counts from the end)
+/// - `[]`: Iterate over array elements or object values
+/// - `a | b`: Feed every result of `a` into `b`
+///
+/// Like jq, a missing key or out-of-range index yields `null`, while
+/// indexing the wrong type (e.g., `.foo` on a number) is an error.
 ///
 /// # Examples
 /// ```text
 /// Input: {"users": [{"name": "Alice"}, {"name": "Bob"}]}
-/// Path: .users[0].name
-/// Output: "Alice"
+/// Filter: .users[0].name      Output: "Alice"
+/// Filter: .users[] | .name    Output: "Alice" "Bob"
 /// ```
 ///
 /// # Limitations
-/// This is a simple implementation. For complex queries, consider:
+/// There are no functions, arithmetic, or object construction. For complex
+/// queries, consider:
 /// - jq: <https://stedolan.github.io/jq/>
 /// - JSONPath: <https://goessner.net/articles/JsonPath/>
-fn cmd_query(input: Option<&Path>, path: &str) -> Result<()> {
+fn cmd_query(input: Option<&Path>, filter: &str) -> Result<()> {
+    // Parse the filter first so syntax errors don't wait on stdin
+    let query = parse_query(filter)?;
+
     let json_str = read_input(input)?;
     let value: Value = serde_json::from_str(&json_str).context("Invalid JSON")?;
 
-    // Parse and execute the path query
-    let result = query_path(&value, path)?;
-
-    // Pretty-print the result (could be any JSON type)
-    println!("{}", serde_json::to_string_pretty(&result)?);
+    // Each result is printed on its own, like jq's output stream
+    for result in eval_query(&value, &query)? {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    }
     Ok(())
 }
 
-/// Execute a path query on a JSON value.
-///
-/// # How It Works
-///
-/// 1. Split the path by `.` to get individual segments
-/// 2. For each segment:
-///    - If it contains `[`, it's a key + array index (e.g., "items[0]")
-///    - Otherwise, it's just a key (e.g., "foo")
-/// 3. Navigate through the JSON structure
-///
-/// # Lifetime Parameter
-/// The `'a` lifetime ensures the returned reference lives as long as
-/// the input `value`. This avoids cloning the result.
+/// One step of a filter path.
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    /// Object key access (`.foo`)
+    Field(String),
+    /// Array index access (`[0]`)
+    Index(i64),
+    /// Iterate over all elements (`[]`)
+    Iterate,
+}
+
+/// A parsed filter: pipeline stages separated by `|`, each a path.
+type Query = Vec<Vec<PathSegment>>;
+
+/// Parse a jq-like filter into its pipeline stages.
 ///
-/// # Error Handling
-/// Returns an error if:
-/// - A key doesn't exist in an object
-/// - An array index is out of bounds
-/// - The path syntax is invalid
-fn query_path<'a>(value: &'a Value, path: &str) -> Result<&'a Value> {
-    let mut current = value;
-
-    // Split by '.' and skip empty segments (handles leading '.')
-    for part in path.split('.').filter(|s| !s.is_empty()) {
-        // Check if this segment includes an array index
-        if let Some(bracket_pos) = part.find('[') {
-            // Parse: "key[index]" -> key="key", index=number
-            let key = &part[..bracket_pos];
-            // Extract the number between [ and ]
-            let index_str = &part[bracket_pos + 1..part.len() - 1];
-            let index: usize = index_str.parse().context("Invalid array index")?;
-
-            // First access the object key (if not empty)
-            if !key.is_empty() {
-                current = current
-                    .get(key)
-                    .with_context(|| format!("Key '{}' not found", key))?;
+/// Errors report the character position (0-based) of the problem.
+fn parse_query(filter: &str) -> Result<Query> {
+    let chars: Vec<char> = filter.chars().collect();
+    let mut pos = 0;
+    let mut stages = Vec::new();
+
+    loop {
+        skip_whitespace(&chars, &mut pos);
+        stages.push(parse_stage(&chars, &mut pos)?);
+        skip_whitespace(&chars, &mut pos);
+        match chars.get(pos) {
+            None => break,
+            Some('|') => pos += 1,
+            Some(c) => anyhow::bail!("Unexpected '{}' at position {}", c, pos),
+        }
+    }
+
+    Ok(stages)
+}
+
+fn skip_whitespace(chars: &[char], pos: &mut usize) {
+    while chars.get(*pos).is_some_and(|c| c.is_whitespace()) {
+        *pos += 1;
+    }
+}
+
+/// Parse one path such as `.users[0].name`.
+fn parse_stage(chars: &[char], pos: &mut usize) -> Result<Vec<PathSegment>> {
+    if chars.get(*pos) != Some(&'.') {
+        anyhow::bail!("Expected '.' at position {}", pos);
+    }
+    *pos += 1;
+
+    let mut segments = Vec::new();
+
+    // The leading '.' may be followed directly by a key; a bare '.' is identity
+    if chars
+        .get(*pos)
+        .is_some_and(|&c| c == '"' || is_ident_start(c))
+    {
+        segments.push(PathSegment::Field(parse_key(chars, pos)?));
+    }
+
+    loop {
+        match chars.get(*pos) {
+            Some('.') => {
+                *pos += 1;
+                match chars.get(*pos) {
+                    Some('[') => {}
+                    Some(&c) if c == '"' || is_ident_start(c) => {
+                        segments.push(PathSegment::Field(parse_key(chars, pos)?));
+                    }
+                    _ => anyhow::bail!("Expected key after '.' at position {}", pos),
+                }
             }
+            Some('[') => segments.push(parse_bracket(chars, pos)?),
+            _ => break,
+        }
+    }
 
-            // Then access the array index
-            current = current
-                .get(index)
-                .with_context(|| format!("Index {} out of bounds", index))?;
-        } else {
-            // Simple key access
-            current = current
-                .get(part)
-                .with_context(|| format!("Key '{}' not found", part))?;
+    Ok(segments)
+}
+
+fn is_ident_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+/// Parse an identifier or a double-quoted key.
+fn parse_key(chars: &[char], pos: &mut usize) -> Result<String> {
+    if chars.get(*pos) == Some(&'"') {
+        return parse_string(chars, pos);
+    }
+
+    let start = *pos;
+    while chars
+        .get(*pos)
+        .is_some_and(|&c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        *pos += 1;
+    }
+    Ok(chars[start..*pos].iter().collect())
+}
+
+/// Parse a double-quoted string with `\"` and `\\` escapes.
+fn parse_string(chars: &[char], pos: &mut usize) -> Result<String> {
+    let start = *pos;
+    *pos += 1;
+
+    let mut out = String::new();
+    loop {
+        match chars.get(*pos) {
+            None => anyhow::bail!("Unterminated string starting at position {}", start),
+            Some('"') => {
+                *pos += 1;
+                return Ok(out);
+            }
+            Some('\\') => {
+                let escaped = chars.get(*pos + 1).with_context(|| {
+                    format!("Unterminated string starting at position {}", start)
+                })?;
+                out.push(*escaped);
+                *pos += 2;
+            }
+            Some(&c) => {
+                out.push(c);
+                *pos += 1;
+            }
+        }
+    }
+}
+
+/// Parse `[]`, `[N]`, or `["key"]`.
+fn parse_bracket(chars: &[char], pos: &mut usize) -> Result<PathSegment> {
+    *pos += 1;
+    skip_whitespace(chars, pos);
+
+    let segment = match chars.get(*pos) {
+        Some(']') => PathSegment::Iterate,
+        Some('"') => PathSegment::Field(parse_string(chars, pos)?),
+        Some(&c) if c == '-' || c.is_ascii_digit() => {
+            let start = *pos;
+            *pos += 1;
+            while chars.get(*pos).is_some_and(|c| c.is_ascii_digit()) {
+                *pos += 1;
+            }
+            let text: String = chars[start..*pos].iter().collect();
+            let index = text
+                .parse()
+                .with_context(|| format!("Invalid array index '{}' at position {}", text, start))?;
+            PathSegment::Index(index)
+        }
+        _ => anyhow::bail!("Expected index, string, or ']' at position {}", pos),
+    };
+
+    skip_whitespace(chars, pos);
+    if chars.get(*pos) != Some(&']') {
+        anyhow::bail!("Expected ']' at position {}", pos);
+    }
+    *pos += 1;
+
+    Ok(segment)
+}
+
+/// Evaluate a parsed filter, returning every output value in order.
+fn eval_query(value: &Value, query: &Query) -> Result<Vec<Value>> {
+    let mut current = vec![value.clone()];
+
+    for stage in query {
+        for segment in stage {
+            let mut next = Vec::new();
+            for v in &current {
+                next.extend(apply_segment(v, segment)?);
+            }
+            current = next;
         }
     }
 
     Ok(current)
 }
 
+/// Apply a single path segment to a value.
+fn apply_segment(value: &Value, segment: &PathSegment) -> Result<Vec<Value>> {
+    match (segment, value) {
+        (PathSegment::Field(key), Value::Object(map)) => {
+            Ok(vec![map.get(key).cloned().unwrap_or(Value::Null)])
+        }
+        (PathSegment::Index(index), Value::Array(arr)) => {
+            // Negative indices count from the end
+            let resolved = if *index < 0 {
+                arr.len() as i64 + index
+            } else {
+                *index
+            };
+            let item = usize::try_from(resolved)
+                .ok()
+                .and_then(|i| arr.get(i))
+                .cloned()
+                .unwrap_or(Value::Null);
+            Ok(vec![item])
+        }
+        // Indexing null yields null, as in jq
+        (PathSegment::Field(_) | PathSegment::Index(_), Value::Null) => Ok(vec![Value::Null]),
+        (PathSegment::Iterate, Value::Array(arr)) => Ok(arr.clone()),
+        (PathSegment::Iterate, Value::Object(map)) => Ok(map.values().cloned().collect()),
+        (PathSegment::Field(key), other) => {
+            anyhow::bail!("Cannot index {} with \"{}\"", type_name(other), key)
+        }
+        (PathSegment::Index(_), other) => {
+            anyhow::bail!("Cannot index {} with a number", type_name(other))
+        }
+        (PathSegment::Iterate, other) => anyhow::bail!("Cannot iterate over {}", type_name(other)),
+    }
+}
+
+/// jq-style name of a value's type for error messages.
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+/// Convert a JSON array of objects to RFC 4180 CSV on stdout.
+///
+/// The header row is the union of keys across all objects, in the order
+/// they are first seen. Objects missing a key get an empty cell. Arrays are
+/// written as compact JSON text; nested objects are either expanded into
+/// dotted columns (`addr.city`) with `--flatten` or rejected.
+fn cmd_to_csv(input: Option<&Path>, flatten: bool) -> Result<()> {
+    let json_str = read_input(input)?;
+    let value: Value = serde_json::from_str(&json_str).context("Invalid JSON")?;
+
+    let csv = json_to_csv(&value, flatten)?;
+    print!("{}", csv);
+    Ok(())
+}
+
+/// Render a JSON array of objects as CSV text.
+fn json_to_csv(value: &Value, flatten: bool) -> Result<String> {
+    let items = value
+        .as_array()
+        .context("Expected a JSON array of objects")?;
+
+    // Union of keys in first-seen order, with an index for each column
+    let mut headers: Vec<String> = Vec::new();
+    let mut columns: HashMap<String, usize> = HashMap::new();
+    let mut rows = Vec::with_capacity(items.len());
+    for (i, item) in items.iter().enumerate() {
+        let obj = item
+            .as_object()
+            .with_context(|| format!("Element {} is {}, expected an object", i, type_name(item)))?;
+        let mut cells = Vec::new();
+        flatten_object(obj, "", flatten, &mut cells)
+            .with_context(|| format!("Cannot convert element {}", i))?;
+
+        let mut row: HashMap<usize, String> = HashMap::with_capacity(cells.len());
+        for (key, cell) in cells {
+            let index = match columns.get(&key) {
+                Some(&index) => index,
+                None => {
+                    columns.insert(key.clone(), headers.len());
+                    headers.push(key.clone());
+                    headers.len() - 1
+                }
+            };
+            if row.insert(index, cell).is_some() {
+                anyhow::bail!(
+                    "Cannot convert element {}: column '{}' appears twice (a dotted key collides with a flattened nested key)",
+                    i,
+                    key
+                );
+            }
+        }
+        rows.push(row);
+    }
+
+    let mut wtr = csv::Writer::from_writer(Vec::new());
+    wtr.write_record(&headers)?;
+    for row in &mut rows {
+        let record = (0..headers.len()).map(|index| row.remove(&index).unwrap_or_default());
+        wtr.write_record(record)?;
+    }
+
+    let bytes = wtr.into_inner().context("Failed to write CSV")?;
+    Ok(String::from_utf8(bytes)?)
+}
+
+/// Collect `(column, cell)` pairs for an object, recursing into nested
+/// objects when `flatten` is set.
+fn flatten_object(
+    obj: &serde_json::Map<String, Value>,
+    prefix: &str,
+    flatten: bool,
+    out: &mut Vec<(String, String)>,
+) -> Result<()> {
+    for (key, value) in obj {
+        let column = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            Value::Object(nested) if flatten => flatten_object(nested, &column, flatten, out)?,
+            Value::Object(_) => anyhow::bail!(
+                "Field '{}' is a nested object (use --flatten to expand it into dotted columns)",
+                column
+            ),
+            Value::Null => out.push((column, String::new())),
+            Value::String(s) => out.push((column, s.clone())),
+            other => out.push((column, other.to_string())),
+        }
+    }
+    Ok(())
+}
+
 /// Recursively sort all object keys in a JSON value.
 ///
 /// # Why Sort Keys?
@@ -363,20 +645,106 @@ fn sort_json(value: &Value) -> Value {
 mod tests {
     use super::*;
 
+    fn query(json: &Value, filter: &str) -> Vec<Value> {
+        eval_query(json, &parse_query(filter).unwrap()).unwrap()
+    }
+
     /// Test simple object key access.
     #[test]
     fn test_query_simple() {
         let json: Value = serde_json::json!({"foo": {"bar": 42}});
-        let result = query_path(&json, ".foo.bar").unwrap();
-        assert_eq!(result, &Value::Number(42.into()));
+        assert_eq!(query(&json, ".foo.bar"), vec![serde_json::json!(42)]);
+        assert_eq!(query(&json, r#".foo["bar"]"#), vec![serde_json::json!(42)]);
+        assert_eq!(query(&json, "."), vec![json.clone()]);
     }
 
     /// Test array index access.
     #[test]
     fn test_query_array() {
         let json: Value = serde_json::json!({"items": [1, 2, 3]});
-        let result = query_path(&json, ".items[1]").unwrap();
-        assert_eq!(result, &Value::Number(2.into()));
+        assert_eq!(query(&json, ".items[1]"), vec![serde_json::json!(2)]);
+        assert_eq!(query(&json, ".items[-1]"), vec![serde_json::json!(3)]);
+    }
+
+    /// Test that missing keys and out-of-range indices yield null.
+    #[test]
+    fn test_query_missing_is_null() {
+        let json: Value = serde_json::json!({"items": [1, 2, 3]});
+        assert_eq!(query(&json, ".items[10]"), vec![Value::Null]);
+        assert_eq!(query(&json, ".missing.deeper"), vec![Value::Null]);
+    }
+
+    /// Test iteration over arrays and objects, with and without pipes.
+    #[test]
+    fn test_query_iterate() {
+        let json: Value = serde_json::json!({
+            "users": [{"name": "Alice"}, {"name": "Bob"}],
+            "counts": {"a": 1, "b": 2}
+        });
+        let names = vec![serde_json::json!("Alice"), serde_json::json!("Bob")];
+        assert_eq!(query(&json, ".users[].name"), names);
+        assert_eq!(query(&json, ".users[] | .name"), names);
+        assert_eq!(
+            query(&json, ".users | .[0] | .name"),
+            vec![names[0].clone()]
+        );
+        assert_eq!(query(&json, ".counts[]").len(), 2);
+    }
+
+    /// Test that syntax and type errors are reported.
+    #[test]
+    fn test_query_errors() {
+        assert!(parse_query("users").is_err());
+        assert!(parse_query(".users[0").is_err());
+        assert!(parse_query(".users | ").is_err());
+        assert!(parse_query(r#"."unterminated"#).is_err());
+
+        let json: Value = serde_json::json!({"n": 1});
+        assert!(eval_query(&json, &parse_query(".n.foo").unwrap()).is_err());
+        assert!(eval_query(&json, &parse_query(".n[]").unwrap()).is_err());
+    }
+
+    /// Test that differing keys produce a union header with empty cells.
+    #[test]
+    fn test_to_csv_union_of_keys() {
+        let json = serde_json::json!([
+            {"name": "Alice", "age": 30},
+            {"name": "Bob, Jr.", "email": "bob@example.com"}
+        ]);
+        let csv = json_to_csv(&json, false).unwrap();
+        assert_eq!(
+            csv,
+            "name,age,email\nAlice,30,\n\"Bob, Jr.\",,bob@example.com\n"
+        );
+    }
+
+    /// Test that nested objects are dotted with --flatten and rejected otherwise.
+    #[test]
+    fn test_to_csv_nested() {
+        let json = serde_json::json!([{"id": 1, "addr": {"city": "Oslo"}, "tags": ["a", "b"]}]);
+        let csv = json_to_csv(&json, true).unwrap();
+        assert_eq!(csv, "id,addr.city,tags\n1,Oslo,\"[\"\"a\"\",\"\"b\"\"]\"\n");
+
+        let err = json_to_csv(&json, false).unwrap_err();
+        assert!(format!("{:#}", err).contains("--flatten"));
+    }
+
+    /// Test that a dotted key colliding with a flattened key is an error.
+    #[test]
+    fn test_to_csv_flatten_collision() {
+        let json = serde_json::json!([{"a.b": 1, "a": {"b": 2}}]);
+        let err = json_to_csv(&json, true).unwrap_err();
+        assert!(err.to_string().contains("column 'a.b' appears twice"));
+
+        let json = serde_json::json!([{"a.b": 1}, {"a": {"b": 2}}]);
+        assert_eq!(json_to_csv(&json, true).unwrap(), "a.b\n1\n2\n");
+    }
+
+    /// Test that non-array input and non-object elements are rejected.
+    #[test]
+    fn test_to_csv_invalid_input() {
+        assert!(json_to_csv(&serde_json::json!({"a": 1}), false).is_err());
+        assert!(json_to_csv(&serde_json::json!([1, 2]), false).is_err());
     }
 
     /// Test that sort_json orders keys alphabetically.
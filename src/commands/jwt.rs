@@ -1,24 +1,46 @@
 //! JWT command - JSON Web Token utilities.
+//!
+//! Decoding never trusts the token: `--verify` and `verify` recompute the
+//! HMAC over `header.payload`, compare it in constant time, require the
+//! header `alg` to match the expected algorithm, and then check `exp`/`nbf`.
 
 use crate::cli::commands::jwt::{DecodeFormat, JwtAlgorithm, JwtArgs, JwtCommand};
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use hmac::digest::KeyInit;
+use hmac::{Hmac, Mac};
 use serde_json::{Map, Value};
-use std::collections::HashMap;
+use sha2::{Sha256, Sha384, Sha512};
+use subtle::ConstantTimeEq;
 
 /// Run the jwt command
 pub fn run(args: JwtArgs) -> Result<()> {
     match args.command {
-        JwtCommand::Decode { token, format } => cmd_decode(&token, format),
-        JwtCommand::Encode {
+        JwtCommand::Decode {
+            token,
+            format,
+            verify,
+            secret,
+            algorithm,
+        } => cmd_decode(&token, format, verify, secret.as_deref(), algorithm),
+        JwtCommand::Sign {
             secret,
             payload,
+            claims,
             algorithm,
             exp,
             sub,
             iss,
-        } => cmd_encode(&secret, &payload, algorithm, exp, sub, iss),
+        } => {
+            let options = ClaimOptions {
+                payload,
+                claims,
+                sub,
+                iss,
+                exp,
+            };
+            cmd_sign(&secret, &options, algorithm)
+        }
         JwtCommand::Verify {
             token,
             secret,
@@ -27,43 +49,44 @@ pub fn run(args: JwtArgs) -> Result<()> {
     }
 }
 
-fn algorithm_to_jsonwebtoken(alg: JwtAlgorithm) -> Algorithm {
-    match alg {
-        JwtAlgorithm::Hs256 => Algorithm::HS256,
-        JwtAlgorithm::Hs384 => Algorithm::HS384,
-        JwtAlgorithm::Hs512 => Algorithm::HS512,
-    }
-}
+fn cmd_decode(
+    token: &str,
+    format: DecodeFormat,
+    verify: bool,
+    secret: Option<&str>,
+    algorithm: JwtAlgorithm,
+) -> Result<()> {
+    let decoded = decode_token(token)?;
+    let now = chrono::Utc::now().timestamp();
 
-fn cmd_decode(token: &str, format: DecodeFormat) -> Result<()> {
-    let parts: Vec<&str> = token.split('.').collect();
-    if parts.len() != 3 {
-        anyhow::bail!("Invalid JWT format: expected 3 parts separated by '.'");
+    if verify {
+        let secret = secret.context("--verify requires --secret")?;
+        verify_token(&decoded, secret.as_bytes(), algorithm, now)?;
     }
 
-    // Decode header (first part)
-    let header_json = base64_decode_url_safe(parts[0]).context("Failed to decode header")?;
-    let header: Value = serde_json::from_slice(&header_json).context("Failed to parse header")?;
-
-    // Decode payload (second part)
-    let payload_json = base64_decode_url_safe(parts[1]).context("Failed to decode payload")?;
-    let payload: Value =
-        serde_json::from_slice(&payload_json).context("Failed to parse payload")?;
-
+    let parts: Vec<&str> = token.trim().split('.').collect();
     match format {
         DecodeFormat::Pretty => {
+            if verify {
+                println!("{}\n", "✓ Signature verified".green().bold());
+            } else if let Err(e) = check_time_claims(&decoded.claims, now) {
+                println!("{} {}\n", "Warning:".yellow().bold(), e);
+            }
             println!("{}", "Header:".cyan().bold());
-            println!("{}", serde_json::to_string_pretty(&header)?);
+            println!("{}", serde_json::to_string_pretty(&decoded.header)?);
             println!("\n{}", "Payload:".cyan().bold());
-            println!("{}", serde_json::to_string_pretty(&payload)?);
+            println!("{}", serde_json::to_string_pretty(&decoded.claims)?);
             println!("\n{}", "Signature:".cyan().bold());
             println!("{}", parts[2]);
         }
         DecodeFormat::Json => {
             let mut output = Map::new();
-            output.insert("header".to_string(), header);
-            output.insert("payload".to_string(), payload);
+            output.insert("header".to_string(), decoded.header);
+            output.insert("payload".to_string(), decoded.claims);
             output.insert("signature".to_string(), Value::String(parts[2].to_string()));
+            if verify {
+                output.insert("verified".to_string(), Value::Bool(true));
+            }
             println!("{}", serde_json::to_string(&Value::Object(output))?);
         }
         DecodeFormat::Raw => {
@@ -76,6 +99,134 @@ fn cmd_decode(token: &str, format: DecodeFormat) -> Result<()> {
     Ok(())
 }
 
+/// A JWT split into its decoded parts.
+#[derive(Debug, Clone)]
+pub struct DecodedToken {
+    /// JOSE header
+    pub header: Value,
+    /// Claims set
+    pub claims: Value,
+    /// Raw signature bytes
+    pub signature: Vec<u8>,
+    /// The `header.payload` text the signature covers
+    signing_input: String,
+}
+
+/// Split and decode a compact JWT without checking its signature.
+pub fn decode_token(token: &str) -> Result<DecodedToken> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+    if parts.len() != 3 {
+        bail!("Invalid JWT format: expected 3 parts separated by '.'");
+    }
+
+    // Decode header (first part)
+    let header_json = base64_decode_url_safe(parts[0]).context("Failed to decode header")?;
+    let header: Value = serde_json::from_slice(&header_json).context("Failed to parse header")?;
+
+    // Decode payload (second part)
+    let payload_json = base64_decode_url_safe(parts[1]).context("Failed to decode payload")?;
+    let claims: Value = serde_json::from_slice(&payload_json).context("Failed to parse payload")?;
+
+    let signature = base64_decode_url_safe(parts[2]).context("Failed to decode signature")?;
+
+    Ok(DecodedToken {
+        header,
+        claims,
+        signature,
+        signing_input: format!("{}.{}", parts[0], parts[1]),
+    })
+}
+
+/// Verify a token's HMAC signature and its `exp`/`nbf` claims at time `now`.
+///
+/// The header's `alg` must match `algorithm`; in particular `none` and
+/// asymmetric algorithms are rejected rather than trusted from the token.
+pub fn verify_token(
+    token: &DecodedToken,
+    secret: &[u8],
+    algorithm: JwtAlgorithm,
+    now: i64,
+) -> Result<()> {
+    let alg = token
+        .header
+        .get("alg")
+        .and_then(Value::as_str)
+        .context("Token header has no 'alg'")?;
+    if alg != algorithm_name(algorithm) {
+        bail!(
+            "Algorithm mismatch: token uses {}, expected {}",
+            alg,
+            algorithm_name(algorithm)
+        );
+    }
+
+    let expected = hmac_sign(algorithm, secret, token.signing_input.as_bytes());
+    if !bool::from(expected.ct_eq(&token.signature)) {
+        bail!("Invalid signature");
+    }
+
+    check_time_claims(&token.claims, now)
+}
+
+/// Check the `exp` and `nbf` claims against `now` (seconds since the epoch).
+///
+/// NumericDate values may carry a fractional part (RFC 7519 section 2), so
+/// the claims are compared as floats.
+pub fn check_time_claims(claims: &Value, now: i64) -> Result<()> {
+    let now = now as f64;
+    if let Some(exp) = claims.get("exp").and_then(Value::as_f64) {
+        if now >= exp {
+            bail!(
+                "Token expired at {} ({} ago)",
+                format_timestamp(exp as i64),
+                format_age((now - exp) as i64)
+            );
+        }
+    }
+    if let Some(nbf) = claims.get("nbf").and_then(Value::as_f64) {
+        if now < nbf {
+            bail!("Token not valid before {}", format_timestamp(nbf as i64));
+        }
+    }
+    Ok(())
+}
+
+fn algorithm_name(alg: JwtAlgorithm) -> &'static str {
+    match alg {
+        JwtAlgorithm::Hs256 => "HS256",
+        JwtAlgorithm::Hs384 => "HS384",
+        JwtAlgorithm::Hs512 => "HS512",
+    }
+}
+
+fn hmac_sign(alg: JwtAlgorithm, secret: &[u8], input: &[u8]) -> Vec<u8> {
+    fn mac<M: Mac + KeyInit>(secret: &[u8], input: &[u8]) -> Vec<u8> {
+        let mut mac = <M as KeyInit>::new_from_slice(secret).expect("HMAC accepts any key length");
+        mac.update(input);
+        mac.finalize().into_bytes().to_vec()
+    }
+    match alg {
+        JwtAlgorithm::Hs256 => mac::<Hmac<Sha256>>(secret, input),
+        JwtAlgorithm::Hs384 => mac::<Hmac<Sha384>>(secret, input),
+        JwtAlgorithm::Hs512 => mac::<Hmac<Sha512>>(secret, input),
+    }
+}
+
+fn format_timestamp(secs: i64) -> String {
+    chrono::DateTime::from_timestamp(secs, 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_else(|| secs.to_string())
+}
+
+fn format_age(secs: i64) -> String {
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m {}s", s / 60, s % 60),
+        s if s < 86400 => format!("{}h {}m", s / 3600, (s % 3600) / 60),
+        s => format!("{}d {}h", s / 86400, (s % 86400) / 3600),
+    }
+}
+
 fn base64_decode_url_safe(input: &str) -> Result<Vec<u8>> {
     use base64::engine::general_purpose::URL_SAFE_NO_PAD;
     use base64::Engine;
@@ -84,63 +235,263 @@ fn base64_decode_url_safe(input: &str) -> Result<Vec<u8>> {
         .context("Invalid base64 encoding")
 }
 
-fn cmd_encode(
-    secret: &str,
-    payload: &str,
-    algorithm: JwtAlgorithm,
-    exp: Option<i64>,
-    sub: Option<String>,
-    iss: Option<String>,
-) -> Result<()> {
-    // Parse the payload JSON
-    let mut claims: HashMap<String, Value> =
-        serde_json::from_str(payload).context("Failed to parse payload JSON")?;
+fn cmd_sign(secret: &str, options: &ClaimOptions, algorithm: JwtAlgorithm) -> Result<()> {
+    let claims = build_claims(options, chrono::Utc::now().timestamp())?;
+    println!("{}", sign_token(&claims, secret.as_bytes(), algorithm)?);
+    Ok(())
+}
+
+/// Claim sources for `jwt sign`, applied in field order so later ones win.
+#[derive(Debug, Clone, Default)]
+pub struct ClaimOptions {
+    /// JSON object of base claims
+    pub payload: Option<String>,
+    /// `KEY=VALUE` claims
+    pub claims: Vec<String>,
+    /// Subject claim
+    pub sub: Option<String>,
+    /// Issuer claim
+    pub iss: Option<String>,
+    /// Lifetime in seconds from `iat`
+    pub exp: Option<i64>,
+}
 
-    // Add standard claims if provided
-    if let Some(exp_secs) = exp {
-        let exp_time = chrono::Utc::now().timestamp() + exp_secs;
-        claims.insert("exp".to_string(), Value::Number(exp_time.into()));
+/// Build the claims set, stamping `iat` (and `exp` if requested) from `now`.
+pub fn build_claims(options: &ClaimOptions, now: i64) -> Result<Map<String, Value>> {
+    let mut claims = match &options.payload {
+        Some(payload) => {
+            match serde_json::from_str(payload).context("Failed to parse payload JSON")? {
+                Value::Object(map) => map,
+                _ => bail!("Payload must be a JSON object"),
+            }
+        }
+        None => Map::new(),
+    };
+
+    for claim in &options.claims {
+        let (key, value) = claim
+            .split_once('=')
+            .with_context(|| format!("Invalid claim '{}': expected KEY=VALUE", claim))?;
+        if key.is_empty() {
+            bail!("Invalid claim '{}': empty key", claim);
+        }
+        // Keep numbers, booleans, and JSON literals typed; anything else is a string
+        let value =
+            serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+        claims.insert(key.to_string(), value);
     }
 
-    if let Some(sub_val) = sub {
-        claims.insert("sub".to_string(), Value::String(sub_val));
+    if let Some(sub) = &options.sub {
+        claims.insert("sub".to_string(), Value::String(sub.clone()));
+    }
+    if let Some(iss) = &options.iss {
+        claims.insert("iss".to_string(), Value::String(iss.clone()));
     }
 
-    if let Some(iss_val) = iss {
-        claims.insert("iss".to_string(), Value::String(iss_val));
+    claims.insert("iat".to_string(), Value::Number(now.into()));
+    if let Some(exp) = options.exp {
+        let exp = now
+            .checked_add(exp)
+            .with_context(|| format!("--exp {} is too large: the expiry time overflows", exp))?;
+        claims.insert("exp".to_string(), Value::Number(exp.into()));
     }
 
-    // Add issued-at time
-    claims.insert(
-        "iat".to_string(),
-        Value::Number(chrono::Utc::now().timestamp().into()),
+    Ok(claims)
+}
+
+/// Sign a claims set as a compact JWT.
+pub fn sign_token(
+    claims: &Map<String, Value>,
+    secret: &[u8],
+    algorithm: JwtAlgorithm,
+) -> Result<String> {
+    let header = serde_json::json!({ "alg": algorithm_name(algorithm), "typ": "JWT" });
+    let signing_input = format!(
+        "{}.{}",
+        base64_encode_url_safe(&serde_json::to_vec(&header)?),
+        base64_encode_url_safe(&serde_json::to_vec(claims)?)
     );
+    let signature = hmac_sign(algorithm, secret, signing_input.as_bytes());
+    Ok(format!(
+        "{}.{}",
+        signing_input,
+        base64_encode_url_safe(&signature)
+    ))
+}
+
+fn base64_encode_url_safe(data: &[u8]) -> String {
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    URL_SAFE_NO_PAD.encode(data)
+}
 
-    let header = Header::new(algorithm_to_jsonwebtoken(algorithm));
-    let key = EncodingKey::from_secret(secret.as_bytes());
+fn cmd_verify(token: &str, secret: &str, algorithm: JwtAlgorithm) -> Result<()> {
+    let decoded = decode_token(token)?;
+    let now = chrono::Utc::now().timestamp();
 
-    let token = encode(&header, &claims, &key).context("Failed to encode JWT")?;
+    if let Err(e) = verify_token(&decoded, secret.as_bytes(), algorithm, now) {
+        bail!("Invalid token: {}", e);
+    }
 
-    println!("{}", token);
+    println!("{}", "Token is valid!".green().bold());
+    println!("\n{}", "Claims:".cyan().bold());
+    println!("{}", serde_json::to_string_pretty(&decoded.claims)?);
     Ok(())
 }
 
-fn cmd_verify(token: &str, secret: &str, algorithm: JwtAlgorithm) -> Result<()> {
-    let key = DecodingKey::from_secret(secret.as_bytes());
-    let mut validation = Validation::new(algorithm_to_jsonwebtoken(algorithm));
-    validation.validate_exp = true;
-    validation.required_spec_claims.clear(); // Don't require any specific claims
-
-    match decode::<HashMap<String, Value>>(token, &key, &validation) {
-        Ok(token_data) => {
-            println!("{}", "Token is valid!".green().bold());
-            println!("\n{}", "Claims:".cyan().bold());
-            println!("{}", serde_json::to_string_pretty(&token_data.claims)?);
-            Ok(())
-        }
-        Err(e) => {
-            eprintln!("{}: {}", "Token verification failed".red().bold(), e);
-            anyhow::bail!("Invalid token")
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example token from jwt.io, signed with "your-256-bit-secret".
+    const JWT_IO_TOKEN: &str = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.\
+        eyJzdWIiOiIxMjM0NTY3ODkwIiwibmFtZSI6IkpvaG4gRG9lIiwiaWF0IjoxNTE2MjM5MDIyfQ.\
+        SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c";
+    const JWT_IO_SECRET: &[u8] = b"your-256-bit-secret";
+    const NOW: i64 = 1_700_000_000;
+
+    #[test]
+    fn test_decode_known_token() {
+        let token = decode_token(JWT_IO_TOKEN).unwrap();
+        assert_eq!(token.header["alg"], "HS256");
+        assert_eq!(token.header["typ"], "JWT");
+        assert_eq!(token.claims["sub"], "1234567890");
+        assert_eq!(token.claims["name"], "John Doe");
+        assert_eq!(token.claims["iat"], 1516239022);
+        assert_eq!(token.signature.len(), 32);
+    }
+
+    #[test]
+    fn test_verify_correct_signature() {
+        let token = decode_token(JWT_IO_TOKEN).unwrap();
+        assert!(verify_token(&token, JWT_IO_SECRET, JwtAlgorithm::Hs256, NOW).is_ok());
+    }
+
+    #[test]
+    fn test_verify_wrong_secret() {
+        let token = decode_token(JWT_IO_TOKEN).unwrap();
+        let err = verify_token(&token, b"wrong", JwtAlgorithm::Hs256, NOW).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid signature");
+    }
+
+    #[test]
+    fn test_verify_tampered_payload() {
+        // Same header and signature, payload with "name":"Jane Doe"
+        let payload = base64::Engine::encode(
+            &base64::engine::general_purpose::URL_SAFE_NO_PAD,
+            r#"{"sub":"1234567890","name":"Jane Doe","iat":1516239022}"#,
+        );
+        let parts: Vec<&str> = JWT_IO_TOKEN.split('.').collect();
+        let tampered = format!("{}.{}.{}", parts[0], payload, parts[2]);
+
+        let token = decode_token(&tampered).unwrap();
+        assert_eq!(token.claims["name"], "Jane Doe");
+        assert!(verify_token(&token, JWT_IO_SECRET, JwtAlgorithm::Hs256, NOW).is_err());
+    }
+
+    #[test]
+    fn test_verify_algorithm_mismatch() {
+        let token = decode_token(JWT_IO_TOKEN).unwrap();
+        let err = verify_token(&token, JWT_IO_SECRET, JwtAlgorithm::Hs512, NOW).unwrap_err();
+        assert!(err.to_string().contains("Algorithm mismatch"));
+    }
+
+    #[test]
+    fn test_check_time_claims() {
+        let claims = serde_json::json!({ "exp": NOW - 90 });
+        let err = check_time_claims(&claims, NOW).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Token expired at 2023-11-14T22:11:50+00:00 (1m 30s ago)"
+        );
+
+        assert!(check_time_claims(&serde_json::json!({ "exp": NOW + 1 }), NOW).is_ok());
+        assert!(check_time_claims(&serde_json::json!({ "nbf": NOW + 60 }), NOW).is_err());
+        assert!(check_time_claims(&serde_json::json!({}), NOW).is_ok());
+    }
+
+    #[test]
+    fn test_check_time_claims_fractional() {
+        let expired = serde_json::json!({ "exp": NOW as f64 - 0.5 });
+        assert!(check_time_claims(&expired, NOW).is_err());
+        let valid = serde_json::json!({ "exp": NOW as f64 + 0.5 });
+        assert!(check_time_claims(&valid, NOW).is_ok());
+    }
+
+    #[test]
+    fn test_sign_round_trip() {
+        let options = ClaimOptions {
+            sub: Some("alice".to_string()),
+            exp: Some(3600),
+            claims: vec!["role=admin".to_string(), "level=3".to_string()],
+            ..Default::default()
+        };
+        let claims = build_claims(&options, NOW).unwrap();
+        let token = sign_token(&claims, b"s3cret", JwtAlgorithm::Hs256).unwrap();
+
+        let decoded = decode_token(&token).unwrap();
+        assert_eq!(decoded.header["alg"], "HS256");
+        assert_eq!(decoded.claims["sub"], "alice");
+        assert_eq!(decoded.claims["role"], "admin");
+        assert_eq!(decoded.claims["level"], 3);
+        assert_eq!(decoded.claims["iat"], NOW);
+        assert_eq!(decoded.claims["exp"], NOW + 3600);
+
+        assert!(verify_token(&decoded, b"s3cret", JwtAlgorithm::Hs256, NOW + 3599).is_ok());
+        assert!(verify_token(&decoded, b"s3cret", JwtAlgorithm::Hs256, NOW + 3600).is_err());
+    }
+
+    #[test]
+    fn test_sign_matches_known_token() {
+        let claims = match serde_json::json!({
+            "sub": "1234567890",
+            "name": "John Doe",
+            "iat": 1516239022
+        }) {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        };
+        let token = sign_token(&claims, JWT_IO_SECRET, JwtAlgorithm::Hs256).unwrap();
+        assert_eq!(token, JWT_IO_TOKEN);
+    }
+
+    #[test]
+    fn test_build_claims_precedence() {
+        let options = ClaimOptions {
+            payload: Some(r#"{"sub":"bob","team":"core"}"#.to_string()),
+            claims: vec!["team=infra".to_string()],
+            sub: Some("alice".to_string()),
+            ..Default::default()
+        };
+        let claims = build_claims(&options, NOW).unwrap();
+        assert_eq!(claims["sub"], "alice");
+        assert_eq!(claims["team"], "infra");
+        assert!(!claims.contains_key("exp"));
+
+        let overflow = ClaimOptions {
+            exp: Some(i64::MAX),
+            ..Default::default()
+        };
+        let err = build_claims(&overflow, NOW).unwrap_err();
+        assert!(err.to_string().contains("overflows"));
+
+        let bad = ClaimOptions {
+            claims: vec!["novalue".to_string()],
+            ..Default::default()
+        };
+        assert!(build_claims(&bad, NOW).is_err());
+
+        let array = ClaimOptions {
+            payload: Some("[1]".to_string()),
+            ..Default::default()
+        };
+        assert!(build_claims(&array, NOW).is_err());
+    }
+
+    #[test]
+    fn test_decode_invalid() {
+        assert!(decode_token("not-a-token").is_err());
+        assert!(decode_token("a.b").is_err());
+        assert!(decode_token("!!!.e30.sig").is_err());
     }
 }
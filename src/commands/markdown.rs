@@ -1,17 +1,32 @@
 //! Markdown command - Markdown utilities.
+//!
+//! `render` prints Markdown as styled terminal text using a small CommonMark
+//! subset: ATX and setext headings, fenced code blocks, bullet and ordered
+//! lists, blockquotes, thematic breaks, and paragraphs with emphasis, code
+//! spans, links, and images. Styling is plain ANSI SGR codes, dropped when
+//! color is off (`--no-color`, or stdout is not a terminal). `--html` keeps
+//! the full HTML rendering.
+//!
+//! `toc` and `links` reuse the same block parsing, so headings and links
+//! inside code blocks are ignored. Anchor slugs follow GitHub's rules.
 
 use crate::cli::commands::markdown::{MarkdownArgs, MarkdownCommand};
 use anyhow::{Context, Result};
 use comrak::{markdown_to_html, Options};
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Read};
 use std::path::PathBuf;
 
+/// Width of the line printed for a thematic break.
+const RULE_WIDTH: usize = 40;
+
 /// Run the markdown command
 pub fn run(args: MarkdownArgs) -> Result<()> {
     match args.command {
-        MarkdownCommand::Render { input } => cmd_render(input),
+        MarkdownCommand::Render { input, html } => cmd_render(input, html),
         MarkdownCommand::Toc { input, depth } => cmd_toc(input, depth),
+        MarkdownCommand::Links { input } => cmd_links(input),
     }
 }
 
@@ -36,48 +51,983 @@ fn read_input(input: Option<PathBuf>) -> Result<String> {
     }
 }
 
-fn cmd_render(input: Option<PathBuf>) -> Result<()> {
+fn cmd_render(input: Option<PathBuf>, html: bool) -> Result<()> {
     let content = read_input(input)?;
 
-    let mut options = Options::default();
-    options.extension.strikethrough = true;
-    options.extension.table = true;
-    options.extension.autolink = true;
-    options.extension.tasklist = true;
-    options.extension.footnotes = true;
+    if html {
+        let mut options = Options::default();
+        options.extension.strikethrough = true;
+        options.extension.table = true;
+        options.extension.autolink = true;
+        options.extension.tasklist = true;
+        options.extension.footnotes = true;
+
+        print!("{}", markdown_to_html(&content, &options));
+        return Ok(());
+    }
 
-    let html = markdown_to_html(&content, &options);
-    print!("{}", html);
+    let color = colored::control::SHOULD_COLORIZE.should_colorize();
+    print!("{}", render_terminal(&content, color));
     Ok(())
 }
 
 fn cmd_toc(input: Option<PathBuf>, max_depth: u8) -> Result<()> {
     let content = read_input(input)?;
 
-    // Simple heading extraction
-    for line in content.lines() {
-        if line.starts_with('#') {
-            let level = line.chars().take_while(|c| *c == '#').count();
-            if level <= max_depth as usize {
-                let text = line.trim_start_matches('#').trim();
-                let indent = "  ".repeat(level.saturating_sub(1));
-                let slug = text
-                    .to_lowercase()
-                    .chars()
-                    .map(|c| {
-                        if c.is_alphanumeric() || c == '-' {
-                            c
+    for heading in headings(&content)
+        .iter()
+        .filter(|h| h.level <= max_depth as usize)
+    {
+        let indent = "  ".repeat(heading.level.saturating_sub(1));
+        println!("{}- [{}](#{})", indent, heading.text, heading.slug);
+    }
+    Ok(())
+}
+
+fn cmd_links(input: Option<PathBuf>) -> Result<()> {
+    let content = read_input(input)?;
+
+    for url in links(&content) {
+        println!("{}", url);
+    }
+    Ok(())
+}
+
+/// Terminal styles used by the renderer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Heading(usize),
+    Strong,
+    Emphasis,
+    Code,
+    Link,
+    Quote,
+    Dim,
+}
+
+impl Style {
+    /// SGR parameters for this style.
+    fn sgr(self) -> &'static str {
+        match self {
+            Style::Heading(1) => "1;4;35",
+            Style::Heading(2) => "1;36",
+            Style::Heading(_) => "1",
+            Style::Strong => "1",
+            Style::Emphasis => "3",
+            Style::Code => "33",
+            Style::Link => "4;34",
+            Style::Quote => "3;2",
+            Style::Dim => "2",
+        }
+    }
+}
+
+/// Wrap `text` in the escape codes for `style` when color is on.
+fn paint(text: &str, style: Style, color: bool) -> String {
+    if color && !text.is_empty() {
+        format!("\x1b[{}m{}\x1b[0m", style.sgr(), text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// An open fenced code block: fence character and length.
+struct Fence {
+    marker: char,
+    len: usize,
+}
+
+/// Render Markdown as terminal text.
+///
+/// Headings keep their `#` markers and are emphasized, code block contents
+/// are copied through verbatim, list items are indented by nesting level,
+/// and paragraph lines are joined. Link reference definitions are resolved
+/// and not printed.
+pub fn render_terminal(markdown: &str, color: bool) -> String {
+    let references = link_references(markdown);
+    let mut out = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut fence: Option<Fence> = None;
+
+    for line in markdown.lines() {
+        if let Some(open) = &fence {
+            if is_closing_fence(line, open) {
+                fence = None;
+                out.push(paint(line, Style::Dim, color));
+            } else {
+                out.push(paint(line, Style::Code, color));
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        // Setext underline turns the pending paragraph into a heading
+        if !paragraph.is_empty() && indent < 4 {
+            if let Some(level) = setext_level(trimmed) {
+                let text = paragraph.join(" ");
+                paragraph.clear();
+                let marker = "#".repeat(level);
+                let plain = render_inline(&text, &references, false);
+                out.push(paint(
+                    &format!("{} {}", marker, plain),
+                    Style::Heading(level),
+                    color,
+                ));
+                continue;
+            }
+        }
+
+        if indent < 4 {
+            if let Some(open) = opening_fence(trimmed) {
+                flush_paragraph(&mut out, &mut paragraph, &references, color);
+                fence = Some(open);
+                out.push(paint(line, Style::Dim, color));
+                continue;
+            }
+        }
+
+        if trimmed.is_empty() {
+            flush_paragraph(&mut out, &mut paragraph, &references, color);
+            if out.last().is_some_and(|last| !last.is_empty()) {
+                out.push(String::new());
+            }
+            continue;
+        }
+
+        if indent < 4 {
+            if let Some((level, text)) = atx_heading(trimmed) {
+                flush_paragraph(&mut out, &mut paragraph, &references, color);
+                let plain = render_inline(text, &references, false);
+                let heading = format!("{} {}", "#".repeat(level), plain);
+                out.push(paint(heading.trim_end(), Style::Heading(level), color));
+                continue;
+            }
+
+            if is_thematic_break(trimmed) {
+                flush_paragraph(&mut out, &mut paragraph, &references, color);
+                out.push(paint(&"─".repeat(RULE_WIDTH), Style::Dim, color));
+                continue;
+            }
+
+            if paragraph.is_empty() && parse_reference(trimmed).is_some() {
+                continue;
+            }
+
+            if let Some(quoted) = trimmed.strip_prefix('>') {
+                flush_paragraph(&mut out, &mut paragraph, &references, color);
+                let quoted = quoted.strip_prefix(' ').unwrap_or(quoted);
+                out.push(format!(
+                    "{} {}",
+                    paint("│", Style::Dim, color),
+                    paint(
+                        &render_inline(quoted, &references, false),
+                        Style::Quote,
+                        color
+                    )
+                ));
+                continue;
+            }
+        }
+
+        if let Some((marker, text)) = list_item(trimmed) {
+            flush_paragraph(&mut out, &mut paragraph, &references, color);
+            let depth = indent / 2 + 1;
+            out.push(format!(
+                "{}{} {}",
+                "  ".repeat(depth),
+                marker,
+                render_inline(text, &references, color)
+            ));
+            continue;
+        }
+
+        paragraph.push(line);
+    }
+    flush_paragraph(&mut out, &mut paragraph, &references, color);
+
+    while out.last().is_some_and(|last| last.is_empty()) {
+        out.pop();
+    }
+    if out.is_empty() {
+        return String::new();
+    }
+    let mut text = out.join("\n");
+    text.push('\n');
+    text
+}
+
+/// Emit the pending paragraph, joining soft line breaks with a space.
+///
+/// A line ending in two spaces or a backslash is a hard break.
+fn flush_paragraph(
+    out: &mut Vec<String>,
+    paragraph: &mut Vec<&str>,
+    references: &HashMap<String, String>,
+    color: bool,
+) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let mut text = String::new();
+    for (i, line) in paragraph.iter().enumerate() {
+        let line = line.trim_start();
+        let last = i + 1 == paragraph.len();
+        if let Some(hard) = line.strip_suffix('\\').filter(|_| !last) {
+            text.push_str(hard);
+            text.push('\n');
+        } else if line.ends_with("  ") && !last {
+            text.push_str(line.trim_end());
+            text.push('\n');
+        } else {
+            text.push_str(line.trim_end());
+            if !last {
+                text.push(' ');
+            }
+        }
+    }
+    paragraph.clear();
+    out.push(render_inline(&text, references, color));
+}
+
+fn opening_fence(line: &str) -> Option<Fence> {
+    let marker = line.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let len = line.chars().take_while(|c| *c == marker).count();
+    if len < 3 {
+        return None;
+    }
+    // Backtick fences may not have backticks in the info string
+    if marker == '`' && line[len..].contains('`') {
+        return None;
+    }
+    Some(Fence { marker, len })
+}
+
+fn is_closing_fence(line: &str, open: &Fence) -> bool {
+    let trimmed = line.trim_start();
+    if line.len() - trimmed.len() >= 4 {
+        return false;
+    }
+    let len = trimmed.chars().take_while(|c| *c == open.marker).count();
+    len >= open.len && trimmed[len..].trim().is_empty()
+}
+
+/// Parse an ATX heading into its level and text.
+fn atx_heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    let text = rest.trim();
+    // Drop an optional closing sequence of #s
+    let stripped = text.trim_end_matches('#');
+    let text = if stripped.is_empty() || stripped.ends_with([' ', '\t']) {
+        stripped.trim_end()
+    } else {
+        text
+    };
+    Some((level, text))
+}
+
+fn setext_level(line: &str) -> Option<usize> {
+    let line = line.trim_end();
+    if !line.is_empty() && line.chars().all(|c| c == '=') {
+        Some(1)
+    } else if !line.is_empty() && line.chars().all(|c| c == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    let Some(marker) = line.chars().next().filter(|c| matches!(c, '-' | '*' | '_')) else {
+        return false;
+    };
+    line.chars().filter(|c| *c == marker).count() >= 3
+        && line.chars().all(|c| c == marker || c == ' ' || c == '\t')
+}
+
+/// Parse a list item into its display marker and text.
+fn list_item(line: &str) -> Option<(String, &str)> {
+    if let Some(rest) = line
+        .strip_prefix(['-', '*', '+'])
+        .filter(|rest| rest.is_empty() || rest.starts_with(' '))
+    {
+        let text = rest.trim_start();
+        if let Some(task) = text.strip_prefix("[ ] ") {
+            return Some(("☐".to_string(), task));
+        }
+        if let Some(task) = text
+            .strip_prefix("[x] ")
+            .or_else(|| text.strip_prefix("[X] "))
+        {
+            return Some(("☑".to_string(), task));
+        }
+        return Some(("•".to_string(), text));
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if !(1..=9).contains(&digits) {
+        return None;
+    }
+    let rest = &line[digits..];
+    let delimiter = rest.chars().next().filter(|c| *c == '.' || *c == ')')?;
+    let rest = &rest[1..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((
+        format!("{}{}", &line[..digits], delimiter),
+        rest.trim_start(),
+    ))
+}
+
+/// Parse a link reference definition (`[label]: url "title"`).
+fn parse_reference(line: &str) -> Option<(String, String)> {
+    let rest = line.strip_prefix('[')?;
+    let (label, rest) = rest.split_once("]:")?;
+    if label.trim().is_empty() || label.contains(['[', ']']) {
+        return None;
+    }
+    let url = rest.split_whitespace().next()?;
+    let url = url
+        .strip_prefix('<')
+        .and_then(|u| u.strip_suffix('>'))
+        .unwrap_or(url);
+    Some((normalize_label(label), url.to_string()))
+}
+
+/// Collect link reference definitions outside code blocks.
+fn link_references(markdown: &str) -> HashMap<String, String> {
+    let mut references = HashMap::new();
+    let mut fence: Option<Fence> = None;
+    for line in markdown.lines() {
+        if let Some(open) = &fence {
+            if is_closing_fence(line, open) {
+                fence = None;
+            }
+            continue;
+        }
+        let trimmed = line.trim_start();
+        if line.len() - trimmed.len() >= 4 {
+            continue;
+        }
+        if let Some(open) = opening_fence(trimmed) {
+            fence = Some(open);
+        } else if let Some((label, url)) = parse_reference(trimmed) {
+            // The first definition of a label wins
+            references.entry(label).or_insert(url);
+        }
+    }
+    references
+}
+
+/// Labels match case-insensitively with internal whitespace collapsed.
+fn normalize_label(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Render inline Markdown: emphasis, code spans, links, images, escapes.
+///
+/// Delimiters are removed; with color off the text is left plain and link
+/// targets follow in parentheses.
+fn render_inline(text: &str, references: &HashMap<String, String>, color: bool) -> String {
+    flatten_inline(text, references, color, true)
+}
+
+/// The text content of inline Markdown: link text and image alt text
+/// without their targets, as used for heading anchors.
+fn inline_text(text: &str, references: &HashMap<String, String>) -> String {
+    flatten_inline(text, references, false, false)
+}
+
+fn flatten_inline(
+    text: &str,
+    references: &HashMap<String, String>,
+    color: bool,
+    urls: bool,
+) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if chars.get(i + 1).is_some_and(|n| n.is_ascii_punctuation()) => {
+                out.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => {
+                let run = run_length(&chars, i, '`');
+                match find_code_span_end(&chars, i + run, run) {
+                    Some(end) => {
+                        let code: String = chars[i + run..end].iter().collect();
+                        let code = strip_code_padding(&code);
+                        out.push_str(&paint(code, Style::Code, color));
+                        i = end + run;
+                    }
+                    None => {
+                        out.extend(&chars[i..i + run]);
+                        i += run;
+                    }
+                }
+            }
+            '*' | '_' => {
+                let run = run_length(&chars, i, c);
+                let intraword = c == '_' && i > 0 && chars[i - 1].is_alphanumeric();
+                let width = if run >= 2 { 2 } else { 1 };
+                let opens = !intraword
+                    && chars
+                        .get(i + width)
+                        .is_some_and(|next| !next.is_whitespace());
+                match find_delimiter(&chars, i + width, c, width).filter(|_| opens) {
+                    Some(end) => {
+                        let inner: String = chars[i + width..end].iter().collect();
+                        let style = if width == 2 {
+                            Style::Strong
                         } else {
-                            '-'
-                        }
-                    })
-                    .collect::<String>()
-                    .replace("--", "-")
-                    .trim_matches('-')
-                    .to_string();
-                println!("{}- [{}](#{})", indent, text, slug);
+                            Style::Emphasis
+                        };
+                        let inner = flatten_inline(&inner, references, false, urls);
+                        out.push_str(&paint(&inner, style, color));
+                        i = end + width;
+                    }
+                    None => {
+                        out.extend(&chars[i..i + run]);
+                        i += run;
+                    }
+                }
+            }
+            '!' if chars.get(i + 1) == Some(&'[') => match parse_link(&chars, i + 1, references) {
+                Some((alt, url, end)) => {
+                    let alt = flatten_inline(&alt, references, false, urls);
+                    if urls {
+                        out.push_str(&paint(&format!("[image: {}]", alt), Style::Link, color));
+                        out.push_str(&paint(&format!(" ({})", url), Style::Dim, color));
+                    } else {
+                        out.push_str(&alt);
+                    }
+                    i = end;
+                }
+                None => {
+                    out.push('!');
+                    i += 1;
+                }
+            },
+            '[' => match parse_link(&chars, i, references) {
+                Some((label, url, end)) => {
+                    let label = flatten_inline(&label, references, false, urls);
+                    out.push_str(&paint(&label, Style::Link, color));
+                    if urls && url != label {
+                        out.push_str(&paint(&format!(" ({})", url), Style::Dim, color));
+                    }
+                    i = end;
+                }
+                None => {
+                    out.push('[');
+                    i += 1;
+                }
+            },
+            '<' => match autolink(&chars, i) {
+                Some((url, end)) => {
+                    out.push_str(&paint(&url, Style::Link, color));
+                    i = end;
+                }
+                None => {
+                    out.push('<');
+                    i += 1;
+                }
+            },
+            _ => {
+                out.push(c);
+                i += 1;
             }
         }
     }
-    Ok(())
+    out
+}
+
+fn run_length(chars: &[char], start: usize, c: char) -> usize {
+    chars[start..].iter().take_while(|&&x| x == c).count()
+}
+
+/// Find a closing backtick run of exactly `run` characters.
+fn find_code_span_end(chars: &[char], start: usize, run: usize) -> Option<usize> {
+    let mut i = start;
+    while i < chars.len() {
+        if chars[i] == '`' {
+            let len = run_length(chars, i, '`');
+            if len == run {
+                return Some(i);
+            }
+            i += len;
+        } else {
+            i += 1;
+        }
+    }
+    None
+}
+
+/// One leading and trailing space is stripped when both are present.
+fn strip_code_padding(code: &str) -> &str {
+    match code.strip_prefix(' ').and_then(|c| c.strip_suffix(' ')) {
+        Some(inner) if !inner.trim().is_empty() => inner,
+        _ => code,
+    }
+}
+
+/// Find the closing emphasis delimiter of `width` characters.
+fn find_delimiter(chars: &[char], start: usize, c: char, width: usize) -> Option<usize> {
+    let mut i = start;
+    while i + width <= chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '`' => {
+                let run = run_length(chars, i, '`');
+                i = find_code_span_end(chars, i + run, run).map_or(i + run, |end| end + run);
+            }
+            x if x == c => {
+                let run = run_length(chars, i, c);
+                let closes = i > start && !chars[i - 1].is_whitespace();
+                let intraword = c == '_'
+                    && chars
+                        .get(i + run)
+                        .is_some_and(|next| next.is_alphanumeric());
+                if closes && !intraword && (run == width || (width == 1 && run >= 3)) {
+                    return Some(i);
+                }
+                if closes && !intraword && width == 2 && run > 2 {
+                    return Some(i + run - 2);
+                }
+                i += run;
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// Parse `[text](url)`, `[text][label]`, `[label][]`, or `[label]` at `start`.
+///
+/// Returns the link text, its destination, and the index after the link.
+fn parse_link(
+    chars: &[char],
+    start: usize,
+    references: &HashMap<String, String>,
+) -> Option<(String, String, usize)> {
+    let close = matching_bracket(chars, start)?;
+    let text: String = chars[start + 1..close].iter().collect();
+    let after = close + 1;
+
+    match chars.get(after) {
+        Some('(') => {
+            let end = after + 1 + chars[after + 1..].iter().position(|&c| c == ')')?;
+            let inside: String = chars[after + 1..end].iter().collect();
+            let url = inside.split_whitespace().next().unwrap_or("");
+            let url = url
+                .strip_prefix('<')
+                .and_then(|u| u.strip_suffix('>'))
+                .unwrap_or(url);
+            Some((text, url.to_string(), end + 1))
+        }
+        Some('[') => {
+            let label_end = matching_bracket(chars, after)?;
+            let label: String = chars[after + 1..label_end].iter().collect();
+            let label = if label.trim().is_empty() {
+                &text
+            } else {
+                &label
+            };
+            let url = references.get(&normalize_label(label))?;
+            Some((text, url.clone(), label_end + 1))
+        }
+        _ => {
+            let url = references.get(&normalize_label(&text))?;
+            Some((text, url.clone(), after))
+        }
+    }
+}
+
+fn matching_bracket(chars: &[char], open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = open;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Parse an autolink such as `<https://example.com>`.
+fn autolink(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let end = start + chars[start..].iter().position(|&c| c == '>')?;
+    let url: String = chars[start + 1..end].iter().collect();
+    let scheme = url.split_once(':')?.0;
+    let valid = !scheme.is_empty()
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+.-".contains(c))
+        && !url.contains(char::is_whitespace);
+    valid.then_some((url, end + 1))
+}
+
+/// A heading with its GitHub-style anchor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Heading {
+    level: usize,
+    text: String,
+    slug: String,
+}
+
+/// Collect ATX and setext headings outside code blocks, in document order.
+fn headings(markdown: &str) -> Vec<Heading> {
+    let references = link_references(markdown);
+    let mut slugger = Slugger::default();
+    let mut found = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut fence: Option<Fence> = None;
+
+    let mut push = |level: usize, text: &str| {
+        let text = inline_text(text, &references).trim().to_string();
+        let slug = slugger.slug(&text);
+        found.push(Heading { level, text, slug });
+    };
+
+    for line in markdown.lines() {
+        if let Some(open) = &fence {
+            if is_closing_fence(line, open) {
+                fence = None;
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if line.len() - trimmed.len() >= 4 {
+            paragraph.push(trimmed);
+            continue;
+        }
+
+        if !paragraph.is_empty() {
+            if let Some(level) = setext_level(trimmed) {
+                push(level, &paragraph.join(" "));
+                paragraph.clear();
+                continue;
+            }
+        }
+
+        if let Some(open) = opening_fence(trimmed) {
+            paragraph.clear();
+            fence = Some(open);
+        } else if let Some((level, text)) = atx_heading(trimmed) {
+            paragraph.clear();
+            push(level, text);
+        } else if trimmed.is_empty()
+            || is_thematic_break(trimmed)
+            || trimmed.starts_with('>')
+            || list_item(trimmed).is_some()
+            || (paragraph.is_empty() && parse_reference(trimmed).is_some())
+        {
+            paragraph.clear();
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+    found
+}
+
+/// Hands out anchor slugs the way GitHub does: a repeated slug gets `-1`,
+/// `-2`, ... appended, skipping any suffix already taken.
+#[derive(Debug, Default)]
+struct Slugger {
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    fn slug(&mut self, text: &str) -> String {
+        let base = slugify(text);
+        let mut slug = base.clone();
+        while self.occurrences.contains_key(&slug) {
+            let count = self.occurrences.entry(base.clone()).or_default();
+            *count += 1;
+            slug = format!("{}-{}", base, count);
+        }
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+}
+
+/// Lowercase, drop punctuation, and turn spaces into hyphens.
+fn slugify(text: &str) -> String {
+    text.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Collect link and image targets outside code, in document order.
+///
+/// Reference-style links are resolved through their definitions; the
+/// definitions themselves are not listed.
+fn links(markdown: &str) -> Vec<String> {
+    let references = link_references(markdown);
+    let mut found = Vec::new();
+    let mut fence: Option<Fence> = None;
+
+    for line in markdown.lines() {
+        if let Some(open) = &fence {
+            if is_closing_fence(line, open) {
+                fence = None;
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if line.len() - trimmed.len() < 4 {
+            if let Some(open) = opening_fence(trimmed) {
+                fence = Some(open);
+                continue;
+            }
+            if parse_reference(trimmed).is_some() {
+                continue;
+            }
+        }
+        collect_links(trimmed, &references, &mut found);
+    }
+    found
+}
+
+fn collect_links(text: &str, references: &HashMap<String, String>, found: &mut Vec<String>) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '`' => {
+                let run = run_length(&chars, i, '`');
+                i = find_code_span_end(&chars, i + run, run).map_or(i + run, |end| end + run);
+            }
+            '!' if chars.get(i + 1) == Some(&'[') => match parse_link(&chars, i + 1, references) {
+                Some((_, url, end)) => {
+                    found.push(url);
+                    i = end;
+                }
+                None => i += 1,
+            },
+            '[' => match parse_link(&chars, i, references) {
+                Some((label, url, end)) => {
+                    found.push(url);
+                    // Badges nest an image inside the link text
+                    collect_links(&label, references, found);
+                    i = end;
+                }
+                None => i += 1,
+            },
+            '<' => match autolink(&chars, i) {
+                Some((url, end)) => {
+                    found.push(url);
+                    i = end;
+                }
+                None => i += 1,
+            },
+            _ => i += 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(markdown: &str) -> String {
+        render_terminal(markdown, false)
+    }
+
+    #[test]
+    fn test_heading_is_emphasized() {
+        let out = render_terminal("# Title\n\nSome text.\n", true);
+        let first = out.lines().next().unwrap();
+        assert_eq!(first, "\x1b[1;4;35m# Title\x1b[0m");
+        assert_eq!(out.lines().nth(2), Some("Some text."));
+    }
+
+    #[test]
+    fn test_heading_levels() {
+        assert_eq!(plain("## Section ##\n"), "## Section\n");
+        assert_eq!(plain("#hashtag\n"), "#hashtag\n");
+        assert!(render_terminal("### Deep\n", true).starts_with("\x1b[1m### Deep"));
+    }
+
+    #[test]
+    fn test_setext_heading() {
+        assert_eq!(plain("Title\n=====\n\nSub\n---\n"), "# Title\n\n## Sub\n");
+    }
+
+    #[test]
+    fn test_fenced_code_block_verbatim() {
+        let code = "fn main() {\n    let x = *ptr; // **not bold** `span`\n}";
+        let markdown = format!("Intro\n\n```rust\n{}\n```\n", code);
+        let out = plain(&markdown);
+        assert!(out.contains(&format!("```rust\n{}\n```", code)));
+
+        let colored = render_terminal(&markdown, true);
+        for line in code.lines() {
+            assert!(colored.contains(&format!("\x1b[33m{}\x1b[0m", line)));
+        }
+    }
+
+    #[test]
+    fn test_code_block_hides_markdown() {
+        let out = plain("~~~\n# not a heading\n- not a list\n~~~\n");
+        assert_eq!(out, "~~~\n# not a heading\n- not a list\n~~~\n");
+    }
+
+    #[test]
+    fn test_lists_indented() {
+        let out = plain("- one\n- two\n  - nested\n1. first\n2) second\n");
+        assert_eq!(
+            out,
+            "  • one\n  • two\n    • nested\n  1. first\n  2) second\n"
+        );
+        assert_eq!(plain("- [ ] todo\n- [x] done\n"), "  ☐ todo\n  ☑ done\n");
+    }
+
+    #[test]
+    fn test_paragraph_lines_joined() {
+        assert_eq!(plain("one\ntwo\n\n\n\nthree\n"), "one two\n\nthree\n");
+        assert_eq!(plain("hard  \nbreak\n"), "hard\nbreak\n");
+    }
+
+    #[test]
+    fn test_inline_styles() {
+        assert_eq!(
+            plain("Some **bold**, *italic*, _under_ and `code`.\n"),
+            "Some bold, italic, under and code.\n"
+        );
+        assert_eq!(
+            render_terminal("**bold** `x`\n", true),
+            "\x1b[1mbold\x1b[0m \x1b[33mx\x1b[0m\n"
+        );
+        assert_eq!(plain("snake_case_name\n"), "snake_case_name\n");
+        assert_eq!(plain("2 * 3 * 4\n"), "2 * 3 * 4\n");
+        assert_eq!(plain("\\*literal\\*\n"), "*literal*\n");
+    }
+
+    #[test]
+    fn test_links() {
+        assert_eq!(
+            plain("See [the docs](https://example.com) or <https://x.org>.\n"),
+            "See the docs (https://example.com) or https://x.org.\n"
+        );
+        assert_eq!(
+            plain("Read [the guide][guide].\n\n[Guide]: https://example.com/guide\n"),
+            "Read the guide (https://example.com/guide).\n"
+        );
+        assert_eq!(plain("![logo](logo.png)\n"), "[image: logo] (logo.png)\n");
+        assert_eq!(plain("[not a link]\n"), "[not a link]\n");
+    }
+
+    #[test]
+    fn test_blockquote_and_rule() {
+        assert_eq!(
+            plain("> quoted *text*\n\n---\n"),
+            format!("│ quoted text\n\n{}\n", "─".repeat(RULE_WIDTH))
+        );
+    }
+
+    #[test]
+    fn test_no_color_has_no_escapes() {
+        let out = plain("# Title\n\n- **item**\n\n```\ncode\n```\n");
+        assert!(!out.contains('\x1b'));
+    }
+
+    #[test]
+    fn test_duplicate_heading_slugs() {
+        let doc = "# Intro\n## Setup\n## Setup\n### Setup\nSetup\n---\n";
+        let slugs: Vec<String> = headings(doc).into_iter().map(|h| h.slug).collect();
+        assert_eq!(slugs, ["intro", "setup", "setup-1", "setup-2", "setup-3"]);
+    }
+
+    #[test]
+    fn test_slug_suffix_already_taken() {
+        let doc = "# Foo\n# Foo-1\n# Foo\n";
+        let slugs: Vec<String> = headings(doc).into_iter().map(|h| h.slug).collect();
+        assert_eq!(slugs, ["foo", "foo-1", "foo-2"]);
+    }
+
+    #[test]
+    fn test_slugify_github_rules() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("What's new in v2.0?"), "whats-new-in-v20");
+        assert_eq!(
+            slugify("snake_case and kebab-case"),
+            "snake_case-and-kebab-case"
+        );
+        assert_eq!(slugify("A  B"), "a--b");
+        assert_eq!(slugify("Ünïcode Ok"), "ünïcode-ok");
+    }
+
+    #[test]
+    fn test_headings_text_and_code_blocks() {
+        let doc = "# The [docs](https://x.org) *now*\n```\n# not a heading\n```\n## `code` ##\n";
+        let found = headings(doc);
+        assert_eq!(
+            found,
+            vec![
+                Heading {
+                    level: 1,
+                    text: "The docs now".to_string(),
+                    slug: "the-docs-now".to_string(),
+                },
+                Heading {
+                    level: 2,
+                    text: "code".to_string(),
+                    slug: "code".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_links_inline_and_reference() {
+        let doc = "See [site](https://a.example) and [the guide][guide].\n\
+                   Also [Guide] and <https://auto.example>.\n\
+                   [![badge](https://img.example/b.svg)](https://ci.example)\n\
+                   `[skip](https://code.example)` and [missing][nope]\n\
+                   ```\n[skip](https://fence.example)\n```\n\
+                   \n[guide]: https://guide.example \"Guide\"\n";
+        assert_eq!(
+            links(doc),
+            [
+                "https://a.example",
+                "https://guide.example",
+                "https://guide.example",
+                "https://auto.example",
+                "https://ci.example",
+                "https://img.example/b.svg",
+            ]
+        );
+    }
 }
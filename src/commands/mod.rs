@@ -1,5 +1,6 @@
 //! Command implementations.
 
+pub mod base;
 pub mod calc;
 pub mod chat;
 pub mod compress;
@@ -22,6 +23,7 @@ pub mod markdown;
 pub mod net;
 pub mod polars;
 pub mod rand;
+pub mod regex;
 pub mod system;
 pub mod template;
 pub mod text;
@@ -9,13 +9,23 @@
 //! dx net url "https://example.com:8080/path?q=1"
 //! dx net port 8080           # Check if port is in use
 //! dx net lookup google.com   # DNS lookup
+//! dx net subnet 192.168.1.0/26  # CIDR breakdown
+//! dx net dns example.com --type MX  # Query specific record types
 //! ```
+//!
+//! ## DNS Queries
+//! `dx net lookup` uses the system resolver, which only returns addresses.
+//! `dx net dns` speaks the DNS wire protocol (RFC 1035) directly over UDP,
+//! so it can ask for MX, TXT, and CNAME records and report NXDOMAIN and
+//! timeouts distinctly. Truncated UDP answers are retried over TCP.
 
-use crate::cli::commands::net::{NetArgs, NetCommand};
-use anyhow::{Context, Result};
+use crate::cli::commands::net::{DnsRecordType, NetArgs, NetCommand};
+use crate::utils::print_warning;
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
-use std::net::{TcpStream, ToSocketAddrs};
-use std::time::Duration;
+use std::io::{Read, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant};
 
 pub fn run(args: NetArgs) -> Result<()> {
     match args.command {
@@ -23,6 +33,13 @@ pub fn run(args: NetArgs) -> Result<()> {
         NetCommand::Url { url } => cmd_url(&url),
         NetCommand::Port { port, host } => cmd_port(&host, port),
         NetCommand::Lookup { domain } => cmd_lookup(&domain),
+        NetCommand::Subnet { cidr } => cmd_subnet(&cidr),
+        NetCommand::Dns {
+            domain,
+            record_type,
+            server,
+            timeout,
+        } => cmd_dns(&domain, record_type, server.as_deref(), timeout),
     }
 }
 
@@ -178,3 +195,707 @@ fn cmd_lookup(domain: &str) -> Result<()> {
 
     Ok(())
 }
+
+/// Breakdown of an IPv4 network.
+#[derive(Debug, PartialEq)]
+struct SubnetV4 {
+    network: Ipv4Addr,
+    prefix: u8,
+    netmask: Ipv4Addr,
+    wildcard: Ipv4Addr,
+    /// `None` for /31 and /32, which have no broadcast address
+    broadcast: Option<Ipv4Addr>,
+    first_host: Ipv4Addr,
+    last_host: Ipv4Addr,
+    hosts: u64,
+}
+
+/// Breakdown of an IPv6 network (IPv6 has no broadcast addresses).
+#[derive(Debug, PartialEq)]
+struct SubnetV6 {
+    network: Ipv6Addr,
+    prefix: u8,
+    wildcard: Ipv6Addr,
+    first_host: Ipv6Addr,
+    last_host: Ipv6Addr,
+}
+
+/// Compute the IPv4 subnet containing `addr`.
+///
+/// Usable hosts exclude the network and broadcast addresses, except for
+/// /31 (point-to-point links, RFC 3021) where both addresses are usable
+/// and /32 which is a single host.
+fn subnet_v4(addr: Ipv4Addr, prefix: u8) -> Result<SubnetV4> {
+    if prefix > 32 {
+        bail!("Invalid IPv4 prefix length /{} (must be 0-32)", prefix);
+    }
+    let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+    let network = u32::from(addr) & mask;
+    let last = network | !mask;
+
+    let (broadcast, first_host, last_host, hosts) = match prefix {
+        32 => (None, network, network, 1),
+        31 => (None, network, last, 2),
+        _ => (
+            Some(Ipv4Addr::from(last)),
+            network + 1,
+            last - 1,
+            (1u64 << (32 - prefix)) - 2,
+        ),
+    };
+
+    Ok(SubnetV4 {
+        network: Ipv4Addr::from(network),
+        prefix,
+        netmask: Ipv4Addr::from(mask),
+        wildcard: Ipv4Addr::from(!mask),
+        broadcast,
+        first_host: Ipv4Addr::from(first_host),
+        last_host: Ipv4Addr::from(last_host),
+        hosts,
+    })
+}
+
+/// Compute the IPv6 subnet containing `addr`.
+///
+/// Every address in an IPv6 prefix is assignable, so the host range spans
+/// the whole block.
+fn subnet_v6(addr: Ipv6Addr, prefix: u8) -> Result<SubnetV6> {
+    if prefix > 128 {
+        bail!("Invalid IPv6 prefix length /{} (must be 0-128)", prefix);
+    }
+    let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+    let network = u128::from(addr) & mask;
+
+    Ok(SubnetV6 {
+        network: Ipv6Addr::from(network),
+        prefix,
+        wildcard: Ipv6Addr::from(!mask),
+        first_host: Ipv6Addr::from(network),
+        last_host: Ipv6Addr::from(network | !mask),
+    })
+}
+
+/// Split CIDR notation into an address and prefix length.
+fn parse_cidr(cidr: &str) -> Result<(IpAddr, u8)> {
+    let (addr, prefix) = cidr
+        .trim()
+        .split_once('/')
+        .with_context(|| format!("Expected CIDR notation like 192.168.1.0/24, got '{}'", cidr))?;
+    let addr: IpAddr = addr
+        .parse()
+        .with_context(|| format!("Invalid IP address '{}'", addr))?;
+    let prefix: u8 = prefix
+        .parse()
+        .with_context(|| format!("Invalid prefix length '{}'", prefix))?;
+    Ok((addr, prefix))
+}
+
+/// Subnet calculator
+fn cmd_subnet(cidr: &str) -> Result<()> {
+    match parse_cidr(cidr)? {
+        (IpAddr::V4(addr), prefix) => {
+            let s = subnet_v4(addr, prefix)?;
+            println!("{}: {}/{}", "network".cyan(), s.network, s.prefix);
+            println!("{}: {}", "netmask".cyan(), s.netmask);
+            println!("{}: {}", "wildcard".cyan(), s.wildcard);
+            match s.broadcast {
+                Some(b) => println!("{}: {}", "broadcast".cyan(), b),
+                None => println!("{}: {}", "broadcast".cyan(), "none".dimmed()),
+            }
+            println!(
+                "{}: {} - {}",
+                "host range".cyan(),
+                s.first_host,
+                s.last_host
+            );
+            println!("{}: {}", "hosts".cyan(), s.hosts.to_string().green());
+        }
+        (IpAddr::V6(addr), prefix) => {
+            let s = subnet_v6(addr, prefix)?;
+            println!("{}: {}/{}", "network".cyan(), s.network, s.prefix);
+            println!("{}: {}", "wildcard".cyan(), s.wildcard);
+            println!(
+                "{}: {} - {}",
+                "host range".cyan(),
+                s.first_host,
+                s.last_host
+            );
+            // 2^128 doesn't fit in a u128, so large blocks are shown as powers
+            let host_bits = 128 - u32::from(prefix);
+            let hosts = if host_bits >= 64 {
+                format!("2^{}", host_bits)
+            } else {
+                (1u128 << host_bits).to_string()
+            };
+            println!("{}: {}", "hosts".cyan(), hosts.green());
+        }
+    }
+    Ok(())
+}
+
+// =============================================================================
+// DNS
+// =============================================================================
+
+impl DnsRecordType {
+    /// Wire-format type code (RFC 1035 section 3.2.2, RFC 3596).
+    fn code(self) -> u16 {
+        match self {
+            DnsRecordType::A => 1,
+            DnsRecordType::Cname => 5,
+            DnsRecordType::Mx => 15,
+            DnsRecordType::Txt => 16,
+            DnsRecordType::Aaaa => 28,
+        }
+    }
+}
+
+/// Response code for "name does not exist".
+const RCODE_NXDOMAIN: u8 = 3;
+
+/// Data of one answer record.
+#[derive(Debug, PartialEq)]
+enum DnsData {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Mx {
+        preference: u16,
+        exchange: String,
+    },
+    Txt(String),
+    Cname(String),
+    /// A record type we don't decode
+    Other(u16),
+}
+
+/// One resource record from the answer section.
+#[derive(Debug, PartialEq)]
+struct DnsAnswer {
+    name: String,
+    ttl: u32,
+    data: DnsData,
+}
+
+/// Decoded parts of a DNS response that we use.
+#[derive(Debug)]
+struct DnsResponse {
+    rcode: u8,
+    truncated: bool,
+    answers: Vec<DnsAnswer>,
+}
+
+/// Build a recursive query for `name` with the given type.
+fn build_dns_query(id: u16, name: &str, record_type: DnsRecordType) -> Result<Vec<u8>> {
+    let mut packet = Vec::with_capacity(32 + name.len());
+    packet.extend_from_slice(&id.to_be_bytes());
+    packet.extend_from_slice(&0x0100u16.to_be_bytes()); // Standard query, recursion desired
+    packet.extend_from_slice(&1u16.to_be_bytes()); // QDCOUNT
+    packet.extend_from_slice(&[0; 6]); // ANCOUNT, NSCOUNT, ARCOUNT
+
+    let name = name.trim_end_matches('.');
+    if name.len() > 253 {
+        bail!("Domain name is too long (max 253 characters)");
+    }
+    for label in name.split('.') {
+        if label.is_empty() || label.len() > 63 {
+            bail!(
+                "Invalid domain name '{}': labels must be 1-63 characters",
+                name
+            );
+        }
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+
+    packet.extend_from_slice(&record_type.code().to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes()); // Class IN
+    Ok(packet)
+}
+
+/// Read a big-endian u16 at `pos`.
+fn read_u16(buf: &[u8], pos: usize) -> Result<u16> {
+    buf.get(pos..pos + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .context("Truncated DNS response")
+}
+
+/// Read a possibly compressed domain name, returning it and the position
+/// just past it in the original (uncompressed) stream.
+fn read_dns_name(buf: &[u8], mut pos: usize) -> Result<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+    // Each pointer must go backwards, which bounds the loop
+    let mut jumps = 0;
+
+    loop {
+        let len = *buf.get(pos).context("Truncated DNS name")?;
+        match len {
+            0 => {
+                pos += 1;
+                break;
+            }
+            l if l & 0xc0 == 0xc0 => {
+                let offset = usize::from(read_u16(buf, pos)? & 0x3fff);
+                end.get_or_insert(pos + 2);
+                jumps += 1;
+                if offset >= pos || jumps > 64 {
+                    bail!("Invalid compression pointer in DNS response");
+                }
+                pos = offset;
+            }
+            l => {
+                let start = pos + 1;
+                let label = buf
+                    .get(start..start + usize::from(l))
+                    .context("Truncated DNS name")?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                pos = start + usize::from(l);
+            }
+        }
+    }
+
+    Ok((labels.join("."), end.unwrap_or(pos)))
+}
+
+/// Parse a DNS response, checking that it answers query `id`.
+fn parse_dns_response(buf: &[u8], id: u16) -> Result<DnsResponse> {
+    if read_u16(buf, 0)? != id {
+        bail!("DNS response ID does not match the query");
+    }
+    let flags = read_u16(buf, 2)?;
+    if flags & 0x8000 == 0 {
+        bail!("Received a DNS query instead of a response");
+    }
+    let qdcount = read_u16(buf, 4)?;
+    let ancount = read_u16(buf, 6)?;
+
+    // Skip the question section
+    let mut pos = 12;
+    for _ in 0..qdcount {
+        let (_, next) = read_dns_name(buf, pos)?;
+        pos = next + 4;
+    }
+
+    let mut answers = Vec::with_capacity(usize::from(ancount));
+    for _ in 0..ancount {
+        let (name, next) = read_dns_name(buf, pos)?;
+        let rtype = read_u16(buf, next)?;
+        let ttl_bytes = buf
+            .get(next + 4..next + 8)
+            .context("Truncated DNS record")?;
+        let ttl = u32::from_be_bytes([ttl_bytes[0], ttl_bytes[1], ttl_bytes[2], ttl_bytes[3]]);
+        let rdlen = usize::from(read_u16(buf, next + 8)?);
+        let rdata_start = next + 10;
+        let rdata = buf
+            .get(rdata_start..rdata_start + rdlen)
+            .context("Truncated DNS record")?;
+
+        let data = match rtype {
+            1 if rdlen == 4 => DnsData::A(Ipv4Addr::new(rdata[0], rdata[1], rdata[2], rdata[3])),
+            28 if rdlen == 16 => {
+                let octets: [u8; 16] = rdata.try_into().expect("length checked");
+                DnsData::Aaaa(Ipv6Addr::from(octets))
+            }
+            5 => DnsData::Cname(read_dns_name(buf, rdata_start)?.0),
+            15 => DnsData::Mx {
+                preference: read_u16(buf, rdata_start)?,
+                exchange: read_dns_name(buf, rdata_start + 2)?.0,
+            },
+            16 => {
+                // TXT data is one or more length-prefixed strings
+                let mut text = String::new();
+                let mut i = 0;
+                while i < rdata.len() {
+                    let len = usize::from(rdata[i]);
+                    let chunk = rdata
+                        .get(i + 1..i + 1 + len)
+                        .context("Truncated TXT record")?;
+                    text.push_str(&String::from_utf8_lossy(chunk));
+                    i += 1 + len;
+                }
+                DnsData::Txt(text)
+            }
+            other => DnsData::Other(other),
+        };
+
+        answers.push(DnsAnswer { name, ttl, data });
+        pos = rdata_start + rdlen;
+    }
+
+    Ok(DnsResponse {
+        rcode: (flags & 0x000f) as u8,
+        truncated: flags & 0x0200 != 0,
+        answers,
+    })
+}
+
+/// Server used when the system's DNS server can't be determined.
+const FALLBACK_NAMESERVER: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)), 53);
+
+/// Find the system's DNS server.
+///
+/// On Unix this is the first `nameserver` in `/etc/resolv.conf`. Elsewhere
+/// (Windows keeps its servers per adapter), or when that file is missing,
+/// a public resolver is used instead and a warning says so.
+fn system_nameserver() -> SocketAddr {
+    let configured = std::fs::read_to_string("/etc/resolv.conf")
+        .ok()
+        .and_then(|conf| parse_resolv_conf(&conf));
+    configured.unwrap_or_else(|| {
+        print_warning(&format!(
+            "No system DNS server found; using {} (use --server to pick one)",
+            FALLBACK_NAMESERVER.ip()
+        ));
+        FALLBACK_NAMESERVER
+    })
+}
+
+/// First `nameserver` entry of a resolv.conf file.
+fn parse_resolv_conf(conf: &str) -> Option<SocketAddr> {
+    conf.lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .find_map(|addr| addr.trim().parse::<IpAddr>().ok())
+        .map(|ip| SocketAddr::new(ip, 53))
+}
+
+/// Parse a `--server` value: an IP with optional port (default 53).
+fn parse_dns_server(server: &str) -> Result<SocketAddr> {
+    if let Ok(addr) = server.parse::<SocketAddr>() {
+        return Ok(addr);
+    }
+    server
+        .parse::<IpAddr>()
+        .map(|ip| SocketAddr::new(ip, 53))
+        .with_context(|| format!("Invalid DNS server '{}' (expected an IP address)", server))
+}
+
+/// Send a query over UDP and wait for the matching response.
+///
+/// Stray packets (from other hosts, or answers to earlier queries) are
+/// skipped, but never past `timeout` from when the query was sent.
+fn query_dns(
+    server: SocketAddr,
+    domain: &str,
+    record_type: DnsRecordType,
+    timeout: Duration,
+) -> Result<DnsResponse> {
+    let id: u16 = rand::random();
+    let query = build_dns_query(id, domain, record_type)?;
+
+    let bind_addr = if server.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    };
+    let socket = UdpSocket::bind(bind_addr).context("Failed to open UDP socket")?;
+    socket
+        .send_to(&query, server)
+        .with_context(|| format!("Failed to send DNS query to {}", server))?;
+    let deadline = Instant::now() + timeout;
+
+    let mut buf = [0u8; 4096];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            bail!(dns_timeout(server, timeout));
+        }
+        socket.set_read_timeout(Some(remaining))?;
+        let (len, from) = match socket.recv_from(&mut buf) {
+            Ok(r) => r,
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+                ) =>
+            {
+                bail!(dns_timeout(server, timeout));
+            }
+            Err(e) => return Err(e).context("Failed to receive DNS response"),
+        };
+        // Ignore stray packets from other hosts or earlier queries
+        if from != server {
+            continue;
+        }
+        match parse_dns_response(&buf[..len], id) {
+            Ok(response) => return Ok(response),
+            Err(_) if read_u16(&buf[..len], 0).ok() != Some(id) => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Send a query over TCP (RFC 1035 section 4.2.2), used when the UDP answer
+/// was truncated.
+fn query_dns_tcp(
+    server: SocketAddr,
+    domain: &str,
+    record_type: DnsRecordType,
+    timeout: Duration,
+) -> Result<DnsResponse> {
+    let id: u16 = rand::random();
+    let query = build_dns_query(id, domain, record_type)?;
+
+    let deadline = Instant::now() + timeout;
+    let mut stream = TcpStream::connect_timeout(&server, timeout)
+        .with_context(|| format!("Failed to connect to {} over TCP", server))?;
+    let remaining = deadline.saturating_duration_since(Instant::now());
+    if remaining.is_zero() {
+        bail!(dns_timeout(server, timeout));
+    }
+    stream.set_read_timeout(Some(remaining))?;
+    stream.set_write_timeout(Some(remaining))?;
+
+    // Messages are prefixed with their length as a big-endian u16
+    let mut message = (query.len() as u16).to_be_bytes().to_vec();
+    message.extend_from_slice(&query);
+    let result = stream.write_all(&message).and_then(|()| {
+        let mut len = [0u8; 2];
+        stream.read_exact(&mut len)?;
+        let mut buf = vec![0u8; usize::from(u16::from_be_bytes(len))];
+        stream.read_exact(&mut buf)?;
+        Ok(buf)
+    });
+    let buf = match result {
+        Ok(buf) => buf,
+        Err(e)
+            if matches!(
+                e.kind(),
+                std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut
+            ) =>
+        {
+            bail!(dns_timeout(server, timeout));
+        }
+        Err(e) => return Err(e).context("Failed to receive DNS response over TCP"),
+    };
+    parse_dns_response(&buf, id)
+}
+
+fn dns_timeout(server: SocketAddr, timeout: Duration) -> String {
+    format!(
+        "DNS query to {} timed out after {}s",
+        server,
+        timeout.as_secs()
+    )
+}
+
+/// Query a specific DNS record type
+fn cmd_dns(
+    domain: &str,
+    record_type: DnsRecordType,
+    server: Option<&str>,
+    timeout: u64,
+) -> Result<()> {
+    let server = match server {
+        Some(s) => parse_dns_server(s)?,
+        None => system_nameserver(),
+    };
+    let timeout = Duration::from_secs(timeout);
+    let mut response = query_dns(server, domain, record_type, timeout)?;
+    if response.truncated {
+        response = query_dns_tcp(server, domain, record_type, timeout)
+            .context("UDP response was truncated and the TCP retry failed")?;
+    }
+
+    match response.rcode {
+        0 => {}
+        RCODE_NXDOMAIN => bail!("Domain not found: {} (NXDOMAIN)", domain),
+        2 => bail!("DNS server {} failed to answer (SERVFAIL)", server),
+        5 => bail!("DNS server {} refused the query (REFUSED)", server),
+        other => bail!("DNS query failed with response code {}", other),
+    }
+    if response.truncated {
+        print_warning("Response was truncated even over TCP; some records may be missing");
+    }
+
+    let type_name = format!("{:?}", record_type).to_uppercase();
+    println!("{}: {}", "domain".cyan(), domain);
+    println!("{}: {}", "type".cyan(), type_name);
+    println!("{}: {}", "server".cyan(), server);
+
+    if response.answers.is_empty() {
+        println!("{}", format!("No {} records found", type_name).yellow());
+        return Ok(());
+    }
+
+    for answer in &response.answers {
+        let value = match &answer.data {
+            DnsData::A(ip) => ip.to_string(),
+            DnsData::Aaaa(ip) => ip.to_string(),
+            DnsData::Mx {
+                preference,
+                exchange,
+            } => format!("{} {}", preference, exchange),
+            DnsData::Txt(text) => format!("\"{}\"", text),
+            // CNAMEs appear in A/AAAA answers when the name is an alias
+            DnsData::Cname(target) if record_type != DnsRecordType::Cname => {
+                format!("{} is an alias for {}", answer.name, target)
+            }
+            DnsData::Cname(target) => target.clone(),
+            DnsData::Other(code) => format!("(unsupported record type {})", code),
+        };
+        println!("  {} {}", value, format!("(ttl {})", answer.ttl).dimmed());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subnet_v4_26() {
+        let s = subnet_v4("192.168.1.0".parse().unwrap(), 26).unwrap();
+        assert_eq!(s.hosts, 62);
+        assert_eq!(s.broadcast, Some("192.168.1.63".parse().unwrap()));
+        assert_eq!(s.netmask, "255.255.255.192".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(s.wildcard, "0.0.0.63".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(s.first_host, "192.168.1.1".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(s.last_host, "192.168.1.62".parse::<Ipv4Addr>().unwrap());
+    }
+
+    #[test]
+    fn test_subnet_v4_normalizes_host_bits() {
+        let s = subnet_v4("10.1.2.3".parse().unwrap(), 8).unwrap();
+        assert_eq!(s.network, "10.0.0.0".parse::<Ipv4Addr>().unwrap());
+        assert_eq!(s.broadcast, Some("10.255.255.255".parse().unwrap()));
+        assert_eq!(s.hosts, 16_777_214);
+    }
+
+    #[test]
+    fn test_subnet_v4_31_and_32() {
+        let s = subnet_v4("192.168.1.4".parse().unwrap(), 31).unwrap();
+        assert_eq!(s.hosts, 2);
+        assert_eq!(s.broadcast, None);
+        assert_eq!(s.last_host, "192.168.1.5".parse::<Ipv4Addr>().unwrap());
+
+        let s = subnet_v4("192.168.1.4".parse().unwrap(), 32).unwrap();
+        assert_eq!(s.hosts, 1);
+        assert_eq!(s.broadcast, None);
+        assert_eq!(s.first_host, s.last_host);
+
+        let s = subnet_v4("1.2.3.4".parse().unwrap(), 0).unwrap();
+        assert_eq!(s.network, Ipv4Addr::UNSPECIFIED);
+        assert!(subnet_v4(Ipv4Addr::UNSPECIFIED, 33).is_err());
+    }
+
+    #[test]
+    fn test_subnet_v6() {
+        let s = subnet_v6("2001:db8::1".parse().unwrap(), 64).unwrap();
+        assert_eq!(s.network, "2001:db8::".parse::<Ipv6Addr>().unwrap());
+        assert_eq!(
+            s.last_host,
+            "2001:db8::ffff:ffff:ffff:ffff".parse::<Ipv6Addr>().unwrap()
+        );
+        assert!(subnet_v6(Ipv6Addr::UNSPECIFIED, 129).is_err());
+    }
+
+    #[test]
+    fn test_parse_cidr() {
+        assert!(parse_cidr("192.168.1.0/24").is_ok());
+        assert!(parse_cidr("192.168.1.0").is_err());
+        assert!(parse_cidr("not-an-ip/24").is_err());
+    }
+
+    /// Build a response to `query` with the given flags and raw answers.
+    fn dns_response(query: &[u8], rcode: u8, answers: &[&[u8]]) -> Vec<u8> {
+        let mut resp = query.to_vec();
+        resp[2] = 0x81; // QR + RD
+        resp[3] = 0x80 | rcode; // RA + rcode
+        resp[6..8].copy_from_slice(&(answers.len() as u16).to_be_bytes());
+        for answer in answers {
+            resp.extend_from_slice(answer);
+        }
+        resp
+    }
+
+    #[test]
+    fn test_build_dns_query() {
+        let q = build_dns_query(0xabcd, "example.com", DnsRecordType::Mx).unwrap();
+        assert_eq!(&q[..4], &[0xab, 0xcd, 0x01, 0x00]);
+        assert_eq!(&q[12..25], b"\x07example\x03com\x00");
+        assert_eq!(&q[25..], &[0, 15, 0, 1]);
+
+        assert!(build_dns_query(1, "bad..name", DnsRecordType::A).is_err());
+        assert!(build_dns_query(1, &"a".repeat(64), DnsRecordType::A).is_err());
+    }
+
+    #[test]
+    fn test_parse_dns_a_record() {
+        let query = build_dns_query(7, "example.com", DnsRecordType::A).unwrap();
+        // Name is a compression pointer to the question (offset 12)
+        let answer: &[u8] = &[
+            0xc0, 0x0c, 0, 1, 0, 1, 0, 0, 0x0e, 0x10, 0, 4, 93, 184, 216, 34,
+        ];
+        let resp = parse_dns_response(&dns_response(&query, 0, &[answer]), 7).unwrap();
+
+        assert_eq!(resp.rcode, 0);
+        assert_eq!(
+            resp.answers,
+            vec![DnsAnswer {
+                name: "example.com".to_string(),
+                ttl: 3600,
+                data: DnsData::A(Ipv4Addr::new(93, 184, 216, 34)),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_dns_mx_and_txt() {
+        let query = build_dns_query(9, "example.com", DnsRecordType::Mx).unwrap();
+        let mx: &[u8] = &[
+            0xc0, 0x0c, 0, 15, 0, 1, 0, 0, 0, 60, 0, 9, 0, 10, 4, b'm', b'a', b'i', b'l', 0xc0,
+            0x0c,
+        ];
+        let txt: &[u8] = &[
+            0xc0, 0x0c, 0, 16, 0, 1, 0, 0, 0, 60, 0, 6, 2, b'h', b'i', 2, b'!', b'!',
+        ];
+        let resp = parse_dns_response(&dns_response(&query, 0, &[mx, txt]), 9).unwrap();
+
+        assert_eq!(
+            resp.answers[0].data,
+            DnsData::Mx {
+                preference: 10,
+                exchange: "mail.example.com".to_string()
+            }
+        );
+        assert_eq!(resp.answers[1].data, DnsData::Txt("hi!!".to_string()));
+    }
+
+    #[test]
+    fn test_parse_dns_nxdomain_and_bad_id() {
+        let query = build_dns_query(3, "nope.invalid", DnsRecordType::A).unwrap();
+        let packet = dns_response(&query, RCODE_NXDOMAIN, &[]);
+        let resp = parse_dns_response(&packet, 3).unwrap();
+        assert_eq!(resp.rcode, RCODE_NXDOMAIN);
+        assert!(resp.answers.is_empty());
+
+        assert!(parse_dns_response(&packet, 4).is_err());
+        assert!(parse_dns_response(&packet[..5], 3).is_err());
+    }
+
+    #[test]
+    fn test_parse_resolv_conf() {
+        let conf = "# generated\nsearch lan\nnameserver fe80::1%eth0\nnameserver 10.0.0.1\n";
+        assert_eq!(
+            parse_resolv_conf(conf),
+            Some("10.0.0.1:53".parse().unwrap())
+        );
+        assert_eq!(parse_resolv_conf("search lan\n"), None);
+    }
+
+    #[test]
+    fn test_parse_dns_server() {
+        assert_eq!(
+            parse_dns_server("1.1.1.1").unwrap(),
+            "1.1.1.1:53".parse().unwrap()
+        );
+        assert_eq!(
+            parse_dns_server("127.0.0.1:5353").unwrap(),
+            "127.0.0.1:5353".parse().unwrap()
+        );
+        assert!(parse_dns_server("dns.example").is_err());
+    }
+}
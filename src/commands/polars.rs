@@ -1,6 +1,7 @@
 //! Polars command - DataFrame operations and data analysis demos.
 
 use crate::cli::commands::polars::{PolarsArgs, PolarsCommand, PolarsOutputFormat};
+use crate::utils::{command_rng, write_atomic};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use dx_datagen::{
@@ -43,7 +44,6 @@ struct RandomConfig<'a> {
     min: i64,
     max: i64,
     null_prob: f64,
-    seed: Option<u64>,
     format: PolarsOutputFormat,
 }
 
@@ -84,7 +84,6 @@ pub fn run(args: PolarsArgs) -> Result<()> {
             min,
             max,
             null_prob,
-            seed,
             format,
         } => cmd_random(RandomConfig {
             output: file.as_deref(),
@@ -95,7 +94,6 @@ pub fn run(args: PolarsArgs) -> Result<()> {
             min,
             max,
             null_prob,
-            seed,
             format,
         }),
     }
@@ -439,8 +437,7 @@ impl TruncateStr for String {
 
 /// Generate random data file
 fn cmd_random(config: RandomConfig) -> Result<()> {
-    use rand::rngs::StdRng;
-    use rand::{Rng, SeedableRng};
+    use rand::Rng;
 
     // Check if user wants to list available generators
     if config.columns.len() == 1 {
@@ -453,12 +450,8 @@ fn cmd_random(config: RandomConfig) -> Result<()> {
 
     let start = Instant::now();
 
-    // Initialize RNG with optional seed
-    let mut rng: Box<dyn rand::RngCore> = if let Some(s) = config.seed {
-        Box::new(StdRng::seed_from_u64(s))
-    } else {
-        Box::new(rand::rng())
-    };
+    // Seeded by the global --seed flag when given
+    let mut rng: Box<dyn rand::RngCore> = Box::new(command_rng());
 
     // Column definition with optional parameters
     struct ColDef<'a> {
@@ -1558,22 +1551,17 @@ fn cmd_random(config: RandomConfig) -> Result<()> {
             let write_start = Instant::now();
             let format = detect_format(output_path);
             match format {
-                Format::Parquet => {
-                    let file = std::fs::File::create(output_path).with_context(|| {
-                        format!("Failed to create file: {}", output_path.display())
-                    })?;
+                Format::Parquet => write_atomic(output_path, |file| {
                     ParquetWriter::new(file)
                         .finish(&mut df)
-                        .with_context(|| "Failed to write Parquet")?;
-                }
-                Format::Csv => {
-                    let file = std::fs::File::create(output_path).with_context(|| {
-                        format!("Failed to create file: {}", output_path.display())
-                    })?;
+                        .context("Failed to write Parquet")?;
+                    Ok(())
+                })?,
+                Format::Csv => write_atomic(output_path, |file| {
                     CsvWriter::new(file)
                         .finish(&mut df)
-                        .with_context(|| "Failed to write CSV")?;
-                }
+                        .context("Failed to write CSV")
+                })?,
             }
             let write_time = write_start.elapsed();
 
@@ -9,13 +9,17 @@
 //! dx rand password 20         # 20-char password with symbols
 //! dx rand choice a b c d      # Pick one randomly
 //! dx rand dice 20             # Roll a d20
+//! dx rand category -n fruits  # Pick a fruit
+//! dx rand record -s '{name:string, age:int(18,90)}' -c 3
 //! ```
 
-use crate::cli::commands::rand::{RandArgs, RandCommand};
-use anyhow::Result;
-use dx_datagen::{generators, password};
+use crate::cli::commands::rand::{RandArgs, RandCommand, RecordFormat};
+use crate::utils::{command_rng, Table};
+use anyhow::{bail, Context, Result};
+use dx_datagen::{categories, generators, password, personal};
 use rand::prelude::{IndexedRandom, SliceRandom};
 use rand::Rng;
+use serde_json::{Map, Value};
 
 pub fn run(args: RandArgs) -> Result<()> {
     match args.command {
@@ -32,11 +36,17 @@ pub fn run(args: RandArgs) -> Result<()> {
         RandCommand::Shuffle { items } => cmd_shuffle(items),
         RandCommand::Coin { count } => cmd_coin(count),
         RandCommand::Dice { sides, count } => cmd_dice(sides, count),
+        RandCommand::Category { name, count } => cmd_category(&name, count),
+        RandCommand::Record {
+            schema,
+            count,
+            format,
+        } => cmd_record(&schema, count, format),
     }
 }
 
 fn cmd_int(min: i64, max: i64, count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         println!("{}", rng.random_range(min..=max));
     }
@@ -44,7 +54,7 @@ fn cmd_int(min: i64, max: i64, count: usize) -> Result<()> {
 }
 
 fn cmd_float(min: f64, max: f64, count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         let val: f64 = rng.random_range(min..max);
         println!("{:.6}", val);
@@ -53,7 +63,7 @@ fn cmd_float(min: f64, max: f64, count: usize) -> Result<()> {
 }
 
 fn cmd_string(length: usize, count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         println!("{}", generators::alphanumeric(&mut rng, length));
     }
@@ -61,7 +71,7 @@ fn cmd_string(length: usize, count: usize) -> Result<()> {
 }
 
 fn cmd_hex(bytes: usize, count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         println!("{}", generators::hex_bytes(&mut rng, bytes));
     }
@@ -69,7 +79,7 @@ fn cmd_hex(bytes: usize, count: usize) -> Result<()> {
 }
 
 fn cmd_password(length: usize, no_symbols: bool, count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         println!("{}", password::password(&mut rng, length, !no_symbols));
     }
@@ -77,7 +87,7 @@ fn cmd_password(length: usize, no_symbols: bool, count: usize) -> Result<()> {
 }
 
 fn cmd_choice(items: &[String]) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     if let Some(choice) = items.choose(&mut rng) {
         println!("{}", choice);
     }
@@ -85,7 +95,7 @@ fn cmd_choice(items: &[String]) -> Result<()> {
 }
 
 fn cmd_shuffle(mut items: Vec<String>) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     items.shuffle(&mut rng);
     for item in items {
         println!("{}", item);
@@ -94,7 +104,7 @@ fn cmd_shuffle(mut items: Vec<String>) -> Result<()> {
 }
 
 fn cmd_coin(count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         println!(
             "{}",
@@ -109,9 +119,346 @@ fn cmd_coin(count: usize) -> Result<()> {
 }
 
 fn cmd_dice(sides: u32, count: usize) -> Result<()> {
-    let mut rng = rand::rng();
+    let mut rng = command_rng();
     for _ in 0..count {
         println!("{}", rng.random_range(1..=sides));
     }
     Ok(())
 }
+
+fn cmd_category(name: &str, count: usize) -> Result<()> {
+    let Some(values) = categories::get(name) else {
+        bail!("Unknown category: {}", name);
+    };
+    let mut rng = command_rng();
+    for _ in 0..count {
+        println!("{}", generators::pick_one(&mut rng, values));
+    }
+    Ok(())
+}
+
+fn cmd_record(schema: &str, count: usize, format: RecordFormat) -> Result<()> {
+    let fields = parse_schema(schema)?;
+    let mut rng = command_rng();
+    let records: Vec<Value> = (0..count)
+        .map(|_| generate_record(&mut rng, &fields))
+        .collect();
+    let color = colored::control::SHOULD_COLORIZE.should_colorize();
+    print!("{}", format_records(&fields, &records, format, color)?);
+    Ok(())
+}
+
+/// Render generated records. CSV and table columns follow the schema order.
+fn format_records(
+    fields: &[(String, FieldType)],
+    records: &[Value],
+    format: RecordFormat,
+    color: bool,
+) -> Result<String> {
+    let headers: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+    let rows: Vec<Vec<String>> = records
+        .iter()
+        .map(|record| {
+            headers
+                .iter()
+                .map(|name| match &record[*name] {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect()
+        })
+        .collect();
+
+    let output = match format {
+        RecordFormat::Ndjson => {
+            let mut out = String::new();
+            for record in records {
+                out.push_str(&serde_json::to_string(record)?);
+                out.push('\n');
+            }
+            out
+        }
+        RecordFormat::Json => format!("{}\n", serde_json::to_string_pretty(records)?),
+        RecordFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(Vec::new());
+            writer.write_record(&headers)?;
+            for row in &rows {
+                writer.write_record(row)?;
+            }
+            String::from_utf8(writer.into_inner()?)?
+        }
+        RecordFormat::Table => {
+            let mut table = Table::new(headers);
+            for row in rows {
+                table.push_row(row);
+            }
+            table.render(color)
+        }
+    };
+    Ok(output)
+}
+
+/// A field type in a shorthand record schema.
+#[derive(Debug, Clone, PartialEq)]
+enum FieldType {
+    String(usize),
+    Int(i64, i64),
+    Float(f64, f64),
+    Bool,
+    Name,
+    FirstName,
+    LastName,
+    Email,
+    Uuid,
+    Choice(Vec<String>),
+}
+
+/// Parse a shorthand schema like `{name:string, age:int(18,90)}`.
+///
+/// The surrounding braces are optional. Fields keep their declared order.
+fn parse_schema(schema: &str) -> Result<Vec<(String, FieldType)>> {
+    let mut body = schema.trim();
+    if let Some(inner) = body.strip_prefix('{') {
+        body = inner
+            .strip_suffix('}')
+            .context("Schema is missing its closing '}'")?;
+    }
+
+    let mut fields: Vec<(String, FieldType)> = Vec::new();
+    for entry in split_top_level(body) {
+        let entry = entry.trim();
+        if entry.is_empty() {
+            continue;
+        }
+        let (name, ty) = entry
+            .split_once(':')
+            .with_context(|| format!("Expected 'name:type' in schema, got '{}'", entry))?;
+        let name = name.trim();
+        if name.is_empty() {
+            bail!("Missing field name before ':{}'", ty.trim());
+        }
+        if fields.iter().any(|(existing, _)| existing == name) {
+            bail!("Duplicate field '{}' in schema", name);
+        }
+        let ty = parse_field_type(ty.trim())
+            .with_context(|| format!("Invalid type for field '{}'", name))?;
+        fields.push((name.to_string(), ty));
+    }
+
+    if fields.is_empty() {
+        bail!("Schema has no fields");
+    }
+    Ok(fields)
+}
+
+/// Split on commas that are not inside parentheses.
+fn split_top_level(input: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+fn parse_field_type(spec: &str) -> Result<FieldType> {
+    let (name, args) = match spec.split_once('(') {
+        Some((name, rest)) => {
+            let args = rest
+                .strip_suffix(')')
+                .with_context(|| format!("Missing ')' in '{}'", spec))?;
+            let args: Vec<&str> = args.split(',').map(str::trim).collect();
+            (name.trim(), Some(args))
+        }
+        None => (spec, None),
+    };
+
+    let ty = match (name, args.as_deref()) {
+        ("string", None) => FieldType::String(10),
+        ("string", Some([len])) => FieldType::String(len.parse().context("Invalid length")?),
+        ("int", None) => FieldType::Int(0, 100),
+        ("int", Some([min, max])) => {
+            let min: i64 = min.parse().context("Invalid minimum")?;
+            let max: i64 = max.parse().context("Invalid maximum")?;
+            if min > max {
+                bail!("Minimum {} is greater than maximum {}", min, max);
+            }
+            FieldType::Int(min, max)
+        }
+        ("float", None) => FieldType::Float(0.0, 1.0),
+        ("float", Some([min, max])) => {
+            let min: f64 = min.parse().context("Invalid minimum")?;
+            let max: f64 = max.parse().context("Invalid maximum")?;
+            if min >= max {
+                bail!("Minimum {} must be less than maximum {}", min, max);
+            }
+            FieldType::Float(min, max)
+        }
+        ("bool", None) => FieldType::Bool,
+        ("name", None) => FieldType::Name,
+        ("first_name", None) => FieldType::FirstName,
+        ("last_name", None) => FieldType::LastName,
+        ("email", None) => FieldType::Email,
+        ("uuid", None) => FieldType::Uuid,
+        ("choice", Some(items)) if items.iter().all(|item| !item.is_empty()) => {
+            FieldType::Choice(items.iter().map(|item| item.to_string()).collect())
+        }
+        (
+            "string" | "int" | "float" | "bool" | "name" | "first_name" | "last_name" | "email"
+            | "uuid" | "choice",
+            _,
+        ) => bail!("Wrong arguments in '{}'", spec),
+        _ => bail!(
+            "Unknown type '{}' (expected string, int, float, bool, name, first_name, \
+             last_name, email, uuid, or choice)",
+            name
+        ),
+    };
+    Ok(ty)
+}
+
+fn generate_record<R: Rng>(rng: &mut R, fields: &[(String, FieldType)]) -> Value {
+    let mut record = Map::new();
+    for (name, ty) in fields {
+        let value = match ty {
+            FieldType::String(len) => Value::from(generators::alphanumeric(rng, *len)),
+            FieldType::Int(min, max) => Value::from(rng.random_range(*min..=*max)),
+            FieldType::Float(min, max) => Value::from(rng.random_range(*min..*max)),
+            FieldType::Bool => Value::from(rng.random_bool(0.5)),
+            FieldType::Name => Value::from(personal::full_name(rng)),
+            FieldType::FirstName => Value::from(personal::first_name(rng)),
+            FieldType::LastName => Value::from(personal::last_name(rng)),
+            FieldType::Email => Value::from(personal::email(rng)),
+            FieldType::Uuid => Value::from(
+                uuid::Builder::from_random_bytes(rng.random())
+                    .into_uuid()
+                    .to_string(),
+            ),
+            FieldType::Choice(items) => {
+                Value::from(items[rng.random_range(0..items.len())].clone())
+            }
+        };
+        record.insert(name.clone(), value);
+    }
+    Value::Object(record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_parse_schema() {
+        let fields = parse_schema("{name:string, age:int(18,90)}").unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                ("name".to_string(), FieldType::String(10)),
+                ("age".to_string(), FieldType::Int(18, 90)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_schema_without_braces() {
+        let fields = parse_schema("id:uuid, tag:choice(a, b)").unwrap();
+        assert_eq!(fields[0], ("id".to_string(), FieldType::Uuid));
+        assert_eq!(
+            fields[1],
+            (
+                "tag".to_string(),
+                FieldType::Choice(vec!["a".to_string(), "b".to_string()])
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_schema_errors() {
+        assert!(parse_schema("{}").is_err());
+        assert!(parse_schema("{name}").is_err());
+        assert!(parse_schema("{name:strng}").is_err());
+        assert!(parse_schema("{age:int(90,18)}").is_err());
+        assert!(parse_schema("{age:int(1)}").is_err());
+        assert!(parse_schema("{a:int, a:bool}").is_err());
+        assert!(parse_schema("{a:int").is_err());
+    }
+
+    #[test]
+    fn test_generate_record_respects_schema() {
+        let fields = parse_schema("{name:string(5), age:int(18,90), ok:bool}").unwrap();
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..50 {
+            let record = generate_record(&mut rng, &fields);
+            let keys: Vec<&String> = record.as_object().unwrap().keys().collect();
+            assert_eq!(keys, ["name", "age", "ok"]);
+            assert_eq!(record["name"].as_str().unwrap().len(), 5);
+            let age = record["age"].as_i64().unwrap();
+            assert!((18..=90).contains(&age));
+            assert!(record["ok"].is_boolean());
+        }
+    }
+
+    fn sample() -> (Vec<(String, FieldType)>, Vec<Value>) {
+        let fields = parse_schema("{name:string, age:int}").unwrap();
+        let records = vec![
+            serde_json::json!({"name": "Ada", "age": 36}),
+            serde_json::json!({"name": "Grace, Hopper", "age": 85}),
+        ];
+        (fields, records)
+    }
+
+    #[test]
+    fn test_format_records_ndjson() {
+        let (fields, records) = sample();
+        let out = format_records(&fields, &records, RecordFormat::Ndjson, false).unwrap();
+        assert_eq!(
+            out,
+            "{\"name\":\"Ada\",\"age\":36}\n{\"name\":\"Grace, Hopper\",\"age\":85}\n"
+        );
+    }
+
+    #[test]
+    fn test_format_records_json_array() {
+        let (fields, records) = sample();
+        let out = format_records(&fields, &records, RecordFormat::Json, false).unwrap();
+        let parsed: Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(parsed, Value::Array(records));
+    }
+
+    #[test]
+    fn test_format_records_csv() {
+        let (fields, records) = sample();
+        let out = format_records(&fields, &records, RecordFormat::Csv, false).unwrap();
+        assert_eq!(out, "name,age\nAda,36\n\"Grace, Hopper\",85\n");
+    }
+
+    #[test]
+    fn test_format_records_table() {
+        let (fields, records) = sample();
+        let out = format_records(&fields, &records, RecordFormat::Table, false).unwrap();
+        assert_eq!(
+            out,
+            "name          | age\n--------------+----\nAda           | 36\nGrace, Hopper | 85\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_record_seeded_is_reproducible() {
+        let fields = parse_schema("{name:name, email:email, id:uuid}").unwrap();
+        let a = generate_record(&mut StdRng::seed_from_u64(42), &fields);
+        let b = generate_record(&mut StdRng::seed_from_u64(42), &fields);
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,212 @@
+//! # Regex Command
+//!
+//! Test regular expressions, extract groups and replace matches.
+//!
+//! ## Examples
+//! ```bash
+//! dx regex match '\d+' "a1 b22 c333"                  # 1, 22, 333
+//! dx regex captures '(?P<key>\w+)=(\d+)' "x=1 y=2"    # groups per match
+//! dx regex replace '(\w+)@(\w+)' '$2 at $1' "me@host" # host at me
+//! echo "2024-01-05" | dx regex replace '(\d+)-(\d+)-(\d+)' '$3.$2.$1'
+//! ```
+//!
+//! Patterns use the syntax of the `regex` crate, the same engine as `dx grep`.
+
+use crate::cli::commands::regex::{RegexArgs, RegexCommand};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use regex::{Regex, RegexBuilder};
+use std::borrow::Cow;
+use std::io::{self, Read};
+
+pub fn run(args: RegexArgs) -> Result<()> {
+    match args.command {
+        RegexCommand::Match {
+            pattern,
+            input,
+            ignore_case,
+        } => cmd_match(&build(&pattern, ignore_case)?, &get_text(input)?),
+        RegexCommand::Captures {
+            pattern,
+            input,
+            ignore_case,
+        } => cmd_captures(&build(&pattern, ignore_case)?, &get_text(input)?),
+        RegexCommand::Replace {
+            pattern,
+            replacement,
+            input,
+            ignore_case,
+            first,
+        } => {
+            let regex = build(&pattern, ignore_case)?;
+            println!(
+                "{}",
+                replace(&regex, &get_text(input)?, &replacement, !first)
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Compile a pattern
+fn build(pattern: &str, ignore_case: bool) -> Result<Regex> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(ignore_case)
+        .build()
+        .context("Invalid regex pattern")
+}
+
+/// Get text from argument or stdin
+fn get_text(text: Option<String>) -> Result<String> {
+    match text {
+        Some(t) => Ok(t),
+        None => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input.trim_end().to_string())
+        }
+    }
+}
+
+fn cmd_match(regex: &Regex, text: &str) -> Result<()> {
+    let mut found = false;
+    for m in regex.find_iter(text) {
+        println!("{}", m.as_str());
+        found = true;
+    }
+    if !found {
+        // No match, exit with non-zero status like grep
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn cmd_captures(regex: &Regex, text: &str) -> Result<()> {
+    let matches = captures(regex, text);
+    if matches.is_empty() {
+        std::process::exit(1);
+    }
+
+    for (i, groups) in matches.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        for group in groups {
+            let label = match group.name {
+                Some(name) => format!("{} {}", group.index, name),
+                None => group.index.to_string(),
+            };
+            let value = match group.value {
+                Some(value) => value.normal(),
+                None => "(no match)".dimmed(),
+            };
+            println!("{}: {}", label.cyan(), value);
+        }
+    }
+    Ok(())
+}
+
+/// One capture group of a match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Group<'a> {
+    /// Group number; 0 is the whole match
+    index: usize,
+    name: Option<&'a str>,
+    /// `None` when an optional group did not participate
+    value: Option<&'a str>,
+}
+
+/// The groups of every match, whole match first.
+fn captures<'a>(regex: &'a Regex, text: &'a str) -> Vec<Vec<Group<'a>>> {
+    let names: Vec<Option<&str>> = regex.capture_names().collect();
+    regex
+        .captures_iter(text)
+        .map(|caps| {
+            names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| Group {
+                    index,
+                    name: *name,
+                    value: caps.get(index).map(|m| m.as_str()),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Replace all matches, or only the first, expanding `$1` / `${name}`.
+fn replace<'a>(regex: &Regex, text: &'a str, replacement: &str, all: bool) -> Cow<'a, str> {
+    if all {
+        regex.replace_all(text, replacement)
+    } else {
+        regex.replace(text, replacement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_captures_numbered_and_named_groups() {
+        let regex = build(r"(?P<key>\w+)=(\d+)(%)?", false).unwrap();
+        let matches = captures(&regex, "x=1 y=20%");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(
+            matches[1],
+            vec![
+                Group {
+                    index: 0,
+                    name: None,
+                    value: Some("y=20%")
+                },
+                Group {
+                    index: 1,
+                    name: Some("key"),
+                    value: Some("y")
+                },
+                Group {
+                    index: 2,
+                    name: None,
+                    value: Some("20")
+                },
+                Group {
+                    index: 3,
+                    name: None,
+                    value: Some("%")
+                },
+            ]
+        );
+        // The optional group did not take part in the first match
+        assert_eq!(matches[0][3].value, None);
+        assert!(captures(&regex, "no pairs").is_empty());
+    }
+
+    #[test]
+    fn test_replace_with_backreferences() {
+        let regex = build(r"(\d+)-(\d+)-(\d+)", false).unwrap();
+        assert_eq!(
+            replace(&regex, "2024-01-05 and 2023-12-31", "$3.$2.$1", true),
+            "05.01.2024 and 31.12.2023"
+        );
+        assert_eq!(
+            replace(&regex, "2024-01-05 and 2023-12-31", "${3}x", false),
+            "05x and 2023-12-31"
+        );
+
+        let named = build(r"(?P<user>\w+)@(?P<host>\w+)", false).unwrap();
+        assert_eq!(
+            replace(&named, "me@box", "$host: $$$user", true),
+            "box: $me"
+        );
+    }
+
+    #[test]
+    fn test_build() {
+        assert!(build("HELLO", true).unwrap().is_match("hello"));
+        assert!(!build("HELLO", false).unwrap().is_match("hello"));
+        let err = build("(unclosed", false).unwrap_err();
+        assert!(err.to_string().contains("Invalid regex pattern"));
+    }
+}
@@ -3,7 +3,7 @@
 use crate::cli::commands::system::{SystemArgs, SystemCommand};
 use anyhow::Result;
 use colored::Colorize;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use sysinfo::{CpuRefreshKind, Disks, MemoryRefreshKind, RefreshKind, System};
 
 pub fn run(args: SystemArgs) -> Result<()> {
@@ -12,7 +12,11 @@ pub fn run(args: SystemArgs) -> Result<()> {
     }
 }
 
-#[derive(Serialize)]
+/// Machine-readable system snapshot emitted by `system info --json`.
+///
+/// Field names are part of the JSON contract; add fields rather than
+/// renaming or removing them.
+#[derive(Debug, Serialize, Deserialize)]
 struct SystemInfo {
     os_name: String,
     os_version: String,
@@ -28,7 +32,7 @@ struct SystemInfo {
     disks: Vec<DiskInfo>,
 }
 
-#[derive(Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct DiskInfo {
     mount_point: String,
     total_bytes: u64,
@@ -37,6 +41,19 @@ struct DiskInfo {
 }
 
 fn cmd_info(json_output: bool) -> Result<()> {
+    let info = collect_info();
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        print_info(&info);
+    }
+
+    Ok(())
+}
+
+/// Gather a snapshot of the current machine.
+fn collect_info() -> SystemInfo {
     let sys = System::new_with_specifics(
         RefreshKind::nothing()
             .with_cpu(CpuRefreshKind::everything())
@@ -44,24 +61,13 @@ fn cmd_info(json_output: bool) -> Result<()> {
     );
 
     let disks = Disks::new_with_refreshed_list();
-
-    let os_name = System::name().unwrap_or_else(|| "Unknown".to_string());
-    let os_version = System::os_version().unwrap_or_else(|| "Unknown".to_string());
-    let kernel_version = System::kernel_version().unwrap_or_else(|| "Unknown".to_string());
-    let hostname = System::host_name().unwrap_or_else(|| "Unknown".to_string());
-    let uptime = System::uptime();
+    let unknown = || "Unknown".to_string();
 
     let cpu_name = sys
         .cpus()
         .first()
         .map(|c| c.brand().to_string())
-        .unwrap_or_else(|| "Unknown".to_string());
-    let cpu_cores = sys.cpus().len();
-
-    let memory_total = sys.total_memory();
-    let memory_used = sys.used_memory();
-    let swap_total = sys.total_swap();
-    let swap_used = sys.used_swap();
+        .unwrap_or_else(unknown);
 
     let disk_infos: Vec<DiskInfo> = disks
         .iter()
@@ -83,28 +89,20 @@ fn cmd_info(json_output: bool) -> Result<()> {
         })
         .collect();
 
-    let info = SystemInfo {
-        os_name,
-        os_version,
-        kernel_version,
-        hostname,
-        uptime_seconds: uptime,
+    SystemInfo {
+        os_name: System::name().unwrap_or_else(unknown),
+        os_version: System::os_version().unwrap_or_else(unknown),
+        kernel_version: System::kernel_version().unwrap_or_else(unknown),
+        hostname: System::host_name().unwrap_or_else(unknown),
+        uptime_seconds: System::uptime(),
         cpu_name,
-        cpu_cores,
-        memory_total_bytes: memory_total,
-        memory_used_bytes: memory_used,
-        swap_total_bytes: swap_total,
-        swap_used_bytes: swap_used,
+        cpu_cores: sys.cpus().len(),
+        memory_total_bytes: sys.total_memory(),
+        memory_used_bytes: sys.used_memory(),
+        swap_total_bytes: sys.total_swap(),
+        swap_used_bytes: sys.used_swap(),
         disks: disk_infos,
-    };
-
-    if json_output {
-        println!("{}", serde_json::to_string_pretty(&info)?);
-    } else {
-        print_info(&info);
     }
-
-    Ok(())
 }
 
 fn print_info(info: &SystemInfo) {
@@ -204,3 +202,53 @@ fn format_uptime(seconds: u64) -> String {
         format!("{} minutes", minutes)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let json = serde_json::to_string(&collect_info()).unwrap();
+        let info: SystemInfo = serde_json::from_str(&json).unwrap();
+        assert!(!info.os_name.is_empty());
+        assert!(!info.hostname.is_empty());
+        assert!(info.cpu_cores > 0);
+        assert!(info.memory_total_bytes > 0);
+    }
+
+    #[test]
+    fn test_json_keys_are_stable() {
+        let value = serde_json::to_value(collect_info()).unwrap();
+        let keys: Vec<&str> = value
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            keys,
+            [
+                "os_name",
+                "os_version",
+                "kernel_version",
+                "hostname",
+                "uptime_seconds",
+                "cpu_name",
+                "cpu_cores",
+                "memory_total_bytes",
+                "memory_used_bytes",
+                "swap_total_bytes",
+                "swap_used_bytes",
+                "disks",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_uptime() {
+        assert_eq!(format_uptime(59), "0 minutes");
+        assert_eq!(format_uptime(3_660), "1 hours, 1 minutes");
+        assert_eq!(format_uptime(90_000), "1 days, 1 hours, 0 minutes");
+    }
+}
@@ -1,49 +1,51 @@
 //! Template command - Jinja2-style templating.
+//!
+//! Templates are rendered with Tera, which provides the `{% if %}` and
+//! `{% for %}` blocks. Data comes from JSON or YAML; variables missing from
+//! the data are an error unless `--allow-missing` is given, in which case
+//! they render as empty strings.
 
 use crate::cli::commands::template::{TemplateArgs, TemplateCommand};
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use serde_json::{Map, Value};
 use std::fs;
+use std::path::Path;
+use tera::ast::{Expr, ExprVal, Node};
 use tera::Tera;
 
+/// Name the template is registered under.
+const TEMPLATE_NAME: &str = "template";
+
 /// Run the template command
 pub fn run(args: TemplateArgs) -> Result<()> {
     match args.command {
         TemplateCommand::Render {
             template,
+            template_file,
             data,
             json,
+            allow_missing,
         } => {
+            let template = template
+                .or(template_file)
+                .context("A template file is required")?;
             let template_content = fs::read_to_string(&template)
                 .with_context(|| format!("Failed to read template: {}", template.display()))?;
 
             // Get context data
-            let context_data: serde_json::Value = if let Some(json_str) = json {
+            let context_data = if let Some(json_str) = json {
                 serde_json::from_str(&json_str).context("Failed to parse JSON data")?
             } else if let Some(data_file) = data {
-                let data_content = fs::read_to_string(&data_file).with_context(|| {
-                    format!("Failed to read data file: {}", data_file.display())
-                })?;
-                serde_json::from_str(&data_content).context("Failed to parse data file as JSON")?
+                read_data(&data_file)?
             } else {
-                serde_json::Value::Object(serde_json::Map::new())
+                Value::Object(Map::new())
             };
 
-            // Create Tera instance and add template
-            let mut tera = Tera::default();
-            tera.add_raw_template("template", &template_content)
-                .context("Failed to parse template")?;
-
-            // Convert JSON to Tera context
-            let context = tera::Context::from_value(context_data)
-                .map_err(|e| anyhow::anyhow!("Failed to create context: {}", e))?;
-
-            // Render
-            let result = tera
-                .render("template", &context)
-                .context("Failed to render template")?;
-
-            print!("{}", result);
+            print!(
+                "{}",
+                render(&template_content, context_data, allow_missing)?
+            );
             Ok(())
         }
         TemplateCommand::Validate { template } => {
@@ -51,7 +53,7 @@ pub fn run(args: TemplateArgs) -> Result<()> {
                 .with_context(|| format!("Failed to read template: {}", template.display()))?;
 
             let mut tera = Tera::default();
-            match tera.add_raw_template("template", &template_content) {
+            match tera.add_raw_template(TEMPLATE_NAME, &template_content) {
                 Ok(_) => {
                     println!("{}", "Template is valid".green());
                     Ok(())
@@ -64,3 +66,221 @@ pub fn run(args: TemplateArgs) -> Result<()> {
         }
     }
 }
+
+/// Read template variables from a JSON file, or YAML for `.yaml`/`.yml`.
+fn read_data(path: &Path) -> Result<Value> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read data file: {}", path.display()))?;
+    let is_yaml = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+
+    if is_yaml {
+        serde_saphyr::from_str(&content).context("Failed to parse data file as YAML")
+    } else {
+        serde_json::from_str(&content).context("Failed to parse data file as JSON")
+    }
+}
+
+/// Render `template` with `data` as its context.
+fn render(template: &str, mut data: Value, allow_missing: bool) -> Result<String> {
+    if !data.is_object() {
+        bail!("Template data must be a JSON/YAML object at the top level");
+    }
+
+    let mut tera = Tera::default();
+    tera.add_raw_template(TEMPLATE_NAME, template)
+        .context("Failed to parse template")?;
+
+    if allow_missing {
+        let ast = &tera.get_template(TEMPLATE_NAME)?.ast;
+        fill_missing(ast, &mut data, &mut Vec::new());
+    }
+
+    let context = tera::Context::from_value(data)
+        .map_err(|e| anyhow::anyhow!("Failed to create context: {}", e))?;
+
+    tera.render(TEMPLATE_NAME, &context)
+        .context("Failed to render template")
+}
+
+/// Give every variable the template reads, but `data` lacks, an empty
+/// string, skipping names bound inside the template (loop variables,
+/// `set`) and variables guarded by `is defined` or a `default` filter.
+fn fill_missing(nodes: &[Node], data: &mut Value, locals: &mut Vec<String>) {
+    let bound = locals.len();
+    for node in nodes {
+        match node {
+            Node::VariableBlock(_, expr) => fill_expr(expr, data, locals),
+            Node::Set(_, set) => {
+                fill_expr(&set.value, data, locals);
+                locals.push(set.key.clone());
+            }
+            Node::FilterSection(_, section, _) => {
+                for arg in section.filter.args.values() {
+                    fill_expr(arg, data, locals);
+                }
+                fill_missing(&section.body, data, locals);
+            }
+            Node::Block(_, block, _) => fill_missing(&block.body, data, locals),
+            Node::Forloop(_, forloop, _) => {
+                fill_expr(&forloop.container, data, locals);
+                let outer = locals.len();
+                locals.extend(forloop.key.iter().cloned());
+                locals.push(forloop.value.clone());
+                locals.push("loop".to_string());
+                fill_missing(&forloop.body, data, locals);
+                locals.truncate(outer);
+                if let Some(empty) = &forloop.empty_body {
+                    fill_missing(empty, data, locals);
+                }
+            }
+            Node::If(branches, _) => {
+                for (_, condition, body) in &branches.conditions {
+                    fill_expr(condition, data, locals);
+                    fill_missing(body, data, locals);
+                }
+                if let Some((_, body)) = &branches.otherwise {
+                    fill_missing(body, data, locals);
+                }
+            }
+            _ => {}
+        }
+    }
+    locals.truncate(bound);
+}
+
+fn fill_expr(expr: &Expr, data: &mut Value, locals: &[String]) {
+    // `default` only applies to undefined values, so leave those alone
+    let has_default = expr.filters.first().is_some_and(|f| f.name == "default");
+    for filter in &expr.filters {
+        for arg in filter.args.values() {
+            fill_expr(arg, data, locals);
+        }
+    }
+    if !has_default {
+        fill_value(&expr.val, data, locals);
+    }
+}
+
+fn fill_value(val: &ExprVal, data: &mut Value, locals: &[String]) {
+    match val {
+        ExprVal::Ident(name) => fill_ident(name, data, locals),
+        ExprVal::Math(math) => {
+            fill_expr(&math.lhs, data, locals);
+            fill_expr(&math.rhs, data, locals);
+        }
+        ExprVal::Logic(logic) => {
+            fill_expr(&logic.lhs, data, locals);
+            fill_expr(&logic.rhs, data, locals);
+        }
+        ExprVal::In(inside) => {
+            fill_expr(&inside.lhs, data, locals);
+            fill_expr(&inside.rhs, data, locals);
+        }
+        ExprVal::FunctionCall(call) => {
+            for arg in call.args.values() {
+                fill_expr(arg, data, locals);
+            }
+        }
+        ExprVal::Array(items) => {
+            for item in items {
+                fill_expr(item, data, locals);
+            }
+        }
+        ExprVal::StringConcat(concat) => {
+            for value in &concat.values {
+                fill_value(value, data, locals);
+            }
+        }
+        // Tests such as `is defined` must still see missing values
+        _ => {}
+    }
+}
+
+/// Insert an empty string at a dotted path unless something is already
+/// there. Index syntax (`a[0]`, `a["b"]`) is left to Tera.
+fn fill_ident(name: &str, data: &mut Value, locals: &[String]) {
+    if name.contains('[') || name.starts_with("__tera") {
+        return;
+    }
+    let mut segments = name.split('.').peekable();
+    let Some(first) = segments.peek() else {
+        return;
+    };
+    if locals.iter().any(|local| local == first) {
+        return;
+    }
+
+    let mut current = data;
+    while let Some(segment) = segments.next() {
+        let Value::Object(map) = current else {
+            return;
+        };
+        current = if segments.peek().is_some() {
+            map.entry(segment)
+                .or_insert_with(|| Value::Object(Map::new()))
+        } else {
+            map.entry(segment)
+                .or_insert_with(|| Value::String(String::new()))
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_loop_over_array() {
+        let template = "{% for user in users %}{{ loop.index }}. {{ user.name }}\
+                        {% if user.admin %} (admin){% endif %}\n{% endfor %}";
+        let data = json!({ "users": [
+            { "name": "Alice", "admin": true },
+            { "name": "Bob", "admin": false },
+        ]});
+        assert_eq!(
+            render(template, data, false).unwrap(),
+            "1. Alice (admin)\n2. Bob\n"
+        );
+    }
+
+    #[test]
+    fn test_missing_variable_errors() {
+        let err = render("Hi {{ name }}", json!({}), false).unwrap_err();
+        assert!(format!("{:#}", err).contains("name"));
+    }
+
+    #[test]
+    fn test_allow_missing() {
+        let template = "[{{ name }}][{{ user.email }}][{{ title | default(value=\"n/a\") }}]\
+                        {% if flag is defined %}set{% endif %}\
+                        {% for x in items %}{{ x }}{% endfor %}{% set y = 1 %}{{ y }}";
+        let data = json!({ "user": { "id": 7 }, "items": [1, 2] });
+        assert_eq!(render(template, data, true).unwrap(), "[][][n/a]121");
+    }
+
+    #[test]
+    fn test_allow_missing_keeps_existing_values() {
+        let data = json!({ "name": "Ada", "user": "plain" });
+        assert_eq!(
+            render("{{ name }} {{ user }}", data, true).unwrap(),
+            "Ada plain"
+        );
+    }
+
+    #[test]
+    fn test_data_must_be_object() {
+        assert!(render("x", json!([1]), false).is_err());
+    }
+
+    #[test]
+    fn test_read_yaml_data() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("data.yml");
+        std::fs::write(&path, "items:\n  - a\n  - b\n").unwrap();
+        assert_eq!(read_data(&path).unwrap(), json!({ "items": ["a", "b"] }));
+    }
+}
@@ -7,16 +7,19 @@
 //! dx text upper "hello world"     # HELLO WORLD
 //! dx text snake "HelloWorld"      # hello_world
 //! dx text slug "Hello World!"     # hello-world
+//! dx text case --to kebab "helloWorld_example"  # hello-world-example
 //! dx text lorem 3                 # 3 paragraphs of lorem ipsum
 //! echo "hello" | dx text upper    # HELLO (from stdin)
 //! ```
 
-use crate::cli::commands::text::{TextArgs, TextCommand};
-use anyhow::Result;
+use crate::cli::commands::text::{CaseStyle, TextArgs, TextCommand};
+use anyhow::{Context, Result};
 use heck::{
     ToKebabCase, ToLowerCamelCase, ToPascalCase, ToShoutySnakeCase, ToSnakeCase, ToTitleCase,
 };
 use std::io::{self, Read};
+use std::path::Path;
+use unicode_segmentation::UnicodeSegmentation;
 
 pub fn run(args: TextArgs) -> Result<()> {
     match args.command {
@@ -28,9 +31,10 @@ pub fn run(args: TextArgs) -> Result<()> {
         TextCommand::Pascal { text } => cmd_transform(text, |s| s.to_pascal_case()),
         TextCommand::Kebab { text } => cmd_transform(text, |s| s.to_kebab_case()),
         TextCommand::Scream { text } => cmd_transform(text, |s| s.to_shouty_snake_case()),
+        TextCommand::Case { to, text } => cmd_transform(text, |s| convert_case(s, to)),
         TextCommand::Slug { text } => cmd_slug(text),
         TextCommand::Reverse { text } => cmd_transform(text, |s| s.chars().rev().collect()),
-        TextCommand::Count { text } => cmd_count(text),
+        TextCommand::Count { input, string } => cmd_count(input.as_deref(), string),
         TextCommand::Lorem { paragraphs, words } => cmd_lorem(paragraphs, words),
         TextCommand::Repeat {
             text,
@@ -63,6 +67,71 @@ where
     Ok(())
 }
 
+/// Split text into words on spaces, underscores, hyphens, and other
+/// punctuation, as well as on case boundaries (`helloWorld`, `HTTPServer`).
+fn split_words(input: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for chunk in input.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<char> = chunk.chars().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let prev = chars[i - 1];
+            let cur = chars[i];
+            let next_is_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+            // "helloWorld" -> hello|World, "HTTPServer" -> HTTP|Server
+            let boundary = (prev.is_lowercase() || prev.is_numeric()) && cur.is_uppercase()
+                || prev.is_uppercase() && cur.is_uppercase() && next_is_lower;
+            if boundary {
+                words.push(chars[start..i].iter().collect());
+                start = i;
+            }
+        }
+        if start < chars.len() {
+            words.push(chars[start..].iter().collect());
+        }
+    }
+    words
+}
+
+/// Uppercase the first character and lowercase the rest
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+/// Convert text to the given case style
+fn convert_case(input: &str, style: CaseStyle) -> String {
+    let words = split_words(input);
+    let lower = || words.iter().map(|w| w.to_lowercase());
+    match style {
+        CaseStyle::Snake => lower().collect::<Vec<_>>().join("_"),
+        CaseStyle::Kebab => lower().collect::<Vec<_>>().join("-"),
+        CaseStyle::Camel => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| {
+                if i == 0 {
+                    w.to_lowercase()
+                } else {
+                    capitalize(w)
+                }
+            })
+            .collect(),
+        CaseStyle::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+        CaseStyle::Title => words
+            .iter()
+            .map(|w| capitalize(w))
+            .collect::<Vec<_>>()
+            .join(" "),
+    }
+}
+
 /// Create URL-safe slug
 fn cmd_slug(text: Option<String>) -> Result<()> {
     let input = get_text(text)?;
@@ -78,16 +147,52 @@ fn cmd_slug(text: Option<String>) -> Result<()> {
     Ok(())
 }
 
-/// Count characters, words, and lines
-fn cmd_count(text: Option<String>) -> Result<()> {
-    let input = get_text(text)?;
-    let chars = input.chars().count();
-    let words = input.split_whitespace().count();
-    let lines = input.lines().count();
+/// Line, word, character, and byte counts for a piece of text
+#[derive(Debug, PartialEq, Eq)]
+struct TextCounts {
+    lines: usize,
+    words: usize,
+    /// User-perceived characters (extended grapheme clusters)
+    chars: usize,
+    /// Unicode scalar values
+    scalars: usize,
+    bytes: usize,
+}
+
+fn count_text(text: &str) -> TextCounts {
+    TextCounts {
+        lines: text.lines().count(),
+        words: text.split_whitespace().count(),
+        chars: text.graphemes(true).count(),
+        scalars: text.chars().count(),
+        bytes: text.len(),
+    }
+}
 
-    println!("chars: {}", chars);
-    println!("words: {}", words);
-    println!("lines: {}", lines);
+/// Count lines, words, characters, and bytes of a file, string, or stdin
+fn cmd_count(input: Option<&str>, string: Option<String>) -> Result<()> {
+    let text = match (string, input) {
+        (Some(s), _) => s,
+        (None, Some(path)) if path != "-" && Path::new(path).exists() => {
+            std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path))?
+        }
+        // Not a file, so count the argument itself
+        (None, Some(text)) if text != "-" => text.to_string(),
+        (None, _) => {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .context("Failed to read from stdin")?;
+            buf
+        }
+    };
+    let counts = count_text(&text);
+
+    println!("lines:   {}", counts.lines);
+    println!("words:   {}", counts.words);
+    println!("chars:   {}", counts.chars);
+    println!("scalars: {}", counts.scalars);
+    println!("bytes:   {}", counts.bytes);
     Ok(())
 }
 
@@ -114,3 +219,76 @@ fn cmd_repeat(text: &str, times: usize, separator: &str) -> Result<()> {
     println!("{}", repeated.join(separator));
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_words_mixed_styles() {
+        assert_eq!(
+            split_words("helloWorld_example"),
+            ["hello", "World", "example"]
+        );
+        assert_eq!(
+            split_words("HTTPServer-config"),
+            ["HTTP", "Server", "config"]
+        );
+        assert_eq!(
+            split_words("  some  words\there "),
+            ["some", "words", "here"]
+        );
+        assert_eq!(split_words("version2Update"), ["version2", "Update"]);
+        assert!(split_words("__--").is_empty());
+    }
+
+    #[test]
+    fn test_count_text_ascii() {
+        let counts = count_text("hello world\nsecond line\n");
+        assert_eq!(
+            counts,
+            TextCounts {
+                lines: 2,
+                words: 4,
+                chars: 24,
+                scalars: 24,
+                bytes: 24,
+            }
+        );
+    }
+
+    #[test]
+    fn test_count_text_emoji_graphemes() {
+        // Family emoji: 4 people joined by 3 zero-width joiners, plus a flag
+        let text = "hi 👨‍👩‍👧‍👦 🇳🇴";
+        let counts = count_text(text);
+        assert_eq!(counts.chars, 6);
+        assert_eq!(counts.scalars, 13);
+        assert_eq!(counts.bytes, 37);
+        assert_eq!(counts.words, 3);
+        assert_eq!(counts.lines, 1);
+    }
+
+    #[test]
+    fn test_convert_case_each_target() {
+        let input = "helloWorld_example";
+        assert_eq!(convert_case(input, CaseStyle::Snake), "hello_world_example");
+        assert_eq!(convert_case(input, CaseStyle::Camel), "helloWorldExample");
+        assert_eq!(convert_case(input, CaseStyle::Kebab), "hello-world-example");
+        assert_eq!(convert_case(input, CaseStyle::Pascal), "HelloWorldExample");
+        assert_eq!(convert_case(input, CaseStyle::Title), "Hello World Example");
+    }
+
+    #[test]
+    fn test_convert_case_acronyms_and_unicode() {
+        assert_eq!(
+            convert_case("parseHTTPResponse", CaseStyle::Snake),
+            "parse_http_response"
+        );
+        assert_eq!(
+            convert_case("ÉCOLE normale", CaseStyle::Pascal),
+            "ÉcoleNormale"
+        );
+        assert_eq!(convert_case("", CaseStyle::Camel), "");
+    }
+}
@@ -1,7 +1,8 @@
 //! # Time Command Implementation
 //!
-//! This module provides date/time utilities for parsing, formatting, and
-//! calculating durations between timestamps.
+//! This module provides date/time utilities for parsing, formatting,
+//! calculating durations between timestamps, and shifting timestamps by
+//! human-readable durations.
 //!
 //! ## Key Concepts
 //!
@@ -25,6 +26,13 @@
 //! Unix time is seconds (or milliseconds) since January 1, 1970 00:00:00 UTC.
 //! This is called the "Unix epoch". It's timezone-agnostic by definition.
 //!
+//! ### Durations and Relative Times
+//! Durations combine `<n><unit>` parts: `3d12h`, `1h30m`, or spelled out as
+//! `2 hours 15 minutes`. Units are weeks (`w`), days (`d`), hours (`h`),
+//! minutes (`m`), and seconds (`s`). Months and years have no fixed length,
+//! so they are not supported. Anywhere a timestamp is accepted, a relative
+//! phrase works too: `now`, `2h ago`, `in 3 days`.
+//!
 //! ## Example Usage
 //! ```bash
 //! dx time now                          # Current time in ISO format
@@ -33,6 +41,8 @@
 //! dx time parse "2023-11-14T22:13:20Z" # Parse ISO string
 //! dx time convert 1700000000 --format human  # Convert to readable
 //! dx time diff 1700000000 1700086400   # Duration between timestamps
+//! dx time parse "2 hours ago"          # Relative to now
+//! dx time add --base 1700000000 --delta 3d12h  # Shift a timestamp
 //! ```
 //!
 //! ## External Documentation
@@ -42,7 +52,7 @@
 
 use crate::cli::commands::time::{TimeArgs, TimeCommand, TimeFormat};
 use anyhow::{bail, Context, Result};
-use chrono::{DateTime, Local, TimeZone, Utc};
+use chrono::{DateTime, Duration, FixedOffset, Local, TimeZone, Utc};
 use colored::Colorize;
 
 /// Run the time command, dispatching to the appropriate subcommand.
@@ -52,6 +62,7 @@ use colored::Colorize;
 /// - `parse`: Parse a timestamp and show in all formats
 /// - `convert`: Convert a timestamp to a specific format
 /// - `diff`: Calculate duration between two timestamps
+/// - `add`: Shift a timestamp by a duration
 pub fn run(args: TimeArgs) -> Result<()> {
     match args.command {
         TimeCommand::Now { format, timezone } => cmd_now(format, &timezone),
@@ -61,6 +72,11 @@ pub fn run(args: TimeArgs) -> Result<()> {
         } => cmd_parse(&timestamp, input_format.as_deref()),
         TimeCommand::Convert { input, format } => cmd_convert(&input, format),
         TimeCommand::Diff { start, end } => cmd_diff(&start, end.as_deref()),
+        TimeCommand::Add {
+            base,
+            delta,
+            format,
+        } => cmd_add(base.as_deref(), &delta, format),
     }
 }
 
@@ -199,6 +215,23 @@ fn cmd_diff(start: &str, end: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Add a duration to a timestamp (or to now) and print the result.
+///
+/// The base's timezone offset is preserved, so adding `1d` to a `+02:00`
+/// timestamp yields a `+02:00` timestamp.
+fn cmd_add(base: Option<&str>, delta: &str, format: TimeFormat) -> Result<()> {
+    let base_dt = match base {
+        Some(b) => parse_timestamp(b)?,
+        None => Utc::now().fixed_offset(),
+    };
+    let delta = parse_duration(delta)?;
+    let result = base_dt
+        .checked_add_signed(delta)
+        .context("Resulting timestamp is out of range")?;
+    println!("{}", format_datetime(&result, format));
+    Ok(())
+}
+
 /// Parse a timestamp from various common formats.
 ///
 /// # Auto-Detection Strategy
@@ -207,6 +240,7 @@ fn cmd_diff(start: &str, end: Option<&str>) -> Result<()> {
 /// 1. RFC 3339 (most precise, includes timezone)
 /// 2. RFC 2822 (email format, includes timezone)
 /// 3. Unix timestamp (plain number)
+/// 4. Relative phrase (`now`, `2h ago`, `in 3 days`), resolved against now
 ///
 /// # Unix Timestamp Detection
 ///
@@ -225,7 +259,14 @@ fn cmd_diff(start: &str, end: Option<&str>) -> Result<()> {
 ///
 /// # Errors
 /// Returns an error if the input doesn't match any known format.
-fn parse_timestamp(s: &str) -> Result<DateTime<chrono::FixedOffset>> {
+fn parse_timestamp(s: &str) -> Result<DateTime<FixedOffset>> {
+    parse_timestamp_at(s, Utc::now().fixed_offset())
+}
+
+/// Parse a timestamp, resolving relative phrases against `now`.
+///
+/// Taking `now` as a parameter keeps relative parsing testable.
+fn parse_timestamp_at(s: &str, now: DateTime<FixedOffset>) -> Result<DateTime<FixedOffset>> {
     // Try RFC 3339 first (e.g., "2023-11-14T22:13:20+00:00")
     // This is the most specific format with explicit timezone
     if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
@@ -260,10 +301,147 @@ fn parse_timestamp(s: &str) -> Result<DateTime<chrono::FixedOffset>> {
         return Ok(dt.fixed_offset());
     }
 
+    // Try a relative phrase like "2 hours ago" or "in 3d"
+    if let Some(dt) = parse_relative(s, now)? {
+        return Ok(dt);
+    }
+
     // No format matched - give a helpful error
     bail!("Could not parse timestamp: {}", s)
 }
 
+/// Resolve a relative phrase against `now`.
+///
+/// Returns `Ok(None)` if the input doesn't look relative at all, so the
+/// caller can report a generic parse error. Returns an error if it does
+/// look relative but the duration is malformed.
+///
+/// # Supported Phrases
+/// - `now`
+/// - `<duration> ago` (e.g., "2h ago", "3 days ago")
+/// - `in <duration>` (e.g., "in 90m", "in 1 hour 30 minutes")
+fn parse_relative(s: &str, now: DateTime<FixedOffset>) -> Result<Option<DateTime<FixedOffset>>> {
+    let lower = s.trim().to_lowercase();
+
+    let (duration, sign) = if lower == "now" {
+        return Ok(Some(now));
+    } else if let Some(rest) = lower.strip_suffix(" ago") {
+        (parse_duration(rest)?, -1)
+    } else if let Some(rest) = lower.strip_prefix("in ") {
+        (parse_duration(rest)?, 1)
+    } else {
+        return Ok(None);
+    };
+
+    let dt = now
+        .checked_add_signed(duration * sign)
+        .context("Resulting timestamp is out of range")?;
+    Ok(Some(dt))
+}
+
+/// Parse a human duration such as `3d12h`, `-90m`, or `2 hours 15 minutes`.
+///
+/// # Grammar
+/// An optional leading sign, then one or more `<integer><unit>` parts. Parts
+/// may be separated by whitespace, commas, or "and". Each unit may appear
+/// only once, and every number needs a unit.
+///
+/// # Errors
+/// Fails on empty input, a number without a unit, an unknown unit, or a
+/// repeated unit (e.g., `1h2h`), since those are likely mistakes.
+fn parse_duration(s: &str) -> Result<Duration> {
+    let input = s.trim();
+    let (sign, body) = match input.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let chars: Vec<char> = body.chars().collect();
+    let mut pos = 0;
+    let mut total = Duration::zero();
+    let mut seen: Vec<&str> = Vec::new();
+
+    loop {
+        // Skip separators between parts
+        while pos < chars.len() && (chars[pos].is_whitespace() || chars[pos] == ',') {
+            pos += 1;
+        }
+        if chars[pos..].starts_with(&['a', 'n', 'd']) && !seen.is_empty() {
+            pos += 3;
+            continue;
+        }
+        if pos >= chars.len() {
+            break;
+        }
+
+        let start = pos;
+        while pos < chars.len() && chars[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        if start == pos {
+            bail!(
+                "Invalid duration '{}': expected a number at position {}",
+                s,
+                start
+            );
+        }
+        let amount: i64 = chars[start..pos]
+            .iter()
+            .collect::<String>()
+            .parse()
+            .with_context(|| format!("Invalid duration '{}': number too large", s))?;
+
+        while pos < chars.len() && chars[pos].is_whitespace() {
+            pos += 1;
+        }
+        let unit_start = pos;
+        while pos < chars.len() && chars[pos].is_alphabetic() {
+            pos += 1;
+        }
+        let unit: String = chars[unit_start..pos].iter().collect();
+        if unit.is_empty() {
+            bail!(
+                "Invalid duration '{}': missing unit after {} (use w, d, h, m, or s)",
+                s,
+                amount
+            );
+        }
+
+        let (name, seconds_per_unit) = match unit.as_str() {
+            "w" | "week" | "weeks" => ("weeks", 604_800),
+            "d" | "day" | "days" => ("days", 86_400),
+            "h" | "hr" | "hrs" | "hour" | "hours" => ("hours", 3_600),
+            "m" | "min" | "mins" | "minute" | "minutes" => ("minutes", 60),
+            "s" | "sec" | "secs" | "second" | "seconds" => ("seconds", 1),
+            _ => bail!(
+                "Invalid duration '{}': unknown unit '{}' (use w, d, h, m, or s)",
+                s,
+                unit
+            ),
+        };
+        if seen.contains(&name) {
+            bail!("Invalid duration '{}': {} given more than once", s, name);
+        }
+        seen.push(name);
+
+        let part = amount
+            .checked_mul(seconds_per_unit)
+            .and_then(Duration::try_seconds)
+            .with_context(|| format!("Invalid duration '{}': too large", s))?;
+        total = total
+            .checked_add(&part)
+            .with_context(|| format!("Invalid duration '{}': too large", s))?;
+    }
+
+    if seen.is_empty() {
+        bail!(
+            "Invalid duration '{}': expected e.g. 3d12h or \"2 hours\"",
+            s
+        );
+    }
+    Ok(total * sign)
+}
+
 /// Format a datetime according to the specified format.
 ///
 /// # Generic over Timezone
@@ -347,4 +525,50 @@ mod tests {
         let dt = parse_timestamp("2023-11-14T22:13:20+00:00").unwrap();
         assert_eq!(dt.timestamp(), 1700000000);
     }
+
+    /// Test compact and spelled-out durations.
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(
+            parse_duration("3d12h").unwrap().num_seconds(),
+            3 * 86_400 + 12 * 3_600
+        );
+        assert_eq!(parse_duration("1h30m").unwrap().num_seconds(), 5_400);
+        assert_eq!(parse_duration("2 hours").unwrap().num_seconds(), 7_200);
+        assert_eq!(
+            parse_duration("1 day, 2 hours and 3 seconds")
+                .unwrap()
+                .num_seconds(),
+            86_400 + 7_200 + 3
+        );
+        assert_eq!(parse_duration("-90s").unwrap().num_seconds(), -90);
+        assert_eq!(parse_duration("1w").unwrap().num_days(), 7);
+    }
+
+    /// Test that malformed or ambiguous durations are rejected.
+    #[test]
+    fn test_parse_duration_errors() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("12").is_err());
+        assert!(parse_duration("3x").is_err());
+        assert!(parse_duration("1h2h").is_err());
+        assert!(parse_duration("3 months").is_err());
+        assert!(parse_duration("h").is_err());
+    }
+
+    /// Test relative phrases against a fixed "now".
+    #[test]
+    fn test_parse_relative() {
+        let now = parse_timestamp("2023-11-14T22:13:20+00:00").unwrap();
+
+        let ago = parse_timestamp_at("2h ago", now).unwrap();
+        assert_eq!(ago.timestamp(), now.timestamp() - 7_200);
+
+        let later = parse_timestamp_at("in 3 days", now).unwrap();
+        assert_eq!(later.timestamp(), now.timestamp() + 3 * 86_400);
+
+        assert_eq!(parse_timestamp_at("now", now).unwrap(), now);
+        assert!(parse_timestamp_at("2 fortnights ago", now).is_err());
+        assert!(parse_timestamp_at("next tuesday", now).is_err());
+    }
 }
@@ -9,6 +9,7 @@
 //! |---------|--------|----------|
 //! | V4 | Random | Most common, good for general use |
 //! | V7 | Timestamp + Random | Sortable, good for databases |
+//! | V5 | SHA-1 of namespace + name | Stable IDs derived from names |
 //!
 //! ### V4 (Random)
 //! - 122 bits of randomness (6 bits reserved for version/variant)
@@ -20,6 +21,11 @@
 //! - Naturally sortable (newer UUIDs sort after older ones)
 //! - Best for: database primary keys, event ordering
 //!
+//! ### V5 (Name-based)
+//! - SHA-1 hash of a namespace UUID followed by the name bytes
+//! - The same namespace and name always give the same UUID
+//! - Standard namespaces: `dns`, `url`, `oid`, `x500` (RFC 4122 Appendix C)
+//!
 //! ## UUID Formats
 //!
 //! ```text
@@ -36,6 +42,9 @@
 //! dx uuid --version v7           # Generate V7 (sortable) UUID
 //! dx uuid --format simple        # No hyphens
 //! dx uuid --uppercase            # Uppercase hex digits
+//! dx uuid v5 --namespace dns --name example.com
+//! dx uuid inspect <UUID>         # Version, variant, v7 timestamp
+//! dx uuid validate <UUID>...     # Exit 1 if any are malformed
 //! ```
 //!
 //! ## External Documentation
@@ -43,9 +52,13 @@
 //! - RFC 4122: <https://www.rfc-editor.org/rfc/rfc4122>
 //! - UUID v7 draft: <https://www.ietf.org/archive/id/draft-peabody-dispatch-new-uuid-format-04.html>
 
-use crate::cli::commands::uuid::{UuidArgs, UuidFormat, UuidVersion};
-use anyhow::Result;
-use uuid::Uuid;
+use crate::cli::commands::uuid::{UuidArgs, UuidCommand, UuidFormat, UuidVersion};
+use crate::utils::command_rng;
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use colored::Colorize;
+use rand::Rng;
+use uuid::{Builder, Uuid, Variant};
 
 /// Run the UUID command to generate one or more UUIDs.
 ///
@@ -55,9 +68,26 @@ use uuid::Uuid;
 /// - Format output (standard, simple, URN, braced)
 /// - Uppercase option for hex digits
 pub fn run(args: UuidArgs) -> Result<()> {
+    match args.command {
+        Some(UuidCommand::Inspect { uuid }) => return cmd_inspect(&uuid),
+        Some(UuidCommand::Validate { uuids }) => return cmd_validate(&uuids),
+        Some(UuidCommand::V5 {
+            namespace,
+            name,
+            format,
+            uppercase,
+        }) => {
+            let uuid = uuid_v5(&parse_namespace(&namespace)?, name.as_bytes());
+            println!("{}", format_uuid(&uuid, format, uppercase));
+            return Ok(());
+        }
+        None => {}
+    }
+
     // Generate the requested number of UUIDs
+    let mut rng = command_rng();
     for _ in 0..args.count {
-        let uuid = generate_uuid(args.uuid_version);
+        let uuid = generate_uuid(&mut rng, args.uuid_version);
         let formatted = format_uuid(&uuid, args.format, args.uppercase);
         println!("{}", formatted);
     }
@@ -75,7 +105,7 @@ pub fn run(args: UuidArgs) -> Result<()> {
 ///               |    variant (8, 9, a, or b)
 ///               version (always 4)
 /// ```
-/// Uses cryptographically secure random number generator.
+/// The random bits come from `rng`, so `--seed` makes them reproducible.
 ///
 /// **V7 (Timestamp)**:
 /// ```text
@@ -86,18 +116,21 @@ pub fn run(args: UuidArgs) -> Result<()> {
 /// Unix ms timestamp (48 bits)
 /// ```
 /// Embeds Unix timestamp, making UUIDs naturally sortable.
-fn generate_uuid(version: UuidVersion) -> Uuid {
+fn generate_uuid<R: Rng>(rng: &mut R, version: UuidVersion) -> Uuid {
     match version {
         UuidVersion::V4 => {
             // V4: Pure random UUID
-            // Internally uses getrandom for cryptographic randomness
-            Uuid::new_v4()
+            // The builder sets the version and variant bits
+            Builder::from_random_bytes(rng.random()).into_uuid()
         }
         UuidVersion::V7 => {
             // V7: Timestamp-based UUID
-            // now_v7() uses current system time
+            // Current system time, followed by 10 random bytes
             // Great for database keys where ordering matters
-            Uuid::now_v7()
+            let millis = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_millis() as u64);
+            Builder::from_unix_timestamp_millis(millis, &rng.random()).into_uuid()
         }
     }
 }
@@ -143,6 +176,206 @@ fn format_uuid(uuid: &Uuid, format: UuidFormat, uppercase: bool) -> String {
     }
 }
 
+/// Resolve a standard namespace name or parse a custom namespace UUID.
+fn parse_namespace(input: &str) -> Result<Uuid> {
+    match input.to_ascii_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        _ => parse_uuid(input).context("Namespace must be dns, url, oid, x500, or a UUID"),
+    }
+}
+
+/// Build a version 5 UUID from a namespace and name.
+///
+/// Equivalent to `Uuid::new_v5`, which needs the uuid crate's `v5` feature;
+/// the digest is computed by the local [`sha1`] instead.
+fn uuid_v5(namespace: &Uuid, name: &[u8]) -> Uuid {
+    let mut data = namespace.as_bytes().to_vec();
+    data.extend_from_slice(name);
+    let digest = sha1(&data);
+
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    // Sets the version (5) and variant bits
+    Builder::from_sha1_bytes(bytes).into_uuid()
+}
+
+/// SHA-1 digest (FIPS 180-4).
+///
+/// Only used for v5 UUIDs, where SHA-1 is mandated by the RFC; it is not
+/// offered by `dx hash`.
+fn sha1(data: &[u8]) -> [u8; 20] {
+    let mut h: [u32; 5] = [
+        0x6745_2301,
+        0xEFCD_AB89,
+        0x98BA_DCFE,
+        0x1032_5476,
+        0xC3D2_E1F0,
+    ];
+
+    // Pad: 0x80, zeros to 56 mod 64, then the bit length as big-endian u64
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+        message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for block in message.chunks_exact(64) {
+        let mut w = [0u32; 80];
+        for (i, word) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+        }
+        for i in 16..80 {
+            w[i] = (w[i - 3] ^ w[i - 8] ^ w[i - 14] ^ w[i - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = h;
+        for (i, &word) in w.iter().enumerate() {
+            let (f, k) = match i {
+                0..=19 => ((b & c) | (!b & d), 0x5A82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ED9_EBA1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8F1B_BCDC),
+                _ => (b ^ c ^ d, 0xCA62_C1D6),
+            };
+            let temp = a
+                .rotate_left(5)
+                .wrapping_add(f)
+                .wrapping_add(e)
+                .wrapping_add(k)
+                .wrapping_add(word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+
+        for (state, value) in h.iter_mut().zip([a, b, c, d, e]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+
+    let mut digest = [0u8; 20];
+    for (chunk, word) in digest.chunks_exact_mut(4).zip(h) {
+        chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    digest
+}
+
+/// Parse a UUID in any of the supported formats (case-insensitive).
+fn parse_uuid(input: &str) -> Result<Uuid> {
+    let trimmed = input.trim();
+    // The uuid crate only accepts a lowercase `urn:uuid:` prefix
+    let hex = match trimmed.get(..9) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("urn:uuid:") => &trimmed[9..],
+        _ => trimmed,
+    };
+    Uuid::try_parse(hex).with_context(|| format!("Invalid UUID '{}'", input))
+}
+
+/// Decoded fields of a UUID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct UuidInfo {
+    uuid: Uuid,
+    /// Version nibble (0 for nil, 15 for max)
+    version: usize,
+    /// Unix milliseconds for v7 UUIDs
+    timestamp_ms: Option<u64>,
+}
+
+impl UuidInfo {
+    fn new(uuid: Uuid) -> Self {
+        let version = uuid.get_version_num();
+        let timestamp_ms = (version == 7 && uuid.get_variant() == Variant::RFC4122)
+            .then(|| v7_timestamp_ms(&uuid));
+        Self {
+            uuid,
+            version,
+            timestamp_ms,
+        }
+    }
+
+    fn version_name(&self) -> &'static str {
+        if self.uuid.is_nil() {
+            return "nil";
+        }
+        if self.uuid.is_max() {
+            return "max";
+        }
+        match self.version {
+            1 => "time-based (MAC)",
+            2 => "DCE security",
+            3 => "name-based (MD5)",
+            4 => "random",
+            5 => "name-based (SHA-1)",
+            6 => "reordered time-based",
+            7 => "time-ordered",
+            8 => "custom",
+            _ => "unknown",
+        }
+    }
+
+    fn variant_name(&self) -> &'static str {
+        match self.uuid.get_variant() {
+            Variant::NCS => "NCS (reserved)",
+            Variant::RFC4122 => "RFC 4122",
+            Variant::Microsoft => "Microsoft (reserved)",
+            Variant::Future => "future (reserved)",
+            _ => "unknown",
+        }
+    }
+}
+
+/// The 48-bit big-endian Unix millisecond timestamp at the start of a v7 UUID.
+fn v7_timestamp_ms(uuid: &Uuid) -> u64 {
+    let bytes = uuid.as_bytes();
+    bytes[..6]
+        .iter()
+        .fold(0u64, |acc, &b| (acc << 8) | u64::from(b))
+}
+
+fn cmd_inspect(input: &str) -> Result<()> {
+    let info = UuidInfo::new(parse_uuid(input)?);
+
+    println!("{:<10} {}", "uuid:".bold(), info.uuid.hyphenated());
+    println!(
+        "{:<10} {} ({})",
+        "version:".bold(),
+        info.version,
+        info.version_name()
+    );
+    println!("{:<10} {}", "variant:".bold(), info.variant_name());
+    if let Some(ms) = info.timestamp_ms {
+        let time = i64::try_from(ms)
+            .ok()
+            .and_then(DateTime::<Utc>::from_timestamp_millis)
+            .map(|t| t.to_rfc3339_opts(SecondsFormat::Millis, true))
+            .unwrap_or_else(|| "out of range".to_string());
+        println!("{:<10} {} ({} ms)", "timestamp:".bold(), time, ms);
+    }
+    Ok(())
+}
+
+fn cmd_validate(inputs: &[String]) -> Result<()> {
+    let mut invalid = 0;
+    for input in inputs {
+        match parse_uuid(input) {
+            Ok(_) => println!("{} {}", "✓".green().bold(), input),
+            Err(e) => {
+                invalid += 1;
+                eprintln!("{} {:#}", "✗".red().bold(), e);
+            }
+        }
+    }
+    if invalid > 0 {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -154,17 +387,43 @@ mod tests {
     /// Verify V4 UUIDs have version number 4.
     #[test]
     fn test_uuid_v4_generation() {
-        let uuid = generate_uuid(UuidVersion::V4);
+        let uuid = generate_uuid(&mut rand::rng(), UuidVersion::V4);
         assert_eq!(uuid.get_version_num(), 4);
     }
 
     /// Verify V7 UUIDs have version number 7.
     #[test]
     fn test_uuid_v7_generation() {
-        let uuid = generate_uuid(UuidVersion::V7);
+        let uuid = generate_uuid(&mut rand::rng(), UuidVersion::V7);
         assert_eq!(uuid.get_version_num(), 7);
     }
 
+    /// The same seed gives the same v4 UUIDs, and the same v7 random bits.
+    #[test]
+    fn test_seeded_generation_is_reproducible() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let generate = |version| {
+            let mut rng = StdRng::seed_from_u64(7);
+            [
+                generate_uuid(&mut rng, version),
+                generate_uuid(&mut rng, version),
+            ]
+        };
+
+        let (a, b) = (generate(UuidVersion::V4), generate(UuidVersion::V4));
+        assert_eq!(a, b);
+        assert_ne!(a[0], a[1]);
+        assert_eq!(a[0].get_version_num(), 4);
+
+        // The first 6 bytes are the timestamp
+        let (a, b) = (generate(UuidVersion::V7), generate(UuidVersion::V7));
+        assert_eq!(a[0].as_bytes()[6..], b[0].as_bytes()[6..]);
+        assert_eq!(a[1].as_bytes()[6..], b[1].as_bytes()[6..]);
+        assert_eq!(a[0].get_version_num(), 7);
+    }
+
     /// Test standard (hyphenated) format.
     #[test]
     fn test_format_standard() {
@@ -181,6 +440,106 @@ mod tests {
         assert_eq!(formatted, "00000000000000000000000000000000");
     }
 
+    /// All four display formats parse back to the same UUID.
+    #[test]
+    fn test_parse_formats() {
+        let uuid = Uuid::parse_str("a1b2c3d4-e5f6-4789-abcd-ef0123456789").unwrap();
+        for format in [
+            UuidFormat::Standard,
+            UuidFormat::Simple,
+            UuidFormat::Urn,
+            UuidFormat::Braced,
+        ] {
+            assert_eq!(parse_uuid(&format_uuid(&uuid, format, true)).unwrap(), uuid);
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        for bad in [
+            "",
+            "not-a-uuid",
+            "a1b2c3d4-e5f6-4789-abcd-ef012345678",
+            "g1b2c3d4-e5f6-4789-abcd-ef0123456789",
+            "a1b2c3d4e5f6-4789-abcd-ef0123456789",
+        ] {
+            assert!(parse_uuid(bad).is_err(), "{:?} should be rejected", bad);
+        }
+    }
+
+    /// The timestamp read back from a fresh v7 UUID is its creation time.
+    #[test]
+    fn test_v7_timestamp_matches_creation_time() {
+        let now_ms = || {
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_millis() as u64
+        };
+        let before = now_ms();
+        let uuid = generate_uuid(&mut rand::rng(), UuidVersion::V7);
+        let after = now_ms();
+
+        let info = UuidInfo::new(uuid);
+        assert_eq!(info.version, 7);
+        let ts = info.timestamp_ms.unwrap();
+        assert!(
+            (before..=after).contains(&ts),
+            "{} not in {}..={}",
+            ts,
+            before,
+            after
+        );
+    }
+
+    #[test]
+    fn test_inspect_known_values() {
+        let v7 = Uuid::parse_str("01890a5d-ac96-774b-bcce-b302099a8057").unwrap();
+        let info = UuidInfo::new(v7);
+        assert_eq!(info.timestamp_ms, Some(0x0189_0a5d_ac96));
+        assert_eq!(info.variant_name(), "RFC 4122");
+
+        let v4 = UuidInfo::new(generate_uuid(&mut rand::rng(), UuidVersion::V4));
+        assert_eq!(v4.version_name(), "random");
+        assert_eq!(v4.timestamp_ms, None);
+
+        assert_eq!(UuidInfo::new(Uuid::nil()).version_name(), "nil");
+    }
+
+    #[test]
+    fn test_sha1_vectors() {
+        let hex =
+            |data: &[u8]| -> String { sha1(data).iter().map(|b| format!("{:02x}", b)).collect() };
+        assert_eq!(hex(b""), "da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        assert_eq!(hex(b"abc"), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        // Two blocks: padding crosses a block boundary
+        assert_eq!(
+            hex(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+    }
+
+    /// Reference v5 values (as returned by `Uuid::new_v5` and Python's `uuid5`).
+    #[test]
+    fn test_uuid_v5_known_values() {
+        let dns = uuid_v5(&parse_namespace("dns").unwrap(), b"example.com");
+        assert_eq!(dns.to_string(), "cfbff0d1-9375-5685-968c-48ce8b15ae17");
+        assert_eq!(dns.get_version_num(), 5);
+        assert_eq!(dns.get_variant(), Variant::RFC4122);
+
+        let url = uuid_v5(&Uuid::NAMESPACE_URL, b"https://www.python.org/");
+        assert_eq!(url.to_string(), "5406f80d-92e9-51cd-a176-77445955e733");
+    }
+
+    #[test]
+    fn test_parse_namespace() {
+        assert_eq!(parse_namespace("DNS").unwrap(), Uuid::NAMESPACE_DNS);
+        assert_eq!(parse_namespace("x500").unwrap(), Uuid::NAMESPACE_X500);
+        let custom = "a1b2c3d4-e5f6-4789-abcd-ef0123456789";
+        assert_eq!(parse_namespace(custom).unwrap().to_string(), custom);
+        assert!(parse_namespace("email").is_err());
+    }
+
     /// Test uppercase formatting.
     #[test]
     fn test_format_uppercase() {
@@ -1,14 +1,26 @@
 //! Watch command - watch files for changes and run commands.
+//!
+//! ## Debouncing
+//! Editors often write a file several times per save (temp file, rename,
+//! metadata update). Raw events are collected in a [`Debouncer`] and the
+//! command runs once the stream has been quiet for `--debounce` milliseconds,
+//! so a burst of events triggers a single run.
+//!
+//! ## Filters
+//! `--include` and `--exclude` take glob patterns. A pattern without a `/`
+//! matches against the file name (`*.rs`); a pattern with a `/` matches the
+//! whole path (`src/**/*.rs`). Excludes always win, and hidden files are
+//! ignored.
 
 use crate::cli::commands::watch::WatchArgs;
 use anyhow::{Context, Result};
 use colored::Colorize;
-use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
-use regex::Regex;
-use std::path::Path;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::mpsc::channel;
-use std::time::Duration;
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
 
 /// Run the watch command
 pub fn run(args: WatchArgs) -> Result<()> {
@@ -25,17 +37,11 @@ pub fn run(args: WatchArgs) -> Result<()> {
         format!("→ {}", cmd_display).dimmed()
     );
 
-    // Run initial command if requested
-    if args.initial {
-        run_command(&args.command, args.clear)?;
-    }
+    let filter = PathFilter::new(&args.include, &args.exclude)?;
 
     // Set up file watcher
-    let (tx, rx) = channel::<DebounceEventResult>();
-
-    let debounce_duration = Duration::from_millis(args.debounce);
-    let mut debouncer =
-        new_debouncer(debounce_duration, tx).context("Failed to create file watcher")?;
+    let (tx, rx) = channel::<notify::Result<notify::Event>>();
+    let mut watcher = notify::recommended_watcher(tx).context("Failed to create file watcher")?;
 
     // Watch all specified paths
     let mode = if args.recursive {
@@ -45,88 +51,195 @@ pub fn run(args: WatchArgs) -> Result<()> {
     };
 
     for path in &args.paths {
-        debouncer
-            .watcher()
+        watcher
             .watch(path, mode)
             .context(format!("Failed to watch path: {}", path.display()))?;
     }
 
-    // Build include/exclude patterns
-    let include_pattern = args.include.as_ref().map(|p| build_glob_regex(p));
-    let exclude_pattern = args.exclude.as_ref().map(|p| build_glob_regex(p));
-
     println!("{}", "Press Ctrl+C to stop".dimmed());
     println!();
 
-    // Main event loop
+    event_loop(&args, &filter, &rx, run_command)
+}
+
+/// Drive the watch loop: run the command for each debounced batch of events.
+///
+/// `runner` receives the command and the `--clear` flag; it is injected so
+/// the loop can be tested without spawning processes. With `--initial` the
+/// command runs once before the first event is read. The loop ends when the
+/// event channel disconnects, after flushing any pending batch.
+fn event_loop<F>(
+    args: &WatchArgs,
+    filter: &PathFilter,
+    events: &Receiver<notify::Result<notify::Event>>,
+    mut runner: F,
+) -> Result<()>
+where
+    F: FnMut(&[String], bool) -> Result<()>,
+{
+    // Run initial command if requested
+    if args.initial {
+        runner(&args.command, args.clear)?;
+    }
+
+    let mut debouncer = Debouncer::new(Duration::from_millis(args.debounce));
+
     loop {
-        match rx.recv() {
-            Ok(Ok(events)) => {
-                // Check if any event matches our filters
-                let should_run = events.iter().any(|event| {
-                    let path = &event.path;
-                    should_include_path(path, &include_pattern, &exclude_pattern)
-                });
-
-                if should_run {
-                    // Show which files changed
-                    for event in &events {
-                        println!(
-                            "{} {}",
-                            "Changed:".yellow(),
-                            event.path.display().to_string().dimmed()
-                        );
-                    }
+        // Sleep until the next event, or until pending events are due
+        let received = match debouncer.time_until_ready(Instant::now()) {
+            Some(wait) => events.recv_timeout(wait),
+            None => events.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
 
-                    run_command(&args.command, args.clear)?;
+        let disconnected = match received {
+            Ok(Ok(event)) => {
+                // Reads would retrigger on commands that read the watched files
+                if !matches!(event.kind, EventKind::Access(_)) {
+                    for path in event.paths {
+                        if filter.matches(&path) {
+                            debouncer.push(path, Instant::now());
+                        }
+                    }
                 }
+                false
             }
             Ok(Err(error)) => {
                 eprintln!("{} {}", "Watch error:".red(), error);
+                false
             }
-            Err(e) => {
-                eprintln!("{} {}", "Channel error:".red(), e);
-                break;
+            Err(RecvTimeoutError::Timeout) => false,
+            Err(RecvTimeoutError::Disconnected) => true,
+        };
+
+        let ready = if disconnected {
+            debouncer.flush()
+        } else {
+            debouncer.take_ready(Instant::now())
+        };
+
+        if let Some(paths) = ready {
+            // Show which files changed
+            for path in &paths {
+                println!(
+                    "{} {}",
+                    "Changed:".yellow(),
+                    path.display().to_string().dimmed()
+                );
             }
+
+            runner(&args.command, args.clear)?;
+        }
+
+        if disconnected {
+            return Ok(());
         }
     }
+}
 
-    Ok(())
+/// Coalesces bursts of file events into a single trigger.
+///
+/// Each event restarts the quiet window; once no event has arrived for the
+/// whole window, the distinct changed paths are released together.
+struct Debouncer {
+    window: Duration,
+    pending: Vec<PathBuf>,
+    last_event: Option<Instant>,
 }
 
-fn build_glob_regex(pattern: &str) -> Regex {
-    let regex_pattern = pattern
-        .replace('.', r"\.")
-        .replace('*', ".*")
-        .replace('?', ".");
+impl Debouncer {
+    fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: Vec::new(),
+            last_event: None,
+        }
+    }
+
+    /// Record a changed path at time `now`.
+    fn push(&mut self, path: PathBuf, now: Instant) {
+        if !self.pending.contains(&path) {
+            self.pending.push(path);
+        }
+        self.last_event = Some(now);
+    }
+
+    /// How long until pending events are due, or `None` if nothing is pending.
+    fn time_until_ready(&self, now: Instant) -> Option<Duration> {
+        self.last_event
+            .map(|last| (last + self.window).saturating_duration_since(now))
+    }
+
+    /// Take the pending paths if the quiet window has elapsed.
+    fn take_ready(&mut self, now: Instant) -> Option<Vec<PathBuf>> {
+        let last = self.last_event?;
+        if now.duration_since(last) < self.window {
+            return None;
+        }
+        self.flush()
+    }
 
-    Regex::new(&format!("(?i){}", regex_pattern)).unwrap_or_else(|_| Regex::new(".*").unwrap())
+    /// Take the pending paths regardless of the window.
+    fn flush(&mut self) -> Option<Vec<PathBuf>> {
+        self.last_event.take()?;
+        Some(std::mem::take(&mut self.pending))
+    }
 }
 
-fn should_include_path(path: &Path, include: &Option<Regex>, exclude: &Option<Regex>) -> bool {
-    let name = path
-        .file_name()
-        .map(|n| n.to_string_lossy().to_string())
-        .unwrap_or_default();
+/// Include/exclude glob filters for changed paths.
+struct PathFilter {
+    include: Option<GlobSet>,
+    exclude: Option<GlobSet>,
+}
 
-    // Skip hidden files
-    if name.starts_with('.') {
-        return false;
+impl PathFilter {
+    fn new(include: &[String], exclude: &[String]) -> Result<Self> {
+        Ok(Self {
+            include: build_globset(include)?,
+            exclude: build_globset(exclude)?,
+        })
     }
 
-    // Check exclude pattern
-    if let Some(exclude_re) = exclude {
-        if exclude_re.is_match(&name) {
+    /// Whether a change to `path` should trigger the command.
+    fn matches(&self, path: &Path) -> bool {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        // Skip hidden files
+        if name.starts_with('.') {
             return false;
         }
-    }
 
-    // Check include pattern
-    if let Some(include_re) = include {
-        return include_re.is_match(&name);
+        let hit = |set: &GlobSet| set.is_match(&name) || set.is_match(path);
+
+        // Check exclude patterns
+        if self.exclude.as_ref().is_some_and(hit) {
+            return false;
+        }
+
+        // Check include patterns
+        self.include.as_ref().is_none_or(hit)
     }
+}
 
-    true
+/// Compile glob patterns, returning `None` when there are none.
+///
+/// Patterns containing `/` are also tried with a leading `**/` so that
+/// `src/*.rs` matches the absolute paths reported by the watcher.
+fn build_globset(patterns: &[String]) -> Result<Option<GlobSet>> {
+    if patterns.is_empty() {
+        return Ok(None);
+    }
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = |p: &str| Glob::new(p).with_context(|| format!("Invalid glob: {}", pattern));
+        builder.add(glob(pattern)?);
+        if pattern.contains('/') && !pattern.starts_with('/') && !pattern.starts_with("**") {
+            builder.add(glob(&format!("**/{}", pattern))?);
+        }
+    }
+    Ok(Some(builder.build().context("Invalid glob patterns")?))
 }
 
 fn run_command(command: &[String], clear: bool) -> Result<()> {
@@ -163,3 +276,203 @@ fn run_command(command: &[String], clear: bool) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debouncer_coalesces_rapid_events() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debouncer = Debouncer::new(ms(100));
+
+        // Three saves within the window, two of the same file
+        debouncer.push(PathBuf::from("a.rs"), start);
+        debouncer.push(PathBuf::from("b.rs"), start + ms(30));
+        debouncer.push(PathBuf::from("a.rs"), start + ms(60));
+
+        // Each event restarts the window
+        assert_eq!(debouncer.take_ready(start + ms(120)), None);
+        assert_eq!(debouncer.time_until_ready(start + ms(120)), Some(ms(40)));
+
+        let triggered = debouncer.take_ready(start + ms(160));
+        assert_eq!(
+            triggered,
+            Some(vec![PathBuf::from("a.rs"), PathBuf::from("b.rs")])
+        );
+
+        // Nothing left to fire
+        assert_eq!(debouncer.take_ready(start + ms(500)), None);
+        assert_eq!(debouncer.time_until_ready(start + ms(500)), None);
+    }
+
+    #[test]
+    fn test_debouncer_separate_bursts() {
+        let start = Instant::now();
+        let ms = Duration::from_millis;
+        let mut debouncer = Debouncer::new(ms(50));
+
+        debouncer.push(PathBuf::from("a.rs"), start);
+        assert!(debouncer.take_ready(start + ms(50)).is_some());
+
+        debouncer.push(PathBuf::from("a.rs"), start + ms(200));
+        assert!(debouncer.take_ready(start + ms(260)).is_some());
+    }
+
+    #[test]
+    fn test_path_filter_include_exclude() {
+        let filter = PathFilter::new(
+            &["*.rs".to_string(), "*.toml".to_string()],
+            &["*_test.rs".to_string()],
+        )
+        .unwrap();
+
+        assert!(filter.matches(Path::new("/project/src/main.rs")));
+        assert!(filter.matches(Path::new("/project/Cargo.toml")));
+        assert!(!filter.matches(Path::new("/project/src/foo_test.rs")));
+        assert!(!filter.matches(Path::new("/project/README.md")));
+        assert!(!filter.matches(Path::new("/project/.main.rs.swp")));
+    }
+
+    #[test]
+    fn test_path_filter_path_globs() {
+        let filter =
+            PathFilter::new(&["src/**/*.rs".to_string()], &["target/**".to_string()]).unwrap();
+        assert!(filter.matches(Path::new("/project/src/commands/watch.rs")));
+        assert!(!filter.matches(Path::new("/project/tests/cli.rs")));
+
+        let all = PathFilter::new(&[], &["target/**".to_string()]).unwrap();
+        assert!(!all.matches(Path::new("/project/target/debug/dx")));
+        assert!(all.matches(Path::new("/project/anything.txt")));
+    }
+
+    #[test]
+    fn test_path_filter_brace_glob() {
+        let filter = PathFilter::new(&["*.{rs,toml}".to_string()], &[]).unwrap();
+        assert!(filter.matches(Path::new("/project/src/main.rs")));
+        assert!(filter.matches(Path::new("/project/Cargo.toml")));
+        assert!(!filter.matches(Path::new("/project/README.md")));
+    }
+
+    #[test]
+    fn test_include_flag_keeps_brace_glob_whole() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            watch: WatchArgs,
+        }
+
+        let cli = Cli::try_parse_from([
+            "watch",
+            ".",
+            "--include",
+            "*.{rs,toml}",
+            "--include",
+            "*.md",
+            "--",
+            "true",
+        ])
+        .unwrap();
+        assert_eq!(cli.watch.include, vec!["*.{rs,toml}", "*.md"]);
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        assert!(PathFilter::new(&["[".to_string()], &[]).is_err());
+    }
+
+    fn watch_args(initial: bool, clear: bool) -> WatchArgs {
+        WatchArgs {
+            paths: vec![PathBuf::from(".")],
+            command: vec!["cargo".to_string(), "test".to_string()],
+            debounce: 0,
+            clear,
+            initial,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            recursive: true,
+        }
+    }
+
+    fn modify_event(path: &str) -> notify::Event {
+        notify::Event::new(EventKind::Modify(notify::event::ModifyKind::Any))
+            .add_path(PathBuf::from(path))
+    }
+
+    #[test]
+    fn test_initial_runs_before_any_event() {
+        let args = watch_args(true, false);
+        let filter = PathFilter::new(&[], &[]).unwrap();
+        let (tx, rx) = channel();
+        drop(tx);
+
+        let mut runs = Vec::new();
+        event_loop(&args, &filter, &rx, |command, clear| {
+            runs.push((command.join(" "), clear));
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(runs, vec![("cargo test".to_string(), false)]);
+    }
+
+    #[test]
+    fn test_no_initial_waits_for_event() {
+        let args = watch_args(false, false);
+        let filter = PathFilter::new(&[], &[]).unwrap();
+        let (tx, rx) = channel();
+        drop(tx);
+
+        let mut runs = 0;
+        event_loop(&args, &filter, &rx, |_, _| {
+            runs += 1;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(runs, 0);
+    }
+
+    #[test]
+    fn test_event_triggers_run_with_clear() {
+        let args = watch_args(true, true);
+        let filter = PathFilter::new(&["*.rs".to_string()], &[]).unwrap();
+        let (tx, rx) = channel();
+        tx.send(Ok(modify_event("/project/src/main.rs"))).unwrap();
+        tx.send(Ok(modify_event("/project/README.md"))).unwrap();
+        drop(tx);
+
+        let mut runs = Vec::new();
+        event_loop(&args, &filter, &rx, |_, clear| {
+            runs.push(clear);
+            Ok(())
+        })
+        .unwrap();
+
+        // Initial run plus one run for the .rs change; README.md is filtered
+        assert_eq!(runs, vec![true, true]);
+    }
+
+    #[test]
+    fn test_access_events_ignored() {
+        let args = watch_args(false, false);
+        let filter = PathFilter::new(&[], &[]).unwrap();
+        let (tx, rx) = channel();
+        let read = notify::Event::new(EventKind::Access(notify::event::AccessKind::Any))
+            .add_path(PathBuf::from("/project/src/main.rs"));
+        tx.send(Ok(read)).unwrap();
+        drop(tx);
+
+        let mut runs = 0;
+        event_loop(&args, &filter, &rx, |_, _| {
+            runs += 1;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(runs, 0);
+    }
+}
@@ -1,9 +1,10 @@
 //! XML command - XML utilities.
 
 use crate::cli::commands::xml::{XmlArgs, XmlCommand};
-use anyhow::{Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
 use colored::Colorize;
-use quick_xml::events::{BytesText, Event};
+use quick_xml::escape::{escape, resolve_predefined_entity};
+use quick_xml::events::{BytesStart, BytesText, Event};
 use quick_xml::{Reader, Writer};
 use serde_json::{Map, Value};
 use std::fs;
@@ -16,6 +17,7 @@ pub fn run(args: XmlArgs) -> Result<()> {
         XmlCommand::Format { input, indent } => cmd_format(input, indent),
         XmlCommand::Validate { input, quiet } => cmd_validate(input, quiet),
         XmlCommand::ToJson { input, pretty } => cmd_to_json(input, pretty),
+        XmlCommand::Query { path, input } => cmd_query(&path, input),
     }
 }
 
@@ -42,81 +44,106 @@ fn read_input(input: Option<PathBuf>) -> Result<String> {
 
 fn cmd_format(input: Option<PathBuf>, indent_size: usize) -> Result<()> {
     let content = read_input(input)?;
+    println!("{}", format_xml(&content, indent_size)?);
+    Ok(())
+}
 
-    let mut reader = Reader::from_str(&content);
-    reader.config_mut().trim_text(true);
-
+/// Reindent a document, keeping comments, CDATA, and the prolog.
+///
+/// Whitespace-only text between tags is dropped; other text is trimmed at
+/// its ends but kept intact around entity references.
+fn format_xml(content: &str, indent_size: usize) -> Result<String> {
+    let mut reader = Reader::from_str(content);
     let mut writer = Writer::new_with_indent(Cursor::new(Vec::new()), b' ', indent_size);
-    let mut buf = Vec::new();
+    let mut depth = 0usize;
+    // Escaped text waiting for the next markup event
+    let mut text = String::new();
 
     loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Eof) => break,
-            Ok(Event::Start(e)) => {
-                let elem = e;
-                writer.write_event(Event::Start(elem))?;
-            }
-            Ok(Event::End(e)) => {
-                let elem = e;
-                writer.write_event(Event::End(elem))?;
-            }
-            Ok(Event::Empty(e)) => {
-                let elem = e;
-                writer.write_event(Event::Empty(elem))?;
-            }
-            Ok(Event::Text(e)) => {
-                let text = e.decode()?;
-                if !text.trim().is_empty() {
-                    writer.write_event(Event::Text(BytesText::new(&text)))?;
-                }
-            }
-            Ok(Event::CData(e)) => {
-                writer.write_event(Event::CData(e))?;
-            }
-            Ok(Event::Comment(e)) => {
-                writer.write_event(Event::Comment(e))?;
-            }
-            Ok(Event::Decl(e)) => {
-                writer.write_event(Event::Decl(e))?;
+        let event = reader
+            .read_event()
+            .map_err(|e| parse_error(content, reader.error_position(), e))?;
+
+        match event {
+            Event::Text(e) => {
+                text.push_str(&escape(e.decode()?));
+                continue;
             }
-            Ok(Event::PI(e)) => {
-                writer.write_event(Event::PI(e))?;
+            Event::GeneralRef(e) => {
+                text.push('&');
+                text.push_str(&e.decode()?);
+                text.push(';');
+                continue;
             }
-            Ok(Event::DocType(e)) => {
-                writer.write_event(Event::DocType(e))?;
+            _ => {}
+        }
+
+        let trimmed = text.trim();
+        if !trimmed.is_empty() {
+            writer.write_event(Event::Text(BytesText::from_escaped(trimmed)))?;
+        }
+        text.clear();
+
+        match event {
+            Event::Eof => break,
+            Event::Start(e) => {
+                depth += 1;
+                writer.write_event(Event::Start(e))?;
             }
-            Ok(Event::GeneralRef(e)) => {
-                writer.write_event(Event::GeneralRef(e))?;
+            Event::End(e) => {
+                depth = depth.saturating_sub(1);
+                writer.write_event(Event::End(e))?;
             }
-            Err(e) => anyhow::bail!("Error parsing XML: {}", e),
+            other => writer.write_event(other)?,
         }
-        buf.clear();
     }
 
-    let result = writer.into_inner().into_inner();
-    let formatted = String::from_utf8(result)?;
-    println!("{}", formatted);
-    Ok(())
+    if depth > 0 {
+        bail!(
+            "Error parsing XML at line {}: unexpected end of input ({} unclosed element{})",
+            line_col(content, content.len()).0,
+            depth,
+            if depth == 1 { "" } else { "s" }
+        );
+    }
+
+    Ok(String::from_utf8(writer.into_inner().into_inner())?)
+}
+
+/// Attach the line and column of byte `offset` to a parse error.
+fn parse_error(content: &str, offset: u64, err: impl std::fmt::Display) -> anyhow::Error {
+    let (line, column) = line_col(content, offset as usize);
+    anyhow!(
+        "Error parsing XML at line {}, column {}: {}",
+        line,
+        column,
+        err
+    )
+}
+
+/// 1-based line and column (in characters) of byte `offset`.
+fn line_col(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content.as_bytes()[..offset.min(content.len())];
+    let line = before.iter().filter(|&&b| b == b'\n').count() + 1;
+    let line_start = before
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+    let column = String::from_utf8_lossy(&before[line_start..])
+        .chars()
+        .count()
+        + 1;
+    (line, column)
 }
 
 fn cmd_validate(input: Option<PathBuf>, quiet: bool) -> Result<()> {
     let content = read_input(input)?;
 
-    let mut reader = Reader::from_str(&content);
-    let mut buf = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Eof) => break,
-            Ok(_) => {}
-            Err(e) => {
-                if !quiet {
-                    eprintln!("{}: {}", "Invalid XML".red(), e);
-                }
-                anyhow::bail!("Invalid XML syntax");
-            }
+    if let Err(e) = parse_tree(&content) {
+        if !quiet {
+            eprintln!("{}: {}", "Invalid XML".red(), e);
         }
-        buf.clear();
+        bail!("Invalid XML syntax");
     }
 
     if !quiet {
@@ -131,7 +158,10 @@ fn cmd_to_json(input: Option<PathBuf>, pretty: bool) -> Result<()> {
     let mut reader = Reader::from_str(&content);
     reader.config_mut().trim_text(true);
 
-    let json = xml_to_json(&mut reader)?;
+    let json = xml_to_json(&mut reader).map_err(|e| match e.downcast::<quick_xml::Error>() {
+        Ok(e) => parse_error(&content, reader.error_position(), e),
+        Err(e) => e,
+    })?;
 
     let output = if pretty {
         serde_json::to_string_pretty(&json)?
@@ -240,10 +270,576 @@ fn xml_to_json(reader: &mut Reader<&[u8]>) -> Result<Value> {
                 }
             }
             Ok(_) => {} // Ignore other events
-            Err(e) => anyhow::bail!("Error parsing XML: {}", e),
+            Err(e) => return Err(e.into()),
         }
         buf.clear();
     }
 
     root.ok_or_else(|| anyhow::anyhow!("Empty XML document"))
 }
+
+fn cmd_query(path: &str, input: Option<PathBuf>) -> Result<()> {
+    let content = read_input(input)?;
+    let document = parse_tree(&content)?;
+    let query = parse_query(path)?;
+
+    let matches = evaluate(&query, &document);
+    if matches.is_empty() {
+        bail!("No nodes match '{}'", path);
+    }
+    for found in matches {
+        match found {
+            Match::Element(element) => {
+                let mut out = String::new();
+                write_element(element, 0, &mut out);
+                print!("{}", out);
+            }
+            Match::Value(value) => println!("{}", value),
+        }
+    }
+    Ok(())
+}
+
+/// A parsed XML element.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Element {
+    name: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Element(Element),
+    Text(String),
+}
+
+impl Element {
+    fn elements(&self) -> impl Iterator<Item = &Element> {
+        self.children.iter().filter_map(|child| match child {
+            Node::Element(element) => Some(element),
+            Node::Text(_) => None,
+        })
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Direct text content with entities resolved.
+    fn text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                Node::Text(text) => Some(text.as_str()),
+                Node::Element(_) => None,
+            })
+            .collect::<String>()
+            .trim()
+            .to_string()
+    }
+}
+
+/// Parse a document into a tree rooted at an unnamed document node whose
+/// only element child is the root element.
+///
+/// Comments, processing instructions, and the prolog are dropped;
+/// whitespace-only text between elements is ignored.
+fn parse_tree(content: &str) -> Result<Element> {
+    let mut reader = Reader::from_str(content);
+    let mut stack = vec![Element {
+        name: String::new(),
+        attributes: Vec::new(),
+        children: Vec::new(),
+    }];
+
+    loop {
+        let position = reader.buffer_position();
+        let event = reader
+            .read_event()
+            .map_err(|e| parse_error(content, reader.error_position(), e))?;
+        let at = |e: &dyn std::fmt::Display| parse_error(content, position, e);
+
+        match event {
+            Event::Eof => break,
+            Event::Start(e) => stack.push(element_from(&e).map_err(|e| at(&e))?),
+            Event::Empty(e) => {
+                let element = element_from(&e).map_err(|e| at(&e))?;
+                push_child(&mut stack, Node::Element(element), content, position)?;
+            }
+            Event::End(_) => {
+                let mut element = stack.pop().expect("end tags are matched by the reader");
+                element
+                    .children
+                    .retain(|child| !matches!(child, Node::Text(text) if text.trim().is_empty()));
+                push_child(&mut stack, Node::Element(element), content, position)?;
+            }
+            Event::Text(e) => {
+                let text = e.decode().map_err(|e| at(&e))?;
+                if stack.len() == 1 && !text.trim().is_empty() {
+                    return Err(at(&"text outside the root element"));
+                }
+                push_text(&mut stack, &text);
+            }
+            Event::CData(e) => {
+                let text = e.decode().map_err(|e| at(&e))?;
+                push_text(&mut stack, &text);
+            }
+            Event::GeneralRef(e) => {
+                let text = match e.resolve_char_ref().map_err(|e| at(&e))? {
+                    Some(c) => c.to_string(),
+                    None => {
+                        let name = e.decode().map_err(|e| at(&e))?;
+                        resolve_predefined_entity(&name)
+                            .ok_or_else(|| at(&format!("unknown entity '&{};'", name)))?
+                            .to_string()
+                    }
+                };
+                push_text(&mut stack, &text);
+            }
+            Event::Comment(_) | Event::Decl(_) | Event::PI(_) | Event::DocType(_) => {}
+        }
+    }
+
+    if stack.len() > 1 {
+        let open = stack.last().map(|e| e.name.as_str()).unwrap_or_default();
+        bail!(
+            "Error parsing XML at line {}: unexpected end of input, <{}> is not closed",
+            line_col(content, content.len()).0,
+            open
+        );
+    }
+    let mut document = stack.pop().expect("document node");
+    document
+        .children
+        .retain(|child| matches!(child, Node::Element(_)));
+    if document.children.is_empty() {
+        bail!("Empty XML document");
+    }
+    Ok(document)
+}
+
+fn element_from(start: &BytesStart) -> Result<Element> {
+    let mut attributes = Vec::new();
+    for attr in start.attributes() {
+        let attr = attr?;
+        attributes.push((
+            String::from_utf8_lossy(attr.key.as_ref()).to_string(),
+            attr.unescape_value()?.to_string(),
+        ));
+    }
+    Ok(Element {
+        name: String::from_utf8_lossy(start.name().as_ref()).to_string(),
+        attributes,
+        children: Vec::new(),
+    })
+}
+
+fn push_child(stack: &mut [Element], node: Node, content: &str, position: u64) -> Result<()> {
+    let is_document = stack.len() == 1;
+    let parent = stack.last_mut().expect("document node");
+    if is_document && parent.elements().next().is_some() {
+        return Err(parse_error(content, position, "multiple root elements"));
+    }
+    parent.children.push(node);
+    Ok(())
+}
+
+/// Append text to the open element, merging with a preceding text node.
+fn push_text(stack: &mut [Element], text: &str) {
+    let parent = stack.last_mut().expect("document node");
+    match parent.children.last_mut() {
+        Some(Node::Text(existing)) => existing.push_str(text),
+        _ => parent.children.push(Node::Text(text.to_string())),
+    }
+}
+
+/// Serialize an element with two-space indentation.
+fn write_element(element: &Element, depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    out.push_str(&indent);
+    out.push('<');
+    out.push_str(&element.name);
+    for (key, value) in &element.attributes {
+        out.push_str(&format!(" {}=\"{}\"", key, escape(value.as_str())));
+    }
+
+    if element.children.is_empty() {
+        out.push_str("/>\n");
+        return;
+    }
+    out.push('>');
+    if let [Node::Text(text)] = element.children.as_slice() {
+        out.push_str(&escape(text.trim()));
+    } else {
+        out.push('\n');
+        for child in &element.children {
+            match child {
+                Node::Element(child) => write_element(child, depth + 1, out),
+                Node::Text(text) => {
+                    out.push_str(&"  ".repeat(depth + 1));
+                    out.push_str(&escape(text.trim()));
+                    out.push('\n');
+                }
+            }
+        }
+        out.push_str(&indent);
+    }
+    out.push_str(&format!("</{}>\n", element.name));
+}
+
+/// One step of a query path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    /// Child elements (or, with `descendants`, elements at any depth)
+    Elements {
+        name: String,
+        descendants: bool,
+        predicates: Vec<Predicate>,
+    },
+    /// `@name` or `@*`
+    Attribute(String),
+    /// `text()`
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Predicate {
+    /// `[n]`, 1-based among the matching siblings
+    Position(usize),
+    /// `[@name]`
+    HasAttribute(String),
+    /// `[@name='value']`
+    AttributeEquals(String, String),
+}
+
+/// Parse an XPath subset: `/a/b`, `//b`, `*`, `[n]`, `[@attr]`,
+/// `[@attr='value']`, and a final `@attr`, `@*`, or `text()`.
+fn parse_query(path: &str) -> Result<Vec<Step>> {
+    let mut steps = Vec::new();
+    let mut rest = path.trim();
+    let mut descendants = false;
+    if let Some(r) = rest.strip_prefix("//") {
+        descendants = true;
+        rest = r;
+    } else if let Some(r) = rest.strip_prefix('/') {
+        rest = r;
+    }
+
+    loop {
+        let end = step_end(rest);
+        let segment = &rest[..end];
+        if segment.is_empty() {
+            bail!("Invalid query '{}': empty step", path);
+        }
+        if matches!(steps.last(), Some(Step::Attribute(_) | Step::Text)) {
+            bail!(
+                "Invalid query '{}': attributes and text() must be the last step",
+                path
+            );
+        }
+        steps.push(
+            parse_step(segment, descendants)
+                .with_context(|| format!("Invalid query '{}'", path))?,
+        );
+
+        rest = &rest[end..];
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(r) = rest.strip_prefix("//") {
+            descendants = true;
+            rest = r;
+        } else {
+            descendants = false;
+            rest = &rest[1..];
+        }
+    }
+    Ok(steps)
+}
+
+/// Length of the step at the start of `rest`, ignoring `/` inside brackets.
+fn step_end(rest: &str) -> usize {
+    let mut depth = 0;
+    for (i, c) in rest.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '/' if depth == 0 => return i,
+            _ => {}
+        }
+    }
+    rest.len()
+}
+
+fn parse_step(segment: &str, descendants: bool) -> Result<Step> {
+    if let Some(name) = segment.strip_prefix('@') {
+        if descendants {
+            bail!("'//@{}' is not supported; select elements first", name);
+        }
+        return Ok(Step::Attribute(name.to_string()));
+    }
+    if segment == "text()" {
+        return Ok(Step::Text);
+    }
+
+    let name_end = segment.find('[').unwrap_or(segment.len());
+    let name = &segment[..name_end];
+    if name.is_empty() || name.contains([']', '@', '(']) {
+        bail!("unsupported step '{}'", segment);
+    }
+
+    let mut predicates = Vec::new();
+    let mut rest = &segment[name_end..];
+    while !rest.is_empty() {
+        let close = rest
+            .find(']')
+            .filter(|_| rest.starts_with('['))
+            .with_context(|| format!("unbalanced brackets in '{}'", segment))?;
+        predicates.push(parse_predicate(&rest[1..close])?);
+        rest = &rest[close + 1..];
+    }
+
+    Ok(Step::Elements {
+        name: name.to_string(),
+        descendants,
+        predicates,
+    })
+}
+
+fn parse_predicate(text: &str) -> Result<Predicate> {
+    let text = text.trim();
+    if let Ok(n) = text.parse::<usize>() {
+        if n == 0 {
+            bail!("positions start at 1");
+        }
+        return Ok(Predicate::Position(n));
+    }
+    let Some(attr) = text.strip_prefix('@') else {
+        bail!("unsupported predicate '[{}]'", text);
+    };
+    match attr.split_once('=') {
+        None => Ok(Predicate::HasAttribute(attr.trim().to_string())),
+        Some((name, value)) => {
+            let value = value.trim();
+            let unquoted = value
+                .strip_prefix('\'')
+                .and_then(|v| v.strip_suffix('\''))
+                .or_else(|| value.strip_prefix('"').and_then(|v| v.strip_suffix('"')))
+                .with_context(|| format!("quote the value in '[{}]'", text))?;
+            Ok(Predicate::AttributeEquals(
+                name.trim().to_string(),
+                unquoted.to_string(),
+            ))
+        }
+    }
+}
+
+/// A query result.
+#[derive(Debug, PartialEq, Eq)]
+enum Match<'a> {
+    Element(&'a Element),
+    Value(String),
+}
+
+/// Run `steps` against the document node, returning matches in document order.
+fn evaluate<'a>(steps: &[Step], document: &'a Element) -> Vec<Match<'a>> {
+    let mut context = vec![document];
+
+    for step in steps {
+        match step {
+            Step::Elements {
+                name,
+                descendants,
+                predicates,
+            } => {
+                let mut next: Vec<&Element> = Vec::new();
+                for element in context {
+                    let mut parents = vec![element];
+                    if *descendants {
+                        collect_descendants(element, &mut parents);
+                    }
+                    for parent in parents {
+                        for child in select_children(parent, name, predicates) {
+                            if !next.iter().any(|seen| std::ptr::eq(*seen, child)) {
+                                next.push(child);
+                            }
+                        }
+                    }
+                }
+                context = next;
+            }
+            Step::Attribute(name) => {
+                return context
+                    .iter()
+                    .flat_map(|element| {
+                        element
+                            .attributes
+                            .iter()
+                            .filter(|(key, _)| name == "*" || key == name)
+                            .map(|(_, value)| Match::Value(value.clone()))
+                    })
+                    .collect();
+            }
+            Step::Text => {
+                return context
+                    .iter()
+                    .map(|element| Match::Value(element.text()))
+                    .collect();
+            }
+        }
+    }
+
+    context.into_iter().map(Match::Element).collect()
+}
+
+fn collect_descendants<'a>(element: &'a Element, out: &mut Vec<&'a Element>) {
+    for child in element.elements() {
+        out.push(child);
+        collect_descendants(child, out);
+    }
+}
+
+fn select_children<'a>(
+    parent: &'a Element,
+    name: &str,
+    predicates: &[Predicate],
+) -> Vec<&'a Element> {
+    let mut selected: Vec<&Element> = parent
+        .elements()
+        .filter(|child| name == "*" || child.name == name)
+        .collect();
+    for predicate in predicates {
+        selected = match predicate {
+            Predicate::Position(n) => selected.get(n - 1).copied().into_iter().collect(),
+            Predicate::HasAttribute(attr) => selected
+                .into_iter()
+                .filter(|child| child.attribute(attr).is_some())
+                .collect(),
+            Predicate::AttributeEquals(attr, value) => selected
+                .into_iter()
+                .filter(|child| child.attribute(attr) == Some(value.as_str()))
+                .collect(),
+        };
+    }
+    selected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CATALOG: &str = r#"<?xml version="1.0"?>
+<catalog>
+  <item id="a1" kind="book"><title>Rust &amp; You</title></item>
+  <item id="b2"><title>XML</title><tags><tag>old</tag></tags></item>
+  <item id="c3" kind="book"><title><![CDATA[<raw>]]></title></item>
+</catalog>
+"#;
+
+    fn values(path: &str, xml: &str) -> Vec<String> {
+        let document = parse_tree(xml).unwrap();
+        evaluate(&parse_query(path).unwrap(), &document)
+            .into_iter()
+            .map(|m| match m {
+                Match::Value(value) => value,
+                Match::Element(element) => element.name.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_format_compact_document() {
+        let formatted = format_xml(r#"<root><item id="1">test</item><empty/></root>"#, 2).unwrap();
+        assert_eq!(
+            formatted,
+            "<root>\n  <item id=\"1\">test</item>\n  <empty/>\n</root>"
+        );
+        assert_eq!(format_xml("<a><b/></a>", 4).unwrap(), "<a>\n    <b/>\n</a>");
+    }
+
+    #[test]
+    fn test_format_keeps_text_around_entities() {
+        assert_eq!(
+            format_xml("<a>  x &amp; y  </a>", 2).unwrap(),
+            "<a>x &amp; y</a>"
+        );
+    }
+
+    #[test]
+    fn test_malformed_reports_line() {
+        let err = format_xml("<root>\n  <item>\n  </wrong>\n</root>", 2).unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{}", err);
+
+        let err = parse_tree("<root>\n<item>\n").unwrap_err();
+        assert!(err.to_string().contains("line 3"), "{}", err);
+
+        let err = parse_tree("<a/>\n<b/>").unwrap_err();
+        assert!(err.to_string().contains("multiple root elements"));
+    }
+
+    #[test]
+    fn test_line_col() {
+        assert_eq!(line_col("abc", 0), (1, 1));
+        assert_eq!(line_col("ab\ncd", 4), (2, 2));
+        assert_eq!(line_col("é\nx", 99), (2, 2));
+    }
+
+    #[test]
+    fn test_query_attribute() {
+        assert_eq!(values("/catalog/item/@id", CATALOG), ["a1", "b2", "c3"]);
+        assert_eq!(values("/catalog/item[2]/@id", CATALOG), ["b2"]);
+        assert_eq!(values("/catalog/item[1]/@*", CATALOG), ["a1", "book"]);
+        assert!(values("/catalog/item/@missing", CATALOG).is_empty());
+    }
+
+    #[test]
+    fn test_query_predicates_and_text() {
+        assert_eq!(
+            values("/catalog/item[@kind='book']/title/text()", CATALOG),
+            ["Rust & You", "<raw>"]
+        );
+        assert_eq!(values("/catalog/item[@kind]/@id", CATALOG), ["a1", "c3"]);
+        assert_eq!(
+            values("catalog/*[@id=\"b2\"]/tags/tag/text()", CATALOG),
+            ["old"]
+        );
+    }
+
+    #[test]
+    fn test_query_descendants() {
+        assert_eq!(
+            values("//title/text()", CATALOG),
+            ["Rust & You", "XML", "<raw>"]
+        );
+        assert_eq!(values("/catalog//tag", CATALOG), ["tag"]);
+        assert_eq!(values("//item[1]/@id", CATALOG), ["a1"]);
+    }
+
+    #[test]
+    fn test_parse_query_errors() {
+        assert!(parse_query("/a/@id/b").is_err());
+        assert!(parse_query("/a//").is_err());
+        assert!(parse_query("/a[0]").is_err());
+        assert!(parse_query("/a[@id=x]").is_err());
+        assert!(parse_query("/a[last()]").is_err());
+    }
+
+    #[test]
+    fn test_write_element() {
+        let document = parse_tree(CATALOG).unwrap();
+        let matches = evaluate(&parse_query("/catalog/item[2]").unwrap(), &document);
+        let Match::Element(item) = matches[0] else {
+            panic!("expected an element");
+        };
+        let mut out = String::new();
+        write_element(item, 0, &mut out);
+        assert_eq!(
+            out,
+            "<item id=\"b2\">\n  <title>XML</title>\n  <tags>\n    <tag>old</tag>\n  </tags>\n</item>\n"
+        );
+    }
+}
@@ -1,8 +1,14 @@
 //! YAML command - YAML utilities.
+//!
+//! Documents go through `serde_json::Value`, which keeps mapping keys in
+//! their original order. Anchors, aliases, and `<<` merge keys are expanded
+//! while parsing, and a stream of several `---` documents is handled as a
+//! list of values.
 
 use crate::cli::commands::yaml::{YamlArgs, YamlCommand};
 use anyhow::{Context, Result};
 use colored::Colorize;
+use serde_json::Value;
 use std::fs;
 use std::io::{self, Read};
 use std::path::PathBuf;
@@ -13,7 +19,7 @@ pub fn run(args: YamlArgs) -> Result<()> {
         YamlCommand::Format { input } => cmd_format(input),
         YamlCommand::Validate { input, quiet } => cmd_validate(input, quiet),
         YamlCommand::ToJson { input, pretty } => cmd_to_json(input, pretty),
-        YamlCommand::FromJson { input } => cmd_from_json(input),
+        YamlCommand::FromJson { input, documents } => cmd_from_json(input, documents),
     }
 }
 
@@ -41,11 +47,9 @@ fn read_input(input: Option<PathBuf>) -> Result<String> {
 fn cmd_format(input: Option<PathBuf>) -> Result<()> {
     let content = read_input(input)?;
 
-    // Parse and re-serialize to format (using serde_json::Value as intermediate)
-    let value: serde_json::Value =
-        serde_saphyr::from_str(&content).context("Failed to parse YAML")?;
-
-    let output = serde_saphyr::to_string(&value).context("Failed to serialize YAML")?;
+    let documents = parse_documents(&content)?;
+    let output =
+        serde_saphyr::to_string_multiple(&documents).context("Failed to serialize YAML")?;
     print!("{}", output);
     Ok(())
 }
@@ -53,7 +57,7 @@ fn cmd_format(input: Option<PathBuf>) -> Result<()> {
 fn cmd_validate(input: Option<PathBuf>, quiet: bool) -> Result<()> {
     let content = read_input(input)?;
 
-    match serde_saphyr::from_str::<serde_json::Value>(&content) {
+    match parse_documents(&content) {
         Ok(_) => {
             if !quiet {
                 println!("{}", "Valid YAML".green());
@@ -62,7 +66,7 @@ fn cmd_validate(input: Option<PathBuf>, quiet: bool) -> Result<()> {
         }
         Err(e) => {
             if !quiet {
-                eprintln!("{}: {}", "Invalid YAML".red(), e);
+                eprintln!("{}: {:#}", "Invalid YAML".red(), e);
             }
             anyhow::bail!("Invalid YAML syntax")
         }
@@ -71,12 +75,8 @@ fn cmd_validate(input: Option<PathBuf>, quiet: bool) -> Result<()> {
 
 fn cmd_to_json(input: Option<PathBuf>, pretty: bool) -> Result<()> {
     let content = read_input(input)?;
+    let value = yaml_to_json(&content)?;
 
-    // Parse YAML
-    let value: serde_json::Value =
-        serde_saphyr::from_str(&content).context("Failed to parse YAML")?;
-
-    // Output as JSON
     let output = if pretty {
         serde_json::to_string_pretty(&value)?
     } else {
@@ -87,15 +87,105 @@ fn cmd_to_json(input: Option<PathBuf>, pretty: bool) -> Result<()> {
     Ok(())
 }
 
-fn cmd_from_json(input: Option<PathBuf>) -> Result<()> {
+fn cmd_from_json(input: Option<PathBuf>, documents: bool) -> Result<()> {
     let content = read_input(input)?;
+    print!("{}", json_to_yaml(&content, documents)?);
+    Ok(())
+}
 
-    // Parse JSON
-    let value: serde_json::Value =
-        serde_json::from_str(&content).context("Failed to parse JSON")?;
+/// Parse every document in a YAML stream.
+fn parse_documents(content: &str) -> Result<Vec<Value>> {
+    serde_saphyr::from_multiple(content).context("Failed to parse YAML")
+}
 
-    // Output as YAML
-    let output = serde_saphyr::to_string(&value).context("Failed to serialize YAML")?;
-    print!("{}", output);
-    Ok(())
+/// Convert YAML to a JSON value.
+///
+/// A single document becomes its value, a multi-document stream becomes
+/// an array with one element per document, and an empty stream is `null`.
+fn yaml_to_json(content: &str) -> Result<Value> {
+    let mut documents = parse_documents(content)?;
+    Ok(match documents.len() {
+        0 => Value::Null,
+        1 => documents.remove(0),
+        _ => Value::Array(documents),
+    })
+}
+
+/// Convert JSON to YAML, optionally writing a top-level array as one
+/// document per element.
+fn json_to_yaml(content: &str, documents: bool) -> Result<String> {
+    let value: Value = serde_json::from_str(content).context("Failed to parse JSON")?;
+
+    let yaml = match value {
+        Value::Array(items) if documents => serde_saphyr::to_string_multiple(&items),
+        value => serde_saphyr::to_string(&value),
+    };
+    yaml.context("Failed to serialize YAML")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip_preserves_order() {
+        let yaml = "zebra: 1\napple:\n  name: x\n  tags:\n    - b\n    - a\nmango: true\n";
+        let value = yaml_to_json(yaml).unwrap();
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(
+            json,
+            r#"{"zebra":1,"apple":{"name":"x","tags":["b","a"]},"mango":true}"#
+        );
+
+        let back = json_to_yaml(&json, false).unwrap();
+        assert_eq!(yaml_to_json(&back).unwrap(), value);
+        let keys: Vec<&str> = back
+            .lines()
+            .filter(|line| !line.starts_with(' '))
+            .map(|line| line.split(':').next().unwrap())
+            .collect();
+        assert_eq!(keys, ["zebra", "apple", "mango"]);
+    }
+
+    #[test]
+    fn test_anchor_alias_expanded() {
+        let yaml = "base: &base\n  host: db\n  port: 5432\ncopy: *base\n\
+                    override:\n  <<: *base\n  port: 6543\n";
+        let value = yaml_to_json(yaml).unwrap();
+        assert_eq!(value["copy"], json!({ "host": "db", "port": 5432 }));
+        assert_eq!(value["override"], json!({ "host": "db", "port": 6543 }));
+    }
+
+    #[test]
+    fn test_multi_document_stream() {
+        let value = yaml_to_json("a: 1\n---\na: 2\n---\n- x\n").unwrap();
+        assert_eq!(value, json!([{ "a": 1 }, { "a": 2 }, ["x"]]));
+
+        // A single document that is a list stays a list
+        assert_eq!(yaml_to_json("- 1\n- 2\n").unwrap(), json!([1, 2]));
+        assert_eq!(yaml_to_json("").unwrap(), Value::Null);
+    }
+
+    #[test]
+    fn test_from_json_documents() {
+        let yaml = json_to_yaml(r#"[{"a":1},{"a":2}]"#, true).unwrap();
+        assert_eq!(yaml, "a: 1\n---\na: 2\n");
+        assert_eq!(
+            yaml_to_json(&yaml).unwrap(),
+            json!([{ "a": 1 }, { "a": 2 }])
+        );
+
+        let single = json_to_yaml(r#"[{"a":1},{"a":2}]"#, false).unwrap();
+        assert_eq!(
+            yaml_to_json(&single).unwrap(),
+            json!([{ "a": 1 }, { "a": 2 }])
+        );
+    }
+
+    #[test]
+    fn test_invalid_yaml() {
+        assert!(yaml_to_json("key: [unclosed\n").is_err());
+        assert!(json_to_yaml("{not json", false).is_err());
+    }
 }
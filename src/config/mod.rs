@@ -1,5 +1,6 @@
 //! Configuration management.
 
+pub mod schema;
 mod settings;
 
 pub use settings::Settings;
@@ -0,0 +1,301 @@
+//! # Config Schema
+//!
+//! A typed description of the built-in configuration keys, used by
+//! `dx config check` to validate a config file before it is loaded.
+//!
+//! Loading with serde either fails on the first wrong type or silently
+//! ignores unknown keys in a section. The validator instead walks the raw
+//! TOML table and reports every problem with its full key path:
+//!
+//! ```text
+//! hash.algorithm: expected one of md5, sha256, sha512, blake3, bcrypt, argon2, got "sha1"
+//! output.json_indent: must be between 0 and 8, got 12
+//! profiles.prod.general.color: expected a boolean, got string
+//! ```
+//!
+//! Top-level keys outside the known sections are custom keys and are always
+//! accepted. Profiles (`[profiles.<name>]`) are checked against the same
+//! schema.
+
+use std::fmt;
+
+/// The type a config value must have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// `true` or `false`
+    Bool,
+    /// An integer in an inclusive range
+    Integer { min: i64, max: i64 },
+    /// One of a fixed set of strings
+    Enum(&'static [&'static str]),
+}
+
+/// One built-in key and its type.
+#[derive(Debug, Clone, Copy)]
+pub struct KeySpec {
+    /// Dot-separated key, e.g. `hash.algorithm`
+    pub key: &'static str,
+    pub kind: ValueKind,
+}
+
+/// Hash algorithms accepted by `hash.algorithm` (same as `dx hash --algo`).
+pub const HASH_ALGORITHMS: &[&str] = &["md5", "sha256", "sha512", "blake3", "bcrypt", "argon2"];
+
+/// Output formats accepted by `general.output_format`.
+pub const OUTPUT_FORMATS: &[&str] = &["text", "json"];
+
+/// All built-in keys.
+pub const SCHEMA: &[KeySpec] = &[
+    KeySpec {
+        key: "general.output_format",
+        kind: ValueKind::Enum(OUTPUT_FORMATS),
+    },
+    KeySpec {
+        key: "general.color",
+        kind: ValueKind::Bool,
+    },
+    KeySpec {
+        key: "hash.algorithm",
+        kind: ValueKind::Enum(HASH_ALGORITHMS),
+    },
+    KeySpec {
+        key: "output.json_indent",
+        kind: ValueKind::Integer { min: 0, max: 8 },
+    },
+];
+
+/// A single validation problem.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// Full key path, e.g. `profiles.prod.hash.algorithm`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Parse and validate config file contents.
+///
+/// A TOML syntax error is returned as a single problem at the root.
+pub fn validate_str(content: &str) -> Vec<Problem> {
+    match content.parse::<toml::Table>() {
+        Ok(table) => validate(&table),
+        Err(e) => {
+            let message = match e.span() {
+                Some(span) => {
+                    let line = content[..span.start].matches('\n').count() + 1;
+                    format!("line {}: {}", line, e.message())
+                }
+                None => e.message().to_string(),
+            };
+            vec![Problem {
+                path: "<file>".to_string(),
+                message,
+            }]
+        }
+    }
+}
+
+/// Validate a parsed config table.
+pub fn validate(table: &toml::Table) -> Vec<Problem> {
+    let mut problems = Vec::new();
+    check_settings(table, "", &mut problems);
+
+    match table.get("profiles") {
+        None => {}
+        Some(toml::Value::Table(profiles)) => {
+            for (name, profile) in profiles {
+                let path = format!("profiles.{}", name);
+                match profile {
+                    toml::Value::Table(profile) => {
+                        check_settings(profile, &format!("{}.", path), &mut problems)
+                    }
+                    other => problems.push(Problem {
+                        path,
+                        message: format!("expected a table, got {}", other.type_str()),
+                    }),
+                }
+            }
+        }
+        Some(other) => problems.push(Problem {
+            path: "profiles".to_string(),
+            message: format!("expected a table, got {}", other.type_str()),
+        }),
+    }
+    problems
+}
+
+/// Check the built-in sections of `table`, prefixing paths with `prefix`.
+fn check_settings(table: &toml::Table, prefix: &str, problems: &mut Vec<Problem>) {
+    for section in sections() {
+        let Some(value) = table.get(section) else {
+            continue;
+        };
+        let path = format!("{}{}", prefix, section);
+        let Some(section_table) = value.as_table() else {
+            problems.push(Problem {
+                path,
+                message: format!("expected a table, got {}", value.type_str()),
+            });
+            continue;
+        };
+
+        for (key, value) in section_table {
+            let full_key = format!("{}.{}", section, key);
+            let path = format!("{}{}", prefix, full_key);
+            match SCHEMA.iter().find(|spec| spec.key == full_key) {
+                Some(spec) => {
+                    if let Some(message) = check_value(spec.kind, value) {
+                        problems.push(Problem { path, message });
+                    }
+                }
+                None => problems.push(Problem {
+                    path,
+                    message: "unknown key".to_string(),
+                }),
+            }
+        }
+    }
+}
+
+/// Section names that appear in the schema, in schema order.
+fn sections() -> Vec<&'static str> {
+    let mut sections: Vec<&'static str> = Vec::new();
+    for spec in SCHEMA {
+        let section = spec.key.split('.').next().unwrap_or(spec.key);
+        if !sections.contains(&section) {
+            sections.push(section);
+        }
+    }
+    sections
+}
+
+/// Describe why `value` doesn't match `kind`, or `None` if it does.
+fn check_value(kind: ValueKind, value: &toml::Value) -> Option<String> {
+    match (kind, value) {
+        (ValueKind::Bool, toml::Value::Boolean(_)) => None,
+        (ValueKind::Integer { min, max }, toml::Value::Integer(n)) => {
+            if (min..=max).contains(n) {
+                None
+            } else {
+                Some(format!("must be between {} and {}, got {}", min, max, n))
+            }
+        }
+        (ValueKind::Enum(allowed), toml::Value::String(s)) => {
+            if allowed.contains(&s.as_str()) {
+                None
+            } else {
+                Some(format!(
+                    "expected one of {}, got \"{}\"",
+                    allowed.join(", "),
+                    s
+                ))
+            }
+        }
+        (kind, value) => {
+            let expected = match kind {
+                ValueKind::Bool => "a boolean",
+                ValueKind::Integer { .. } => "an integer",
+                ValueKind::Enum(_) => "a string",
+            };
+            Some(format!("expected {}, got {}", expected, value.type_str()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_config_passes() {
+        let content = r#"
+my_custom_key = "anything"
+
+[general]
+output_format = "json"
+color = false
+
+[hash]
+algorithm = "blake3"
+
+[output]
+json_indent = 4
+
+[profiles.prod.hash]
+algorithm = "sha512"
+"#;
+        assert_eq!(validate_str(content), Vec::new());
+    }
+
+    #[test]
+    fn test_defaults_pass() {
+        let content = toml::to_string(&crate::config::Settings::default()).unwrap();
+        assert_eq!(validate_str(&content), Vec::new());
+    }
+
+    #[test]
+    fn test_wrong_type_and_out_of_range_are_reported() {
+        let content = r#"
+[general]
+color = "yes"
+verbose = true
+
+[hash]
+algorithm = "sha1"
+
+[output]
+json_indent = 12
+
+[profiles.prod.output]
+json_indent = "wide"
+"#;
+        let problems: Vec<String> = validate_str(content)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            problems,
+            vec![
+                "general.color: expected a boolean, got string",
+                "general.verbose: unknown key",
+                "hash.algorithm: expected one of md5, sha256, sha512, blake3, bcrypt, argon2, \
+                 got \"sha1\"",
+                "output.json_indent: must be between 0 and 8, got 12",
+                "profiles.prod.output.json_indent: expected an integer, got string",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_section_and_profile_shapes() {
+        let problems = validate_str("hash = \"md5\"\nprofiles = 3\n");
+        let paths: Vec<&str> = problems.iter().map(|p| p.path.as_str()).collect();
+        assert_eq!(paths, ["hash", "profiles"]);
+    }
+
+    #[test]
+    fn test_syntax_error_is_a_problem() {
+        let problems = validate_str("[general\ncolor = true");
+        assert_eq!(problems.len(), 1);
+        assert_eq!(problems[0].path, "<file>");
+        assert!(problems[0].message.starts_with("line 1:"));
+    }
+
+    #[test]
+    fn test_hash_algorithms_match_cli() {
+        use crate::cli::commands::hash::Algorithm;
+        use clap::ValueEnum;
+
+        let cli: Vec<String> = Algorithm::value_variants()
+            .iter()
+            .filter_map(|a| a.to_possible_value())
+            .map(|v| v.get_name().to_string())
+            .collect();
+        assert_eq!(cli, HASH_ALGORITHMS);
+    }
+}
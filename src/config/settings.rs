@@ -28,8 +28,22 @@
 //!
 //! # Custom keys are also supported
 //! my_custom_key = "value"
+//!
+//! # Named profiles override any of the keys above
+//! [profiles.prod.hash]
+//! algorithm = "sha512"
 //! ```
 //!
+//! ## Resolution Order
+//!
+//! [`Settings::resolve`] looks a key up in three layers, first match wins:
+//!
+//! 1. Environment variable `DX_<KEY>` (`hash.algorithm` → `DX_HASH_ALGORITHM`)
+//! 2. The selected profile (`--profile prod`)
+//! 3. The base settings, which fall back to built-in defaults
+//!
+//! `DX_CONFIG_DIR` replaces the platform config directory.
+//!
 //! ## Key Concepts
 //!
 //! ### Serde Attributes
@@ -67,11 +81,13 @@
 //! - TOML specification: <https://toml.io/>
 //! - Serde attributes: <https://serde.rs/attributes.html>
 
+use crate::utils::write_atomic;
 use anyhow::{Context, Result};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fs;
+use std::io::Write;
 use std::path::PathBuf;
 
 // =============================================================================
@@ -112,6 +128,16 @@ pub struct Settings {
     #[serde(default)]
     pub output: OutputSettings,
 
+    /// Named profiles, each a table of overrides keyed like the base settings.
+    ///
+    /// Example TOML:
+    /// ```toml
+    /// [profiles.prod.hash]
+    /// algorithm = "sha512"
+    /// ```
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, toml::Table>,
+
     /// Custom key-value settings.
     ///
     /// The `#[serde(flatten)]` attribute merges these into the root level.
@@ -265,14 +291,18 @@ impl Settings {
     pub fn config_path() -> Result<PathBuf> {
         // ProjectDirs::from returns Option because it can fail
         // if the home directory can't be determined
-        let proj_dirs =
-            ProjectDirs::from("com", "dx", "dx").context("Could not determine config directory")?;
-
-        let config_dir = proj_dirs.config_dir();
+        // DX_CONFIG_DIR takes precedence over the platform directory
+        let config_dir = match std::env::var_os("DX_CONFIG_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => ProjectDirs::from("com", "dx", "dx")
+                .context("Could not determine config directory")?
+                .config_dir()
+                .to_path_buf(),
+        };
 
         // Create the config directory if it doesn't exist.
         // create_dir_all is idempotent - safe to call if dir exists.
-        fs::create_dir_all(config_dir).context("Failed to create config directory")?;
+        fs::create_dir_all(&config_dir).context("Failed to create config directory")?;
 
         Ok(config_dir.join("config.toml"))
     }
@@ -331,9 +361,10 @@ impl Settings {
         // Serialize to pretty TOML format
         let content = toml::to_string_pretty(self).context("Failed to serialize config")?;
 
-        // Write atomically (well, as atomic as a simple write can be)
-        // For true atomicity, you'd write to a temp file then rename
-        fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+        // Write to a temp file and rename it over the config, so a crash
+        // mid-write can't leave a truncated config behind
+        write_atomic(&path, |writer| Ok(writer.write_all(content.as_bytes())?))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
 
         Ok(())
     }
@@ -366,11 +397,20 @@ impl Settings {
             ["general", "color"] => Some(self.general.color.to_string()),
             ["hash", "algorithm"] => Some(self.hash.algorithm.clone()),
             ["output", "json_indent"] => Some(self.output.json_indent.to_string()),
-            // Fall back to custom keys for anything else
-            _ => self.custom.get(key).map(|v| v.to_string()),
+            // Fall back to custom keys for anything else, either dotted
+            // (`"dhis2.server" = ...`) or in a table (`[dhis2] server = ...`)
+            _ => self.custom_value(key).map(display_value),
         }
     }
 
+    fn custom_value(&self, key: &str) -> Option<&toml::Value> {
+        if let Some(value) = self.custom.get(key) {
+            return Some(value);
+        }
+        let (head, rest) = key.split_once('.')?;
+        lookup(self.custom.get(head)?.as_table()?, rest)
+    }
+
     /// Set a value by key using dot-notation.
     ///
     /// # Path Syntax
@@ -432,6 +472,119 @@ impl Settings {
         Ok(())
     }
 
+    /// Resolve a key through environment, profile, and base settings.
+    ///
+    /// # Precedence
+    ///
+    /// 1. `DX_<KEY>` environment variable (see [`Settings::env_var_name`])
+    /// 2. The value in `profile`, if one is selected
+    /// 3. The base value from [`Settings::get`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `profile` names a profile that doesn't exist.
+    pub fn resolve(&self, key: &str, profile: Option<&str>) -> Result<Option<String>> {
+        self.resolve_with_env(key, profile, |name| std::env::var(name).ok())
+    }
+
+    /// Same as `resolve()`, with environment lookups going through `env`.
+    fn resolve_with_env(
+        &self,
+        key: &str,
+        profile: Option<&str>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Result<Option<String>> {
+        if let Some(value) = env(&Self::env_var_name(key)) {
+            return Ok(Some(value));
+        }
+        if let Some(name) = profile {
+            if let Some(value) = lookup(self.profile(name)?, key) {
+                return Ok(Some(display_value(value)));
+            }
+        }
+        Ok(self.get(key))
+    }
+
+    /// Environment variable that overrides `key`.
+    ///
+    /// The key is uppercased and dots/dashes become underscores:
+    /// `hash.algorithm` → `DX_HASH_ALGORITHM`.
+    pub fn env_var_name(key: &str) -> String {
+        format!("DX_{}", key.to_uppercase().replace(['.', '-'], "_"))
+    }
+
+    /// Get a profile's table of overrides.
+    fn profile(&self, name: &str) -> Result<&toml::Table> {
+        self.profiles
+            .get(name)
+            .with_context(|| format!("Profile '{}' not found", name))
+    }
+
+    /// Set a value in a profile, creating the profile if needed.
+    ///
+    /// Dotted keys become nested tables (`hash.algorithm` is stored under
+    /// `[profiles.<name>.hash]`). Built-in keys are type-checked the same way
+    /// as `set()`.
+    pub fn set_in_profile(&mut self, profile: &str, key: &str, value: &str) -> Result<()> {
+        let value = typed_value(key, value)?;
+        let mut table = self.profiles.entry(profile.to_string()).or_default();
+        let mut parts = key.split('.').peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                table.insert(part.to_string(), value);
+                break;
+            }
+            let entry = table
+                .entry(part)
+                .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+            if !entry.is_table() {
+                *entry = toml::Value::Table(toml::Table::new());
+            }
+            table = entry.as_table_mut().expect("entry was just made a table");
+        }
+        Ok(())
+    }
+
+    /// Remove a value from a profile.
+    pub fn unset_in_profile(&mut self, profile: &str, key: &str) -> Result<()> {
+        let table = self
+            .profiles
+            .get_mut(profile)
+            .with_context(|| format!("Profile '{}' not found", profile))?;
+        if table.remove(key).is_none() {
+            if let Some((parent, leaf)) = key.rsplit_once('.') {
+                if let Some(toml::Value::Table(parent)) = lookup_mut(table, parent) {
+                    parent.remove(leaf);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// List settings with values resolved for `profile` and the environment.
+    ///
+    /// Keys that only exist in the profile are appended after the base keys.
+    pub fn list_resolved(&self, profile: Option<&str>) -> Result<Vec<(String, String)>> {
+        let mut keys: Vec<String> = self.list().into_iter().map(|(k, _)| k).collect();
+        if let Some(name) = profile {
+            let mut profile_keys = Vec::new();
+            flatten_keys("", self.profile(name)?, &mut profile_keys);
+            for key in profile_keys {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+
+        let mut result = Vec::with_capacity(keys.len());
+        for key in keys {
+            if let Some(value) = self.resolve(&key, profile)? {
+                result.push((key, value));
+            }
+        }
+        Ok(result)
+    }
+
     /// List all settings as key-value pairs.
     ///
     /// Returns a vector of (key, value) tuples for all settings,
@@ -472,6 +625,62 @@ impl Settings {
     }
 }
 
+// =============================================================================
+// PROFILE HELPERS
+// =============================================================================
+
+/// Find a dotted key in a table, trying the literal key before nested tables.
+fn lookup<'a>(table: &'a toml::Table, key: &str) -> Option<&'a toml::Value> {
+    if let Some(value) = table.get(key) {
+        return Some(value);
+    }
+    let (head, rest) = key.split_once('.')?;
+    lookup(table.get(head)?.as_table()?, rest)
+}
+
+fn lookup_mut<'a>(table: &'a mut toml::Table, key: &str) -> Option<&'a mut toml::Value> {
+    if table.contains_key(key) {
+        return table.get_mut(key);
+    }
+    let (head, rest) = key.split_once('.')?;
+    lookup_mut(table.get_mut(head)?.as_table_mut()?, rest)
+}
+
+/// Collect the dotted paths of all non-table values.
+fn flatten_keys(prefix: &str, table: &toml::Table, out: &mut Vec<String>) {
+    for (key, value) in table {
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            toml::Value::Table(inner) => flatten_keys(&path, inner, out),
+            _ => out.push(path),
+        }
+    }
+}
+
+/// Strings are shown without quotes; other values use TOML syntax.
+fn display_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Parse a value for a built-in key into its TOML type.
+fn typed_value(key: &str, value: &str) -> Result<toml::Value> {
+    Ok(match key {
+        "general.color" => toml::Value::Boolean(value.parse().context("Invalid boolean value")?),
+        "output.json_indent" => {
+            let indent: usize = value.parse().context("Invalid number")?;
+            toml::Value::Integer(indent as i64)
+        }
+        _ => toml::Value::String(value.to_string()),
+    })
+}
+
 // =============================================================================
 // TESTS
 // =============================================================================
@@ -497,11 +706,132 @@ mod tests {
         assert_eq!(settings.get("hash.algorithm"), Some("md5".to_string()));
     }
 
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    /// Test env var names derived from keys.
+    #[test]
+    fn test_env_var_name() {
+        assert_eq!(
+            Settings::env_var_name("hash.algorithm"),
+            "DX_HASH_ALGORITHM"
+        );
+        assert_eq!(Settings::env_var_name("api-url"), "DX_API_URL");
+    }
+
+    /// Test that an environment variable beats a profile value.
+    #[test]
+    fn test_env_overrides_profile() {
+        let mut settings = Settings::default();
+        settings
+            .set_in_profile("prod", "hash.algorithm", "sha512")
+            .unwrap();
+        let env = |name: &str| (name == "DX_HASH_ALGORITHM").then(|| "md5".to_string());
+
+        let resolved = settings
+            .resolve_with_env("hash.algorithm", Some("prod"), env)
+            .unwrap();
+        assert_eq!(resolved.as_deref(), Some("md5"));
+    }
+
+    /// Test that switching profiles changes the resolved value.
+    #[test]
+    fn test_profiles_change_resolved_value() {
+        let mut settings = Settings::default();
+        settings
+            .set_in_profile("dev", "api_url", "http://localhost")
+            .unwrap();
+        settings
+            .set_in_profile("prod", "api_url", "https://api.example.com")
+            .unwrap();
+        settings
+            .set_in_profile("prod", "hash.algorithm", "sha512")
+            .unwrap();
+
+        let get = |profile| {
+            settings
+                .resolve_with_env("api_url", profile, no_env)
+                .unwrap()
+        };
+        assert_eq!(get(Some("dev")).as_deref(), Some("http://localhost"));
+        assert_eq!(
+            get(Some("prod")).as_deref(),
+            Some("https://api.example.com")
+        );
+        assert_eq!(get(None), None);
+
+        // Keys missing from a profile fall back to the base settings
+        let algo = |profile| {
+            settings
+                .resolve_with_env("hash.algorithm", profile, no_env)
+                .unwrap()
+        };
+        assert_eq!(algo(Some("prod")).as_deref(), Some("sha512"));
+        assert_eq!(algo(Some("dev")).as_deref(), Some("sha256"));
+        assert!(settings
+            .resolve_with_env("hash.algorithm", Some("staging"), no_env)
+            .is_err());
+    }
+
+    /// Test that profiles round-trip through TOML as nested tables.
+    #[test]
+    fn test_profiles_toml_round_trip() {
+        let mut settings = Settings::default();
+        settings
+            .set_in_profile("prod", "general.color", "false")
+            .unwrap();
+        settings.set("my_key", "x").unwrap();
+        assert!(settings
+            .set_in_profile("prod", "general.color", "maybe")
+            .is_err());
+
+        let toml = toml::to_string_pretty(&settings).unwrap();
+        assert!(toml.contains("[profiles.prod.general]"));
+        let parsed: Settings = toml::from_str(&toml).unwrap();
+        assert_eq!(
+            parsed
+                .resolve_with_env("general.color", Some("prod"), no_env)
+                .unwrap()
+                .as_deref(),
+            Some("false")
+        );
+        assert!(parsed.custom.contains_key("my_key"));
+        assert!(!parsed.custom.contains_key("profiles"));
+    }
+
+    /// Test removing a nested profile value.
+    #[test]
+    fn test_unset_in_profile() {
+        let mut settings = Settings::default();
+        settings
+            .set_in_profile("prod", "hash.algorithm", "sha512")
+            .unwrap();
+        settings.unset_in_profile("prod", "hash.algorithm").unwrap();
+        assert_eq!(
+            settings
+                .resolve_with_env("hash.algorithm", Some("prod"), no_env)
+                .unwrap()
+                .as_deref(),
+            Some("sha256")
+        );
+    }
+
     /// Test custom keys are stored and retrieved correctly.
     #[test]
     fn test_custom_keys() {
         let mut settings = Settings::default();
         settings.set("my.custom.key", "value").unwrap();
-        assert!(settings.get("my.custom.key").is_some());
+        assert_eq!(settings.get("my.custom.key").as_deref(), Some("value"));
+
+        let settings: Settings =
+            toml::from_str("[dhis2]\nserver = \"https://dhis2.example.org\"\nport = 8080\n")
+                .unwrap();
+        assert_eq!(
+            settings.get("dhis2.server").as_deref(),
+            Some("https://dhis2.example.org")
+        );
+        assert_eq!(settings.get("dhis2.port").as_deref(), Some("8080"));
+        assert_eq!(settings.get("dhis2.user"), None);
     }
 }
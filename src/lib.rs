@@ -4,8 +4,8 @@
 //!
 //! This library provides:
 //! - CLI argument parsing with clap
-//! - File hashing (MD5, SHA256, SHA512)
-//! - Base64/hex encoding and decoding
+//! - File hashing (MD5, SHA256, SHA512, BLAKE3)
+//! - Base64/Base32/Base58/hex encoding and decoding
 //! - UUID generation (v4, v7)
 //! - Timestamp conversions
 //! - JSON formatting and validation
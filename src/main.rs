@@ -53,6 +53,7 @@ use anyhow::Result;
 use clap::Parser;
 use rust_cli_complete::cli::{Cli, Commands};
 use rust_cli_complete::commands;
+use rust_cli_complete::utils::{print_error, set_output_mode, set_seed, OutputMode};
 
 /// Application entry point.
 ///
@@ -85,6 +86,17 @@ fn main() -> Result<()> {
         colored::control::set_override(false);
     }
 
+    // --seed: derive every generator command's RNG from one seeded master,
+    // so `dx rand ... --seed 7` prints the same thing on every run.
+    if let Some(seed) = cli.seed {
+        set_seed(seed);
+    }
+
+    // DX_LOG_FORMAT=json: print_error/print_warning/print_success emit one
+    // JSON object per line, so CI logs can be parsed.
+    let output_mode = OutputMode::from_env();
+    set_output_mode(output_mode);
+
     // Dispatch to the appropriate command handler based on the subcommand.
     //
     // Each command module exposes a `run(args)` function that:
@@ -92,9 +104,9 @@ fn main() -> Result<()> {
     // 2. Performs the command's logic
     // 3. Returns Result<()> for error propagation
     //
-    // The `?` operator at the end (implicit via the match expression's use
-    // as the function's return value) propagates any errors to main's caller.
-    match cli.command {
+    // Errors are returned to main's caller, which prints them. In JSON mode
+    // they go through print_error instead so they stay machine-parseable.
+    let result = match cli.command {
         // Hash command: generate cryptographic hashes of files/strings
         Commands::Hash(args) => commands::hash::run(args),
 
@@ -152,6 +164,9 @@ fn main() -> Result<()> {
         // Calc command: unit conversions
         Commands::Calc(args) => commands::calc::run(args),
 
+        // Base command: number base conversion
+        Commands::Base(args) => commands::base::run(args),
+
         // Expr command: expression evaluator
         Commands::Expr(args) => commands::expr::run(args),
 
@@ -173,6 +188,9 @@ fn main() -> Result<()> {
         // Grep command: search for patterns in files
         Commands::Grep(args) => commands::grep::run(args),
 
+        // Regex command: test, capture and replace
+        Commands::Regex(args) => commands::regex::run(args),
+
         // Http command: make HTTP requests
         Commands::Http(args) => commands::http::run(args),
 
@@ -196,5 +214,11 @@ fn main() -> Result<()> {
             Cli::print_completions(shell);
             Ok(())
         }
+    };
+
+    if let (Err(e), OutputMode::Json) = (&result, output_mode) {
+        print_error(&format!("{:#}", e));
+        std::process::exit(1);
     }
+    result
 }
@@ -0,0 +1,158 @@
+//! Crash-safe file output.
+//!
+//! Commands that write files go through [`write_atomic`], so an error or a
+//! crash halfway through never leaves a truncated file behind: the data goes
+//! to a temporary file next to the target, which is renamed over the target
+//! only once everything has been written and synced.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::io::BufWriter;
+use std::path::Path;
+use tempfile::NamedTempFile;
+
+/// Write `path` through `write`, replacing it only if `write` succeeds.
+///
+/// The temporary file lives in the same directory, so the final rename
+/// stays on one filesystem. An existing file keeps its permissions, and a
+/// symlink is followed so its target is replaced rather than the link. If
+/// `write` fails, the temporary file is removed and `path` is untouched.
+pub fn write_atomic<T>(
+    path: &Path,
+    write: impl FnOnce(&mut BufWriter<NamedTempFile>) -> Result<T>,
+) -> Result<T> {
+    let target = fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+    let dir = match target.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let name = target
+        .file_name()
+        .with_context(|| format!("Not a file path: {}", path.display()))?;
+
+    // e.g. `.report.csv.a1B2c3.tmp`, hidden and easy to trace back
+    let mut prefix = std::ffi::OsString::from(".");
+    prefix.push(name);
+    prefix.push(".");
+    let mut builder = tempfile::Builder::new();
+    builder.prefix(&prefix).suffix(".tmp");
+    // Temporary files are private; give the replacement the current file's
+    // permissions, or the usual ones for a new file
+    let existing = fs::metadata(&target).ok().map(|m| m.permissions());
+    if existing.is_none() {
+        if let Some(permissions) = default_permissions() {
+            builder.permissions(permissions);
+        }
+    }
+    let temp = builder
+        .tempfile_in(dir)
+        .with_context(|| format!("Failed to create a temporary file in {}", dir.display()))?;
+    if let Some(permissions) = existing {
+        temp.as_file()
+            .set_permissions(permissions)
+            .with_context(|| format!("Failed to copy permissions of {}", path.display()))?;
+    }
+
+    let mut writer = BufWriter::new(temp);
+    let value = write(&mut writer)?;
+    let temp = writer
+        .into_inner()
+        .map_err(|e| e.into_error())
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    temp.as_file()
+        .sync_all()
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    temp.persist(&target)
+        .with_context(|| format!("Failed to replace {}", path.display()))?;
+    Ok(value)
+}
+
+#[cfg(unix)]
+fn default_permissions() -> Option<fs::Permissions> {
+    use std::os::unix::fs::PermissionsExt;
+    // Subject to the umask, like `File::create`
+    Some(fs::Permissions::from_mode(0o666))
+}
+
+#[cfg(not(unix))]
+fn default_permissions() -> Option<fs::Permissions> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::bail;
+    use std::io::Write;
+
+    fn entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_replaces_file_on_success() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.txt");
+        fs::write(&path, "old contents that are longer").unwrap();
+
+        let n = write_atomic(&path, |w| {
+            w.write_all(b"new")?;
+            Ok(3)
+        })
+        .unwrap();
+        assert_eq!(n, 3);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(entries(dir.path()), ["out.txt"]);
+    }
+
+    #[test]
+    fn test_failing_writer_leaves_original_intact() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.txt");
+        fs::write(&path, "original").unwrap();
+
+        let err = write_atomic(&path, |w| -> Result<()> {
+            // More than the BufWriter holds, so part of it reaches the disk
+            w.write_all(&[b'x'; 64 * 1024])?;
+            bail!("disk on fire")
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "disk on fire");
+        assert_eq!(fs::read_to_string(&path).unwrap(), "original");
+        assert_eq!(entries(dir.path()), ["out.txt"]);
+
+        // Nor is a new file created
+        let missing = dir.path().join("missing.txt");
+        write_atomic(&missing, |w| -> Result<()> {
+            w.write_all(b"partial")?;
+            bail!("interrupted")
+        })
+        .unwrap_err();
+        assert!(!missing.exists());
+        assert_eq!(entries(dir.path()), ["out.txt"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_keeps_permissions_and_follows_symlinks() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("script.sh");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o664)).unwrap();
+        let link = dir.path().join("link.sh");
+        std::os::unix::fs::symlink(&path, &link).unwrap();
+
+        write_atomic(&link, |w| Ok(w.write_all(b"new")?)).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        assert!(fs::symlink_metadata(&link).unwrap().is_symlink());
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o664);
+    }
+}
@@ -1,7 +1,14 @@
 //! Shared utilities.
 
-mod output;
+pub mod fs;
+pub mod output;
 pub mod progress;
+pub mod rng;
 
-pub use output::{print_error, print_success, print_warning};
+pub use fs::write_atomic;
+pub use output::{
+    output_mode, print_error, print_success, print_warning, set_output_mode, Align, OutputMode,
+    Table,
+};
 pub use progress::{osc_progress, osc_progress_clear, ProgressState, TerminalProgress};
+pub use rng::{command_rng, set_seed};
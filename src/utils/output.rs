@@ -1,23 +1,294 @@
 //! Output formatting utilities.
 
 use colored::Colorize;
+use std::sync::atomic::{AtomicU8, Ordering};
+use unicode_width::UnicodeWidthStr;
+
+/// How the message helpers format their output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputMode {
+    /// Symbols and colors for people
+    #[default]
+    Human,
+    /// One JSON object per message: `{"level":"error","msg":"..."}`
+    Json,
+}
+
+impl OutputMode {
+    /// Read the mode from `DX_LOG_FORMAT` (`json` selects [`OutputMode::Json`])
+    pub fn from_env() -> Self {
+        match std::env::var("DX_LOG_FORMAT") {
+            Ok(value) if value.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Human,
+        }
+    }
+}
+
+static OUTPUT_MODE: AtomicU8 = AtomicU8::new(0);
+
+/// Set the process-wide output mode. Called once at startup.
+pub fn set_output_mode(mode: OutputMode) {
+    OUTPUT_MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+/// The current process-wide output mode
+pub fn output_mode() -> OutputMode {
+    match OUTPUT_MODE.load(Ordering::Relaxed) {
+        1 => OutputMode::Json,
+        _ => OutputMode::Human,
+    }
+}
+
+/// Severity of a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Success,
+    Warning,
+    Error,
+}
+
+/// Format a message line for the given mode
+fn format_message(level: Level, message: &str, mode: OutputMode) -> String {
+    match mode {
+        OutputMode::Human => {
+            let symbol = match level {
+                Level::Success => "✓".green().bold(),
+                Level::Warning => "⚠".yellow().bold(),
+                Level::Error => "✗".red().bold(),
+            };
+            format!("{} {}", symbol, message)
+        }
+        OutputMode::Json => {
+            let level = match level {
+                Level::Success => "success",
+                Level::Warning => "warning",
+                Level::Error => "error",
+            };
+            serde_json::json!({ "level": level, "msg": message }).to_string()
+        }
+    }
+}
 
 /// Print a success message
 pub fn print_success(message: &str) {
-    println!("{} {}", "✓".green().bold(), message);
+    println!("{}", format_message(Level::Success, message, output_mode()));
 }
 
 /// Print a warning message
 pub fn print_warning(message: &str) {
-    eprintln!("{} {}", "⚠".yellow().bold(), message);
+    eprintln!("{}", format_message(Level::Warning, message, output_mode()));
 }
 
 /// Print an error message
 pub fn print_error(message: &str) {
-    eprintln!("{} {}", "✗".red().bold(), message);
+    eprintln!("{}", format_message(Level::Error, message, output_mode()));
+}
+
+/// Column alignment for [`Table`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+}
+
+/// An aligned plain-text table.
+///
+/// Column widths use terminal display width, so CJK and emoji cells line up.
+/// Headers are bold when color is on.
+///
+/// ```text
+/// name | size
+/// -----+-----
+/// a.md |  12K
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+    align: Vec<Align>,
+}
+
+impl Table {
+    /// Create a table with the given headers. With no headers, only rows are
+    /// printed.
+    pub fn new<I, S>(headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            headers: headers.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Add a row. Missing trailing cells are printed empty.
+    pub fn row<I, S>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.push_row(cells);
+        self
+    }
+
+    /// Add a row in place
+    pub fn push_row<I, S>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// Set the alignment of column `index`
+    pub fn align(mut self, index: usize, align: Align) -> Self {
+        if self.align.len() <= index {
+            self.align.resize(index + 1, Align::Left);
+        }
+        self.align[index] = align;
+        self
+    }
+
+    /// Display width of each column
+    pub fn widths(&self) -> Vec<usize> {
+        let columns = self
+            .rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.headers.len()])
+            .max()
+            .unwrap_or(0);
+        let mut widths = vec![0; columns];
+        for cells in std::iter::once(&self.headers).chain(&self.rows) {
+            for (width, cell) in widths.iter_mut().zip(cells) {
+                *width = (*width).max(cell.width());
+            }
+        }
+        widths
+    }
+
+    /// Render the table, styling headers only when `color` is set
+    pub fn render(&self, color: bool) -> String {
+        let widths = self.widths();
+        let mut out = String::new();
+        if !self.headers.is_empty() {
+            let header = self.render_line(&self.headers, &widths);
+            if color {
+                out.push_str(&format!("\x1b[1m{}\x1b[0m", header));
+            } else {
+                out.push_str(&header);
+            }
+            out.push('\n');
+            let sep: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+            out.push_str(&sep.join("-+-"));
+            out.push('\n');
+        }
+        for row in &self.rows {
+            out.push_str(&self.render_line(row, &widths));
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Print the table to stdout, honoring `--no-color`
+    pub fn print(&self) {
+        print!(
+            "{}",
+            self.render(colored::control::SHOULD_COLORIZE.should_colorize())
+        );
+    }
+
+    fn render_line(&self, cells: &[String], widths: &[usize]) -> String {
+        let padded: Vec<String> = widths
+            .iter()
+            .enumerate()
+            .map(|(i, width)| {
+                let cell = cells.get(i).map(String::as_str).unwrap_or("");
+                let pad = " ".repeat(width - cell.width());
+                match self.align.get(i).copied().unwrap_or_default() {
+                    Align::Left => format!("{}{}", cell, pad),
+                    Align::Right => format!("{}{}", pad, cell),
+                }
+            })
+            .collect();
+        padded.join(" | ").trim_end().to_string()
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    // Output tests would need to capture stdout/stderr
+    use super::*;
+
+    #[test]
+    fn test_json_mode_error_is_valid_json() {
+        let line = format_message(Level::Error, "disk \"full\"\nretry", OutputMode::Json);
+        let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"level": "error", "msg": "disk \"full\"\nretry"})
+        );
+        assert!(!line.contains('\n'));
+    }
+
+    #[test]
+    fn test_json_mode_levels() {
+        for (level, name) in [
+            (Level::Success, "success"),
+            (Level::Warning, "warning"),
+            (Level::Error, "error"),
+        ] {
+            let line = format_message(level, "m", OutputMode::Json);
+            let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+            assert_eq!(value["level"], name);
+        }
+    }
+
+    #[test]
+    fn test_human_mode_keeps_symbols() {
+        let line = format_message(Level::Warning, "careful", OutputMode::Human);
+        assert!(line.contains('⚠'));
+        assert!(line.ends_with("careful"));
+    }
+
+    #[test]
+    fn test_table_widths_use_display_width() {
+        let table = Table::new(["name", "city"])
+            .row(["Zoë", "東京"])
+            .row(["Al", "Oslo"]);
+        // "東京" is 2 chars and 6 bytes but 4 columns wide
+        assert_eq!(table.widths(), vec![4, 4]);
+    }
+
+    #[test]
+    fn test_table_render_alignment() {
+        let table = Table::new(["file", "size"])
+            .row(["café.txt", "9"])
+            .row(["東京.md", "120"])
+            .align(1, Align::Right);
+        assert_eq!(
+            table.render(false),
+            "file     | size\n\
+             ---------+-----\n\
+             café.txt |    9\n\
+             東京.md  |  120\n"
+        );
+    }
+
+    #[test]
+    fn test_table_without_headers_and_short_rows() {
+        let table = Table::new(Vec::<String>::new())
+            .row(["a", "b", "c"])
+            .row(["long"]);
+        assert_eq!(table.render(false), "a    | b | c\nlong |   |\n");
+    }
+
+    #[test]
+    fn test_table_color_only_styles_header() {
+        let rendered = Table::new(["k"]).row(["v"]).render(true);
+        assert!(rendered.starts_with("\x1b[1mk\x1b[0m\n"));
+        assert!(rendered.ends_with("-\nv\n"));
+        assert_eq!(Table::new(["k"]).row(["v"]).render(false), "k\n-\nv\n");
+    }
 }